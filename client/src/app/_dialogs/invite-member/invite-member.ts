@@ -8,6 +8,16 @@ import { Brawler } from "../../_models/brawler";
 import { MatIconModule } from "@angular/material/icon";
 import { MatSnackBar } from "@angular/material/snack-bar";
 import { ConfirmationDialogComponent } from "../confirmation-dialog/confirmation-dialog";
+import { MAX_PAGE_LIMIT } from '../../_models/page';
+import { BulkInviteOutcome, BulkInviteResult } from '../../_models/mission-invite';
+
+const OUTCOME_LABELS: Record<BulkInviteOutcome, string> = {
+    invited: 'invited',
+    already_member: 'already in the crew',
+    already_invited: 'already invited',
+    banned: 'banned',
+    not_found: 'not found',
+};
 
 @Component({
     selector: 'app-invite-member',
@@ -26,6 +36,8 @@ export class InviteMemberComponent {
     users: Brawler[] = [];
     filteredUsers: Brawler[] = [];
     invitedUserIds: Set<number> = new Set();
+    selectedUserIds: Set<number> = new Set();
+    usernamesText: string = '';
     missionId: number;
     currentMembers: Set<number> = new Set();
 
@@ -40,7 +52,7 @@ export class InviteMemberComponent {
 
     async loadUsers() {
         try {
-            this.users = await this._userService.getAllBrawlers();
+            this.users = (await this._userService.getAllBrawlers({ limit: MAX_PAGE_LIMIT })).items;
             this.filterUsers();
         } catch (error) {
             console.error('Failed to load users', error);
@@ -59,16 +71,36 @@ export class InviteMemberComponent {
         );
     }
 
-    async invite(user: Brawler) {
+    toggle(user: Brawler) {
         if (this.invitedUserIds.has(user.id)) return;
+        if (this.selectedUserIds.has(user.id)) {
+            this.selectedUserIds.delete(user.id);
+        } else {
+            this.selectedUserIds.add(user.id);
+        }
+    }
+
+    get usernames(): string[] {
+        return this.usernamesText
+            .split(/[\s,]+/)
+            .map(name => name.trim())
+            .filter(name => name.length > 0);
+    }
+
+    get recipientCount(): number {
+        return this.selectedUserIds.size + this.usernames.length;
+    }
+
+    sendInvites() {
+        if (this.recipientCount === 0) return;
 
         const dialogRef = this._dialog.open(ConfirmationDialogComponent, {
             width: '400px',
             panelClass: 'premium-dialog-panel',
             data: {
                 title: 'Confirm Recruitment',
-                message: `Send mission invite to ${user.display_name}?`,
-                confirmText: 'Send Invite',
+                message: `Send mission invites to ${this.recipientCount} brawler(s)?`,
+                confirmText: 'Send Invites',
                 cancelText: 'Cancel'
             }
         });
@@ -76,16 +108,35 @@ export class InviteMemberComponent {
         dialogRef.afterClosed().subscribe(async result => {
             if (result) {
                 try {
-                    await this._inviteService.invite(this.missionId, user.id);
-                    this.invitedUserIds.add(user.id);
-                    this._snackBar.open(`Invitation sent to ${user.display_name}`, 'Close', { duration: 3000 });
+                    const results = await this._inviteService.inviteMany(this.missionId, [...this.selectedUserIds], this.usernames);
+                    for (const r of results) {
+                        if (r.user_id !== null && (r.outcome === 'invited' || r.outcome === 'already_invited')) {
+                            this.invitedUserIds.add(r.user_id);
+                        }
+                    }
+                    this.selectedUserIds.clear();
+                    this.usernamesText = '';
+                    this._snackBar.open(this.summarize(results), 'Close', { duration: 5000 });
                 } catch (e: any) {
-                    this._snackBar.open(e?.error?.message || 'Failed to send invite', 'Close', { duration: 3000 });
+                    this._snackBar.open(e?.error?.message || 'Failed to send invites', 'Close', { duration: 3000 });
                 }
             }
         });
     }
 
+    // "2 invited · bob: banned · carl: not found"
+    private summarize(results: BulkInviteResult[]): string {
+        const invited = results.filter(r => r.outcome === 'invited').length;
+        const skipped = results
+            .filter(r => r.outcome !== 'invited')
+            .map(r => `${r.username ?? r.recipient}: ${OUTCOME_LABELS[r.outcome]}`);
+        return [`${invited} invited`, ...skipped].join(' · ');
+    }
+
+    isSelected(userId: number): boolean {
+        return this.selectedUserIds.has(userId);
+    }
+
     isInvited(userId: number): boolean {
         return this.invitedUserIds.has(userId);
     }
@@ -22,8 +22,15 @@ export class NewMission {
     name: this._data?.name || '',
     description: this._data?.description || '',
     category: this._data?.category || '',
-    max_crew: this._data?.max_crew || 5
+    max_crew: this._data?.max_crew || 5,
+    duration_minutes: this._data?.duration_minutes,
+    objectives_required: this._data?.objectives_required ?? false,
+    join_policy: this._data?.join_policy ?? 'open',
+    min_reputation: this._data?.min_reputation ?? null
   }
+  // datetime-local inputs work in local time; the API takes UTC
+  startLocal = toLocalInput(this._data?.scheduled_start_at)
+  deadlineLocal = toLocalInput(this._data?.deadline_at)
   private readonly _dialogRef = inject(MatDialogRef<NewMission>)
 
   onSubmit() {
@@ -40,8 +47,28 @@ export class NewMission {
       name: addMission.name.trim() || 'untitled',
       description: addMission.description?.trim() || undefined,
       category: addMission.category?.trim() || undefined,
-      max_crew
+      max_crew,
+      // unchanged times are left out so editing a started mission does not reschedule it
+      scheduled_start_at: this.startLocal !== toLocalInput(this._data?.scheduled_start_at) ? toUtc(this.startLocal) : undefined,
+      deadline_at: this.deadlineLocal !== toLocalInput(this._data?.deadline_at) ? toUtc(this.deadlineLocal) : undefined,
+      duration_minutes: addMission.duration_minutes || undefined,
+      objectives_required: addMission.objectives_required,
+      join_policy: addMission.join_policy,
+      // an emptied field removes an existing requirement
+      min_reputation: addMission.min_reputation || (this._data?.min_reputation ? 0 : undefined)
     }
   }
 }
 
+// The API sends naive UTC timestamps without a zone suffix
+function toLocalInput(utc?: string): string {
+  if (!utc) return ''
+  const date = new Date(/[zZ]|[+-]\d\d:\d\d$/.test(utc) ? utc : utc + 'Z')
+  const offset = date.getTimezoneOffset() * 60000
+  return new Date(date.getTime() - offset).toISOString().slice(0, 16)
+}
+
+function toUtc(local: string): string | undefined {
+  return local ? new Date(local).toISOString() : undefined
+}
+
@@ -1,6 +1,14 @@
+export type JoinPolicy = 'open' | 'approval' | 'invite_only'
+
 export interface AddMission {
     name: string
     description?: string
     category?: string //*เพิ่ม
     max_crew?: number //*เพิ่ม
+    scheduled_start_at?: string // ISO 8601, UTC
+    deadline_at?: string // ISO 8601, UTC
+    duration_minutes?: number
+    objectives_required?: boolean // completing needs every mandatory objective done
+    join_policy?: JoinPolicy
+    min_reputation?: number | null // 1-5; 0 removes it when editing
 }
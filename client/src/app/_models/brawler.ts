@@ -4,4 +4,9 @@ export interface Brawler {
     avatar_url: string,
     mission_success_count: number,
     mission_join_count: number,
+    reputation: number,
+    rating_count: number,
+    role_slot_id?: number | null,
+    role?: string | null,
+    is_co_chief?: boolean,
 }
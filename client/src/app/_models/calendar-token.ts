@@ -0,0 +1,4 @@
+export interface CalendarToken {
+    token: string
+    feed_path: string
+}
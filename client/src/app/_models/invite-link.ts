@@ -0,0 +1,34 @@
+export interface InviteLink {
+    id: number,
+    mission_id: number,
+    created_by: number | null,
+    role_slot_id: number | null,
+    role_label: string | null,
+    max_uses: number | null,
+    use_count: number,
+    expires_at: string | null,
+    revoked_at: string | null,
+    created_at: string,
+}
+
+export interface CreatedInviteLink {
+    token: string,
+    link: InviteLink,
+}
+
+export interface InviteLinkOptions {
+    max_uses?: number,
+    expires_at?: string,
+    role_slot_id?: number,
+}
+
+export interface InviteLinkPreview {
+    mission_id: number,
+    mission_name: string,
+    mission_status: string,
+    chief_display_name: string,
+    crew_count: number,
+    max_crew: number,
+    role_label: string | null,
+    expires_at: string | null,
+}
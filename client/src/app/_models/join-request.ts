@@ -0,0 +1,11 @@
+export interface JoinRequest {
+    id: number,
+    mission_id: number,
+    brawler_id: number,
+    brawler_display_name: string,
+    brawler_avatar_url: string,
+    role_slot_id: number | null,
+    role: string | null,
+    status: 'pending' | 'approved' | 'rejected' | 'cancelled',
+    created_at: string,
+}
@@ -0,0 +1,15 @@
+export interface MissionBan {
+    id: number,
+    mission_id: number,
+    brawler_id: number,
+    brawler_display_name: string,
+    banned_by: number | null,
+    reason: string | null,
+    expires_at: string | null,
+    created_at: string,
+}
+
+export interface BanOptions {
+    reason?: string,
+    expires_at?: string,
+}
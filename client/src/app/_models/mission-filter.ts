@@ -1,11 +1,17 @@
 export interface MissionFilter {
     name?: string
-    status?: MissionStatus
-    category?: string //*เพิ่ม
+    q?: string
+    status?: MissionStatus | MissionStatus[]
+    category?: string | string[] //*เพิ่ม
+    chief_id?: number
+    has_open_slots?: boolean
+    joinable?: boolean
+    created_from?: string
+    created_to?: string
 }
 
 export type MissionStatus =
     'Open' |
     'InProgress' |
     'Completed' |
-    'Failed'
\ No newline at end of file
+    'Failed'
@@ -0,0 +1,21 @@
+export type InviteStatus = 'pending' | 'accepted' | 'rejected' | 'cancelled' | 'expired'
+
+export interface SentMissionInvite {
+    id: number,
+    user_id: number,
+    user_display_name: string,
+    invited_by: number | null,
+    status: InviteStatus,
+    expires_at: string,
+    created_at: string,
+}
+
+export type BulkInviteOutcome = 'invited' | 'already_member' | 'already_invited' | 'banned' | 'not_found'
+
+export interface BulkInviteResult {
+    recipient: number | string,
+    outcome: BulkInviteOutcome,
+    user_id: number | null,
+    username: string | null,
+    invite_id: number | null,
+}
@@ -0,0 +1,17 @@
+export interface MissionObjective {
+    id: number
+    mission_id: number
+    title: string
+    position: number
+    is_mandatory: boolean
+    completed_by?: number
+    completed_by_display_name?: string
+    completed_at?: string // naive UTC
+    created_at: string
+    updated_at: string
+}
+
+export interface AddMissionObjective {
+    title: string
+    is_mandatory?: boolean
+}
@@ -0,0 +1,38 @@
+export interface MissionTemplate {
+    id: number
+    owner_id: number
+    name: string
+    description?: string
+    category?: string
+    max_crew: number
+    image_url?: string
+    recurrence?: string // FREQ=WEEKLY;BYDAY=MO,TH;BYHOUR=19;BYMINUTE=30 (UTC)
+    duration_minutes?: number
+    auto_invite: boolean
+    last_scheduled_at?: string
+    next_occurrence_at?: string
+    created_at: string
+    updated_at: string
+}
+
+export interface AddMissionTemplate {
+    name: string
+    description?: string
+    category?: string
+    max_crew: number
+    image_url?: string
+    recurrence?: string
+    duration_minutes?: number
+    auto_invite?: boolean
+}
+
+export const WEEKDAYS = ['MO', 'TU', 'WE', 'TH', 'FR', 'SA', 'SU'] as const
+
+// days and a local HH:mm time -> weekly RRULE in UTC; days shift when the time crosses midnight in UTC
+export function weeklyRule(days: string[], localTime: string): string {
+    const [hours, minutes] = localTime.split(':').map(Number)
+    const reference = new Date(2024, 0, 1, hours || 0, minutes || 0) // a Monday, so the UTC day is Sunday, Monday or Tuesday
+    const shift = reference.getUTCDay() - reference.getDay()
+    const utcDays = days.map(d => WEEKDAYS[(WEEKDAYS.indexOf(d as typeof WEEKDAYS[number]) + shift + 7) % 7])
+    return `FREQ=WEEKLY;BYDAY=${utcDays.join(',')};BYHOUR=${reference.getUTCHours()};BYMINUTE=${reference.getUTCMinutes()}`
+}
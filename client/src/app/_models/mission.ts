@@ -1,3 +1,5 @@
+import { JoinPolicy } from './add-mission'
+
 export interface Mission {
     id: number
     name: string
@@ -11,7 +13,18 @@ export interface Mission {
     created_at: Date
     updated_at: Date
     is_member?: boolean //*เพิ่ม
+    is_co_chief?: boolean
     image_url?: string
+    scheduled_start_at?: string
+    deadline_at?: string
+    duration_minutes?: number
+    objectives_required?: boolean
+    objectives_total?: number
+    objectives_done?: number
+    objectives_progress?: number // 0-100
+    join_policy?: JoinPolicy
+    finished_at?: string | null
+    min_reputation?: number | null
     difficulty?: string
     duration?: string
     location?: string
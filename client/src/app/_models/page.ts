@@ -0,0 +1,33 @@
+export interface Page<T> {
+    items: T[]
+    total: number
+    next_cursor?: string
+}
+
+export interface PageRequest {
+    limit?: number
+    cursor?: string
+    sort?: string
+    direction?: 'asc' | 'desc'
+}
+
+export const MAX_PAGE_LIMIT = 100
+
+export function pageQueryString(page: PageRequest): string {
+    const params: string[] = []
+
+    if (page.limit) {
+        params.push(`limit=${page.limit}`)
+    }
+    if (page.cursor) {
+        params.push(`cursor=${encodeURIComponent(page.cursor)}`)
+    }
+    if (page.sort) {
+        params.push(`sort=${encodeURIComponent(page.sort)}`)
+    }
+    if (page.direction) {
+        params.push(`direction=${page.direction}`)
+    }
+
+    return params.join('&')
+}
@@ -0,0 +1,36 @@
+export type RatingTag = 'shotcaller' | 'team-player' | 'skilled' | 'friendly' | 'toxic' | 'no-show'
+
+export const RATING_TAGS: RatingTag[] = ['shotcaller', 'team-player', 'skilled', 'friendly', 'toxic', 'no-show']
+
+export interface PeerRating {
+    brawler_id: number,
+    display_name: string,
+    avatar_url: string,
+    is_chief: boolean,
+    score: number | null,
+    tags: RatingTag[],
+}
+
+export interface RatingSheet {
+    mission_id: number,
+    closes_at: string,
+    is_open: boolean,
+    peers: PeerRating[],
+}
+
+export interface MissionRating {
+    id: number,
+    mission_id: number | null,
+    rater_id: number | null,
+    ratee_id: number,
+    score: number,
+    tags: RatingTag[],
+    updated_at: string,
+}
+
+export interface Reputation {
+    brawler_id: number,
+    reputation: number,
+    rating_count: number,
+    tags: { tag: RatingTag, count: number }[],
+}
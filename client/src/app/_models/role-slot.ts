@@ -0,0 +1,10 @@
+export interface RoleSlot {
+    id: number,
+    mission_id: number,
+    label: string,
+    position: number,
+    brawler_id: number | null,
+    brawler_display_name: string | null,
+}
+
+export type RoleSlotPreset = 'league_of_legends'
@@ -0,0 +1,11 @@
+export interface WaitlistEntry {
+    id: number,
+    mission_id: number,
+    mission_name: string,
+    brawler_id: number,
+    brawler_display_name: string,
+    position: number,
+    status: 'waiting' | 'offered',
+    offer_expires_at: string | null,
+    created_at: string,
+}
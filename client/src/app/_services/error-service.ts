@@ -16,13 +16,20 @@ export class ErrorService {
 
   handleError(error: any): Observable<never> {
     if (error) {
+      // API errors are `{ code, message, fields? }`; older endpoints may still send plain text.
+      const fields: { field: string, message: string }[] = error.error?.fields ?? []
+      const message: string = fields.length > 0
+        ? fields.map(f => `${f.field} ${f.message}`).join(', ')
+        : typeof error.error === 'string' ? error.error : (error.error?.message ?? '')
       switch (error.status) {
         case 400:
+        case 403:
+        case 409:
+        case 422:
+        case 429:
           console.log(error)
-          if (error.error === 'Record not found')
-            this._snackBar.open("Invalid username or password", 'ok', this._snackBarConfig)
-          else if (error.error !== '')
-            this._snackBar.open(error.error, 'ok', this._snackBarConfig)
+          if (message !== '')
+            this._snackBar.open(message, 'ok', this._snackBarConfig)
           else
             this._snackBar.open('bad request', 'ok', this._snackBarConfig)
           break
@@ -45,7 +52,7 @@ export class ErrorService {
         case 510:
         case 511:
           const navExtra: NavigationExtras = {
-            state: { error: error.error }
+            state: { error: message }
           }
           if (this._router.url !== '/server-error') {
             this._router.navigate(['/server-error'], navExtra)
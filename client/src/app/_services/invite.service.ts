@@ -2,6 +2,8 @@ import { inject, Injectable, signal } from '@angular/core'
 import { environment } from '../../environments/environment'
 import { HttpClient } from '@angular/common/http'
 import { firstValueFrom } from 'rxjs'
+import { BulkInviteResult, SentMissionInvite } from '../_models/mission-invite'
+import { CreatedInviteLink, InviteLink, InviteLinkOptions, InviteLinkPreview } from '../_models/invite-link'
 
 @Injectable({
     providedIn: 'root',
@@ -16,8 +18,23 @@ export class InviteService {
         return await firstValueFrom(this._http.post(url, { user_id: userId }));
     }
 
-    async accept(inviteId: number): Promise<any> {
-        const url = `${this._base_url}/invite/${inviteId}/accept`;
+    async inviteMany(missionId: number, userIds: number[], usernames: string[]): Promise<BulkInviteResult[]> {
+        const url = `${this._base_url}/mission/${missionId}/invites`;
+        return await firstValueFrom(this._http.post<BulkInviteResult[]>(url, { user_ids: userIds, usernames }));
+    }
+
+    async getMissionInvites(missionId: number): Promise<SentMissionInvite[]> {
+        const url = `${this._base_url}/mission/${missionId}/invites`;
+        return await firstValueFrom(this._http.get<SentMissionInvite[]>(url));
+    }
+
+    async cancel(inviteId: number): Promise<void> {
+        const url = `${this._base_url}/invite/${inviteId}`;
+        await firstValueFrom(this._http.delete(url));
+    }
+
+    async accept(inviteId: number, slotId?: number): Promise<any> {
+        const url = `${this._base_url}/invite/${inviteId}/accept` + (slotId ? `?slot_id=${slotId}` : '');
         const res = await firstValueFrom(this._http.post<any>(url, {}));
         this.invites.update(prev => prev.filter(i => i.id !== inviteId));
         return res;
@@ -30,6 +47,31 @@ export class InviteService {
         return res;
     }
 
+    async createLink(missionId: number, options: InviteLinkOptions): Promise<CreatedInviteLink> {
+        const url = `${this._base_url}/mission/${missionId}/links`;
+        return await firstValueFrom(this._http.post<CreatedInviteLink>(url, options));
+    }
+
+    async getLinks(missionId: number): Promise<InviteLink[]> {
+        const url = `${this._base_url}/mission/${missionId}/links`;
+        return await firstValueFrom(this._http.get<InviteLink[]>(url));
+    }
+
+    async revokeLink(missionId: number, linkId: number): Promise<void> {
+        const url = `${this._base_url}/mission/${missionId}/links/${linkId}`;
+        await firstValueFrom(this._http.delete(url));
+    }
+
+    async previewLink(token: string): Promise<InviteLinkPreview> {
+        const url = `${this._base_url}/link/${encodeURIComponent(token)}`;
+        return await firstValueFrom(this._http.get<InviteLinkPreview>(url));
+    }
+
+    async acceptLink(token: string): Promise<any> {
+        const url = `${this._base_url}/link/${encodeURIComponent(token)}/accept`;
+        return await firstValueFrom(this._http.post<any>(url, {}));
+    }
+
     async getMyInvites(): Promise<any[]> {
         const url = `${this._base_url}/my-invites`;
         const data = await firstValueFrom(this._http.get<any[]>(url));
@@ -0,0 +1,42 @@
+import { inject, Injectable } from '@angular/core'
+import { environment } from '../../environments/environment'
+import { HttpClient } from '@angular/common/http'
+import { firstValueFrom } from 'rxjs'
+import { AddMissionObjective, MissionObjective } from '../_models/mission-objective'
+
+@Injectable({
+  providedIn: 'root',
+})
+export class MissionObjectiveService {
+  private _base_url = environment.baseUrl + '/api/mission-objectives'
+  private _http = inject(HttpClient)
+
+  async getAll(missionId: number): Promise<MissionObjective[]> {
+    return await firstValueFrom(this._http.get<MissionObjective[]>(`${this._base_url}/${missionId}`))
+  }
+
+  async add(missionId: number, objective: AddMissionObjective): Promise<MissionObjective> {
+    return await firstValueFrom(this._http.post<MissionObjective>(`${this._base_url}/${missionId}`, objective))
+  }
+
+  async edit(missionId: number, id: number, objective: Partial<AddMissionObjective>): Promise<MissionObjective> {
+    return await firstValueFrom(this._http.patch<MissionObjective>(`${this._base_url}/${missionId}/${id}`, objective))
+  }
+
+  async delete(missionId: number, id: number): Promise<void> {
+    await firstValueFrom(this._http.delete(`${this._base_url}/${missionId}/${id}`))
+  }
+
+  async reorder(missionId: number, objectiveIds: number[]): Promise<MissionObjective[]> {
+    const url = `${this._base_url}/${missionId}/order`
+    return await firstValueFrom(this._http.put<MissionObjective[]>(url, { objective_ids: objectiveIds }))
+  }
+
+  async complete(missionId: number, id: number): Promise<MissionObjective> {
+    return await firstValueFrom(this._http.patch<MissionObjective>(`${this._base_url}/${missionId}/${id}/complete`, {}))
+  }
+
+  async reopen(missionId: number, id: number): Promise<MissionObjective> {
+    return await firstValueFrom(this._http.patch<MissionObjective>(`${this._base_url}/${missionId}/${id}/reopen`, {}))
+  }
+}
@@ -7,6 +7,12 @@ import { Mission } from '../_models/mission'
 import { AddMission } from '../_models/add-mission'
 import { MissionSummary } from '../_models/mission-summary'
 import { MissionMessage } from '../_models/mission-message'
+import { Page, PageRequest, pageQueryString } from '../_models/page'
+import { RoleSlot, RoleSlotPreset } from '../_models/role-slot'
+import { JoinRequest } from '../_models/join-request'
+import { WaitlistEntry } from '../_models/waitlist-entry'
+import { BanOptions, MissionBan } from '../_models/mission-ban'
+import { MissionRating, RatingSheet, RatingTag, Reputation } from '../_models/rating'
 
 
 @Injectable({
@@ -19,10 +25,10 @@ export class MissionService {
 
   filter: MissionFilter = {}
 
-  async getByFilter(filter: MissionFilter): Promise<Mission[]> {
-    const queryString = this.createQueryString(filter)
+  async getByFilter(filter: MissionFilter, page: PageRequest = {}): Promise<Page<Mission>> {
+    const queryString = [this.createQueryString(filter), pageQueryString(page)].filter(q => q).join('&')
     const url = this._base_url + '/view/filter' + (queryString ? '?' + queryString : '')
-    const missions = await firstValueFrom(this._http.get<Mission[]>(url))
+    const missions = await firstValueFrom(this._http.get<Page<Mission>>(url))
     return missions
   }
 
@@ -33,11 +39,31 @@ export class MissionService {
     if (filter.name && filter.name.trim()) {
       params.push(`name=${encodeURIComponent(filter.name.trim())}`)
     }
-    if (filter.status) {
-      params.push(`status=${encodeURIComponent(filter.status)}`)
+    if (filter.q && filter.q.trim()) {
+      params.push(`q=${encodeURIComponent(filter.q.trim())}`)
+    }
+    const status = [filter.status ?? []].flat().filter(s => s)
+    if (status.length) {
+      params.push(`status=${encodeURIComponent(status.join(','))}`)
+    }
+    const category = [filter.category ?? []].flat().map(c => c.trim()).filter(c => c)
+    if (category.length) {
+      params.push(`category=${encodeURIComponent(category.join(','))}`)
+    }
+    if (filter.chief_id) {
+      params.push(`chief_id=${filter.chief_id}`)
+    }
+    if (filter.has_open_slots) {
+      params.push('has_open_slots=true')
+    }
+    if (filter.joinable) {
+      params.push('joinable=true')
     }
-    if (filter.category && filter.category.trim()) {
-      params.push(`category=${encodeURIComponent(filter.category.trim())}`)
+    if (filter.created_from) {
+      params.push(`created_from=${filter.created_from}`)
+    }
+    if (filter.created_to) {
+      params.push(`created_to=${filter.created_to}`)
     }
 
     return params.join("&")
@@ -60,18 +86,65 @@ export class MissionService {
     await firstValueFrom(this._http.delete(url))
   }
 
-  async getMyMissions(): Promise<Mission[]> {
-    const url = this._base_url + '/brawler/my-missions'
+  async restore(id: number): Promise<void> {
+    const url = this._base_url + '/mission-management/' + id + '/restore'
+    await firstValueFrom(this._http.post(url, {}, { responseType: 'text' }))
+  }
+
+  async getMyMissions(page: PageRequest = {}): Promise<Page<Mission>> {
+    const queryString = pageQueryString(page)
+    const url = this._base_url + '/brawler/my-missions' + (queryString ? '?' + queryString : '')
     console.log('get ' + url)
-    const observable = this._http.get<Mission[]>(url)
+    const observable = this._http.get<Page<Mission>>(url)
     const missions = await firstValueFrom(observable)
     return missions
   }
 
   // *เพิ่ม 
-  async joinMission(mission_id: number): Promise<void> {
-    const url = `${this._base_url}/crew/join/${mission_id}`
-    await firstValueFrom(this._http.post<void>(url, {}))
+  // resolves to the pending request when the mission needs the chief's approval
+  async joinMission(mission_id: number, slotId?: number): Promise<JoinRequest | null> {
+    const url = `${this._base_url}/crew/join/${mission_id}` + (slotId ? `?slot_id=${slotId}` : '')
+    const res = await firstValueFrom(this._http.post<JoinRequest>(url, {}, { observe: 'response' }))
+    return res.status === 202 ? res.body : null
+  }
+
+  async getJoinRequests(missionId: number): Promise<JoinRequest[]> {
+    return firstValueFrom(this._http.get<JoinRequest[]>(`${this._base_url}/crew/requests/${missionId}`))
+  }
+
+  async cancelJoinRequest(missionId: number): Promise<void> {
+    await firstValueFrom(this._http.delete(`${this._base_url}/crew/requests/${missionId}`))
+  }
+
+  async decideJoinRequest(missionId: number, requestId: number, approve: boolean): Promise<void> {
+    const action = approve ? 'approve' : 'reject'
+    await firstValueFrom(this._http.patch(`${this._base_url}/crew/requests/${missionId}/${requestId}/${action}`, {}))
+  }
+
+  async setCoChief(missionId: number, brawlerId: number, isCoChief: boolean): Promise<void> {
+    const url = `${this._base_url}/crew/co-chiefs/${missionId}/${brawlerId}`
+    await firstValueFrom(isCoChief ? this._http.put(url, {}) : this._http.delete(url))
+  }
+
+  async transferChief(missionId: number, brawlerId: number): Promise<void> {
+    await firstValueFrom(this._http.patch(`${this._base_url}/crew/chief/${missionId}`, { brawler_id: brawlerId }))
+  }
+
+  async getWaitlist(missionId: number): Promise<WaitlistEntry[]> {
+    return firstValueFrom(this._http.get<WaitlistEntry[]>(`${this._base_url}/crew/waitlist/${missionId}`))
+  }
+
+  async joinWaitlist(missionId: number): Promise<WaitlistEntry> {
+    return firstValueFrom(this._http.post<WaitlistEntry>(`${this._base_url}/crew/waitlist/${missionId}`, {}))
+  }
+
+  async leaveWaitlist(missionId: number): Promise<void> {
+    await firstValueFrom(this._http.delete(`${this._base_url}/crew/waitlist/${missionId}`))
+  }
+
+  async confirmWaitlistOffer(missionId: number, slotId?: number): Promise<void> {
+    const url = `${this._base_url}/crew/waitlist/${missionId}/confirm` + (slotId ? `?slot_id=${slotId}` : '')
+    await firstValueFrom(this._http.post(url, {}))
   }
 
   // *เพิ่ม 
@@ -81,9 +154,10 @@ export class MissionService {
   }
 
   // *เพิ่ม 
-  async getJoinedMissions(): Promise<Mission[]> {
-    const url = this._base_url + '/view/joined'
-    const missions = await firstValueFrom(this._http.get<Mission[]>(url))
+  async getJoinedMissions(page: PageRequest = {}): Promise<Page<Mission>> {
+    const queryString = pageQueryString(page)
+    const url = this._base_url + '/view/joined' + (queryString ? '?' + queryString : '')
+    const missions = await firstValueFrom(this._http.get<Page<Mission>>(url))
     return missions
   }
 
@@ -121,8 +195,31 @@ export class MissionService {
     return firstValueFrom(this._http.get<any[]>(`${this._base_url}/view/crew/${missionId}`).pipe(timeout(5000)))
   }
 
-  async kickCrew(missionId: number, memberId: number): Promise<void> {
-    await firstValueFrom(this._http.post(`${this._base_url}/crew/kick/${missionId}`, { member_id: memberId }))
+  async kickCrew(missionId: number, memberId: number, ban?: BanOptions): Promise<void> {
+    const body = ban ? { member_id: memberId, ban } : { member_id: memberId }
+    await firstValueFrom(this._http.post(`${this._base_url}/crew/kick/${missionId}`, body))
+  }
+
+  async getBans(missionId: number): Promise<MissionBan[]> {
+    return firstValueFrom(this._http.get<MissionBan[]>(`${this._base_url}/crew/bans/${missionId}`))
+  }
+
+  async liftBan(missionId: number, brawlerId: number): Promise<void> {
+    await firstValueFrom(this._http.delete(`${this._base_url}/crew/bans/${missionId}/${brawlerId}`))
+  }
+
+  async getRoleSlots(missionId: number): Promise<RoleSlot[]> {
+    return firstValueFrom(this._http.get<RoleSlot[]>(`${this._base_url}/crew/roles/${missionId}`))
+  }
+
+  async setRoleSlots(missionId: number, labels: string[], preset?: RoleSlotPreset): Promise<RoleSlot[]> {
+    const body = preset ? { preset } : { labels }
+    return firstValueFrom(this._http.put<RoleSlot[]>(`${this._base_url}/crew/roles/${missionId}`, body))
+  }
+
+  async assignRoleSlot(missionId: number, brawlerId: number, slotId: number | null): Promise<RoleSlot[]> {
+    const body = { brawler_id: brawlerId, slot_id: slotId }
+    return firstValueFrom(this._http.patch<RoleSlot[]>(`${this._base_url}/crew/roles/${missionId}/assign`, body))
   }
 
 
@@ -138,6 +235,19 @@ export class MissionService {
     await firstValueFrom(this._http.post<void>(url, { content }))
   }
 
+  async getRatingSheet(missionId: number): Promise<RatingSheet> {
+    return firstValueFrom(this._http.get<RatingSheet>(`${this._base_url}/mission-ratings/mission/${missionId}`))
+  }
+
+  async rateBrawler(missionId: number, brawlerId: number, score: number, tags: RatingTag[]): Promise<MissionRating> {
+    const url = `${this._base_url}/mission-ratings/mission/${missionId}/brawler/${brawlerId}`
+    return firstValueFrom(this._http.put<MissionRating>(url, { score, tags }))
+  }
+
+  async getReputation(brawlerId: number): Promise<Reputation> {
+    return firstValueFrom(this._http.get<Reputation>(`${this._base_url}/mission-ratings/brawler/${brawlerId}`))
+  }
+
   async uploadMissionImg(missionId: number, file: File): Promise<string | null> {
     const url = `${this._base_url}/mission-management/${missionId}/image`
     const { fileToBase64 } = await import('../_helpers/file')
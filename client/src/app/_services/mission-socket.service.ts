@@ -13,17 +13,27 @@ export class MissionSocketService {
     private passport = inject(PassportService);
     private zone = inject(NgZone);
 
-    connect(missionId: number) {
+    async connect(missionId: number) {
         if (this.socket) {
             this.socket.close();
         }
 
-        const token = this.passport.data()?.token;
-        if (!token) {
+        if (!this.passport.data()?.token) {
             console.error("No token found, cannot connect to Mission WS");
             return;
         }
 
+        let ticket: string;
+        try {
+            ticket = await this.passport.getStreamTicket();
+        } catch (e) {
+            console.error("Failed to get stream ticket for Mission WS", e);
+            return;
+        }
+
+        // Another connect() may have finished while we waited for the ticket
+        this.socket?.close();
+
         let wsBaseUrl = environment.baseUrl;
         if (!wsBaseUrl) {
             const protocol = window.location.protocol.replace('http', 'ws');
@@ -35,7 +45,7 @@ export class MissionSocketService {
         // Ensure we don't have double slashes if baseUrl has trailing slash
         if (wsBaseUrl.endsWith('/')) wsBaseUrl = wsBaseUrl.slice(0, -1);
 
-        const url = `${wsBaseUrl}/api/ws/mission/${missionId}?token=${token}`;
+        const url = `${wsBaseUrl}/api/ws/mission/${missionId}?ticket=${encodeURIComponent(ticket)}`;
 
         this.socket = new WebSocket(url);
 
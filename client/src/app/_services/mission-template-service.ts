@@ -0,0 +1,29 @@
+import { inject, Injectable } from '@angular/core'
+import { environment } from '../../environments/environment'
+import { HttpClient } from '@angular/common/http'
+import { firstValueFrom } from 'rxjs'
+import { AddMissionTemplate, MissionTemplate } from '../_models/mission-template'
+
+@Injectable({
+  providedIn: 'root',
+})
+export class MissionTemplateService {
+  private _base_url = environment.baseUrl + '/api/mission-templates'
+  private _http = inject(HttpClient)
+
+  async getAll(): Promise<MissionTemplate[]> {
+    return await firstValueFrom(this._http.get<MissionTemplate[]>(this._base_url))
+  }
+
+  async add(template: AddMissionTemplate): Promise<MissionTemplate> {
+    return await firstValueFrom(this._http.post<MissionTemplate>(this._base_url, template))
+  }
+
+  async edit(id: number, template: Partial<AddMissionTemplate>): Promise<MissionTemplate> {
+    return await firstValueFrom(this._http.patch<MissionTemplate>(`${this._base_url}/${id}`, template))
+  }
+
+  async delete(id: number): Promise<void> {
+    await firstValueFrom(this._http.delete(`${this._base_url}/${id}`))
+  }
+}
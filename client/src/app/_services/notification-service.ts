@@ -38,7 +38,7 @@ export class NotificationService {
       this.notificationSubject.next(notification);
     });
   }
-  connect(): void {
+  async connect(): Promise<void> {
     if (this.eventSource) {
       this.eventSource.close();
     }
@@ -48,8 +48,18 @@ export class NotificationService {
       console.log('[NotificationService] Connecting with token:', token ? 'YES' : 'NO');
       if (!token) return;
 
-      const url = `${environment.baseUrl}/api/notifications/events?token=${token}`;
-      console.log('[NotificationService] URL:', url);
+      let ticket: string;
+      try {
+        ticket = await this.passportService.getStreamTicket();
+      } catch (e) {
+        console.error('[NotificationService] Failed to get stream ticket', e);
+        return;
+      }
+
+      // Another connect() may have finished while we waited for the ticket
+      this.eventSource?.close();
+
+      const url = `${environment.baseUrl}/api/notifications/events?ticket=${encodeURIComponent(ticket)}`;
       this.eventSource = new EventSource(url);
 
       this.eventSource.onopen = (event) => {
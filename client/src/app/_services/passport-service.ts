@@ -92,6 +92,13 @@ export class PassportService {
     return await this.fetchPassport(api_url, register)
   }
 
+  // Single-use ticket for EventSource/WebSocket URLs, which cannot send an Authorization header
+  async getStreamTicket(): Promise<string> {
+    const api_url = this._base_url + '/authentication/stream-ticket'
+    const result = await firstValueFrom(this._http.post<{ ticket: string }>(api_url, {}))
+    return result.ticket
+  }
+
   // // *เพิ่ม
   // async recoverPassword(username: string): Promise<null | string> {
   //   const api_url = this._base_url + '/authentication/recover-password'
@@ -120,7 +127,7 @@ export class PassportService {
     } catch (error: any) {
       // console.error(error)
       // console.log(error.error)
-      return error.error
+      return typeof error.error === 'string' ? error.error : (error.error?.message ?? 'Request failed')
     }
 
   }
@@ -7,6 +7,8 @@ import { firstValueFrom } from 'rxjs'
 import { CloudinaryImage } from '../_models/cludinary-image'
 import { Brawler } from '../_models/brawler'
 import { Passport } from '../_models/passport'
+import { Page, PageRequest, pageQueryString } from '../_models/page'
+import { CalendarToken } from '../_models/calendar-token'
 
 @Injectable({
   providedIn: 'root',
@@ -45,7 +47,7 @@ export class UserService {
       localStorage.setItem('passport', JSON.stringify(newPassport))
       return null
     } catch (error: any) {
-      return error.error as string
+      return typeof error.error === 'string' ? error.error : (error.error?.message ?? 'Failed to update profile')
     }
   }
 
@@ -55,14 +57,34 @@ export class UserService {
     return await firstValueFrom(this._http.get<Brawler[]>(url))
   }
 
-  async getAllBrawlers(): Promise<Brawler[]> {
-    const url = this._base_url + '/all'
-    return await firstValueFrom(this._http.get<Brawler[]>(url))
+  async getAllBrawlers(page: PageRequest = {}): Promise<Page<Brawler>> {
+    const queryString = pageQueryString(page)
+    const url = this._base_url + '/all' + (queryString ? '?' + queryString : '')
+    return await firstValueFrom(this._http.get<Page<Brawler>>(url))
   }
 
   // *เพิ่ม
-  async getMyMissions(): Promise<import('../_models/mission').Mission[]> { // using importtype or Mission if imported
-    const url = this._base_url + '/my-missions'
-    return await firstValueFrom(this._http.get<import('../_models/mission').Mission[]>(url))
+  async getMyMissions(page: PageRequest = {}): Promise<Page<import('../_models/mission').Mission>> { // using importtype or Mission if imported
+    const queryString = pageQueryString(page)
+    const url = this._base_url + '/my-missions' + (queryString ? '?' + queryString : '')
+    return await firstValueFrom(this._http.get<Page<import('../_models/mission').Mission>>(url))
+  }
+
+  // Returns the full subscribe URL; the token is only shown once, so a new one replaces the old feed.
+  async rotateCalendarToken(): Promise<string> {
+    const url = this._base_url + '/calendar-token'
+    const calendarToken = await firstValueFrom(this._http.post<CalendarToken>(url, {}))
+    return environment.baseUrl + calendarToken.feed_path
+  }
+
+  async revokeCalendarToken(): Promise<void> {
+    const url = this._base_url + '/calendar-token'
+    await firstValueFrom(this._http.delete<void>(url))
+  }
+
+  // Missions the user leads pass to their longest-standing member.
+  async deleteAccount(): Promise<void> {
+    await firstValueFrom(this._http.delete<void>(this._base_url + '/me'))
+    this._passport.destroy()
   }
 }
@@ -57,6 +57,11 @@ export const routes: Routes = [
         loadComponent: () => import('./missions/mission-specific-manager/mission-specific-manager').then(m => m.MissionSpecificManager),
         canActivate: [authGuard]
     },
+    // Shared invite links; the preview works signed out, joining asks to sign in
+    {
+        path: 'invite/:token',
+        loadComponent: () => import('./invite-link/invite-link').then(m => m.InviteLinkPage)
+    },
 
     { path: 'server-error', component: ServerError },
     { path: '**', component: NotFound },
@@ -41,7 +41,7 @@ export class Home {
       ])
 
       // สุ่มรูป 1-4 ให้แต่ละภารกิจ
-      const randomizedMissions = missions.map(m => ({
+      const randomizedMissions = missions.items.map(m => ({
         ...m,
         randomImage: `/assets/card/card-img-0${Math.floor(Math.random() * 4) + 1}.jpg`
       }));
@@ -0,0 +1,57 @@
+import { Component, inject, OnInit } from '@angular/core'
+import { CommonModule } from '@angular/common'
+import { ActivatedRoute, Router, RouterLink } from '@angular/router'
+import { MatSnackBar } from '@angular/material/snack-bar'
+import { InviteService } from '../_services/invite.service'
+import { PassportService } from '../_services/passport-service'
+import { InviteLinkPreview } from '../_models/invite-link'
+
+@Component({
+  selector: 'app-invite-link',
+  standalone: true,
+  imports: [CommonModule, RouterLink],
+  templateUrl: './invite-link.html',
+  styleUrl: './invite-link.scss',
+})
+export class InviteLinkPage implements OnInit {
+  private _route = inject(ActivatedRoute)
+  private _router = inject(Router)
+  private _inviteService = inject(InviteService)
+  private _passport = inject(PassportService)
+  private _snackBar = inject(MatSnackBar)
+
+  token = ''
+  preview: InviteLinkPreview | undefined
+  error: string | null = null
+  isLoading = true
+  isJoining = false
+
+  get isSignedIn(): boolean {
+    return !!this._passport.userId()
+  }
+
+  async ngOnInit() {
+    this.token = this._route.snapshot.paramMap.get('token') || ''
+    try {
+      this.preview = await this._inviteService.previewLink(this.token)
+    } catch (e: any) {
+      this.error = e?.error?.message || 'This invite link is no longer valid'
+    } finally {
+      this.isLoading = false
+    }
+  }
+
+  async join() {
+    if (!this.preview) return
+    this.isJoining = true
+    try {
+      await this._inviteService.acceptLink(this.token)
+      this._snackBar.open(`Joined ${this.preview.mission_name}`, 'Close', { duration: 3000 })
+      this._router.navigate(['/missions', this.preview.mission_id])
+    } catch (e: any) {
+      this._snackBar.open(e?.error?.message || 'Failed to join mission', 'Close', { duration: 3000 })
+    } finally {
+      this.isJoining = false
+    }
+  }
+}
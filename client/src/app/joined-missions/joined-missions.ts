@@ -8,6 +8,7 @@ import { BehaviorSubject } from 'rxjs'
 import { RouterLink } from '@angular/router'
 
 import { ThreeDTiltDirective } from '../_directives/three-d-tilt.directive'
+import { MAX_PAGE_LIMIT } from '../_models/page'
 
 @Component({
   selector: 'app-joined-missions',
@@ -27,8 +28,8 @@ export class JoinedMissions {
 
   private async loadMissions() {
     try {
-      const missions = await this._mission.getJoinedMissions()
-      this._missionsSubject.next(missions)
+      const missions = await this._mission.getJoinedMissions({ limit: MAX_PAGE_LIMIT })
+      this._missionsSubject.next(missions.items)
     } catch (e) {
       console.error(e)
     }
@@ -6,6 +6,12 @@ import { PassportService } from '../../_services/passport-service'
 import { Mission } from '../../_models/mission'
 import { ThreeDTiltDirective } from '../../_directives/three-d-tilt.directive'
 import { MissionChatComponent } from '../../_components/mission-chat/mission-chat'
+import { MissionObjectiveService } from '../../_services/mission-objective-service'
+import { MissionObjective } from '../../_models/mission-objective'
+import { RoleSlot } from '../../_models/role-slot'
+import { JoinRequest } from '../../_models/join-request'
+import { WaitlistEntry } from '../../_models/waitlist-entry'
+import { PeerRating, RATING_TAGS, RatingSheet, RatingTag } from '../../_models/rating'
 
 import { MatDialog, MatDialogModule } from '@angular/material/dialog'
 
@@ -22,9 +28,18 @@ export class MissionDetail implements OnInit {
     private _missionService: MissionService = inject(MissionService)
     public _passport: PassportService = inject(PassportService)
     private _dialog = inject(MatDialog)
+    private _objectives = inject(MissionObjectiveService)
 
     mission = signal<Mission | undefined>(undefined)
     crew = signal<any[]>([])
+    objectives = signal<MissionObjective[]>([])
+    roleSlots = signal<RoleSlot[]>([])
+    selectedSlotId = signal<number | null>(null)
+    joinRequests = signal<JoinRequest[]>([])
+    requestPending = signal<boolean>(false)
+    waitlist = signal<WaitlistEntry[]>([])
+    ratingSheet = signal<RatingSheet | null>(null)
+    readonly ratingTags = RATING_TAGS
     isLoading = signal<boolean>(true)
     error = signal<string | null>(null)
 
@@ -48,6 +63,31 @@ export class MissionDetail implements OnInit {
         return this.mission()?.chief_id === this._passport.userId()
     })
 
+    canManage = computed(() => this.isChief() || !!this.mission()?.is_co_chief)
+
+    isFull = computed(() => {
+        const m = this.mission()
+        return !!m && m.crew_count >= m.max_crew
+    })
+
+    myWaitlistEntry = computed(() => {
+        const userId = this._passport.userId()
+        return this.waitlist().find(entry => entry.brawler_id === userId)
+    })
+
+    isFinished = computed(() => {
+        const status = this.mission()?.status
+        return status === 'Completed' || status === 'Failed'
+    })
+
+    freeSlots = computed(() => this.roleSlots().filter(slot => slot.brawler_id === null))
+
+    objectiveProgress = computed(() => {
+        const objectives = this.objectives()
+        if (objectives.length === 0) return 0
+        return Math.floor(100 * objectives.filter(o => o.completed_at).length / objectives.length)
+    })
+
     ngOnInit() {
         this._route.params.subscribe(params => {
             if (params['id'] && this.missionId === 0) {
@@ -86,6 +126,11 @@ export class MissionDetail implements OnInit {
 
             this.mission.set(missionWithImg as Mission)
             this.crew.set(crew)
+            await this.loadObjectives()
+            await this.loadRoleSlots()
+            if (this.canManage() && !this.isFinished()) await this.loadJoinRequests()
+            await this.loadWaitlist()
+            if (this.isFinished() && (this.isChief() || this.isMember())) await this.loadRatingSheet()
         } catch (e: any) {
             console.error('Failed to load mission:', e)
             this.error.set(e?.message || e?.error?.message || 'Failed to load mission data')
@@ -94,10 +139,217 @@ export class MissionDetail implements OnInit {
         }
     }
 
+    async loadObjectives() {
+        try {
+            this.objectives.set(await this._objectives.getAll(this.missionId))
+        } catch (err) {
+            console.warn(`Could not load objectives for mission ${this.missionId}:`, err)
+        }
+    }
+
+    async loadRoleSlots() {
+        try {
+            this.roleSlots.set(await this._missionService.getRoleSlots(this.missionId))
+        } catch (err) {
+            console.warn(`Could not load role slots for mission ${this.missionId}:`, err)
+        }
+    }
+
+    async loadJoinRequests() {
+        try {
+            this.joinRequests.set(await this._missionService.getJoinRequests(this.missionId))
+        } catch (err) {
+            console.warn(`Could not load join requests for mission ${this.missionId}:`, err)
+        }
+    }
+
+    async setCoChief(memberId: number, isCoChief: boolean) {
+        try {
+            await this._missionService.setCoChief(this.missionId, memberId, isCoChief)
+            this.crew.set(await this._missionService.getCrew(this.missionId))
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to update co-chief')
+        }
+    }
+
+    async transferChief(member: any) {
+        if (!confirm(`Hand ${this.mission()?.name} over to ${member.display_name}?`)) return
+        try {
+            await this._missionService.transferChief(this.missionId, member.id)
+            await this.loadMissionData()
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to transfer the mission')
+        }
+    }
+
+    async loadRatingSheet() {
+        try {
+            this.ratingSheet.set(await this._missionService.getRatingSheet(this.missionId))
+        } catch (err) {
+            console.warn(`Could not load ratings for mission ${this.missionId}:`, err)
+        }
+    }
+
+    async rate(peer: PeerRating, score: number, tags: RatingTag[] = peer.tags) {
+        try {
+            await this._missionService.rateBrawler(this.missionId, peer.brawler_id, score, tags)
+            await this.loadRatingSheet()
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to save rating')
+        }
+    }
+
+    async toggleRatingTag(peer: PeerRating, tag: RatingTag) {
+        // a tag needs a score to go with it
+        if (!peer.score) return
+        const tags = peer.tags.includes(tag) ? peer.tags.filter(t => t !== tag) : [...peer.tags, tag]
+        await this.rate(peer, peer.score, tags)
+    }
+
+    async loadWaitlist() {
+        try {
+            this.waitlist.set(await this._missionService.getWaitlist(this.missionId))
+        } catch (err) {
+            console.warn(`Could not load waitlist for mission ${this.missionId}:`, err)
+        }
+    }
+
+    async joinWaitlist() {
+        try {
+            await this._missionService.joinWaitlist(this.missionId)
+            await this.loadWaitlist()
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to join waitlist')
+        }
+    }
+
+    async leaveWaitlist() {
+        try {
+            await this._missionService.leaveWaitlist(this.missionId)
+            await this.loadWaitlist()
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to leave waitlist')
+        }
+    }
+
+    async confirmWaitlistOffer() {
+        try {
+            await this._missionService.confirmWaitlistOffer(this.missionId, this.selectedSlotId() ?? undefined)
+            this.selectedSlotId.set(null)
+            await this.loadMissionData()
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to confirm waitlist offer')
+        }
+    }
+
+    async decideJoinRequest(request: JoinRequest, approve: boolean) {
+        try {
+            await this._missionService.decideJoinRequest(this.missionId, request.id, approve)
+            if (approve) {
+                await this.loadMissionData()
+            } else {
+                await this.loadJoinRequests()
+            }
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to update join request')
+        }
+    }
+
+    async cancelJoinRequest() {
+        try {
+            await this._missionService.cancelJoinRequest(this.missionId)
+            this.requestPending.set(false)
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to cancel join request')
+        }
+    }
+
+    async saveRoleSlots(input: HTMLInputElement) {
+        const labels = input.value.split(',').map(label => label.trim()).filter(label => label)
+        try {
+            this.roleSlots.set(await this._missionService.setRoleSlots(this.missionId, labels))
+            input.value = ''
+            this.crew.set(await this._missionService.getCrew(this.missionId))
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to save role slots')
+        }
+    }
+
+    async useLeaguePreset() {
+        try {
+            this.roleSlots.set(await this._missionService.setRoleSlots(this.missionId, [], 'league_of_legends'))
+            this.crew.set(await this._missionService.getCrew(this.missionId))
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to save role slots')
+        }
+    }
+
+    async assignRole(memberId: number, value: string) {
+        const slotId = value ? Number(value) : null
+        try {
+            this.roleSlots.set(await this._missionService.assignRoleSlot(this.missionId, memberId, slotId))
+            this.crew.set(await this._missionService.getCrew(this.missionId))
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to assign role')
+        }
+    }
+
+    async addObjective(input: HTMLInputElement, mandatory: boolean) {
+        const title = input.value.trim()
+        if (!title) return
+        try {
+            await this._objectives.add(this.missionId, { title, is_mandatory: mandatory })
+            input.value = ''
+            await this.loadObjectives()
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to add objective')
+        }
+    }
+
+    async toggleObjective(objective: MissionObjective) {
+        try {
+            if (objective.completed_at) {
+                await this._objectives.reopen(this.missionId, objective.id)
+            } else {
+                await this._objectives.complete(this.missionId, objective.id)
+            }
+            await this.loadObjectives()
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to update objective')
+        }
+    }
+
+    async removeObjective(objective: MissionObjective) {
+        if (!confirm(`Remove objective "${objective.title}"?`)) return
+        try {
+            await this._objectives.delete(this.missionId, objective.id)
+            await this.loadObjectives()
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to remove objective')
+        }
+    }
+
+    async moveObjective(index: number, delta: number) {
+        const ids = this.objectives().map(o => o.id)
+        const target = index + delta
+        if (target < 0 || target >= ids.length) return
+        ;[ids[index], ids[target]] = [ids[target], ids[index]]
+        try {
+            this.objectives.set(await this._objectives.reorder(this.missionId, ids))
+        } catch (e: any) {
+            alert(e?.error?.message || 'Failed to reorder objectives')
+        }
+    }
+
     async joinMission() {
         if (!this.mission()) return
         try {
-            await this._missionService.joinMission(this.missionId)
+            const request = await this._missionService.joinMission(this.missionId, this.selectedSlotId() ?? undefined)
+            this.selectedSlotId.set(null)
+            if (request) {
+                this.requestPending.set(true)
+                return
+            }
             await this.loadMissionData() // Reload to update crew list
         } catch (e: any) {
             alert(e?.error?.message || 'Failed to join mission')
@@ -12,10 +12,14 @@ import { PassportService } from '../../_services/passport-service'
 import { NotificationService } from '../../_services/notification-service'
 import { Router } from '@angular/router'
 import { ThreeDTiltDirective } from '../../_directives/three-d-tilt.directive'
+import { MAX_PAGE_LIMIT } from '../../_models/page'
+import { FormsModule } from '@angular/forms'
+import { MissionTemplateService } from '../../_services/mission-template-service'
+import { AddMissionTemplate, MissionTemplate, WEEKDAYS, weeklyRule } from '../../_models/mission-template'
 
 @Component({
   selector: 'app-mission-manager',
-  imports: [MatIconModule, MatButtonModule, MatDialogModule, DatePipe, AsyncPipe, ThreeDTiltDirective],
+  imports: [MatIconModule, MatButtonModule, MatDialogModule, DatePipe, AsyncPipe, ThreeDTiltDirective, FormsModule],
   templateUrl: './mission-manager.html',
   styleUrl: './mission-manager.scss',
 })
@@ -28,9 +32,16 @@ export class MissionManager {
   private _notification = inject(NotificationService)
   private _router = inject(Router)
   joinAlerts = new Set<number>()
+  private _templates = inject(MissionTemplateService)
+  templates: MissionTemplate[] = []
+  readonly weekdays = WEEKDAYS
+  newTemplate: AddMissionTemplate = { name: '', max_crew: 5, auto_invite: true }
+  templateDays = new Set<string>()
+  templateTime = '19:00'
 
   constructor() {
     this.loadMyMission()
+    this.loadTemplates()
     this._notification.notifications$.subscribe((n: any) => {
       if (n.type === 'JoinMission' && n.metadata?.mission_id) {
         this.joinAlerts.add(Number(n.metadata.mission_id))
@@ -41,8 +52,8 @@ export class MissionManager {
   private async loadMyMission() {
     try {
       const [owned, joined] = await Promise.all([
-        this._mission.getMyMissions(),
-        this._mission.getJoinedMissions()
+        this._mission.getMyMissions({ limit: MAX_PAGE_LIMIT }),
+        this._mission.getJoinedMissions({ limit: MAX_PAGE_LIMIT })
       ])
 
       // Add a flag or just merge. Mission model has chief_id, so we can check ownership dynamically.
@@ -51,8 +62,8 @@ export class MissionManager {
 
       // Let's filter out duplicates if any (though shouldn't be if logic is correct: created != joined)
       const missionMap = new Map<number, Mission>()
-      owned.forEach((m: Mission) => missionMap.set(m.id, m))
-      joined.forEach((m: Mission) => missionMap.set(m.id, m))
+      owned.items.forEach((m: Mission) => missionMap.set(m.id, m))
+      joined.items.forEach((m: Mission) => missionMap.set(m.id, m))
 
       const allMissions = Array.from(missionMap.values())
 
@@ -106,7 +117,10 @@ export class MissionManager {
         name: mission.name,
         description: mission.description,
         category: mission.category,
-        max_crew: mission.max_crew
+        max_crew: mission.max_crew,
+        scheduled_start_at: mission.scheduled_start_at,
+        deadline_at: mission.deadline_at,
+        duration_minutes: mission.duration_minutes
       }
     })
     ref.afterClosed().subscribe(async (updatedData: AddMission) => {
@@ -170,4 +184,44 @@ export class MissionManager {
       alert(e?.error?.message ?? e?.error ?? 'Complete mission failed')
     }
   }
+
+  private async loadTemplates() {
+    try {
+      this.templates = await this._templates.getAll()
+    } catch (e) {
+      console.error('Failed to load templates', e)
+    }
+  }
+
+  toggleTemplateDay(day: string) {
+    if (this.templateDays.has(day)) this.templateDays.delete(day)
+    else this.templateDays.add(day)
+  }
+
+  async addTemplate() {
+    const days = WEEKDAYS.filter(d => this.templateDays.has(d))
+    if (!this.newTemplate.name.trim() || !days.length) return
+    try {
+      await this._templates.add({
+        ...this.newTemplate,
+        name: this.newTemplate.name.trim(),
+        recurrence: weeklyRule(days, this.templateTime)
+      })
+      this.newTemplate = { name: '', max_crew: 5, auto_invite: true }
+      this.templateDays.clear()
+      await this.loadTemplates()
+    } catch (e: any) {
+      alert(e?.error?.message ?? e?.error ?? 'Failed to save template')
+    }
+  }
+
+  async deleteTemplate(template: MissionTemplate) {
+    if (!confirm(`Stop recurring "${template.name}"? Missions already scheduled are kept.`)) return
+    try {
+      await this._templates.delete(template.id)
+      this.templates = this.templates.filter(t => t.id !== template.id)
+    } catch (e: any) {
+      alert(e?.error?.message ?? e?.error ?? 'Delete failed')
+    }
+  }
 }
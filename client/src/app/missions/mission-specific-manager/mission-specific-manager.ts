@@ -14,6 +14,10 @@ import { MissionSocketService } from '../../_services/mission-socket.service'
 import { MatSnackBar } from '@angular/material/snack-bar'
 import { Subject, takeUntil } from 'rxjs'
 import { ConfirmationDialogComponent } from '../../_dialogs/confirmation-dialog/confirmation-dialog'
+import { MissionBan } from '../../_models/mission-ban'
+import { SentMissionInvite } from '../../_models/mission-invite'
+import { InviteService } from '../../_services/invite.service'
+import { InviteLink } from '../../_models/invite-link'
 
 @Component({
   selector: 'app-mission-specific-manager',
@@ -30,11 +34,15 @@ export class MissionSpecificManager implements OnInit, OnDestroy {
   private _cdr = inject(ChangeDetectorRef)
   private _passport = inject(PassportService)
   private _dialog = inject(MatDialog)
+  private _inviteService = inject(InviteService)
   private _loadingTimer: any = null
 
   missionId: number = 0
   mission: Mission | undefined
   crew: any[] = []
+  bans: MissionBan[] = []
+  pendingInvites: SentMissionInvite[] = []
+  inviteLinks: InviteLink[] = []
   isLoading = false
   error: string | null = null
 
@@ -43,6 +51,16 @@ export class MissionSpecificManager implements OnInit, OnDestroy {
   editName = ''
   editDescription = ''
 
+  // Ban form, shown for one member at a time
+  banTarget: any = null
+  banReason = ''
+  banDays = 0
+
+  // Invite link form; the created URL is only available right after creation
+  linkMaxUses = 0
+  linkDays = 7
+  createdLinkUrl: string | null = null
+
   get isChief(): boolean {
     const userId = this._passport.userId()
     return !!(this.mission && userId && this.mission.chief_id === userId)
@@ -95,6 +113,11 @@ export class MissionSpecificManager implements OnInit, OnDestroy {
 
       this.mission = mission
       this.crew = crew
+      this.bans = this.isChief ? await this._missionService.getBans(this.missionId) : []
+      this.pendingInvites = this.isChief
+        ? (await this._inviteService.getMissionInvites(this.missionId)).filter(invite => invite.status === 'pending')
+        : []
+      this.inviteLinks = this.isChief ? await this._inviteService.getLinks(this.missionId) : []
 
       this.editName = this.mission.name
       this.editDescription = this.mission.description || ''
@@ -170,7 +193,7 @@ export class MissionSpecificManager implements OnInit, OnDestroy {
       panelClass: 'premium-dialog-panel',
       data: {
         title: 'Delete Mission?',
-        message: 'Permanently scrub this mission from records? This cannot be undone.',
+        message: 'Scrub this mission from records? It can be restored for a limited time.',
         confirmText: 'Delete',
         cancelText: 'Cancel'
       }
@@ -179,8 +202,17 @@ export class MissionSpecificManager implements OnInit, OnDestroy {
     dialogRef.afterClosed().subscribe(async result => {
       if (result) {
         try {
-          await this._missionService.delete(this.missionId)
+          const missionId = this.missionId
+          await this._missionService.delete(missionId)
           this._router.navigate(['/chief'])
+          this._snackBar.open('Mission deleted', 'Undo', { duration: 8000 }).onAction().subscribe(async () => {
+            try {
+              await this._missionService.restore(missionId)
+              this._router.navigate(['/chief/mission', missionId])
+            } catch (e: any) {
+              this._snackBar.open(e?.error?.message || 'Restore failed', 'Close', { duration: 3000 })
+            }
+          })
         } catch (e: any) {
           this._snackBar.open(e?.error?.message || 'Failed', 'Close', { duration: 3000 })
         }
@@ -239,6 +271,88 @@ export class MissionSpecificManager implements OnInit, OnDestroy {
 
 
 
+  openBan(member: any) {
+    this.banTarget = member
+    this.banReason = ''
+    this.banDays = 0
+  }
+
+  async confirmBan() {
+    if (!this.banTarget) return
+    const expires_at = this.banDays > 0
+      ? new Date(Date.now() + this.banDays * 24 * 60 * 60 * 1000).toISOString()
+      : undefined
+    try {
+      await this._missionService.kickCrew(this.missionId, this.banTarget.id, {
+        reason: this.banReason.trim() || undefined,
+        expires_at
+      })
+      this._snackBar.open('Member kicked and banned', 'Close', { duration: 3000 })
+      this.banTarget = null
+      await this.loadData()
+    } catch (e: any) {
+      this._snackBar.open(e?.error?.message || 'Failed to ban', 'Close', { duration: 3000 })
+    }
+  }
+
+  async cancelInvite(invite: SentMissionInvite) {
+    try {
+      await this._inviteService.cancel(invite.id)
+      this._snackBar.open(`Invite to ${invite.user_display_name} withdrawn`, 'Close', { duration: 3000 })
+      await this.loadData()
+    } catch (e: any) {
+      this._snackBar.open(e?.error?.message || 'Failed to cancel invite', 'Close', { duration: 3000 })
+    }
+  }
+
+  async createInviteLink() {
+    try {
+      const expires_at = this.linkDays > 0
+        ? new Date(Date.now() + this.linkDays * 24 * 60 * 60 * 1000).toISOString()
+        : undefined
+      const created = await this._inviteService.createLink(this.missionId, {
+        max_uses: this.linkMaxUses > 0 ? this.linkMaxUses : undefined,
+        expires_at
+      })
+      this.createdLinkUrl = `${window.location.origin}/invite/${created.token}`
+      await this.loadData()
+    } catch (e: any) {
+      this._snackBar.open(e?.error?.message || 'Failed to create invite link', 'Close', { duration: 3000 })
+    }
+  }
+
+  async copyInviteLink() {
+    if (!this.createdLinkUrl) return
+    await navigator.clipboard.writeText(this.createdLinkUrl)
+    this._snackBar.open('Invite link copied', 'Close', { duration: 3000 })
+  }
+
+  linkIsActive(link: InviteLink): boolean {
+    const expired = !!link.expires_at && new Date(link.expires_at + 'Z') <= new Date()
+    const usedUp = link.max_uses !== null && link.use_count >= link.max_uses
+    return !link.revoked_at && !expired && !usedUp
+  }
+
+  async revokeInviteLink(link: InviteLink) {
+    try {
+      await this._inviteService.revokeLink(this.missionId, link.id)
+      this._snackBar.open('Invite link revoked', 'Close', { duration: 3000 })
+      await this.loadData()
+    } catch (e: any) {
+      this._snackBar.open(e?.error?.message || 'Failed to revoke invite link', 'Close', { duration: 3000 })
+    }
+  }
+
+  async liftBan(ban: MissionBan) {
+    try {
+      await this._missionService.liftBan(this.missionId, ban.brawler_id)
+      this._snackBar.open(`${ban.brawler_display_name} may join again`, 'Close', { duration: 3000 })
+      await this.loadData()
+    } catch (e: any) {
+      this._snackBar.open(e?.error?.message || 'Failed to lift ban', 'Close', { duration: 3000 })
+    }
+  }
+
   async leaveMission() {
     const dialogRef = this._dialog.open(ConfirmationDialogComponent, {
       width: '400px',
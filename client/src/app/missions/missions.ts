@@ -32,6 +32,7 @@ export class Missions {
   isSignin: Signal<boolean>
   userId = this._passport.userId
   isLoading = signal(true)
+  nextCursor = signal<string | undefined>(undefined)
 
   constructor() {
     this.isSignin = computed(() => this._passport.data() !== undefined)
@@ -42,12 +43,21 @@ export class Missions {
   private async loadMyMission() {
     this.isLoading.set(true)
     try {
-      const missions = await this._mission.getByFilter(this.filter)
-      this._missionsSubject.next(missions)
+      const page = await this._mission.getByFilter(this.filter)
+      this._missionsSubject.next(page.items)
+      this.nextCursor.set(page.next_cursor)
     } finally {
       this.isLoading.set(false)
     }
   }
+
+  async loadMore() {
+    const cursor = this.nextCursor()
+    if (!cursor) return
+    const page = await this._mission.getByFilter(this.filter, { cursor })
+    this._missionsSubject.next([...this._missionsSubject.value, ...page.items])
+    this.nextCursor.set(page.next_cursor)
+  }
   async onSubmit() {
     this.loadMyMission()
   }
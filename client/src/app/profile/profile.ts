@@ -11,6 +11,7 @@ import { DatePipe, CommonModule } from '@angular/common'
 import { Mission } from '../_models/mission'
 import { InviteService } from '../_services/invite.service'
 import { EditProfileDialog } from '../_dialogs/edit-profile/edit-profile'
+import { MAX_PAGE_LIMIT } from '../_models/page'
 
 @Component({
   selector: 'app-profile',
@@ -24,6 +25,7 @@ export class Profile implements OnInit {
   achievements: Achievement[] = []
   invites: any[] = [] // List of pending invites
   missions: Mission[] = [] // List of user's missions
+  calendarUrl: string | null = null
   systemLogs = [
     { type: 'success', message: 'Neural Link Established', time: new Date() },
     { type: 'info', message: 'System Diagnostics: Optimal', time: new Date(Date.now() - 3600000 * 2) },
@@ -71,7 +73,7 @@ export class Profile implements OnInit {
 
   async loadMissions() {
     try {
-      this.missions = await this._user.getMyMissions()
+      this.missions = (await this._user.getMyMissions({ limit: MAX_PAGE_LIMIT })).items
       this._cdr.detectChanges()
     } catch (e) {
       console.error('Failed to load missions', e)
@@ -112,6 +114,43 @@ export class Profile implements OnInit {
     }
   }
 
+  async createCalendarLink() {
+    if (this.calendarUrl === null && !confirm('Any calendar already subscribed with an older link will stop updating. Continue?')) return;
+    try {
+      this.calendarUrl = await this._user.rotateCalendarToken()
+    } catch (e: any) {
+      alert(e?.error?.message || 'Failed to create calendar link')
+    } finally {
+      this._cdr.detectChanges()
+    }
+  }
+
+  async revokeCalendarLink() {
+    if (!confirm('Disable the calendar feed? Subscribed calendars will stop updating.')) return;
+    try {
+      await this._user.revokeCalendarToken()
+      this.calendarUrl = null
+    } catch (e: any) {
+      alert(e?.error?.message || 'Failed to disable calendar feed')
+    } finally {
+      this._cdr.detectChanges()
+    }
+  }
+
+  async deleteAccount() {
+    if (!confirm('Delete your account? Missions you lead pass to their longest-serving member. This cannot be undone.')) return;
+    try {
+      await this._user.deleteAccount()
+      window.location.href = '/'
+    } catch (e: any) {
+      alert(e?.error?.message || 'Failed to delete account')
+    }
+  }
+
+  copyCalendarLink() {
+    if (this.calendarUrl) navigator.clipboard?.writeText(this.calendarUrl)
+  }
+
   openDialog() {
     const ref = this._dialog.open(UploadImg)
     ref.afterClosed().subscribe((isSuccess: boolean | undefined) => {
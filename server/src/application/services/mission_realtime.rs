@@ -1,5 +1,6 @@
 use std::{
     collections::HashMap,
+    future::Future,
     sync::{Arc, Mutex},
 };
 use tokio::sync::broadcast;
@@ -20,12 +21,21 @@ pub struct ChatMessage {
 pub struct MissionRealtimeService {
     // Map mission_id -> broadcast channel
     channels: Arc<Mutex<HashMap<i32, broadcast::Sender<ChatMessage>>>>,
+    /// `(mission_id, brawler_id)` of kicked and banned members, so their open chat sockets close.
+    evictions: broadcast::Sender<(i32, i32)>,
+}
+
+impl Default for MissionRealtimeService {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl MissionRealtimeService {
     pub fn new() -> Self {
         Self {
             channels: Arc::new(Mutex::new(HashMap::new())),
+            evictions: broadcast::channel(256).0,
         }
     }
 
@@ -51,4 +61,23 @@ impl MissionRealtimeService {
         // We ignore error if there are no active receivers
         let _ = tx.send(message);
     }
+
+    /// Cuts the brawler off from the mission's live chat.
+    pub fn evict(&self, mission_id: i32, brawler_id: i32) {
+        let _ = self.evictions.send((mission_id, brawler_id));
+    }
+
+    /// Resolves once the brawler is evicted from the mission; subscribe before serving them.
+    pub fn evicted(&self, mission_id: i32, brawler_id: i32) -> impl Future<Output = ()> + Send + 'static {
+        let mut evictions = self.evictions.subscribe();
+        async move {
+            loop {
+                match evictions.recv().await {
+                    Ok(evicted) if evicted == (mission_id, brawler_id) => return,
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => std::future::pending::<()>().await,
+                }
+            }
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 use crate::domain::{
     repositories::AchievementRepository,
     value_objects::achievement_model::AchievementViewModel,
@@ -20,7 +20,7 @@ where
         Self { repository }
     }
 
-    pub async fn get_my_achievements(&self, brawler_id: i32) -> Result<Vec<AchievementViewModel>> {
+    pub async fn get_my_achievements(&self, brawler_id: i32) -> AppResult<Vec<AchievementViewModel>> {
         let data = self.repository.get_by_brawler_id(brawler_id).await?;
         
         let view_models = data.into_iter().map(|(achievement, brawler_achievement)| {
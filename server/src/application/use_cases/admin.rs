@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use crate::config::{config_loader::get_mission_retention_env, config_model::MissionRetentionEnv};
+use crate::domain::errors::{AppError, AppResult};
+
+use crate::domain::{
+    entities::achievements::Achievement,
+    repositories::{
+        AchievementRepository, BrawlerRepository,
+        mission_management::MissionManagementRepository,
+    },
+    value_objects::{
+        achievement_model::{AddAchievementModel, EditAchievementModel},
+        admin_model::AdminBrawlerModel,
+        roles::Roles,
+    },
+};
+
+pub struct AdminUseCase<T1, T2, T3>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    mission_management_repository: Arc<T2>,
+    achievement_repository: Arc<T3>,
+    retention: MissionRetentionEnv,
+}
+
+impl<T1, T2, T3> AdminUseCase<T1, T2, T3>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        mission_management_repository: Arc<T2>,
+        achievement_repository: Arc<T3>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            mission_management_repository,
+            achievement_repository,
+            retention: get_mission_retention_env(),
+        }
+    }
+
+    pub async fn list_brawlers(&self) -> AppResult<Vec<AdminBrawlerModel>> {
+        let brawlers = self.brawler_repository.list_brawlers().await?;
+        Ok(brawlers.into_iter().map(AdminBrawlerModel::from).collect())
+    }
+
+    /// Staff can only disable accounts ranked below their own role.
+    pub async fn set_disabled(
+        &self,
+        actor_id: i32,
+        actor_role: Roles,
+        brawler_id: i32,
+        disabled: bool,
+    ) -> AppResult<()> {
+        if actor_id == brawler_id {
+            return Err(AppError::Forbidden(
+                "You cannot change your own account status".to_string(),
+            ));
+        }
+
+        let target = self.brawler_repository.find_by_id(brawler_id).await?;
+        let target_role: Roles = target.role.parse()?;
+        if target_role >= actor_role {
+            return Err(AppError::Forbidden("Insufficient role".to_string()));
+        }
+
+        self.brawler_repository.set_disabled(brawler_id, disabled).await
+    }
+
+    pub async fn set_role(&self, actor_id: i32, brawler_id: i32, role: Roles) -> AppResult<()> {
+        if actor_id == brawler_id {
+            return Err(AppError::Forbidden("You cannot change your own role".to_string()));
+        }
+
+        self.brawler_repository.set_role(brawler_id, role).await
+    }
+
+    pub async fn force_delete_mission(&self, mission_id: i32) -> AppResult<()> {
+        self.mission_management_repository.force_remove(mission_id).await
+    }
+
+    pub async fn restore_mission(&self, mission_id: i32) -> AppResult<()> {
+        self.mission_management_repository
+            .force_restore(mission_id, self.retention.retention_days)
+            .await
+    }
+
+    pub async fn list_achievements(&self) -> AppResult<Vec<Achievement>> {
+        self.achievement_repository.get_all().await
+    }
+
+    pub async fn add_achievement(&self, model: AddAchievementModel) -> AppResult<i32> {
+        self.achievement_repository.create(model.to_entity()).await
+    }
+
+    pub async fn edit_achievement(&self, achievement_id: i32, model: EditAchievementModel) -> AppResult<()> {
+        self.achievement_repository
+            .update(achievement_id, model.to_entity())
+            .await
+    }
+
+    pub async fn remove_achievement(&self, achievement_id: i32) -> AppResult<()> {
+        self.achievement_repository.delete(achievement_id).await
+    }
+
+    pub async fn award_achievement(&self, achievement_id: i32, brawler_id: i32) -> AppResult<()> {
+        self.achievement_repository
+            .award_achievement(brawler_id, achievement_id)
+            .await
+    }
+
+    pub async fn revoke_achievement(&self, achievement_id: i32, brawler_id: i32) -> AppResult<()> {
+        self.achievement_repository
+            .revoke_achievement(brawler_id, achievement_id)
+            .await
+    }
+}
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+
+use crate::{
+    domain::{
+        entities::{
+            api_keys::{ApiKeyEntity, NewApiKeyEntity},
+            brawlers::RegisterBotEntity,
+        },
+        repositories::{ApiKeyRepository, BrawlerRepository},
+        value_objects::api_key_model::{
+            AddApiKeyModel, AddBotModel, ApiKeyModel, ApiKeyPrincipal, ApiKeyScopes, BotModel,
+            CreatedApiKeyModel,
+        },
+    },
+    infrastructure::{argon2::hash, secure_token},
+};
+
+/// Keys look like `bbk_<prefix>.<secret>`; the prefix is stored in clear for lookup.
+const API_KEY_MARKER: &str = "bbk_";
+
+pub struct ApiKeyUseCase<T1, T2>
+where
+    T1: ApiKeyRepository + Send + Sync,
+    T2: BrawlerRepository + Send + Sync,
+{
+    api_key_repository: Arc<T1>,
+    brawler_repository: Arc<T2>,
+}
+
+impl<T1, T2> ApiKeyUseCase<T1, T2>
+where
+    T1: ApiKeyRepository + Send + Sync,
+    T2: BrawlerRepository + Send + Sync,
+{
+    pub fn new(api_key_repository: Arc<T1>, brawler_repository: Arc<T2>) -> Self {
+        Self {
+            api_key_repository,
+            brawler_repository,
+        }
+    }
+
+    pub async fn add_bot(&self, owner_id: i32, model: AddBotModel) -> AppResult<BotModel> {
+        let owner = self.brawler_repository.find_by_id(owner_id).await?;
+        if owner.is_bot {
+            return Err(AppError::Forbidden("Bots cannot own other bots".to_string()));
+        }
+
+        // Bots never log in with a password, so store a hash nobody knows the input of.
+        let password = hash(secure_token::generate(32))?;
+        let bot = self
+            .brawler_repository
+            .register_bot(RegisterBotEntity {
+                username: model.username,
+                password,
+                display_name: model.display_name,
+                is_bot: true,
+                bot_owner_id: Some(owner_id),
+            })
+            .await?;
+
+        Ok(BotModel::from(bot))
+    }
+
+    pub async fn get_bots(&self, owner_id: i32) -> AppResult<Vec<BotModel>> {
+        let bots = self.brawler_repository.find_bots_by_owner(owner_id).await?;
+        Ok(bots.into_iter().map(BotModel::from).collect())
+    }
+
+    pub async fn add_key(&self, brawler_id: i32, model: AddApiKeyModel) -> AppResult<CreatedApiKeyModel> {
+        let key_owner_id = match model.bot_id {
+            Some(bot_id) => {
+                self.ensure_owns_bot(brawler_id, bot_id).await?;
+                bot_id
+            }
+            None => brawler_id,
+        };
+
+        let prefix = secure_token::generate(6);
+        let key = format!("{}{}.{}", API_KEY_MARKER, prefix, secure_token::generate(32));
+
+        let mut scopes: Vec<String> = model.scopes.iter().map(ToString::to_string).collect();
+        scopes.sort();
+        scopes.dedup();
+
+        let api_key = self
+            .api_key_repository
+            .create(NewApiKeyEntity {
+                brawler_id: key_owner_id,
+                name: model.name.trim().to_string(),
+                prefix,
+                key_hash: secure_token::hash(&key),
+                scopes,
+            })
+            .await?;
+
+        Ok(CreatedApiKeyModel {
+            key,
+            api_key: ApiKeyModel::from(api_key),
+        })
+    }
+
+    /// Keys of the caller and of every bot the caller owns.
+    pub async fn get_keys(&self, brawler_id: i32) -> AppResult<Vec<ApiKeyModel>> {
+        let mut brawler_ids = vec![brawler_id];
+        brawler_ids.extend(
+            self.brawler_repository
+                .find_bots_by_owner(brawler_id)
+                .await?
+                .into_iter()
+                .map(|bot| bot.id),
+        );
+
+        let keys = self.api_key_repository.find_by_brawlers(brawler_ids).await?;
+        Ok(keys.into_iter().map(ApiKeyModel::from).collect())
+    }
+
+    pub async fn revoke_key(&self, brawler_id: i32, api_key_id: i32) -> AppResult<()> {
+        let api_key = self.api_key_repository.find_by_id(api_key_id).await?;
+        if api_key.brawler_id != brawler_id {
+            self.ensure_owns_bot(brawler_id, api_key.brawler_id).await?;
+        }
+
+        self.api_key_repository.revoke(api_key_id).await
+    }
+
+    pub async fn authenticate(&self, key: &str) -> AppResult<ApiKeyPrincipal> {
+        let invalid = || AppError::Unauthorized("Invalid API key".to_string());
+
+        let (prefix, _) = key
+            .strip_prefix(API_KEY_MARKER)
+            .and_then(|rest| rest.split_once('.'))
+            .ok_or_else(invalid)?;
+
+        let api_key = self
+            .api_key_repository
+            .find_active_by_prefix(prefix.to_string())
+            .await?
+            .ok_or_else(invalid)?;
+        if !secure_token::matches(key, &api_key.key_hash) {
+            return Err(invalid());
+        }
+
+        let brawler = self.brawler_repository.find_by_id(api_key.brawler_id).await?;
+        if brawler.disabled_at.is_some() {
+            return Err(AppError::Forbidden("Account is disabled".to_string()));
+        }
+
+        if let Err(e) = self.api_key_repository.touch(api_key.id).await {
+            tracing::error!("Failed to record API key usage: {}", e);
+        }
+
+        Ok(ApiKeyPrincipal {
+            brawler_id: brawler.id,
+            role: brawler.role.parse()?,
+            scopes: scopes_of(&api_key),
+        })
+    }
+
+    async fn ensure_owns_bot(&self, owner_id: i32, bot_id: i32) -> AppResult<()> {
+        let bot = self.brawler_repository.find_by_id(bot_id).await?;
+        if !bot.is_bot || bot.bot_owner_id != Some(owner_id) {
+            return Err(AppError::Forbidden("Not your bot".to_string()));
+        }
+        Ok(())
+    }
+}
+
+fn scopes_of(api_key: &ApiKeyEntity) -> Vec<ApiKeyScopes> {
+    api_key
+        .scopes
+        .iter()
+        .filter_map(|scope| scope.parse().ok())
+        .collect()
+}
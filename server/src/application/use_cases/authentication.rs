@@ -1,46 +1,174 @@
 use std::sync::Arc;
+use chrono::{Duration, NaiveDateTime, Utc};
 use tokio::io::AsyncWriteExt;
 
 
-use anyhow::Result;
+use crate::domain::errors::{AppError, AppResult};
 
 use crate::{
-    domain::repositories::brawlers::BrawlerRepository,
+    config::{config_loader::get_login_guard_env, config_model::LoginGuardEnv},
+    domain::{
+        entities::login_attempts::NewLoginAttemptEntity,
+        repositories::{brawlers::BrawlerRepository, login_attempts::LoginAttemptRepository},
+        value_objects::{
+            login_attempt_model::{FailedLoginModel, FailedLoginSummary},
+            roles::Roles,
+        },
+    },
     infrastructure::{
         argon2,
-        jwt::{authentication_model::{LoginModel, RecoverPasswordModel}, jwt_model::Passport},
+        jwt::{
+            JwtKeys,
+            authentication_model::{LoginModel, RecoverPasswordModel},
+            jwt_model::Passport,
+        },
     },
 };
-pub struct AuthenticationUseCase<T>
+
+const FAILED_LOGIN_HISTORY_LIMIT: i64 = 50;
+
+/// Seconds left before another attempt is allowed, using exponential backoff
+/// once `threshold` consecutive failures have been reached.
+fn remaining_lockout(guard: &LoginGuardEnv, summary: &FailedLoginSummary, threshold: i64, now: NaiveDateTime) -> Option<i64> {
+    if summary.count < threshold {
+        return None;
+    }
+    let last_failed_at = summary.last_failed_at?;
+
+    let exponent = (summary.count - threshold).min(16) as u32;
+    let lockout_secs = guard
+        .base_lockout_secs
+        .saturating_mul(2_i64.saturating_pow(exponent))
+        .min(guard.max_lockout_secs);
+
+    let remaining = (last_failed_at + Duration::seconds(lockout_secs) - now).num_seconds();
+    (remaining > 0).then_some(remaining)
+}
+
+pub struct AuthenticationUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: LoginAttemptRepository + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    login_attempt_repository: Arc<T2>,
+    jwt_keys: Arc<JwtKeys>,
+    login_guard: LoginGuardEnv,
 }
-impl<T> AuthenticationUseCase<T>
+impl<T1, T2> AuthenticationUseCase<T1, T2>
 where
-    T: BrawlerRepository + Sync + Send,
+    T1: BrawlerRepository + Sync + Send,
+    T2: LoginAttemptRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        login_attempt_repository: Arc<T2>,
+        jwt_keys: Arc<JwtKeys>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            login_attempt_repository,
+            jwt_keys,
+            login_guard: get_login_guard_env(),
+        }
+    }
+
+    /// Rejects the attempt while its username or IP address is locked out.
+    fn ensure_not_locked(&self, by_username: &FailedLoginSummary, by_ip: Option<&FailedLoginSummary>, now: NaiveDateTime) -> AppResult<()> {
+        let mut remaining = remaining_lockout(&self.login_guard, by_username, self.login_guard.max_attempts_per_user, now);
+        if let Some(by_ip) = by_ip {
+            remaining = remaining.max(remaining_lockout(&self.login_guard, by_ip, self.login_guard.max_attempts_per_ip, now));
+        }
+
+        match remaining {
+            Some(seconds) => Err(AppError::TooManyRequests(format!(
+                "Too many failed login attempts, try again in {} seconds",
+                seconds
+            ))),
+            None => Ok(()),
+        }
     }
 
-    pub async fn login(&self, login_model: LoginModel) -> Result<Passport> {
+    pub async fn login(&self, login_model: LoginModel, ip_address: Option<String>) -> AppResult<Passport> {
         let username = login_model.username.clone();
 
         //find this user in database
-        let user = self.brawler_repository.find_by_username(username).await?;
-        let hashed_password = user.password;
+        let user = match self.brawler_repository.find_by_username(username.clone()).await {
+            Ok(user) => Some(user),
+            Err(AppError::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+
+        // The attempt counts as a failure until the password checks out, and it is recorded in
+        // the same locked step as the lockout check, so parallel guesses cannot slip past it.
+        let now = Utc::now().naive_utc();
+        let since = now - Duration::minutes(self.login_guard.window_minutes);
+        let attempt = NewLoginAttemptEntity {
+            username,
+            brawler_id: user.as_ref().map(|user| user.id),
+            ip_address,
+        };
+        let attempt_id = self
+            .login_attempt_repository
+            .record_failure(attempt, since, &|by_username, by_ip| self.ensure_not_locked(by_username, by_ip, now))
+            .await?;
+
+        // Unknown and deleted accounts are checked against a dummy hash, so a wrong username
+        // takes as long as a wrong password.
+        let hashed_password = match user.as_ref().filter(|user| !user.password.is_empty()) {
+            Some(user) => user.password.clone(),
+            None => argon2::dummy_hash()?,
+        };
+        let verified = argon2::verify(login_model.password, hashed_password)?;
+
+        let user = match user {
+            Some(user) if verified && !user.password.is_empty() => user,
+            _ => return Err(AppError::Unauthorized("Invalid credentials".to_string())),
+        };
 
-        if !argon2::verify(login_model.password, hashed_password)? {
-            return Err(anyhow::anyhow!("Invalid Password !!"));
+        // A disabled account stays a failed attempt, so it does not reset the lockout streak.
+        if user.disabled_at.is_some() {
+            return Err(AppError::Forbidden("Account is disabled".to_string()));
         }
 
-        let passport = Passport::new(user.id, user.display_name, user.avatar_url, user.mission_success_count, user.mission_join_count)?;
+        if let Err(e) = self.login_attempt_repository.mark_succeeded(attempt_id).await {
+            tracing::error!("Failed to record login attempt: {}", e);
+        }
+
+        let passport = Passport::new(&self.jwt_keys, user)?;
         Ok(passport)
     }
 
-    pub async fn recover_password(&self, model: RecoverPasswordModel) -> Result<String> {
+    /// Checks an access token against its brawler and returns their current role, so disabling an
+    /// account or changing its role takes effect before the token expires.
+    pub async fn authenticate_session(&self, brawler_id: i32, token_version: i32) -> AppResult<Roles> {
+        let brawler = match self.brawler_repository.find_by_id(brawler_id).await {
+            Ok(brawler) => brawler,
+            Err(AppError::NotFound(_)) => {
+                return Err(AppError::Unauthorized("Invalid or expired token".to_string()));
+            }
+            Err(e) => return Err(e),
+        };
+
+        if brawler.disabled_at.is_some() {
+            return Err(AppError::Unauthorized("Account is disabled".to_string()));
+        }
+        if brawler.token_version != token_version {
+            return Err(AppError::Unauthorized("Session has been revoked".to_string()));
+        }
+
+        Ok(brawler.role.parse()?)
+    }
+
+    pub async fn get_failed_logins(&self, brawler_id: i32) -> AppResult<Vec<FailedLoginModel>> {
+        let attempts = self
+            .login_attempt_repository
+            .find_failed_by_brawler(brawler_id, FAILED_LOGIN_HISTORY_LIMIT)
+            .await?;
+
+        Ok(attempts.into_iter().map(FailedLoginModel::from).collect())
+    }
+    pub async fn recover_password(&self, model: RecoverPasswordModel) -> AppResult<String> {
         let username = model.username;
         
         // Find user by username
@@ -75,8 +203,55 @@ where
             Err(_) => {
                 // User not found
                 // In production, we should probably return Ok("Recovery email sent") to prevent user enumeration
-                Err(anyhow::anyhow!("User with this username not found"))
+                Err(AppError::NotFound("User with this username not found".to_string()))
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard() -> LoginGuardEnv {
+        LoginGuardEnv {
+            max_attempts_per_user: 5,
+            max_attempts_per_ip: 20,
+            window_minutes: 15,
+            base_lockout_secs: 30,
+            max_lockout_secs: 600,
+        }
+    }
+
+    fn failures(count: i64, now: NaiveDateTime) -> FailedLoginSummary {
+        FailedLoginSummary { count, last_failed_at: Some(now) }
+    }
+
+    #[test]
+    fn no_lockout_below_the_threshold() {
+        let now = Utc::now().naive_utc();
+        assert_eq!(remaining_lockout(&guard(), &failures(4, now), 5, now), None);
+        assert_eq!(remaining_lockout(&guard(), &FailedLoginSummary::default(), 5, now), None);
+    }
+
+    #[test]
+    fn lockout_doubles_per_failure_past_the_threshold_up_to_the_cap() {
+        let now = Utc::now().naive_utc();
+        let lockout = |count| remaining_lockout(&guard(), &failures(count, now), 5, now);
+
+        assert_eq!(lockout(5), Some(30));
+        assert_eq!(lockout(6), Some(60));
+        assert_eq!(lockout(8), Some(240));
+        assert_eq!(lockout(10), Some(600));
+        assert_eq!(lockout(1_000), Some(600));
+    }
+
+    #[test]
+    fn lockout_counts_down_from_the_last_failure_and_then_lifts() {
+        let now = Utc::now().naive_utc();
+        let summary = failures(5, now - Duration::seconds(20));
+
+        assert_eq!(remaining_lockout(&guard(), &summary, 5, now), Some(10));
+        assert_eq!(remaining_lockout(&guard(), &summary, 5, now + Duration::seconds(10)), None);
+    }
+}
@@ -1,44 +1,76 @@
 use crate::{
+    config::{config_loader::get_mission_waitlist_env, config_model::MissionWaitlistEnv},
     domain::{
-        repositories::BrawlerRepository,
+        entities::notification::{Notification, NotificationType},
+        repositories::{BrawlerRepository, mission_waitlist::MissionWaitlistRepository},
+        services::notification_service::NotificationService,
         value_objects::{
             base64_img::Base64Img,
-            brawler_model::{BrawlerModel, RegisterBrawlerModel, UpdateProfileModel},
+            brawler_model::{BrawlerModel, BrawlerSort, RegisterBrawlerModel, UpdateProfileModel},
+            calendar::{self, CalendarTokenModel},
             MissionModel,
-            mission_summary::MissionSummaryModel, uploaded_img::UploadedImg,
+            mission_model::MissionSort,
+            mission_summary::MissionSummaryModel,
+            pagination::{Page, PageRequest},
+            uploaded_img::UploadedImg,
         },
     },
-    infrastructure::{argon2::hash, cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
+    infrastructure::{
+        argon2::hash,
+        cloudinary::UploadImageOptions,
+        jwt::{JwtKeys, jwt_model::Passport},
+        secure_token,
+    },
 };
-use anyhow::{Ok, Result};
+use crate::domain::errors::{AppError, AppResult};
+use crate::application::use_cases::crew_operation::promote_waitlist;
 use std::sync::Arc;
 
-pub struct BrawlersUseCase<T>
+pub struct BrawlersUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    waitlist_repository: Arc<T2>,
+    jwt_keys: Arc<JwtKeys>,
+    notification_service: Arc<dyn NotificationService>,
+    waitlist: MissionWaitlistEnv,
 }
 
-impl<T> BrawlersUseCase<T>
+impl<T1, T2> BrawlersUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        waitlist_repository: Arc<T2>,
+        jwt_keys: Arc<JwtKeys>,
+        notification_service: Arc<dyn NotificationService>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            waitlist_repository,
+            jwt_keys,
+            notification_service,
+            waitlist: get_mission_waitlist_env(),
+        }
     }
 
     pub async fn register(
         &self,
         mut register_brawler_model: RegisterBrawlerModel,
-    ) -> Result<Passport> {
+    ) -> AppResult<Passport> {
         let hashed_password = hash(register_brawler_model.password.clone())?;
 
         register_brawler_model.password = hashed_password;
 
         let register_entity = register_brawler_model.to_entity();
 
-        let passport = self.brawler_repository.register(register_entity).await?;
+        let brawler = self.brawler_repository.register(register_entity).await?;
+
+        let passport = Passport::new(&self.jwt_keys, brawler)?;
 
         Ok(passport)
     }
@@ -47,7 +79,7 @@ where
         &self,
         user_id: i32,
         base64string: String,
-    ) -> Result<UploadedImg> {
+    ) -> AppResult<UploadedImg> {
         let opt = UploadImageOptions {
             folder: Some("avatar".to_string()),
             public_id: Some(user_id.to_string()),
@@ -64,25 +96,29 @@ where
         Ok(uploaded)
     }
 
-    pub async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
-        let missions = self.brawler_repository.get_missions(brawler_id).await?;
+    pub async fn get_missions(
+        &self,
+        brawler_id: i32,
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>> {
+        let missions = self.brawler_repository.get_missions(brawler_id, page).await?;
         Ok(missions)
     }
 
     // *เพิ่ม
-    pub async fn get_mission_summary(&self, brawler_id: i32) -> Result<MissionSummaryModel> {
+    pub async fn get_mission_summary(&self, brawler_id: i32) -> AppResult<MissionSummaryModel> {
         let summary = self.brawler_repository.get_mission_summary(brawler_id).await?;
         Ok(summary)
     }
 
     // *เพิ่ม
-    pub async fn get_leaderboard(&self) -> Result<Vec<BrawlerModel>> {
+    pub async fn get_leaderboard(&self) -> AppResult<Vec<BrawlerModel>> {
         let leaderboard = self.brawler_repository.get_leaderboard().await?;
         Ok(leaderboard)
     }
 
-    pub async fn get_all_brawlers(&self) -> Result<Vec<BrawlerModel>> {
-        let brawlers = self.brawler_repository.get_all_brawlers().await?;
+    pub async fn get_all_brawlers(&self, page: &PageRequest<BrawlerSort>) -> AppResult<Page<BrawlerModel>> {
+        let brawlers = self.brawler_repository.get_all_brawlers(page).await?;
         Ok(brawlers)
     }
 
@@ -90,16 +126,79 @@ where
         &self,
         user_id: i32,
         model: UpdateProfileModel,
-    ) -> Result<Passport> {
+    ) -> AppResult<Passport> {
         let updated_user = self.brawler_repository.update_profile(user_id, model).await?;
         
         // Return a new passport with updated info
-        Passport::new(
-            updated_user.id,
-            updated_user.display_name,
-            updated_user.avatar_url,
-            updated_user.mission_success_count,
-            updated_user.mission_join_count,
-        )
+        let passport = Passport::new(&self.jwt_keys, updated_user)?;
+
+        Ok(passport)
+    }
+
+    /// Issues a new feed token, replacing any earlier one.
+    pub async fn rotate_calendar_token(&self, brawler_id: i32) -> AppResult<CalendarTokenModel> {
+        let token = secure_token::generate(32);
+        self.brawler_repository
+            .set_calendar_token_hash(brawler_id, Some(secure_token::hash(&token)))
+            .await?;
+
+        Ok(CalendarTokenModel {
+            feed_path: format!("/api/brawler/calendar.ics?token={}", token),
+            token,
+        })
+    }
+
+    pub async fn revoke_calendar_token(&self, brawler_id: i32) -> AppResult<()> {
+        self.brawler_repository
+            .set_calendar_token_hash(brawler_id, None)
+            .await
+    }
+
+    /// Closes the account; missions it leads pass to their longest-standing member
+    /// and the crew places it held are offered to the waitlist.
+    pub async fn delete_account(&self, brawler_id: i32) -> AppResult<()> {
+        let deletion = self.brawler_repository.delete_account(brawler_id).await?;
+
+        for handover in deletion.handovers {
+            let _ = self
+                .notification_service
+                .send(Notification {
+                    recipient_id: Some(handover.new_chief_id),
+                    title: "You are now the chief".to_string(),
+                    message: format!(
+                        "The chief of {} deleted their account, so you now lead it",
+                        handover.mission_name
+                    ),
+                    notification_type: NotificationType::ChiefChange,
+                    metadata: serde_json::json!({ "mission_id": handover.mission_id }),
+                })
+                .await;
+        }
+        for mission_id in deletion.vacated_mission_ids {
+            promote_waitlist(
+                self.waitlist_repository.as_ref(),
+                self.notification_service.as_ref(),
+                &self.waitlist,
+                mission_id,
+            )
+            .await;
+        }
+        Ok(())
+    }
+
+    pub async fn calendar_feed(&self, token: &str) -> AppResult<String> {
+        let brawler = self
+            .brawler_repository
+            .find_by_calendar_token_hash(secure_token::hash(token))
+            .await?
+            .filter(|brawler| brawler.disabled_at.is_none())
+            .ok_or_else(|| AppError::NotFound("Calendar feed not found".to_string()))?;
+
+        let missions = self
+            .brawler_repository
+            .get_calendar_missions(brawler.id)
+            .await?;
+
+        Ok(calendar::render_feed(&missions))
     }
 }
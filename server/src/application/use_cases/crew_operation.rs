@@ -1,53 +1,82 @@
 use crate::domain::{
     entities::{
-        crew_memberships::CrewMemberShips, 
+        crew_memberships::CrewMemberShips,
+        mission_bans::AddMissionBanEntity,
+        mission_join_requests::AddMissionJoinRequestEntity,
+        mission_waitlist::AddMissionWaitlistEntity,
         notification::{Notification, NotificationType},
         mission_messages::NewMissionMessageEntity,
     },
     repositories::{
         crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
-        AchievementRepository, BrawlerRepository, mission_message_repository::MissionMessageRepository,
+        AchievementRepository, BrawlerRepository, MissionBanRepository, MissionJoinRequestRepository,
+        MissionWaitlistRepository,
+        mission_message_repository::MissionMessageRepository,
     },
     services::notification_service::NotificationService,
-    value_objects::mission_statuses::MissionStatuses,
+    value_objects::{
+        ban_model::{BanModel, MissionBanModel},
+        join_policy::JoinPolicy,
+        join_request_model::{JoinOutcome, JoinRequestModel, JoinRequestStatus},
+        mission_model::MissionModel,
+        mission_role_model::{AssignRoleSlotModel, RoleSlotModel, SetRoleSlotsModel},
+        mission_statuses::MissionStatuses,
+        waitlist_model::{WaitlistEntryModel, WaitlistStatus},
+    },
 };
-use anyhow::Result;
+use crate::config::{config_loader::get_mission_waitlist_env, config_model::MissionWaitlistEnv};
+use crate::domain::errors::{AppError, AppResult};
+use chrono::{Duration, Utc};
 use std::sync::Arc;
 
 
 use crate::application::services::mission_realtime::{MissionRealtimeService, ChatMessage};
 
-pub struct CrewOperationUseCase<T1, T2, T3, T4, T5>
+pub struct CrewOperationUseCase<T1, T2, T3, T4, T5, T6, T7, T8>
 where
     T1: CrewOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
     T3: AchievementRepository + Send + Sync,
     T4: BrawlerRepository + Send + Sync,
     T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
 {
     crew_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
     achievement_repository: Arc<T3>,
     brawler_repository: Arc<T4>,
     mission_message_repository: Arc<T5>,
+    join_request_repository: Arc<T6>,
+    waitlist_repository: Arc<T7>,
+    ban_repository: Arc<T8>,
     notification_service: Arc<dyn NotificationService>,
     realtime_service: Arc<MissionRealtimeService>,
+    waitlist: MissionWaitlistEnv,
 }
 
-impl<T1, T2, T3, T4, T5> CrewOperationUseCase<T1, T2, T3, T4, T5>
+impl<T1, T2, T3, T4, T5, T6, T7, T8> CrewOperationUseCase<T1, T2, T3, T4, T5, T6, T7, T8>
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
     T3: AchievementRepository + Send + Sync,
     T4: BrawlerRepository + Send + Sync,
     T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         crew_operation_repository: Arc<T1>, 
         mission_viewing_repository: Arc<T2>,
         achievement_repository: Arc<T3>,
         brawler_repository: Arc<T4>,
         mission_message_repository: Arc<T5>,
+        join_request_repository: Arc<T6>,
+        waitlist_repository: Arc<T7>,
+        ban_repository: Arc<T8>,
         notification_service: Arc<dyn NotificationService>,
         realtime_service: Arc<MissionRealtimeService>,
     ) -> Self {
@@ -57,8 +86,12 @@ where
             achievement_repository,
             brawler_repository,
             mission_message_repository,
+            join_request_repository,
+            waitlist_repository,
+            ban_repository,
             notification_service,
             realtime_service,
+            waitlist: get_mission_waitlist_env(),
         }
     }
     
@@ -86,63 +119,114 @@ where
         self.realtime_service.broadcast(mission_id, msg);
     }
 
-    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+    pub async fn join(&self, mission_id: i32, brawler_id: i32, slot_id: Option<i32>) -> AppResult<JoinOutcome> {
         let mission = self.mission_viewing_repository.get_one(mission_id, brawler_id).await?;
 
+        match mission.join_policy.parse::<JoinPolicy>().unwrap_or_default() {
+            JoinPolicy::InviteOnly => Err(AppError::Forbidden("This mission is invite only".to_string())),
+            JoinPolicy::Approval => self
+                .request_to_join(&mission, brawler_id, slot_id)
+                .await
+                .map(JoinOutcome::Requested),
+            JoinPolicy::Open => {
+                self.check_joinable(&mission, brawler_id).await?;
+                let slots = self.crew_operation_repository.role_slots(mission_id).await?;
+                let role = RoleSlotModel::pick(&slots, slot_id)?;
+                self.admit(&mission, brawler_id, role).await?;
+
+                // Notification: Notify Chief
+                let notification = Notification {
+                    recipient_id: Some(mission.chief_id),
+                    title: "New Crew Member".to_string(),
+                    message: format!("Someone joined your mission: {}", mission.name),
+                    notification_type: NotificationType::JoinMission,
+                    metadata: serde_json::json!({
+                        "mission_id": mission_id,
+                        "joiner_id": brawler_id
+                    }),
+                };
+                let _ = self.notification_service.send(notification).await;
+
+                Ok(JoinOutcome::Joined)
+            }
+        }
+    }
+
+    /// Checks shared by direct joins, join requests and their approval.
+    async fn check_joinable(&self, mission: &MissionModel, brawler_id: i32) -> AppResult<()> {
+        self.check_eligible(mission, brawler_id).await?;
+        // คนเต็ม
+        if !self.has_room(mission, brawler_id).await? {
+            return Err(AppError::Conflict("Mission is full".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Everything but capacity: the brawler is not the chief nor crew yet, is not banned, meets the
+    /// mission's minimum reputation and the mission takes crew.
+    async fn check_eligible(&self, mission: &MissionModel, brawler_id: i32) -> AppResult<()> {
         // หัวหน้าห้ามจอย
         if mission.chief_id == brawler_id {
-            return Err(anyhow::anyhow!(
-                "The Chief can not join in his own mission as a crew member!!"
+            return Err(AppError::Conflict(
+                "The Chief can not join in his own mission as a crew member!!".to_string(),
             ));
         }
 
         // เช็คว่าเข้าซ้ำมั้ย *เพิ่ม
         let is_joined = self
             .crew_operation_repository
-            .is_member(mission_id,brawler_id)
+            .is_member(mission.id, brawler_id)
             .await?;
         if is_joined {
-            return Err(anyhow::anyhow!("Already joined"));
+            return Err(AppError::Conflict("Already joined".to_string()));
         }
 
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
+        let banned = self
+            .ban_repository
+            .find_active(mission.id, brawler_id, Utc::now().naive_utc())
             .await?;
+        if banned.is_some() {
+            return Err(AppError::Forbidden("Banned from this mission".to_string()));
+        }
 
         // เช็คสถานะ
         let mission_status_condition = mission.status == MissionStatuses::Open.to_string()
             || mission.status == MissionStatuses::Failed.to_string()
             || mission.status == MissionStatuses::InProgress.to_string();
         if !mission_status_condition {
-            return Err(anyhow::anyhow!("Mission is not joinable"));
+            return Err(AppError::Conflict("Mission is not joinable".to_string()));
         }
-        // คนเต็ม
-        let crew_count_condition = (crew_count as i32) < mission.max_crew;
-        if !crew_count_condition {
-            return Err(anyhow::anyhow!("Mission is full"));
+
+        if mission.min_reputation.is_some() {
+            let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+            mission.check_reputation(brawler.reputation)?;
         }
 
+        Ok(())
+    }
+
+    async fn has_room(&self, mission: &MissionModel, brawler_id: i32) -> AppResult<bool> {
+        has_room(
+            self.mission_viewing_repository.as_ref(),
+            self.waitlist_repository.as_ref(),
+            mission,
+            brawler_id,
+        )
+        .await
+    }
+
+    /// Adds the brawler to the crew and announces it in the mission chat.
+    async fn admit(&self, mission: &MissionModel, brawler_id: i32, role: Option<&RoleSlotModel>) -> AppResult<()> {
+        let mission_id = mission.id;
+
         self.crew_operation_repository
             .join(CrewMemberShips {
                 mission_id,
                 brawler_id,
+                role_slot_id: role.map(|slot| slot.id),
             })
             .await?;
 
-        // Notification: Notify Chief
-        let notification = Notification {
-            recipient_id: Some(mission.chief_id),
-            title: "New Crew Member".to_string(),
-            message: format!("Someone joined your mission: {}", mission.name),
-            notification_type: NotificationType::JoinMission,
-            metadata: serde_json::json!({
-                "mission_id": mission_id,
-                "joiner_id": brawler_id
-            }),
-        };
-        let _ = self.notification_service.send(notification).await;
-
         // Check Achievements AND Broadcast
         if let Ok(brawler) = self.brawler_repository.find_by_id(brawler_id).await {
             // Log for debug
@@ -153,9 +237,13 @@ where
                     self.log_and_broadcast_system_message(mission_id, format!("{} earned achievement: {}", brawler.display_name, name)).await;
                 }
             }
-            
+
             // Broadcast join
-            self.log_and_broadcast_system_message(mission_id, format!("{} joined the mission", brawler.display_name)).await;
+            let content = match role {
+                Some(slot) => format!("{} joined the mission as {}", brawler.display_name, slot.label),
+                None => format!("{} joined the mission", brawler.display_name),
+            };
+            self.log_and_broadcast_system_message(mission_id, content).await;
         } else {
              self.log_and_broadcast_system_message(mission_id, "A new member joined the mission".to_string()).await;
         }
@@ -163,7 +251,130 @@ where
         Ok(())
     }
 
-    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+    async fn request_to_join(&self, mission: &MissionModel, brawler_id: i32, slot_id: Option<i32>) -> AppResult<JoinRequestModel> {
+        self.check_joinable(mission, brawler_id).await?;
+        if self
+            .join_request_repository
+            .find_pending_by_brawler(mission.id, brawler_id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::Conflict("Join request already pending".to_string()));
+        }
+        let slots = self.crew_operation_repository.role_slots(mission.id).await?;
+        let role = RoleSlotModel::pick(&slots, slot_id)?;
+
+        let request_id = self
+            .join_request_repository
+            .create(AddMissionJoinRequestEntity {
+                mission_id: mission.id,
+                brawler_id,
+                role_slot_id: role.map(|slot| slot.id),
+            })
+            .await?;
+        let request = self.join_request_repository.find_one(request_id, mission.id).await?;
+
+        let notification = Notification {
+            recipient_id: Some(mission.chief_id),
+            title: "Join Request".to_string(),
+            message: format!("{} asked to join your mission: {}", request.brawler_display_name, mission.name),
+            notification_type: NotificationType::JoinRequest,
+            metadata: serde_json::json!({
+                "mission_id": mission.id,
+                "request_id": request.id,
+                "requester_id": brawler_id
+            }),
+        };
+        let _ = self.notification_service.send(notification).await;
+
+        Ok(request)
+    }
+
+    async fn chief_mission(&self, mission_id: i32, chief_id: i32) -> AppResult<MissionModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
+        if !mission.can_manage(chief_id) {
+            return Err(AppError::Forbidden("Only the Chief or a co-chief can review join requests".to_string()));
+        }
+        Ok(mission)
+    }
+
+    async fn pending_request(&self, mission_id: i32, request_id: i32) -> AppResult<JoinRequestModel> {
+        let request = self.join_request_repository.find_one(request_id, mission_id).await?;
+        if !request.is_pending() {
+            return Err(AppError::Conflict("Join request is no longer pending".to_string()));
+        }
+        Ok(request)
+    }
+
+    async fn notify_requester(&self, mission: &MissionModel, request: &JoinRequestModel, approved: bool) {
+        let (title, message) = if approved {
+            ("Join Request Approved", format!("You are now in the crew of: {}", mission.name))
+        } else {
+            ("Join Request Rejected", format!("Your request to join {} was declined", mission.name))
+        };
+        let notification = Notification {
+            recipient_id: Some(request.brawler_id),
+            title: title.to_string(),
+            message,
+            notification_type: NotificationType::JoinRequestDecision,
+            metadata: serde_json::json!({
+                "mission_id": mission.id,
+                "request_id": request.id,
+                "approved": approved
+            }),
+        };
+        let _ = self.notification_service.send(notification).await;
+    }
+
+    pub async fn get_join_requests(&self, mission_id: i32, chief_id: i32) -> AppResult<Vec<JoinRequestModel>> {
+        self.chief_mission(mission_id, chief_id).await?;
+        self.join_request_repository.find_pending(mission_id).await
+    }
+
+    pub async fn approve_join_request(&self, mission_id: i32, chief_id: i32, request_id: i32) -> AppResult<()> {
+        let mission = self.chief_mission(mission_id, chief_id).await?;
+        let request = self.pending_request(mission_id, request_id).await?;
+
+        self.check_joinable(&mission, request.brawler_id).await?;
+        // The asked-for slot may have been taken meanwhile; fall back to the first free one.
+        let slots = self.crew_operation_repository.role_slots(mission_id).await?;
+        let slot_id = RoleSlotModel::free_or_first_free(&slots, request.role_slot_id);
+        let role = RoleSlotModel::pick(&slots, slot_id)?;
+
+        self.admit(&mission, request.brawler_id, role).await?;
+        self.join_request_repository
+            .decide(request.id, JoinRequestStatus::Approved, Some(chief_id))
+            .await?;
+        self.notify_requester(&mission, &request, true).await;
+
+        Ok(())
+    }
+
+    pub async fn reject_join_request(&self, mission_id: i32, chief_id: i32, request_id: i32) -> AppResult<()> {
+        let mission = self.chief_mission(mission_id, chief_id).await?;
+        let request = self.pending_request(mission_id, request_id).await?;
+
+        self.join_request_repository
+            .decide(request.id, JoinRequestStatus::Rejected, Some(chief_id))
+            .await?;
+        self.notify_requester(&mission, &request, false).await;
+
+        Ok(())
+    }
+
+    pub async fn cancel_join_request(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        let request = self
+            .join_request_repository
+            .find_pending_by_brawler(mission_id, brawler_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("No pending join request".to_string()))?;
+
+        self.join_request_repository
+            .decide(request.id, JoinRequestStatus::Cancelled, None)
+            .await
+    }
+
+    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
         let mission = self.mission_viewing_repository.get_one(mission_id, brawler_id).await?;
         
         // Check if member exists? We can just try to leave.
@@ -171,6 +382,7 @@ where
             .leave(CrewMemberShips {
                 mission_id,
                 brawler_id,
+                role_slot_id: None,
             })
             .await;
             
@@ -195,32 +407,65 @@ where
             } else {
                 self.log_and_broadcast_system_message(mission_id, "A member left the mission".to_string()).await;
             }
+            self.promote_waitlist(mission_id).await;
         }
         
         result
     }
 
-    pub async fn kick_crew(&self, mission_id: i32, chief_id: i32, member_id: i32) -> Result<()> {
+    /// Removes a member; with `ban` the member also can not come back until the ban ends or is lifted.
+    pub async fn kick_crew(&self, mission_id: i32, chief_id: i32, member_id: i32, ban: Option<BanModel>) -> AppResult<()> {
         let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
 
-        if mission.chief_id != chief_id {
-            return Err(anyhow::anyhow!("Only the Chief can kick members"));
+        if !mission.can_manage(chief_id) {
+            return Err(AppError::Forbidden("Only the Chief or a co-chief can kick members".to_string()));
+        }
+        if member_id == mission.chief_id {
+            return Err(AppError::Forbidden("The Chief can not be kicked".to_string()));
+        }
+        let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
+        let member = crew
+            .iter()
+            .find(|member| member.id == member_id)
+            .ok_or_else(|| AppError::NotFound("Crew member not found".to_string()))?;
+        if mission.chief_id != chief_id && member.is_co_chief {
+            return Err(AppError::Forbidden("Only the Chief can kick a co-chief".to_string()));
         }
 
         self.crew_operation_repository
             .leave(CrewMemberShips {
                 mission_id,
                 brawler_id: member_id,
+                role_slot_id: None,
             })
             .await?;
+        self.withdraw_pending(mission_id, member_id).await?;
+
+        if let Some(ban) = &ban {
+            self.ban_repository
+                .ban(AddMissionBanEntity {
+                    mission_id,
+                    brawler_id: member_id,
+                    banned_by: Some(chief_id),
+                    reason: ban.trimmed_reason(),
+                    expires_at: ban.expires_at.map(|at| at.naive_utc()),
+                })
+                .await?;
+        }
+        self.realtime_service.evict(mission_id, member_id);
 
         // Notification: Notify Kicked Member
+        let message = match ban.as_ref().and_then(BanModel::trimmed_reason) {
+            Some(reason) => format!("You were kicked and banned from mission: {} ({})", mission.name, reason),
+            None if ban.is_some() => format!("You were kicked and banned from mission: {}", mission.name),
+            None => format!("You were kicked from mission: {}", mission.name),
+        };
         let notification = Notification {
             recipient_id: Some(member_id),
             title: "You have been kicked".to_string(),
-            message: format!("You were kicked from mission: {}", mission.name),
+            message,
             notification_type: NotificationType::MissionStatusUpdate, 
-            metadata: serde_json::json!({ "mission_id": mission_id }),
+            metadata: serde_json::json!({ "mission_id": mission_id, "banned": ban.is_some() }),
         };
         let _ = self.notification_service.send(notification).await;
 
@@ -230,7 +475,306 @@ where
         } else {
             self.log_and_broadcast_system_message(mission_id, "A member was kicked from the mission".to_string()).await;
         }
+        self.promote_waitlist(mission_id).await;
+
+        Ok(())
+    }
 
+    /// Drops the brawler's waitlist place and pending join request for the mission, if any.
+    async fn withdraw_pending(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        if let Some(entry) = self.waitlist_repository.find_active(mission_id, brawler_id).await? {
+            self.waitlist_repository.set_status(entry.id, WaitlistStatus::Left).await?;
+        }
+        if let Some(request) = self
+            .join_request_repository
+            .find_pending_by_brawler(mission_id, brawler_id)
+            .await?
+        {
+            self.join_request_repository
+                .decide(request.id, JoinRequestStatus::Cancelled, None)
+                .await?;
+        }
         Ok(())
     }
+
+    /// The mission, when the brawler may see and change its bans.
+    async fn ban_managed_mission(&self, mission_id: i32, manager_id: i32) -> AppResult<MissionModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id, manager_id).await?;
+        if !mission.can_manage(manager_id) {
+            return Err(AppError::Forbidden("Only the Chief or a co-chief can manage bans".to_string()));
+        }
+        Ok(mission)
+    }
+
+    pub async fn get_bans(&self, mission_id: i32, manager_id: i32) -> AppResult<Vec<MissionBanModel>> {
+        self.ban_managed_mission(mission_id, manager_id).await?;
+        self.ban_repository.find_by_mission(mission_id, Utc::now().naive_utc()).await
+    }
+
+    pub async fn lift_ban(&self, mission_id: i32, manager_id: i32, brawler_id: i32) -> AppResult<()> {
+        self.ban_managed_mission(mission_id, manager_id).await?;
+        self.ban_repository.lift(mission_id, brawler_id).await
+    }
+
+    /// Promotes a crew member to co-chief or demotes them again.
+    pub async fn set_co_chief(&self, mission_id: i32, chief_id: i32, member_id: i32, is_co_chief: bool) -> AppResult<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(AppError::Forbidden("Only the Chief can appoint co-chiefs".to_string()));
+        }
+
+        self.crew_operation_repository
+            .set_co_chief(mission_id, member_id, is_co_chief)
+            .await?;
+
+        let (title, message, log) = if is_co_chief {
+            ("You are now a co-chief", format!("You can now help run mission: {}", mission.name), "is now a co-chief")
+        } else {
+            ("You are no longer a co-chief", format!("You no longer help run mission: {}", mission.name), "is no longer a co-chief")
+        };
+        let _ = self
+            .notification_service
+            .send(Notification {
+                recipient_id: Some(member_id),
+                title: title.to_string(),
+                message,
+                notification_type: NotificationType::ChiefChange,
+                metadata: serde_json::json!({ "mission_id": mission_id, "is_co_chief": is_co_chief }),
+            })
+            .await;
+
+        if let Ok(brawler) = self.brawler_repository.find_by_id(member_id).await {
+            self.log_and_broadcast_system_message(mission_id, format!("{} {}", brawler.display_name, log)).await;
+        }
+        Ok(())
+    }
+
+    /// Hands the mission to a crew member; the old chief stays on as crew.
+    pub async fn transfer_chief(&self, mission_id: i32, chief_id: i32, new_chief_id: i32) -> AppResult<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(AppError::Forbidden("Only the Chief can hand over the mission".to_string()));
+        }
+        if new_chief_id == chief_id {
+            return Err(AppError::validation("brawler_id", "must be a crew member, not the chief"));
+        }
+
+        self.crew_operation_repository
+            .transfer_chief(mission_id, chief_id, new_chief_id)
+            .await?;
+
+        let _ = self
+            .notification_service
+            .send(Notification {
+                recipient_id: Some(new_chief_id),
+                title: "You are now the chief".to_string(),
+                message: format!("You now lead mission: {}", mission.name),
+                notification_type: NotificationType::ChiefChange,
+                metadata: serde_json::json!({ "mission_id": mission_id }),
+            })
+            .await;
+
+        if let Ok(brawler) = self.brawler_repository.find_by_id(new_chief_id).await {
+            self.log_and_broadcast_system_message(mission_id, format!("{} is now the chief", brawler.display_name)).await;
+        }
+        Ok(())
+    }
+
+    /// Missions whose lineup the chief may still change.
+    async fn editable_lineup(&self, mission_id: i32, chief_id: i32) -> AppResult<MissionModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
+        if !mission.can_manage(chief_id) {
+            return Err(AppError::Forbidden("Only the Chief or a co-chief can manage role slots".to_string()));
+        }
+        if mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string()
+        {
+            return Err(AppError::Conflict("Mission is already finished".to_string()));
+        }
+        Ok(mission)
+    }
+
+    pub async fn get_role_slots(&self, mission_id: i32, brawler_id: i32) -> AppResult<Vec<RoleSlotModel>> {
+        // Resolves visibility the same way the mission itself does.
+        self.mission_viewing_repository.get_one(mission_id, brawler_id).await?;
+        self.crew_operation_repository.role_slots(mission_id).await
+    }
+
+    pub async fn set_role_slots(&self, mission_id: i32, chief_id: i32, model: SetRoleSlotsModel) -> AppResult<Vec<RoleSlotModel>> {
+        let mission = self.editable_lineup(mission_id, chief_id).await?;
+
+        let labels = model.resolved_labels();
+        if labels.len() > mission.max_crew as usize {
+            return Err(AppError::validation(
+                "labels",
+                format!("must not have more slots than the mission's {} crew places", mission.max_crew),
+            ));
+        }
+
+        self.crew_operation_repository
+            .set_role_slots(mission_id, labels.clone())
+            .await?;
+
+        let content = if labels.is_empty() {
+            "Role slots were removed".to_string()
+        } else {
+            format!("Role slots set: {}", labels.join(", "))
+        };
+        self.log_and_broadcast_system_message(mission_id, content).await;
+
+        self.crew_operation_repository.role_slots(mission_id).await
+    }
+
+    pub async fn assign_role_slot(&self, mission_id: i32, chief_id: i32, model: AssignRoleSlotModel) -> AppResult<Vec<RoleSlotModel>> {
+        self.editable_lineup(mission_id, chief_id).await?;
+
+        let slots = self.crew_operation_repository.role_slots(mission_id).await?;
+        let label = match model.slot_id {
+            Some(slot_id) => Some(
+                slots
+                    .iter()
+                    .find(|slot| slot.id == slot_id)
+                    .map(|slot| slot.label.clone())
+                    .ok_or_else(|| AppError::validation("slot_id", "is not a role slot of this mission"))?,
+            ),
+            None => None,
+        };
+
+        self.crew_operation_repository
+            .assign_role_slot(mission_id, model.brawler_id, model.slot_id)
+            .await?;
+
+        let name = match self.brawler_repository.find_by_id(model.brawler_id).await {
+            Ok(brawler) => brawler.display_name,
+            Err(_) => "A member".to_string(),
+        };
+        let content = match label {
+            Some(label) => format!("{} was assigned to {}", name, label),
+            None => format!("{} no longer has a role", name),
+        };
+        self.log_and_broadcast_system_message(mission_id, content).await;
+
+        self.crew_operation_repository.role_slots(mission_id).await
+    }
+
+    async fn promote_waitlist(&self, mission_id: i32) {
+        promote_waitlist(
+            self.waitlist_repository.as_ref(),
+            self.notification_service.as_ref(),
+            &self.waitlist,
+            mission_id,
+        )
+        .await;
+    }
+
+    /// Passes lapsed offers on to the next brawlers in line. Returns the number of missions affected.
+    pub async fn expire_waitlist_offers(&self) -> AppResult<usize> {
+        let mission_ids = self
+            .waitlist_repository
+            .expire_offers(Utc::now().naive_utc())
+            .await?;
+        for mission_id in &mission_ids {
+            self.promote_waitlist(*mission_id).await;
+        }
+        Ok(mission_ids.len())
+    }
+
+    pub async fn get_waitlist(&self, mission_id: i32, brawler_id: i32) -> AppResult<Vec<WaitlistEntryModel>> {
+        self.mission_viewing_repository.get_one(mission_id, brawler_id).await?;
+        self.waitlist_repository.find_by_mission(mission_id).await
+    }
+
+    pub async fn join_waitlist(&self, mission_id: i32, brawler_id: i32) -> AppResult<WaitlistEntryModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id, brawler_id).await?;
+        if mission.join_policy.parse::<JoinPolicy>().unwrap_or_default() != JoinPolicy::Open {
+            return Err(AppError::Conflict("Only missions open to everyone have a waitlist".to_string()));
+        }
+        self.check_eligible(&mission, brawler_id).await?;
+        if self.has_room(&mission, brawler_id).await? {
+            return Err(AppError::Conflict("Mission has room; join it directly".to_string()));
+        }
+
+        self.waitlist_repository
+            .enqueue(AddMissionWaitlistEntity { mission_id, brawler_id })
+            .await?;
+        self.waitlist_repository
+            .find_active(mission_id, brawler_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Waitlist entry not found".to_string()))
+    }
+
+    /// Leaving also declines a pending offer, which then passes to the next brawler.
+    pub async fn leave_waitlist(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        let entry = self
+            .waitlist_repository
+            .find_active(mission_id, brawler_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Not on the waitlist".to_string()))?;
+
+        self.waitlist_repository.set_status(entry.id, WaitlistStatus::Left).await?;
+        if entry.is_offered() {
+            self.promote_waitlist(mission_id).await;
+        }
+        Ok(())
+    }
+
+    pub async fn confirm_waitlist_offer(&self, mission_id: i32, brawler_id: i32, slot_id: Option<i32>) -> AppResult<()> {
+        let entry = self
+            .waitlist_repository
+            .find_active(mission_id, brawler_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Not on the waitlist".to_string()))?;
+        let offer_open = entry.is_offered()
+            && entry.offer_expires_at.is_some_and(|at| at > Utc::now().naive_utc());
+        if !offer_open {
+            return Err(AppError::Conflict("No open place has been offered to you".to_string()));
+        }
+
+        let mission = self.mission_viewing_repository.get_one(mission_id, brawler_id).await?;
+        self.check_joinable(&mission, brawler_id).await?;
+        let slots = self.crew_operation_repository.role_slots(mission_id).await?;
+        let role = RoleSlotModel::pick(&slots, slot_id)?;
+        self.admit(&mission, brawler_id, role).await?;
+        self.waitlist_repository.set_status(entry.id, WaitlistStatus::Joined).await?;
+
+        Ok(())
+    }
+}
+
+/// Places held for promoted waitlist brawlers count as taken, except the brawler's own.
+/// Shared with invites, which admit crew through their own use case.
+pub(crate) async fn has_room(
+    mission_viewing_repository: &dyn MissionViewingRepository,
+    waitlist_repository: &dyn MissionWaitlistRepository,
+    mission: &MissionModel,
+    brawler_id: i32,
+) -> AppResult<bool> {
+    let crew_count = mission_viewing_repository.crew_counting(mission.id).await?;
+    let held = waitlist_repository
+        .count_held_places(mission.id, brawler_id, Utc::now().naive_utc())
+        .await?;
+    Ok(crew_count + held < mission.max_crew as i64)
+}
+
+/// Offers freed places to the next waitlisted brawlers. Failures are logged, not returned,
+/// so they never undo the leave, kick, edit or account deletion that freed the place.
+pub(crate) async fn promote_waitlist(
+    waitlist_repository: &dyn MissionWaitlistRepository,
+    notification_service: &dyn NotificationService,
+    waitlist: &MissionWaitlistEnv,
+    mission_id: i32,
+) {
+    let now = Utc::now();
+    let expires_at = now + Duration::minutes(waitlist.offer_window_minutes);
+    match waitlist_repository
+        .offer_open_places(mission_id, now.naive_utc(), expires_at.naive_utc())
+        .await
+    {
+        Ok(offers) => {
+            for offer in offers {
+                let _ = notification_service.send(offer.offer_notification()).await;
+            }
+        }
+        Err(e) => tracing::error!("Waitlist promotion for mission {} failed: {}", mission_id, e),
+    }
 }
@@ -1,31 +1,49 @@
 use std::sync::Arc;
-use anyhow::Result;
+use chrono::Utc;
+use crate::domain::errors::{AppError, AppResult};
 use crate::domain::{
     entities::mission_messages::NewMissionMessageEntity,
-    repositories::mission_message_repository::MissionMessageRepository,
+    repositories::{mission_message_repository::MissionMessageRepository, MissionBanRepository},
     value_objects::mission_message_model::MissionMessageModel,
 };
 
 use crate::application::services::mission_realtime::{MissionRealtimeService, ChatMessage};
 
-pub struct MissionChatUseCase<T> {
-    repository: Arc<T>,
+pub struct MissionChatUseCase<T1, T2> {
+    repository: Arc<T1>,
+    ban_repository: Arc<T2>,
     realtime_service: Arc<MissionRealtimeService>,
 }
 
-impl<T> MissionChatUseCase<T>
+impl<T1, T2> MissionChatUseCase<T1, T2>
 where
-    T: MissionMessageRepository + Send + Sync,
+    T1: MissionMessageRepository + Send + Sync,
+    T2: MissionBanRepository + Send + Sync,
 {
-    pub fn new(repository: Arc<T>, realtime_service: Arc<MissionRealtimeService>) -> Self {
-        Self { repository, realtime_service }
+    pub fn new(repository: Arc<T1>, ban_repository: Arc<T2>, realtime_service: Arc<MissionRealtimeService>) -> Self {
+        Self { repository, ban_repository, realtime_service }
     }
 
-    pub async fn get_messages(&self, mission_id: i32) -> Result<Vec<MissionMessageModel>> {
+    /// Banned brawlers lose access to the mission chat until the ban ends.
+    async fn check_not_banned(&self, mission_id: i32, user_id: i32) -> AppResult<()> {
+        let banned = self
+            .ban_repository
+            .find_active(mission_id, user_id, Utc::now().naive_utc())
+            .await?;
+        if banned.is_some() {
+            return Err(AppError::Forbidden("Banned from this mission".to_string()));
+        }
+        Ok(())
+    }
+
+    pub async fn get_messages(&self, mission_id: i32, user_id: i32) -> AppResult<Vec<MissionMessageModel>> {
+        self.check_not_banned(mission_id, user_id).await?;
         self.repository.get_by_mission_id(mission_id).await
     }
 
-    pub async fn send_message(&self, mission_id: i32, user_id: i32, content: String) -> Result<()> {
+    pub async fn send_message(&self, mission_id: i32, user_id: i32, content: String) -> AppResult<()> {
+        self.check_not_banned(mission_id, user_id).await?;
+
         let entity = NewMissionMessageEntity {
             mission_id,
             user_id: Some(user_id),
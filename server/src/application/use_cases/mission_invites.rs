@@ -1,8 +1,10 @@
 use crate::domain::{
     entities::{
-        mission_invites::{MissionInviteDetails, MissionInvite, NewMissionInvite},
+        mission_invites::{MissionInviteDetails, MissionInvite, NewMissionInvite, SentMissionInvite},
         crew_memberships::CrewMemberShips,
         mission_messages::NewMissionMessageEntity,
+        notification::{Notification, NotificationType},
+        mission_invite_links::AddMissionInviteLinkEntity,
     },
     repositories::{
         mission_invites::MissionInviteRepository,
@@ -11,12 +13,27 @@ use crate::domain::{
         mission_message_repository::MissionMessageRepository,
         brawlers::BrawlerRepository,
         achievements::AchievementRepository,
+        mission_bans::MissionBanRepository,
+        mission_invite_links::MissionInviteLinkRepository,
+        mission_waitlist::MissionWaitlistRepository,
+    },
+    services::notification_service::NotificationService,
+    value_objects::{
+        invite_link_model::{AddInviteLinkModel, CreatedInviteLinkModel, InviteLinkModel, InviteLinkPreviewModel, InviteLinkUseModel},
+        invite_model::{BulkInviteModel, BulkInviteOutcome, BulkInviteResultModel, InviteStatus},
+        mission_model::MissionModel,
+        mission_role_model::RoleSlotModel,
+        mission_statuses::MissionStatuses,
     },
 };
-use anyhow::{anyhow, Result};
+use crate::config::{config_loader::get_mission_invite_env, config_model::MissionInviteEnv};
+use crate::domain::errors::{AppError, AppResult};
+use crate::infrastructure::secure_token;
+use chrono::{Duration, NaiveDateTime, Utc};
 use std::sync::Arc;
 
 use crate::application::services::mission_realtime::{MissionRealtimeService, ChatMessage};
+use crate::application::use_cases::crew_operation::has_room;
 
 pub struct MissionInviteUseCase {
     invite_repo: Arc<dyn MissionInviteRepository>,
@@ -25,10 +42,16 @@ pub struct MissionInviteUseCase {
     brawler_repo: Arc<dyn BrawlerRepository>,
     message_repo: Arc<dyn MissionMessageRepository>,
     achievement_repo: Arc<dyn AchievementRepository>,
+    ban_repo: Arc<dyn MissionBanRepository>,
+    link_repo: Arc<dyn MissionInviteLinkRepository>,
+    waitlist_repo: Arc<dyn MissionWaitlistRepository>,
+    notification_service: Arc<dyn NotificationService>,
     realtime_service: Arc<MissionRealtimeService>,
+    invites: MissionInviteEnv,
 }
 
 impl MissionInviteUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         invite_repo: Arc<dyn MissionInviteRepository>,
         mission_repo: Arc<dyn MissionViewingRepository>,
@@ -36,6 +59,10 @@ impl MissionInviteUseCase {
         brawler_repo: Arc<dyn BrawlerRepository>,
         message_repo: Arc<dyn MissionMessageRepository>,
         achievement_repo: Arc<dyn AchievementRepository>,
+        ban_repo: Arc<dyn MissionBanRepository>,
+        link_repo: Arc<dyn MissionInviteLinkRepository>,
+        waitlist_repo: Arc<dyn MissionWaitlistRepository>,
+        notification_service: Arc<dyn NotificationService>,
         realtime_service: Arc<MissionRealtimeService>,
     ) -> Self {
         Self {
@@ -45,7 +72,12 @@ impl MissionInviteUseCase {
             brawler_repo,
             message_repo,
             achievement_repo,
+            ban_repo,
+            link_repo,
+            waitlist_repo,
+            notification_service,
             realtime_service,
+            invites: get_mission_invite_env(),
         }
     }
 
@@ -62,57 +94,176 @@ impl MissionInviteUseCase {
         self.realtime_service.broadcast(mission_id, msg);
     }
 
-    pub async fn invite(&self, mission_id: i32, inviter_id: i32, user_id: i32) -> Result<MissionInvite> {
-        let max_crew = self.mission_repo.crew_counting(mission_id).await?;
-        // Simplified check: assume invite logic is handled after proper checks
-        
-        if self.crew_repo.is_member(user_id, mission_id).await? {
-             return Err(anyhow::anyhow!("User is already a member"));
+    async fn is_banned(&self, mission_id: i32, user_id: i32) -> AppResult<bool> {
+        let ban = self
+            .ban_repo
+            .find_active(mission_id, user_id, Utc::now().naive_utc())
+            .await?;
+        Ok(ban.is_some())
+    }
+
+    async fn managed_mission(&self, mission_id: i32, manager_id: i32) -> AppResult<MissionModel> {
+        // Deleted missions read as not found.
+        let mission = self.mission_repo.get_one(mission_id, manager_id).await?;
+        if !mission.can_manage(manager_id) {
+            return Err(AppError::Forbidden("Only the chief or a co-chief can manage invites".to_string()));
+        }
+        Ok(mission)
+    }
+
+    fn check_joinable(mission: &MissionModel) -> AppResult<()> {
+        let joinable = mission.status == MissionStatuses::Open.to_string()
+            || mission.status == MissionStatuses::InProgress.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+        if !joinable {
+            return Err(AppError::Conflict("Mission is not joinable".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Checks shared by sending and accepting an invite or invite link: the mission takes crew,
+    /// has room and the brawler is neither in it nor banned from it.
+    async fn check_invitable(&self, mission: &MissionModel, user_id: i32) -> AppResult<()> {
+        Self::check_joinable(mission)?;
+        if mission.chief_id == user_id || self.crew_repo.is_member(mission.id, user_id).await? {
+            return Err(AppError::Conflict("User is already a member".to_string()));
+        }
+        if self.is_banned(mission.id, user_id).await? {
+            return Err(AppError::Forbidden("User is banned from this mission".to_string()));
         }
-        
-        if self.invite_repo.check_exists(mission_id, user_id).await? {
-            return Err(anyhow::anyhow!("User is already invited"));
+        self.check_room(mission, user_id).await
+    }
+
+    /// Places held for promoted waitlist brawlers are not up for grabs.
+    async fn check_room(&self, mission: &MissionModel, user_id: i32) -> AppResult<()> {
+        if !has_room(self.mission_repo.as_ref(), self.waitlist_repo.as_ref(), mission, user_id).await? {
+            return Err(AppError::Conflict("Mission is full".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn notify_invited(&self, mission: &MissionModel, inviter_id: i32, user_id: i32, invite_id: i32, expires_at: NaiveDateTime) {
+        let notification = Notification {
+            recipient_id: Some(user_id),
+            title: "Mission Invite".to_string(),
+            message: format!("You were invited to join the mission: {}", mission.name),
+            notification_type: NotificationType::MissionInvite,
+            metadata: serde_json::json!({
+                "mission_id": mission.id,
+                "invite_id": invite_id,
+                "inviter_id": inviter_id,
+                "expires_at": expires_at
+            }),
+        };
+        let _ = self.notification_service.send(notification).await;
+    }
+
+    /// A pending invite addressed to `user_id`; one past its expiry is marked expired on the spot.
+    async fn open_invite(&self, invite_id: i32, user_id: i32) -> AppResult<MissionInvite> {
+        let invite = self.invite_repo.find_by_id(invite_id).await?
+            .ok_or_else(|| AppError::NotFound("Invite not found".to_string()))?;
+
+        if invite.user_id != user_id {
+            return Err(AppError::Forbidden("Not authorized to answer this invite".to_string()));
+        }
+        if invite.status != InviteStatus::Pending.to_string() {
+            return Err(AppError::Conflict("Invite is not pending".to_string()));
+        }
+        if invite.is_expired(Utc::now().naive_utc()) {
+            self.invite_repo.update_status(invite.id, InviteStatus::Expired).await?;
+            return Err(AppError::Conflict("Invite has expired".to_string()));
+        }
+        Ok(invite)
+    }
+
+    pub async fn invite(&self, mission_id: i32, inviter_id: i32, user_id: i32) -> AppResult<MissionInvite> {
+        let mission = self.managed_mission(mission_id, inviter_id).await?;
+        // Also rejects unknown brawlers.
+        let brawler = self.brawler_repo.find_by_id(user_id).await?;
+        self.check_invitable(&mission, user_id).await?;
+
+        let now = Utc::now();
+        if self.invite_repo.check_exists(mission_id, user_id, now.naive_utc()).await? {
+            return Err(AppError::Conflict("User is already invited".to_string()));
         }
 
         let invite = self.invite_repo.create(NewMissionInvite {
             mission_id,
             user_id,
-            status: "pending".to_string(),
+            status: InviteStatus::Pending.to_string(),
+            invited_by: Some(inviter_id),
+            expires_at: (now + Duration::hours(self.invites.ttl_hours)).naive_utc(),
         }).await?;
 
-        // Broadcast invite if needed
-        if let Ok(brawler) = self.brawler_repo.find_by_id(user_id).await {
-            self.broadcast_system_message(mission_id, format!("{} was invited to the mission", brawler.username));
-        }
+        self.notify_invited(&mission, inviter_id, user_id, invite.id, invite.expires_at).await;
+
+        self.broadcast_system_message(mission_id, format!("{} was invited to the mission", brawler.username));
 
         Ok(invite)
     }
 
-    pub async fn accept(&self, invite_id: i32, user_id: i32) -> Result<()> {
-        let invite = self.invite_repo.find_by_id(invite_id).await?
-            .ok_or_else(|| anyhow::anyhow!("Invite not found"))?;
+    /// Invites many brawlers in one go. Recipients who cannot be invited are reported rather
+    /// than failing the batch; the room gets one message naming everyone invited.
+    pub async fn invite_many(&self, mission_id: i32, inviter_id: i32, model: BulkInviteModel) -> AppResult<Vec<BulkInviteResultModel>> {
+        let mission = self.managed_mission(mission_id, inviter_id).await?;
+        Self::check_joinable(&mission)?;
+        self.check_room(&mission, inviter_id).await?;
 
-        if invite.user_id != user_id {
-            return Err(anyhow::anyhow!("Not authorized to accept this invite"));
+        let now = Utc::now();
+        let expires_at = (now + Duration::hours(self.invites.ttl_hours)).naive_utc();
+        let results = self
+            .invite_repo
+            .create_many(mission_id, inviter_id, model.recipients(), expires_at, now.naive_utc())
+            .await?;
+
+        let mut invited = Vec::new();
+        for result in &results {
+            if let (BulkInviteOutcome::Invited, Some(user_id), Some(invite_id)) = (result.outcome, result.user_id, result.invite_id) {
+                self.notify_invited(&mission, inviter_id, user_id, invite_id, expires_at).await;
+                invited.extend(result.username.clone());
+            }
         }
-        if invite.status != "pending" {
-            return Err(anyhow::anyhow!("Invite is not pending"));
+        if !invited.is_empty() {
+            self.broadcast_system_message(mission_id, Self::invited_message(&invited));
         }
 
-        let mission = self.mission_repo.get_one(invite.mission_id, user_id).await?;
-        if mission.crew_count >= mission.max_crew as i64 {
-            return Err(anyhow::anyhow!("Mission is full"));
+        Ok(results)
+    }
+
+    /// "Alpha was invited…", "Alpha and Bravo were invited…", "Alpha, Bravo and Charlie were invited…".
+    fn invited_message(usernames: &[String]) -> String {
+        match usernames {
+            [only] => format!("{only} was invited to the mission"),
+            [rest @ .., last] => format!("{} and {last} were invited to the mission", rest.join(", ")),
+            [] => String::new(),
         }
+    }
+
+    /// Joins the invited mission. `slot_id` follows the rules of a direct join: while free
+    /// role slots remain one must be chosen.
+    pub async fn accept(&self, invite_id: i32, user_id: i32, slot_id: Option<i32>) -> AppResult<()> {
+        let invite = self.open_invite(invite_id, user_id).await?;
+
+        let mission = self.mission_repo.get_one(invite.mission_id, user_id).await?;
+        self.check_invitable(&mission, user_id).await?;
+        let slots = self.crew_repo.role_slots(mission.id).await?;
+        let role = RoleSlotModel::pick(&slots, slot_id)?;
 
         // Add member
         self.crew_repo.join(CrewMemberShips {
             mission_id: invite.mission_id,
             brawler_id: user_id,
+            role_slot_id: role.map(|slot| slot.id),
         }).await?;
 
         // Update invite status
-        self.invite_repo.update_status(invite_id, "accepted".to_string()).await?;
+        self.invite_repo.update_status(invite_id, InviteStatus::Accepted).await?;
 
+        self.welcome(invite.mission_id, user_id, "via invite").await
+    }
+
+    /// Achievements and the room announcement after someone joined through an invite or link.
+    async fn welcome(&self, mission_id: i32, user_id: i32, how: &str) -> AppResult<()> {
         // Update stats
         // self.brawler_repo.increment_join_count(user_id).await?; // Done in crew_repo.join
         if let Ok(brawler) = self.brawler_repo.find_by_id(user_id).await {
@@ -121,9 +272,9 @@ impl MissionInviteUseCase {
 
         // System Message
         let brawler = self.brawler_repo.find_by_id(user_id).await?; // Assuming it returns BrawlerEntity directly on success
-        let msg_content = format!("{} joined the mission via invite", brawler.username);
+        let msg_content = format!("{} joined the mission {}", brawler.username, how);
         let msg = NewMissionMessageEntity {
-            mission_id: invite.mission_id,
+            mission_id,
             user_id: None,
             content: msg_content.clone(),
             type_: "system".to_string(),
@@ -131,24 +282,141 @@ impl MissionInviteUseCase {
         self.message_repo.create(msg).await?;
 
         // Broadcast accept
-        self.broadcast_system_message(invite.mission_id, msg_content);
+        self.broadcast_system_message(mission_id, msg_content);
 
         Ok(())
     }
 
-    pub async fn decline(&self, invite_id: i32, user_id: i32) -> Result<()> {
+    pub async fn decline(&self, invite_id: i32, user_id: i32) -> AppResult<()> {
+        let invite = self.open_invite(invite_id, user_id).await?;
+        self.invite_repo.update_status(invite.id, InviteStatus::Rejected).await?;
+        Ok(())
+    }
+
+    /// Withdraws a pending invite; the chief or any co-chief may cancel it.
+    pub async fn cancel(&self, invite_id: i32, manager_id: i32) -> AppResult<()> {
         let invite = self.invite_repo.find_by_id(invite_id).await?
-            .ok_or_else(|| anyhow::anyhow!("Invite not found"))?;
+            .ok_or_else(|| AppError::NotFound("Invite not found".to_string()))?;
+        self.managed_mission(invite.mission_id, manager_id).await?;
 
-        if invite.user_id != user_id {
-            return Err(anyhow::anyhow!("Not authorized to decline this invite"));
+        if invite.status != InviteStatus::Pending.to_string() {
+            return Err(AppError::Conflict("Invite is not pending".to_string()));
         }
-        
-        self.invite_repo.update_status(invite_id, "rejected".to_string()).await?;
+        self.invite_repo.update_status(invite.id, InviteStatus::Cancelled).await?;
         Ok(())
     }
 
-    pub async fn get_my_pending_invites(&self, user_id: i32) -> Result<Vec<MissionInviteDetails>> {
-        self.invite_repo.find_invites_details_by_user(user_id).await
+    pub async fn get_mission_invites(&self, mission_id: i32, manager_id: i32) -> AppResult<Vec<SentMissionInvite>> {
+        self.managed_mission(mission_id, manager_id).await?;
+        self.invite_repo.find_invites_by_mission(mission_id).await
+    }
+
+    /// Marks lapsed pending invites as expired. Returns how many.
+    pub async fn expire_invites(&self) -> AppResult<usize> {
+        self.invite_repo.expire_invites(Utc::now().naive_utc()).await
+    }
+
+    /// Creates a shareable link; the token is returned this once and only its hash is kept.
+    pub async fn create_link(&self, mission_id: i32, manager_id: i32, model: AddInviteLinkModel) -> AppResult<CreatedInviteLinkModel> {
+        let mission = self.managed_mission(mission_id, manager_id).await?;
+        Self::check_joinable(&mission)?;
+
+        if let Some(slot_id) = model.role_slot_id {
+            let slots = self.crew_repo.role_slots(mission_id).await?;
+            if !slots.iter().any(|slot| slot.id == slot_id) {
+                return Err(AppError::validation("role_slot_id", "is not a role slot of this mission"));
+            }
+        }
+
+        let token = secure_token::generate(32);
+        let link = self.link_repo.create(AddMissionInviteLinkEntity {
+            mission_id,
+            token_hash: secure_token::hash(&token),
+            created_by: Some(manager_id),
+            role_slot_id: model.role_slot_id,
+            max_uses: model.max_uses,
+            expires_at: model.expires_at.map(|at| at.naive_utc()),
+        }).await?;
+
+        Ok(CreatedInviteLinkModel { token, link })
+    }
+
+    pub async fn get_links(&self, mission_id: i32, manager_id: i32) -> AppResult<Vec<InviteLinkModel>> {
+        self.managed_mission(mission_id, manager_id).await?;
+        self.link_repo.find_by_mission(mission_id).await
+    }
+
+    pub async fn get_link_uses(&self, mission_id: i32, link_id: i32, manager_id: i32) -> AppResult<Vec<InviteLinkUseModel>> {
+        self.managed_mission(mission_id, manager_id).await?;
+        let links = self.link_repo.find_by_mission(mission_id).await?;
+        if !links.iter().any(|link| link.id == link_id) {
+            return Err(AppError::NotFound("Invite link not found".to_string()));
+        }
+        self.link_repo.find_uses(link_id).await
+    }
+
+    pub async fn revoke_link(&self, mission_id: i32, link_id: i32, manager_id: i32) -> AppResult<()> {
+        self.managed_mission(mission_id, manager_id).await?;
+        self.link_repo.revoke(link_id, mission_id, Utc::now().naive_utc()).await
+    }
+
+    /// Public summary of the mission behind a link. Revoked, lapsed and used-up links read as not found.
+    pub async fn preview_link(&self, token: &str) -> AppResult<InviteLinkPreviewModel> {
+        self.link_repo
+            .preview(secure_token::hash(token), Utc::now().naive_utc())
+            .await?
+            .ok_or_else(|| AppError::NotFound("Invite link not found".to_string()))
+    }
+
+    /// Joins through a link with the same checks as accepting an invite. The link's role slot
+    /// is taken when it is still free, otherwise the first free one; only once every slot is
+    /// filled does the brawler join without a role.
+    pub async fn accept_link(&self, token: &str, user_id: i32) -> AppResult<()> {
+        let link = self.link_repo.find_by_token_hash(secure_token::hash(token)).await?
+            .ok_or_else(|| AppError::NotFound("Invite link not found".to_string()))?;
+
+        let now = Utc::now().naive_utc();
+        if link.revoked_at.is_some() {
+            return Err(AppError::Conflict("Invite link has been revoked".to_string()));
+        }
+        if link.is_expired(now) {
+            return Err(AppError::Conflict("Invite link has expired".to_string()));
+        }
+        if link.is_used_up() {
+            return Err(AppError::Conflict("Invite link has no uses left".to_string()));
+        }
+
+        let mission = self.mission_repo.get_one(link.mission_id, user_id).await?;
+        self.check_invitable(&mission, user_id).await?;
+        // A shared link reaches anyone, so unlike a personal invite it keeps the reputation bar.
+        if mission.min_reputation.is_some() {
+            let brawler = self.brawler_repo.find_by_id(user_id).await?;
+            mission.check_reputation(brawler.reputation)?;
+        }
+
+        let slots = self.crew_repo.role_slots(mission.id).await?;
+        let slot_id = RoleSlotModel::free_or_first_free(&slots, link.role_slot_id);
+        let role = RoleSlotModel::pick(&slots, slot_id)?;
+
+        let counted = self.link_repo.record_use(link.id, user_id, now).await?;
+        // The join re-checks room under the mission lock and the slot through its unique index;
+        // losing either race gives the use back.
+        let joined = self.crew_repo.join(CrewMemberShips {
+            mission_id: mission.id,
+            brawler_id: user_id,
+            role_slot_id: role.map(|slot| slot.id),
+        }).await;
+        if let Err(e) = joined {
+            if counted {
+                self.link_repo.release_use(link.id, user_id).await?;
+            }
+            return Err(e);
+        }
+
+        self.welcome(mission.id, user_id, "via invite link").await
+    }
+
+    pub async fn get_my_pending_invites(&self, user_id: i32) -> AppResult<Vec<MissionInviteDetails>> {
+        self.invite_repo.find_invites_details_by_user(user_id, Utc::now().naive_utc()).await
     }
 }
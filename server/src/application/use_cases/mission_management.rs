@@ -1,50 +1,71 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Duration, Utc};
+
+use crate::config::{
+    config_loader::{get_mission_retention_env, get_mission_waitlist_env},
+    config_model::{MissionRetentionEnv, MissionWaitlistEnv},
+};
 use crate::domain::{
     repositories::{
         mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+        mission_waitlist::MissionWaitlistRepository,
     },
-    value_objects::{AddMissionModel, EditMissionModel, MissionModel},
+    services::notification_service::NotificationService,
+    value_objects::{AddMissionModel, EditMissionModel},
 };
 
-pub struct MissionManagementUseCase<T1, T2>
+pub struct MissionManagementUseCase<T1, T2, T3>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionWaitlistRepository + Send + Sync,
 {
     mission_management_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    waitlist_repository: Arc<T3>,
+    notification_service: Arc<dyn NotificationService>,
+    retention: MissionRetentionEnv,
+    waitlist: MissionWaitlistEnv,
 }
 
-use anyhow::Result;
+use crate::domain::errors::{AppError, AppResult};
+use crate::application::use_cases::crew_operation::promote_waitlist;
 
-impl<T1, T2> MissionManagementUseCase<T1, T2>
+impl<T1, T2, T3> MissionManagementUseCase<T1, T2, T3>
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionWaitlistRepository + Send + Sync,
 {
     pub fn new(
         mission_management_repository: Arc<T1>,
         mission_viewing_repository: Arc<T2>,
+        waitlist_repository: Arc<T3>,
+        notification_service: Arc<dyn NotificationService>,
     ) -> Self {
         Self {
             mission_management_repository,
             mission_viewing_repository,
+            waitlist_repository,
+            notification_service,
+            retention: get_mission_retention_env(),
+            waitlist: get_mission_waitlist_env(),
         }
     }
 
-    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> Result<i32> {
-        if add_mission_model.name.trim().is_empty() || add_mission_model.name.trim().len() < 3 {
-            return Err(anyhow::anyhow!(
-                "Mission name must be least 4 characters long"
-            ));
+    pub async fn add(&self, chief_id: i32, mut add_mission_model: AddMissionModel) -> AppResult<i32> {
+        let now = Utc::now();
+        if add_mission_model.scheduled_start_at.is_some_and(|start| start <= now) {
+            return Err(AppError::validation("scheduled_start_at", "must be in the future"));
         }
-
-        if add_mission_model.max_crew < 2 || add_mission_model.max_crew > 10 {
-            return Err(anyhow::anyhow!(
-                "Mission capacity must be between 2 and 10"
-            ));
+        if add_mission_model.deadline_at.is_none() {
+            add_mission_model.deadline_at = default_deadline(
+                add_mission_model.scheduled_start_at,
+                add_mission_model.duration_minutes,
+            );
         }
+        check_deadline(now, add_mission_model.scheduled_start_at, add_mission_model.deadline_at)?;
 
         let insert_mission_entity = add_mission_model.to_entity(chief_id);
 
@@ -59,28 +80,20 @@ where
     pub async fn edit(
         &self,
         mission_id: i32,
-        chief_id: i32,
+        brawler_id: i32,
         mut edit_mission_model: EditMissionModel,
-    ) -> Result<i32> {
+    ) -> AppResult<i32> {
+        let chief_id = self.managed_chief_id(mission_id, brawler_id).await?;
+
         if let Some(name) = edit_mission_model.name {
             if name.trim().is_empty() {
                 edit_mission_model.name = None;
-            } else if name.trim().len() < 3 {
-                return Err(anyhow::anyhow!(
-                    "Mission name must be least 4 characters long"
-                ));
             } else {
                 edit_mission_model.name = Some(name.trim().to_string())
             }
         }
 
         if let Some(max_crew) = edit_mission_model.max_crew {
-            if max_crew < 2 || max_crew > 10 {
-                return Err(anyhow::anyhow!(
-                    "Mission capacity must be between 2 and 10"
-                ));
-            }
-
             // Check current crew count
             let current_crew_count = self
                 .mission_viewing_repository
@@ -89,13 +102,23 @@ where
                 .unwrap_or(0); // If fails, defaulting to 0 might be risky but acceptable for basic validation
             
             if (max_crew as i64) < current_crew_count {
-                 return Err(anyhow::anyhow!(
+                 return Err(AppError::Conflict(format!(
                     "Cannot reduce capacity below current crew count ({})",
                     current_crew_count
-                ));
+                )));
             }
         }
 
+        if edit_mission_model.scheduled_start_at.is_some()
+            || edit_mission_model.deadline_at.is_some()
+            || edit_mission_model.duration_minutes.is_some()
+        {
+            self.check_edited_schedule(mission_id, chief_id, &mut edit_mission_model)
+                .await?;
+        }
+
+        let opens_places =
+            edit_mission_model.max_crew.is_some() || edit_mission_model.join_policy.is_some();
         let edit_mission_entity = edit_mission_model.to_entity(chief_id);
 
         let result = self
@@ -103,24 +126,97 @@ where
             .edit(mission_id, edit_mission_entity)
             .await?;
 
+        if opens_places {
+            // A larger crew or a reopened mission frees places for the waitlist.
+            promote_waitlist(
+                self.waitlist_repository.as_ref(),
+                self.notification_service.as_ref(),
+                &self.waitlist,
+                mission_id,
+            )
+            .await;
+        }
+
         Ok(result)
     }
 
+    /// The chief of a mission managed by `brawler_id`, who must be its chief or a co-chief.
+    async fn managed_chief_id(&self, mission_id: i32, brawler_id: i32) -> AppResult<i32> {
+        let mission = self
+            .mission_viewing_repository
+            .get_one(mission_id, brawler_id)
+            .await?;
+        if !mission.can_manage(brawler_id) {
+            return Err(AppError::Forbidden(
+                "Only the chief or a co-chief can manage this mission".to_string(),
+            ));
+        }
+        Ok(mission.chief_id)
+    }
+
+    /// Checks the schedule the mission will have once `edit` is applied.
+    async fn check_edited_schedule(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        edit_mission_model: &mut EditMissionModel,
+    ) -> AppResult<()> {
+        let current = self
+            .mission_viewing_repository
+            .get_one(mission_id, chief_id)
+            .await?;
+
+        // Resending the current start is not a reschedule.
+        if edit_mission_model.scheduled_start_at.map(|at| at.naive_utc()) == current.scheduled_start_at {
+            edit_mission_model.scheduled_start_at = None;
+        }
+        let now = Utc::now();
+        if edit_mission_model.scheduled_start_at.is_some_and(|start| start <= now) {
+            return Err(AppError::validation("scheduled_start_at", "must be in the future"));
+        }
+        let start = edit_mission_model
+            .scheduled_start_at
+            .or(current.scheduled_start_at.map(|at| at.and_utc()));
+        let duration = edit_mission_model.duration_minutes.or(current.duration_minutes);
 
+        if edit_mission_model.deadline_at.is_none() && current.deadline_at.is_none() {
+            edit_mission_model.deadline_at = default_deadline(start, duration);
+        }
+        let deadline = edit_mission_model
+            .deadline_at
+            .or(current.deadline_at.map(|at| at.and_utc()));
 
-    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+        check_deadline(now, start, deadline)
+    }
+
+    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
         self.mission_management_repository
             .remove(mission_id, chief_id)
             .await?;
         Ok(())
     }
 
+    pub async fn restore(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
+        self.mission_management_repository
+            .restore(mission_id, chief_id, self.retention.retention_days)
+            .await
+    }
+
+    /// Run periodically by the purge job.
+    pub async fn purge_expired(&self) -> AppResult<usize> {
+        self.mission_management_repository
+            .purge_deleted(self.retention.retention_days)
+            .await
+    }
+
     pub async fn upload_image(
         &self,
         mission_id: i32,
-        chief_id: i32,
+        brawler_id: i32,
         base64string: String,
-    ) -> Result<crate::domain::value_objects::uploaded_img::UploadedImg> {
+    ) -> AppResult<crate::domain::value_objects::uploaded_img::UploadedImg> {
+        let chief_id = self.managed_chief_id(mission_id, brawler_id).await?;
+
         use crate::domain::value_objects::base64_img::Base64Img;
         use crate::infrastructure::cloudinary::UploadImageOptions;
 
@@ -140,3 +236,24 @@ where
         Ok(uploaded)
     }
 }
+
+fn default_deadline(start: Option<DateTime<Utc>>, duration_minutes: Option<i32>) -> Option<DateTime<Utc>> {
+    Some(start? + Duration::minutes(i64::from(duration_minutes?)))
+}
+
+fn check_deadline(
+    now: DateTime<Utc>,
+    start: Option<DateTime<Utc>>,
+    deadline: Option<DateTime<Utc>>,
+) -> AppResult<()> {
+    match (start, deadline) {
+        (Some(start), Some(deadline)) if deadline <= start => Err(AppError::validation(
+            "deadline_at",
+            "must be after scheduled_start_at",
+        )),
+        (None, Some(deadline)) if deadline <= now => {
+            Err(AppError::validation("deadline_at", "must be in the future"))
+        }
+        _ => Ok(()),
+    }
+}
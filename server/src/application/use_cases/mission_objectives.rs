@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use crate::{
+    application::services::mission_realtime::{ChatMessage, MissionRealtimeService},
+    domain::{
+        entities::mission_messages::NewMissionMessageEntity,
+        errors::{AppError, AppResult},
+        repositories::{
+            mission_message_repository::MissionMessageRepository,
+            mission_objectives::MissionObjectiveRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            MissionModel,
+            mission_objective_model::{
+                AddMissionObjectiveModel, EditMissionObjectiveModel, MissionObjectiveModel,
+                ReorderMissionObjectivesModel,
+            },
+            mission_statuses::MissionStatuses,
+        },
+    },
+};
+
+/// Keeps a checklist readable and bounds the cost of reordering it.
+const MAX_OBJECTIVES_PER_MISSION: usize = 50;
+
+pub struct MissionObjectiveUseCase<T1, T2, T3>
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    objective_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+    mission_message_repository: Arc<T3>,
+    realtime_service: Arc<MissionRealtimeService>,
+}
+
+impl<T1, T2, T3> MissionObjectiveUseCase<T1, T2, T3>
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    pub fn new(
+        objective_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        mission_message_repository: Arc<T3>,
+        realtime_service: Arc<MissionRealtimeService>,
+    ) -> Self {
+        Self {
+            objective_repository,
+            mission_viewing_repository,
+            mission_message_repository,
+            realtime_service,
+        }
+    }
+
+    pub async fn get_all(&self, mission_id: i32, brawler_id: i32) -> AppResult<Vec<MissionObjectiveModel>> {
+        // Deleted missions read as not found.
+        self.mission_viewing_repository.get_one(mission_id, brawler_id).await?;
+        self.objective_repository.find_by_mission(mission_id).await
+    }
+
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        model: AddMissionObjectiveModel,
+    ) -> AppResult<MissionObjectiveModel> {
+        self.editable_mission(mission_id, chief_id).await?;
+
+        let existing = self.objective_repository.find_by_mission(mission_id).await?;
+        if existing.len() >= MAX_OBJECTIVES_PER_MISSION {
+            return Err(AppError::Conflict(format!(
+                "A mission can have at most {} objectives",
+                MAX_OBJECTIVES_PER_MISSION
+            )));
+        }
+
+        let objective_id = self
+            .objective_repository
+            .create(model.to_entity(mission_id))
+            .await?;
+        let objective = self.objective_repository.find_one(objective_id, mission_id).await?;
+
+        self.log_and_broadcast(mission_id, format!("Objective added: {}", objective.title))
+            .await;
+
+        Ok(objective)
+    }
+
+    pub async fn edit(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+        chief_id: i32,
+        model: EditMissionObjectiveModel,
+    ) -> AppResult<MissionObjectiveModel> {
+        self.editable_mission(mission_id, chief_id).await?;
+
+        let changes = model.to_entity();
+        if changes.title.is_none() && changes.is_mandatory.is_none() {
+            return self.objective_repository.find_one(objective_id, mission_id).await;
+        }
+
+        self.objective_repository
+            .edit(objective_id, mission_id, changes)
+            .await?;
+        let objective = self.objective_repository.find_one(objective_id, mission_id).await?;
+
+        self.log_and_broadcast(mission_id, format!("Objective updated: {}", objective.title))
+            .await;
+
+        Ok(objective)
+    }
+
+    pub async fn remove(&self, mission_id: i32, objective_id: i32, chief_id: i32) -> AppResult<()> {
+        self.editable_mission(mission_id, chief_id).await?;
+
+        let objective = self.objective_repository.find_one(objective_id, mission_id).await?;
+        self.objective_repository.remove(objective_id, mission_id).await?;
+
+        self.log_and_broadcast(mission_id, format!("Objective removed: {}", objective.title))
+            .await;
+
+        Ok(())
+    }
+
+    pub async fn reorder(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        model: ReorderMissionObjectivesModel,
+    ) -> AppResult<Vec<MissionObjectiveModel>> {
+        self.editable_mission(mission_id, chief_id).await?;
+
+        self.objective_repository
+            .reorder(mission_id, model.objective_ids)
+            .await?;
+
+        self.log_and_broadcast(mission_id, "Objectives reordered".to_string())
+            .await;
+
+        self.objective_repository.find_by_mission(mission_id).await
+    }
+
+    /// The chief and crew members can tick objectives off; the objective records who did.
+    pub async fn complete(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+        brawler_id: i32,
+    ) -> AppResult<MissionObjectiveModel> {
+        self.participating_mission(mission_id, brawler_id).await?;
+
+        let objective = self.objective_repository.find_one(objective_id, mission_id).await?;
+        if objective.is_done() {
+            return Err(AppError::Conflict("Objective is already done".to_string()));
+        }
+
+        self.objective_repository
+            .set_completed(objective_id, mission_id, Some(brawler_id))
+            .await?;
+        let objective = self.objective_repository.find_one(objective_id, mission_id).await?;
+
+        let by = objective
+            .completed_by_display_name
+            .clone()
+            .unwrap_or_else(|| "A crew member".to_string());
+        self.log_and_broadcast(mission_id, format!("{} completed objective: {}", by, objective.title))
+            .await;
+
+        Ok(objective)
+    }
+
+    pub async fn reopen(
+        &self,
+        mission_id: i32,
+        objective_id: i32,
+        brawler_id: i32,
+    ) -> AppResult<MissionObjectiveModel> {
+        self.participating_mission(mission_id, brawler_id).await?;
+
+        let objective = self.objective_repository.find_one(objective_id, mission_id).await?;
+        if !objective.is_done() {
+            return Err(AppError::Conflict("Objective is not done".to_string()));
+        }
+
+        self.objective_repository
+            .set_completed(objective_id, mission_id, None)
+            .await?;
+        let objective = self.objective_repository.find_one(objective_id, mission_id).await?;
+
+        self.log_and_broadcast(mission_id, format!("Objective reopened: {}", objective.title))
+            .await;
+
+        Ok(objective)
+    }
+
+    /// Only the chief and co-chiefs shape the checklist, and only while the mission is still running.
+    async fn editable_mission(&self, mission_id: i32, chief_id: i32) -> AppResult<MissionModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
+
+        if !mission.can_manage(chief_id) {
+            return Err(AppError::Forbidden(
+                "Only the chief or a co-chief can change the mission's objectives".to_string(),
+            ));
+        }
+        ensure_not_finished(&mission)?;
+
+        Ok(mission)
+    }
+
+    async fn participating_mission(&self, mission_id: i32, brawler_id: i32) -> AppResult<MissionModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id, brawler_id).await?;
+
+        if mission.chief_id != brawler_id && !mission.is_member {
+            return Err(AppError::Forbidden(
+                "Only the chief and crew can update objectives".to_string(),
+            ));
+        }
+        ensure_not_finished(&mission)?;
+
+        Ok(mission)
+    }
+
+    async fn log_and_broadcast(&self, mission_id: i32, content: String) {
+        let entity = NewMissionMessageEntity {
+            mission_id,
+            user_id: None,
+            content: content.clone(),
+            type_: "system".to_string(),
+        };
+        let _ = self.mission_message_repository.create(entity).await;
+
+        self.realtime_service.broadcast(
+            mission_id,
+            ChatMessage {
+                mission_id,
+                user_id: None,
+                user_display_name: None,
+                user_avatar_url: None,
+                content,
+                type_: "system".to_string(),
+                created_at: chrono::Utc::now().to_rfc3339(),
+            },
+        );
+    }
+}
+
+fn ensure_not_finished(mission: &MissionModel) -> AppResult<()> {
+    if mission.status == MissionStatuses::Completed.to_string()
+        || mission.status == MissionStatuses::Failed.to_string()
+    {
+        return Err(AppError::Conflict(
+            "Objectives of a finished mission cannot change".to_string(),
+        ));
+    }
+    Ok(())
+}
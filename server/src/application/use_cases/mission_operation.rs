@@ -1,11 +1,19 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use chrono::{Duration, Utc};
+use tracing::warn;
+
+use crate::config::{
+    config_loader::get_mission_schedule_env,
+    config_model::MissionScheduleEnv,
+};
+use crate::domain::errors::{AppError, AppResult};
 
 use crate::domain::{
     entities::{
         notification::{Notification, NotificationType},
         mission_messages::NewMissionMessageEntity,
+        missions::MissionEntity,
     },
     repositories::{
         mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
@@ -32,6 +40,7 @@ where
     mission_message_repository: Arc<T5>,
     notification_service: Arc<dyn NotificationService>,
     realtime_service: Arc<MissionRealtimeService>,
+    schedule: MissionScheduleEnv,
 }
 
 impl<T1, T2, T3, T4, T5> MissionOperationUseCase<T1, T2, T3, T4, T5>
@@ -59,24 +68,37 @@ where
             mission_message_repository,
             notification_service,
             realtime_service,
+            schedule: get_mission_schedule_env(),
         }
     }
 
-    async fn notify_crew(&self, mission_id: i32, title: &str, message: &str) -> Result<()> {
+    async fn notify_crew(&self, mission_id: i32, title: &str, message: &str) -> AppResult<()> {
         let crew = self.mission_viewing_repository.get_crew(mission_id).await?;
         for member in crew {
-            let notification = Notification {
-                recipient_id: Some(member.id),
-                title: title.to_string(),
-                message: message.to_string(),
-                notification_type: NotificationType::MissionStatusUpdate,
-                metadata: serde_json::json!({ "mission_id": mission_id }),
-            };
-            let _ = self.notification_service.send(notification).await;
+            self.notify(member.id, mission_id, NotificationType::MissionStatusUpdate, title, message)
+                .await;
         }
         Ok(())
     }
 
+    async fn notify(
+        &self,
+        recipient_id: i32,
+        mission_id: i32,
+        notification_type: NotificationType,
+        title: &str,
+        message: &str,
+    ) {
+        let notification = Notification {
+            recipient_id: Some(recipient_id),
+            title: title.to_string(),
+            message: message.to_string(),
+            notification_type,
+            metadata: serde_json::json!({ "mission_id": mission_id }),
+        };
+        let _ = self.notification_service.send(notification).await;
+    }
+
     async fn log_and_broadcast_status_change(&self, mission_id: i32, content: String) {
         let entity = NewMissionMessageEntity {
             mission_id,
@@ -101,7 +123,7 @@ where
         self.realtime_service.broadcast(mission_id, msg);
     }
 
-    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> AppResult<i32> {
         let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
 
         let crew_count = self
@@ -112,21 +134,17 @@ where
         let is_status_open_or_fail = mission.status == MissionStatuses::Open.to_string()
             || mission.status == MissionStatuses::Failed.to_string();
 
-        let max_crew_per_mission: i64 = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .parse()?;
-
         let update_condition = is_status_open_or_fail
             && crew_count > 0
-            && crew_count < max_crew_per_mission
-            && mission.chief_id == chief_id;
+            && crew_count <= i64::from(mission.max_crew)
+            && mission.can_manage(chief_id);
         if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
+            return Err(AppError::Conflict("Invalid condition to change stages!".to_string()));
         }
 
         let result = self
             .mission_operation_repository
-            .to_progress(mission_id, chief_id)
+            .to_progress(mission_id, mission.chief_id)
             .await?;
         
         self.notify_crew(
@@ -140,13 +158,26 @@ where
 
         Ok(result)
     }
-    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> AppResult<i32> {
         let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
 
         let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
+            && mission.can_manage(chief_id);
         if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
+            return Err(AppError::Conflict("Invalid condition to change stages!".to_string()));
+        }
+
+        if mission.objectives_required {
+            let open = self
+                .mission_operation_repository
+                .count_open_mandatory_objectives(mission_id)
+                .await?;
+            if open > 0 {
+                return Err(AppError::Conflict(format!(
+                    "{} mandatory objective(s) still open",
+                    open
+                )));
+            }
         }
 
         // Get crew before completion (to know who to award)
@@ -154,7 +185,7 @@ where
 
         let result = self
             .mission_operation_repository
-            .to_completed(mission_id, chief_id)
+            .to_completed(mission_id, mission.chief_id)
             .await?;
 
         self.notify_crew(
@@ -168,11 +199,11 @@ where
 
         // Award achievements
         // 1. Chief
-        if let Ok(chief) = self.brawler_repository.find_by_id(chief_id).await {
+        if let Ok(chief) = self.brawler_repository.find_by_id(mission.chief_id).await {
             // Log for debug
             println!("DEBUG: Chief {} success_count is {}", chief.display_name, chief.mission_success_count);
             
-            if let Ok(awarded) = self.achievement_repository.check_and_award(chief_id, "mission_complete", chief.mission_success_count).await {
+            if let Ok(awarded) = self.achievement_repository.check_and_award(mission.chief_id, "mission_complete", chief.mission_success_count).await {
                 for name in awarded {
                      self.log_and_broadcast_status_change(mission_id, format!("{} earned achievement: {}", chief.display_name, name)).await;
                 }
@@ -195,17 +226,17 @@ where
         Ok(result)
     }
 
-    pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> AppResult<i32> {
         let mission = self.mission_viewing_repository.get_one(mission_id, chief_id).await?;
 
         let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
+            && mission.can_manage(chief_id);
         if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
+            return Err(AppError::Conflict("Invalid condition to change stages!".to_string()));
         }
         let result = self
             .mission_operation_repository
-            .to_failed(mission_id, chief_id)
+            .to_failed(mission_id, mission.chief_id)
             .await?;
 
         self.notify_crew(
@@ -219,4 +250,102 @@ where
 
         Ok(result)
     }
+
+    /// Reminds the chief and crew of open missions starting within the reminder window.
+    pub async fn send_start_reminders(&self) -> AppResult<usize> {
+        let before = (Utc::now() + Duration::minutes(self.schedule.reminder_minutes)).naive_utc();
+        let missions = self
+            .mission_operation_repository
+            .find_due_reminders(before)
+            .await?;
+
+        let mut reminded = 0;
+        for mission in &missions {
+            match self.send_start_reminder(mission).await {
+                Ok(()) => reminded += 1,
+                Err(e) => warn!("Start reminder for mission {} not sent: {}", mission.id, e),
+            }
+        }
+
+        Ok(reminded)
+    }
+
+    /// Marks the reminder as sent only once everyone has been notified, so a failure is retried.
+    async fn send_start_reminder(&self, mission: &MissionEntity) -> AppResult<()> {
+        let message = format!("Mission '{}' starts soon.", mission.name);
+        let crew = self.mission_viewing_repository.get_crew(mission.id).await?;
+        let recipients = std::iter::once(mission.chief_id).chain(crew.into_iter().map(|member| member.id));
+        for recipient_id in recipients {
+            self.notify(recipient_id, mission.id, NotificationType::MissionReminder, "Mission Reminder", &message)
+                .await;
+        }
+
+        self.mission_operation_repository
+            .mark_reminder_sent(mission.id)
+            .await
+    }
+
+    /// Starts open missions whose scheduled start has passed, under the same rules as `in_progress`,
+    /// once they have enough crew. Each mission is tried once; the chief hears about the ones left open.
+    pub async fn start_due_missions(&self) -> AppResult<usize> {
+        let missions = self
+            .mission_operation_repository
+            .find_due_starts(Utc::now().naive_utc())
+            .await?;
+
+        let mut started = 0;
+        for mission in missions {
+            self.mission_operation_repository
+                .mark_auto_start_checked(mission.id)
+                .await?;
+
+            let crew_count = self
+                .mission_viewing_repository
+                .crew_counting(mission.id)
+                .await?;
+            let result = if crew_count >= self.schedule.auto_start_min_crew.max(1) {
+                self.in_progress(mission.id, mission.chief_id).await
+            } else {
+                Err(AppError::Conflict("Not enough crew to start".to_string()))
+            };
+
+            match result {
+                Ok(_) => started += 1,
+                Err(e) => {
+                    warn!("Scheduled start of mission {} skipped: {}", mission.id, e);
+                    self.notify(
+                        mission.chief_id,
+                        mission.id,
+                        NotificationType::MissionStatusUpdate,
+                        "Mission Not Started",
+                        &format!(
+                            "Mission '{}' reached its start time but could not start ({} crew).",
+                            mission.name, crew_count
+                        ),
+                    )
+                    .await;
+                }
+            }
+        }
+
+        Ok(started)
+    }
+
+    /// Fails in-progress missions past their deadline through `to_failed`.
+    pub async fn fail_overdue_missions(&self) -> AppResult<usize> {
+        let missions = self
+            .mission_operation_repository
+            .find_overdue(Utc::now().naive_utc())
+            .await?;
+
+        let mut failed = 0;
+        for mission in missions {
+            match self.to_failed(mission.id, mission.chief_id).await {
+                Ok(_) => failed += 1,
+                Err(e) => warn!("Deadline of mission {} not applied: {}", mission.id, e),
+            }
+        }
+
+        Ok(failed)
+    }
 }
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+
+use crate::config::{config_loader::get_mission_rating_env, config_model::MissionRatingEnv};
+use crate::domain::{
+    entities::mission_ratings::AddMissionRatingEntity,
+    errors::{AppError, AppResult},
+    repositories::{
+        mission_ratings::MissionRatingRepository,
+        mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_model::MissionModel,
+        mission_statuses::MissionStatuses,
+        rating_model::{MissionRatingModel, RateBrawlerModel, RatingSheetModel, ReputationModel},
+    },
+};
+
+pub struct MissionRatingUseCase {
+    rating_repo: Arc<dyn MissionRatingRepository>,
+    mission_repo: Arc<dyn MissionViewingRepository>,
+    ratings: MissionRatingEnv,
+}
+
+impl MissionRatingUseCase {
+    pub fn new(
+        rating_repo: Arc<dyn MissionRatingRepository>,
+        mission_repo: Arc<dyn MissionViewingRepository>,
+    ) -> Self {
+        Self {
+            rating_repo,
+            mission_repo,
+            ratings: get_mission_rating_env(),
+        }
+    }
+
+    /// A finished mission the brawler took part in, with the time its ratings close.
+    async fn finished_mission(&self, mission_id: i32, brawler_id: i32) -> AppResult<(MissionModel, NaiveDateTime)> {
+        let mission = self.mission_repo.get_one(mission_id, brawler_id).await?;
+        if mission.chief_id != brawler_id && !mission.is_member {
+            return Err(AppError::Forbidden("Only the chief and crew can rate this mission".to_string()));
+        }
+
+        let finished = mission.status == MissionStatuses::Completed.to_string()
+            || mission.status == MissionStatuses::Failed.to_string();
+        let finished_at = match mission.finished_at {
+            Some(at) if finished => at,
+            _ => return Err(AppError::Conflict("Mission has not finished yet".to_string())),
+        };
+        if !self.rating_repo.took_part(mission.id, brawler_id).await? {
+            return Err(AppError::Forbidden("Only the crew the mission finished with can rate it".to_string()));
+        }
+
+        let closes_at = finished_at + Duration::hours(self.ratings.window_hours);
+        Ok((mission, closes_at))
+    }
+
+    pub async fn get_sheet(&self, mission_id: i32, brawler_id: i32) -> AppResult<RatingSheetModel> {
+        let (mission, closes_at) = self.finished_mission(mission_id, brawler_id).await?;
+        let peers = self.rating_repo.find_peers(mission.id, brawler_id).await?;
+
+        Ok(RatingSheetModel {
+            mission_id: mission.id,
+            closes_at,
+            is_open: Utc::now().naive_utc() < closes_at,
+            peers,
+        })
+    }
+
+    pub async fn rate(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        ratee_id: i32,
+        model: RateBrawlerModel,
+    ) -> AppResult<MissionRatingModel> {
+        if rater_id == ratee_id {
+            return Err(AppError::Conflict("You can not rate yourself".to_string()));
+        }
+
+        let (mission, closes_at) = self.finished_mission(mission_id, rater_id).await?;
+        if Utc::now().naive_utc() >= closes_at {
+            return Err(AppError::Conflict("Ratings for this mission are closed".to_string()));
+        }
+
+        let peers = self.rating_repo.find_peers(mission.id, rater_id).await?;
+        if !peers.iter().any(|peer| peer.brawler_id == ratee_id) {
+            return Err(AppError::NotFound("Brawler was not part of this mission".to_string()));
+        }
+
+        self.rating_repo
+            .rate(AddMissionRatingEntity {
+                mission_id: mission.id,
+                rater_id,
+                ratee_id,
+                score: model.score,
+                tags: model.tag_names(),
+            })
+            .await
+    }
+
+    pub async fn get_reputation(&self, brawler_id: i32) -> AppResult<ReputationModel> {
+        self.rating_repo.reputation(brawler_id).await
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use tracing::warn;
+
+use crate::{
+    application::use_cases::mission_invites::MissionInviteUseCase,
+    config::{config_loader::get_mission_recurrence_env, config_model::MissionRecurrenceEnv},
+    domain::{
+        entities::{mission_templates::MissionTemplateEntity, missions::AddMissionEntity},
+        errors::AppResult,
+        repositories::MissionTemplateRepository,
+        value_objects::{
+            join_policy::JoinPolicy,
+            mission_statuses::MissionStatuses,
+            mission_template_model::{
+                AddMissionTemplateModel, EditMissionTemplateModel, MissionTemplateModel,
+            },
+            recurrence::RecurrenceRule,
+        },
+    },
+};
+
+pub struct MissionTemplateUseCase<T>
+where
+    T: MissionTemplateRepository + Send + Sync,
+{
+    template_repository: Arc<T>,
+    invite_use_case: Arc<MissionInviteUseCase>,
+    recurrence: MissionRecurrenceEnv,
+}
+
+impl<T> MissionTemplateUseCase<T>
+where
+    T: MissionTemplateRepository + Send + Sync,
+{
+    pub fn new(template_repository: Arc<T>, invite_use_case: Arc<MissionInviteUseCase>) -> Self {
+        Self {
+            template_repository,
+            invite_use_case,
+            recurrence: get_mission_recurrence_env(),
+        }
+    }
+
+    pub async fn add(&self, owner_id: i32, model: AddMissionTemplateModel) -> AppResult<MissionTemplateModel> {
+        let template = self
+            .template_repository
+            .create(model.to_entity(owner_id))
+            .await?;
+
+        Ok(MissionTemplateModel::from_entity(template, Utc::now().naive_utc()))
+    }
+
+    pub async fn get_all(&self, owner_id: i32) -> AppResult<Vec<MissionTemplateModel>> {
+        let now = Utc::now().naive_utc();
+        let templates = self.template_repository.find_by_owner(owner_id).await?;
+
+        Ok(templates
+            .into_iter()
+            .map(|template| MissionTemplateModel::from_entity(template, now))
+            .collect())
+    }
+
+    pub async fn get_one(&self, template_id: i32, owner_id: i32) -> AppResult<MissionTemplateModel> {
+        let template = self
+            .template_repository
+            .find_owned(template_id, owner_id)
+            .await?;
+
+        Ok(MissionTemplateModel::from_entity(template, Utc::now().naive_utc()))
+    }
+
+    pub async fn edit(
+        &self,
+        template_id: i32,
+        owner_id: i32,
+        model: EditMissionTemplateModel,
+    ) -> AppResult<MissionTemplateModel> {
+        let template = self
+            .template_repository
+            .edit(template_id, owner_id, model.to_entity())
+            .await?;
+
+        Ok(MissionTemplateModel::from_entity(template, Utc::now().naive_utc()))
+    }
+
+    /// Missions already created from the template stay, detached from it.
+    pub async fn remove(&self, template_id: i32, owner_id: i32) -> AppResult<()> {
+        self.template_repository.remove(template_id, owner_id).await
+    }
+
+    /// Run periodically by the recurrence job: creates a mission for every
+    /// occurrence within the horizon that does not have one yet.
+    pub async fn materialise_upcoming(&self) -> AppResult<usize> {
+        let now = Utc::now().naive_utc();
+        let until = now + Duration::days(self.recurrence.horizon_days.max(1));
+
+        let mut created = 0;
+        for template in self.template_repository.find_recurring().await? {
+            let Some(rule) = template
+                .recurrence
+                .as_deref()
+                .and_then(|rule| rule.parse::<RecurrenceRule>().ok())
+            else {
+                warn!("Mission template {} has an unreadable recurrence rule", template.id);
+                continue;
+            };
+
+            // Occurrences that already have a mission are skipped by the repository,
+            // so a changed rule only ever adds the slots it is missing.
+            for starts_at in rule.occurrences_between(now, until) {
+                match self.schedule(&template, starts_at).await {
+                    Ok(true) => created += 1,
+                    Ok(false) => {}
+                    Err(e) => warn!("Occurrence of mission template {} not created: {}", template.id, e),
+                }
+            }
+        }
+
+        Ok(created)
+    }
+
+    async fn schedule(&self, template: &MissionTemplateEntity, starts_at: NaiveDateTime) -> AppResult<bool> {
+        let mission = AddMissionEntity {
+            chief_id: template.owner_id,
+            name: template.name.clone(),
+            status: MissionStatuses::Open.to_string(),
+            description: template.description.clone(),
+            category: template.category.clone(),
+            max_crew: template.max_crew,
+            image_url: template.image_url.clone(),
+            image_public_id: None,
+            scheduled_start_at: Some(starts_at),
+            deadline_at: template
+                .duration_minutes
+                .map(|minutes| starts_at + Duration::minutes(i64::from(minutes))),
+            duration_minutes: template.duration_minutes,
+            template_id: Some(template.id),
+            objectives_required: false,
+            join_policy: JoinPolicy::Open.to_string(),
+            min_reputation: None,
+        };
+
+        let Some(mission_id) = self
+            .template_repository
+            .schedule_occurrence(template.id, mission)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        if template.auto_invite {
+            let crew = self
+                .template_repository
+                .previous_crew(template.id, starts_at)
+                .await?;
+            for brawler_id in crew.into_iter().filter(|id| *id != template.owner_id) {
+                if let Err(e) = self
+                    .invite_use_case
+                    .invite(mission_id, template.owner_id, brawler_id)
+                    .await
+                {
+                    warn!("Brawler {} not invited to mission {}: {}", brawler_id, mission_id, e);
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
@@ -1,11 +1,15 @@
 use std::sync::Arc;
 
-use anyhow::{Ok, Result};
+use crate::domain::errors::AppResult;
 
 use crate::domain::{
     repositories::mission_viewing::MissionViewingRepository,
     value_objects::{
-        brawler_model::BrawlerModel, mission_filter::MissionFilter, MissionModel,
+        brawler_model::BrawlerModel,
+        mission_filter::MissionFilter,
+        mission_model::MissionSort,
+        pagination::{Page, PageRequest},
+        MissionModel,
     },
 };
 pub struct MissionViewingUseCase<T>
@@ -25,7 +29,7 @@ where
         }
     }
 
-    pub async fn get_one(&self, mission_id: i32, brawler_id: i32) -> Result<MissionModel> {
+    pub async fn get_one(&self, mission_id: i32, brawler_id: i32) -> AppResult<MissionModel> {
         // let crew_count = self
         //     .mission_viewing_repository
         //     .crew_counting(mission_id)
@@ -38,13 +42,21 @@ where
         Ok(model)
     }
 
-    pub async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
+    pub async fn get_crew(&self, mission_id: i32) -> AppResult<Vec<BrawlerModel>> {
         let result = self.mission_viewing_repository.get_crew(mission_id).await?;
         Ok(result)
     }
 
-    pub async fn get_all(&self, filter: &MissionFilter, brawler_id: i32) -> Result<Vec<MissionModel>> {
-        let models = self.mission_viewing_repository.get_all(filter, brawler_id).await?;
+    pub async fn get_all(
+        &self,
+        filter: &MissionFilter,
+        brawler_id: i32,
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>> {
+        let models = self
+            .mission_viewing_repository
+            .get_all(filter, brawler_id, page)
+            .await?;
 
         // let mut result = Vec::new();
 
@@ -61,15 +73,19 @@ where
         Ok(models)
     }
 
-    pub async fn get_joined_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+    pub async fn get_joined_missions(
+        &self,
+        brawler_id: i32,
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>> {
         let models = self
             .mission_viewing_repository
-            .get_joined_missions(brawler_id)
+            .get_joined_missions(brawler_id, page)
             .await?;
         Ok(models)
     }
 
-    pub async fn get_popular_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+    pub async fn get_popular_missions(&self, brawler_id: i32) -> AppResult<Vec<MissionModel>> {
         let models = self
             .mission_viewing_repository
             .get_popular_missions(brawler_id)
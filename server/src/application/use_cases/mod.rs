@@ -7,3 +7,9 @@ pub mod mission_viewing;
 pub mod achievements;
 pub mod mission_chat;
 pub mod mission_invites;
+pub mod oidc_authentication;
+pub mod admin;
+pub mod api_keys;
+pub mod mission_templates;
+pub mod mission_objectives;
+pub mod mission_ratings;
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+
+use crate::{
+    config::config_loader::get_oidc_env,
+    domain::{
+        entities::{
+            brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+            identities::NewIdentityEntity,
+        },
+        repositories::{brawlers::BrawlerRepository, identities::IdentityRepository},
+    },
+    infrastructure::{
+        argon2::hash,
+        jwt::{
+            authentication_model::{OidcAuthorizationModel, OidcCallbackModel},
+            JwtKeys,
+            jwt_model::Passport,
+        },
+        oidc::{
+            self, IdTokenClaims,
+            pending_login::{PendingLogin, PendingLoginStore},
+        },
+        secure_token,
+    },
+};
+
+const MAX_USERNAME_ATTEMPTS: usize = 5;
+
+pub struct OidcAuthenticationUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: IdentityRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    identity_repository: Arc<T2>,
+    pending_logins: PendingLoginStore,
+    jwt_keys: Arc<JwtKeys>,
+}
+
+impl<T1, T2> OidcAuthenticationUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: IdentityRepository + Send + Sync,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        identity_repository: Arc<T2>,
+        pending_logins: PendingLoginStore,
+        jwt_keys: Arc<JwtKeys>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            identity_repository,
+            pending_logins,
+            jwt_keys,
+        }
+    }
+
+    /// Starts an authorization code + PKCE flow. When `link_brawler_id` is set the
+    /// resulting identity is attached to that brawler instead of signing in.
+    /// Also returns the state, which the browser has to present again at the callback.
+    pub async fn authorize(&self, link_brawler_id: Option<i32>) -> AppResult<(OidcAuthorizationModel, String)> {
+        let oidc_env = get_oidc_env().map_err(provider_unavailable)?;
+        let metadata = oidc::discover(&oidc_env).await.map_err(provider_unavailable)?;
+
+        let state = secure_token::generate(32);
+        let nonce = secure_token::generate(32);
+        let code_verifier = secure_token::generate(48);
+
+        let authorization_url =
+            oidc::authorization_url(&metadata, &oidc_env, &state, &nonce, &code_verifier)?;
+
+        self.pending_logins.insert(
+            state.clone(),
+            PendingLogin::new(code_verifier, nonce, link_brawler_id),
+        );
+
+        Ok((OidcAuthorizationModel { authorization_url }, state))
+    }
+
+    /// `browser_state` is the state this browser was given by `authorize`; a callback for a
+    /// login started elsewhere is refused so nobody can be signed in or linked to another account.
+    pub async fn callback(&self, model: OidcCallbackModel, browser_state: Option<&str>) -> AppResult<Passport> {
+        if browser_state != Some(model.state.as_str()) {
+            return Err(AppError::Unauthorized("Login was not started in this browser".to_string()));
+        }
+
+        let pending = self
+            .pending_logins
+            .take(&model.state)
+            .ok_or_else(|| AppError::Unauthorized("Invalid or expired login state".to_string()))?;
+
+        let oidc_env = get_oidc_env().map_err(provider_unavailable)?;
+        let metadata = oidc::discover(&oidc_env).await.map_err(provider_unavailable)?;
+        let tokens = oidc::exchange_code(&metadata, &oidc_env, &model.code, &pending.code_verifier)
+            .await
+            .map_err(login_rejected)?;
+        let claims = oidc::verify_id_token(&metadata, &oidc_env, &tokens.id_token, &pending.nonce)
+            .await
+            .map_err(login_rejected)?;
+
+        let existing = self
+            .identity_repository
+            .find_by_provider_subject(claims.iss.clone(), claims.sub.clone())
+            .await?;
+
+        let brawler = match (pending.link_brawler_id, existing) {
+            (Some(brawler_id), Some(identity)) => {
+                if identity.brawler_id != brawler_id {
+                    return Err(AppError::Conflict(
+                        "Identity is already linked to another brawler".to_string(),
+                    ));
+                }
+                self.brawler_repository.find_by_id(brawler_id).await?
+            }
+            (Some(brawler_id), None) => {
+                self.identity_repository
+                    .link(NewIdentityEntity {
+                        brawler_id,
+                        provider: claims.iss.clone(),
+                        subject: claims.sub.clone(),
+                        email: claims.email.clone(),
+                    })
+                    .await?;
+                self.brawler_repository.find_by_id(brawler_id).await?
+            }
+            (None, Some(identity)) => self.brawler_repository.find_by_id(identity.brawler_id).await?,
+            (None, None) => self.register_from_claims(&claims).await?,
+        };
+
+        if brawler.disabled_at.is_some() {
+            return Err(AppError::Forbidden("Account is disabled".to_string()));
+        }
+
+        let passport = Passport::new(&self.jwt_keys, brawler)?;
+
+        Ok(passport)
+    }
+
+    async fn register_from_claims(&self, claims: &IdTokenClaims) -> AppResult<BrawlerEntity> {
+        let base_username = claims
+            .preferred_username
+            .clone()
+            .or_else(|| claims.email.as_ref().and_then(|email| email.split('@').next().map(str::to_string)))
+            .map(|name| {
+                name.chars()
+                    .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+                    .take(40)
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "brawler".to_string());
+
+        let mut username = None;
+        for attempt in 0..MAX_USERNAME_ATTEMPTS {
+            let candidate = if attempt == 0 {
+                base_username.clone()
+            } else {
+                format!("{}_{}", base_username, &secure_token::generate(4)[..5])
+            };
+            if self.brawler_repository.find_by_username(candidate.clone()).await.is_err() {
+                username = Some(candidate);
+                break;
+            }
+        }
+        let username = username.ok_or_else(|| AppError::Conflict("Could not allocate a username".to_string()))?;
+
+        let display_name = claims
+            .name
+            .clone()
+            .unwrap_or_else(|| username.clone())
+            .chars()
+            .take(50)
+            .collect();
+
+        // External accounts sign in through the provider; the local password is never revealed.
+        let password = hash(secure_token::generate(32))?;
+
+        self.identity_repository
+            .register_with_identity(
+                RegisterBrawlerEntity {
+                    username,
+                    password,
+                    display_name,
+                },
+                NewIdentityEntity {
+                    brawler_id: 0,
+                    provider: claims.iss.clone(),
+                    subject: claims.sub.clone(),
+                    email: claims.email.clone(),
+                },
+            )
+            .await
+    }
+}
+
+fn provider_unavailable(e: anyhow::Error) -> AppError {
+    AppError::Unavailable(format!("OIDC provider unavailable: {}", e))
+}
+
+/// Provider responses can carry token details, so only the log sees them.
+fn login_rejected(e: anyhow::Error) -> AppError {
+    tracing::warn!("OIDC login rejected: {}", e);
+    AppError::Unauthorized("OIDC login failed".to_string())
+}
@@ -1,12 +1,25 @@
-use std::env;
+use std::{env, str::FromStr};
 
 use anyhow::Result;
 
 use crate::config::{
-    config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server},
+    config_model::{
+        AuthCookieEnv, CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, LoginGuardEnv,
+        MissionInviteEnv, MissionRatingEnv, MissionRecurrenceEnv, MissionRetentionEnv, MissionScheduleEnv,
+        MissionWaitlistEnv, OidcEnv,
+        Server, TrustedProxyEnv, ValidationEnv,
+    },
     stage::Stage,
 };
 
+/// The parsed value of `key`, or `default` when it is unset or does not parse.
+fn read_env_or<T: FromStr>(key: &str, default: T) -> T {
+    env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
 pub fn load() -> Result<DotEnvyConfig> {
     dotenvy::dotenv().ok();
     println!(">>> CONFIG: STARTING LOAD...");
@@ -61,9 +74,135 @@ pub fn get_jwt_env() -> Result<JwtEnv> {
     Ok(JwtEnv {
         secret: env::var("JWT_USER_SECRET")?,
         ttl: env::var("JWT_TTL")?.parse::<i64>()?,
+        kid: env::var("JWT_KID").unwrap_or_else(|_| "primary".to_string()),
+        previous_secrets: env::var("JWT_PREVIOUS_SECRETS")
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|pair| pair.trim().split_once(':'))
+            .map(|(kid, secret)| (kid.to_string(), secret.to_string()))
+            .collect(),
+    })
+}
+
+pub fn get_oidc_env() -> Result<OidcEnv> {
+    dotenvy::dotenv().ok();
+    Ok(OidcEnv {
+        issuer_url: env::var("OIDC_ISSUER_URL")?.trim_end_matches('/').to_string(),
+        client_id: env::var("OIDC_CLIENT_ID")?,
+        client_secret: env::var("OIDC_CLIENT_SECRET")?,
+        redirect_url: env::var("OIDC_REDIRECT_URL")?,
+        scopes: env::var("OIDC_SCOPES").unwrap_or_else(|_| "openid profile email".to_string()),
+        id_token_signing_algs: env::var("OIDC_ID_TOKEN_SIGNING_ALGS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|alg| alg.trim().to_string())
+            .filter(|alg| !alg.is_empty())
+            .collect(),
     })
 }
 
+pub fn get_login_guard_env() -> LoginGuardEnv {
+    dotenvy::dotenv().ok();
+
+    LoginGuardEnv {
+        max_attempts_per_user: read_env_or("LOGIN_MAX_ATTEMPTS_PER_USER", 5),
+        max_attempts_per_ip: read_env_or("LOGIN_MAX_ATTEMPTS_PER_IP", 20),
+        window_minutes: read_env_or("LOGIN_ATTEMPT_WINDOW_MINUTES", 60),
+        base_lockout_secs: read_env_or("LOGIN_BASE_LOCKOUT_SECS", 30),
+        max_lockout_secs: read_env_or("LOGIN_MAX_LOCKOUT_SECS", 900),
+    }
+}
+
+/// `TRUSTED_PROXIES` is a comma-separated list of proxy IPs; entries that do not parse are ignored.
+pub fn get_trusted_proxy_env() -> TrustedProxyEnv {
+    dotenvy::dotenv().ok();
+    TrustedProxyEnv {
+        proxies: env::var("TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|ip| ip.trim().parse().ok())
+            .collect(),
+    }
+}
+
+pub fn get_auth_cookie_env() -> AuthCookieEnv {
+    dotenvy::dotenv().ok();
+
+    AuthCookieEnv {
+        enabled: read_env_or("AUTH_COOKIE_ENABLED", false),
+        name: env::var("AUTH_COOKIE_NAME").unwrap_or_else(|_| "access_token".to_string()),
+        secure: read_env_or("AUTH_COOKIE_SECURE", true),
+    }
+}
+
+pub fn get_validation_env() -> ValidationEnv {
+    dotenvy::dotenv().ok();
+
+    ValidationEnv {
+        username_min_len: read_env_or("USERNAME_MIN_LENGTH", 4),
+        username_max_len: read_env_or("USERNAME_MAX_LENGTH", 50),
+        password_min_len: read_env_or("PASSWORD_MIN_LENGTH", 8),
+        password_max_len: read_env_or("PASSWORD_MAX_LENGTH", 128),
+        display_name_min_len: read_env_or("DISPLAY_NAME_MIN_LENGTH", 3),
+        display_name_max_len: read_env_or("DISPLAY_NAME_MAX_LENGTH", 50),
+        mission_name_min_len: read_env_or("MISSION_NAME_MIN_LENGTH", 3),
+        mission_name_max_len: read_env_or("MISSION_NAME_MAX_LENGTH", 100),
+        mission_description_max_len: read_env_or("MISSION_DESCRIPTION_MAX_LENGTH", 2000),
+        mission_category_max_len: read_env_or("MISSION_CATEGORY_MAX_LENGTH", 50),
+        min_crew: read_env_or("MISSION_MIN_CREW", 2),
+        max_crew: read_env_or("MISSION_MAX_CREW", 10),
+        max_message_len: read_env_or("CHAT_MESSAGE_MAX_LENGTH", 1000),
+        max_search_len: read_env_or("SEARCH_MAX_LENGTH", 100),
+        max_duration_minutes: read_env_or("MISSION_MAX_DURATION_MINUTES", 10080),
+    }
+}
+
+pub fn get_mission_retention_env() -> MissionRetentionEnv {
+    dotenvy::dotenv().ok();
+
+    MissionRetentionEnv {
+        retention_days: read_env_or("MISSION_RETENTION_DAYS", 30),
+        purge_interval_secs: read_env_or("MISSION_PURGE_INTERVAL_SECS", 3600),
+    }
+}
+
+pub fn get_mission_schedule_env() -> MissionScheduleEnv {
+    dotenvy::dotenv().ok();
+
+    MissionScheduleEnv {
+        interval_secs: read_env_or("MISSION_SCHEDULER_INTERVAL_SECS", 60),
+        reminder_minutes: read_env_or("MISSION_REMINDER_MINUTES", 30),
+        auto_start_min_crew: read_env_or("MISSION_AUTO_START_MIN_CREW", 1),
+    }
+}
+
+pub fn get_mission_recurrence_env() -> MissionRecurrenceEnv {
+    dotenvy::dotenv().ok();
+
+    MissionRecurrenceEnv {
+        interval_secs: read_env_or("MISSION_RECURRENCE_INTERVAL_SECS", 900),
+        horizon_days: read_env_or("MISSION_RECURRENCE_HORIZON_DAYS", 7),
+    }
+}
+
+pub fn get_mission_waitlist_env() -> MissionWaitlistEnv {
+    dotenvy::dotenv().ok();
+
+    MissionWaitlistEnv {
+        offer_window_minutes: read_env_or("MISSION_WAITLIST_OFFER_MINUTES", 30),
+        interval_secs: read_env_or("MISSION_WAITLIST_INTERVAL_SECS", 60),
+    }
+}
+
+pub fn get_mission_invite_env() -> MissionInviteEnv {
+    dotenvy::dotenv().ok();
+
+    MissionInviteEnv {
+        ttl_hours: read_env_or("MISSION_INVITE_TTL_HOURS", 72),
+        interval_secs: read_env_or("MISSION_INVITE_EXPIRY_INTERVAL_SECS", 300),
+    }
+}
+
 pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
     dotenvy::dotenv().ok();
     Ok(CloudinaryEnv {
@@ -72,3 +211,11 @@ pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
         api_secret: env::var("CLOUDINARY_API_SECRET")?,
     })
 }
+
+pub fn get_mission_rating_env() -> MissionRatingEnv {
+    dotenvy::dotenv().ok();
+
+    MissionRatingEnv {
+        window_hours: read_env_or("MISSION_RATING_WINDOW_HOURS", 168),
+    }
+}
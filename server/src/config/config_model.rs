@@ -14,6 +14,103 @@ pub struct Database {
 pub struct JwtEnv {
     pub secret: String,
     pub ttl: i64,
+    pub kid: String,
+    /// Retired `(kid, secret)` pairs still accepted for verification during rotation.
+    pub previous_secrets: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OidcEnv {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_url: String,
+    pub scopes: String,
+    /// ID token algorithms to accept; empty means whatever the provider advertises.
+    pub id_token_signing_algs: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoginGuardEnv {
+    pub max_attempts_per_user: i64,
+    pub max_attempts_per_ip: i64,
+    pub window_minutes: i64,
+    pub base_lockout_secs: i64,
+    pub max_lockout_secs: i64,
+}
+
+/// Reverse proxies whose `X-Forwarded-For` header is believed for the client address.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxyEnv {
+    pub proxies: Vec<std::net::IpAddr>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthCookieEnv {
+    pub enabled: bool,
+    pub name: String,
+    pub secure: bool,
+}
+
+/// Limits checked on request bodies and queries before they reach a use case.
+#[derive(Debug, Clone)]
+pub struct ValidationEnv {
+    pub username_min_len: usize,
+    pub username_max_len: usize,
+    pub password_min_len: usize,
+    pub password_max_len: usize,
+    pub display_name_min_len: usize,
+    pub display_name_max_len: usize,
+    pub mission_name_min_len: usize,
+    pub mission_name_max_len: usize,
+    pub mission_description_max_len: usize,
+    pub mission_category_max_len: usize,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub max_message_len: usize,
+    pub max_search_len: usize,
+    pub max_duration_minutes: i32,
+}
+
+/// How long soft-deleted missions can be restored before the purge job removes them.
+#[derive(Debug, Clone)]
+pub struct MissionRetentionEnv {
+    pub retention_days: i32,
+    pub purge_interval_secs: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MissionScheduleEnv {
+    pub interval_secs: u64,
+    pub reminder_minutes: i64,
+    pub auto_start_min_crew: i64,
+}
+
+/// How often and how far ahead recurring templates are turned into missions.
+#[derive(Debug, Clone)]
+pub struct MissionRecurrenceEnv {
+    pub interval_secs: u64,
+    pub horizon_days: i64,
+}
+
+/// How long a promoted waitlist brawler has to confirm before the place passes on.
+#[derive(Debug, Clone)]
+pub struct MissionWaitlistEnv {
+    pub offer_window_minutes: i64,
+    pub interval_secs: u64,
+}
+
+/// How long a mission invite stays open, and how often lapsed ones are expired.
+#[derive(Debug, Clone)]
+pub struct MissionInviteEnv {
+    pub ttl_hours: i64,
+    pub interval_secs: u64,
+}
+
+/// How long after a mission ends its crew and chief may rate each other.
+#[derive(Debug, Clone)]
+pub struct MissionRatingEnv {
+    pub window_hours: i64,
 }
 
 #[derive(Debug, Clone)]
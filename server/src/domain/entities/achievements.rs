@@ -3,8 +3,9 @@
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Debug, Clone)]
+#[derive(Queryable, Selectable, Identifiable, Serialize, Deserialize, Debug, Clone, ToSchema)]
 #[diesel(table_name = crate::schema::achievements)]
 #[diesel(check_for_backend(diesel::pg::Pg))]
 pub struct Achievement {
@@ -28,3 +29,23 @@ pub struct BrawlerAchievement {
     pub achievement_id: i32,
     pub earned_at: NaiveDateTime,
 }
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = crate::schema::achievements)]
+pub struct AddAchievementEntity {
+    pub name: String,
+    pub description: Option<String>,
+    pub icon_url: Option<String>,
+    pub condition_type: Option<String>,
+    pub condition_value: Option<i32>,
+}
+
+#[derive(AsChangeset, Debug, Clone)]
+#[diesel(table_name = crate::schema::achievements)]
+pub struct EditAchievementEntity {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub icon_url: Option<String>,
+    pub condition_type: Option<String>,
+    pub condition_value: Option<i32>,
+}
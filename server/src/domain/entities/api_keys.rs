@@ -0,0 +1,33 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::entities::brawlers::BrawlerEntity,
+    infrastructure::database::schema::api_keys,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = brawler_id))]
+#[diesel(table_name = api_keys)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ApiKeyEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = api_keys)]
+pub struct NewApiKeyEntity {
+    pub brawler_id: i32,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+}
@@ -16,6 +16,13 @@ pub struct BrawlerEntity {
     pub avatar_public_id: Option<String>,
     pub mission_success_count: i32,
     pub mission_join_count: i32,
+    pub role: String,
+    pub disabled_at: Option<NaiveDateTime>,
+    pub is_bot: bool,
+    pub bot_owner_id: Option<i32>,
+    pub reputation: f64,
+    pub rating_count: i32,
+    pub token_version: i32,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -25,3 +32,13 @@ pub struct RegisterBrawlerEntity {
     pub password: String,
     pub display_name: String,
 }
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = brawlers)]
+pub struct RegisterBotEntity {
+    pub username: String,
+    pub password: String,
+    pub display_name: String,
+    pub is_bot: bool,
+    pub bot_owner_id: Option<i32>,
+}
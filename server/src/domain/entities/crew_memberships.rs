@@ -13,4 +13,6 @@ use crate::{
 pub struct CrewMemberShips {
     pub brawler_id: i32,
     pub mission_id: i32,
+    /// The mission role slot the member fills, if any.
+    pub role_slot_id: Option<i32>,
 }
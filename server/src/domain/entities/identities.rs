@@ -0,0 +1,30 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::entities::brawlers::BrawlerEntity,
+    infrastructure::database::schema::identities,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations, Serialize, Deserialize)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = brawler_id))]
+#[diesel(table_name = identities)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IdentityEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = identities)]
+pub struct NewIdentityEntity {
+    pub brawler_id: i32,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+}
@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::login_attempts;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(table_name = login_attempts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct LoginAttemptEntity {
+    pub id: i32,
+    pub username: String,
+    pub brawler_id: Option<i32>,
+    pub ip_address: Option<String>,
+    pub succeeded: bool,
+    pub attempted_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = login_attempts)]
+pub struct NewLoginAttemptEntity {
+    pub username: String,
+    pub brawler_id: Option<i32>,
+    pub ip_address: Option<String>,
+}
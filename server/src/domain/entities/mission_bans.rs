@@ -0,0 +1,15 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_bans;
+
+#[derive(Debug, Clone, Insertable, AsChangeset)]
+#[diesel(table_name = mission_bans)]
+#[diesel(treat_none_as_null = true)]
+pub struct AddMissionBanEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub banned_by: Option<i32>,
+    pub reason: Option<String>,
+    pub expires_at: Option<NaiveDateTime>,
+}
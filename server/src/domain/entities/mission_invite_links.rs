@@ -0,0 +1,15 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_invite_links;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_invite_links)]
+pub struct AddMissionInviteLinkEntity {
+    pub mission_id: i32,
+    pub token_hash: String,
+    pub created_by: Option<i32>,
+    pub role_slot_id: Option<i32>,
+    pub max_uses: Option<i32>,
+    pub expires_at: Option<NaiveDateTime>,
+}
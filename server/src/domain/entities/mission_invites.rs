@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use diesel::{Insertable, Queryable, Associations};
+use chrono::NaiveDateTime;
 use crate::infrastructure::database::schema::mission_invites;
 use crate::domain::entities::missions::MissionEntity;
 use crate::domain::entities::brawlers::BrawlerEntity;
 
-#[derive(Debug, Serialize, Deserialize, Queryable, Clone, Associations)]
+#[derive(Debug, Serialize, Deserialize, Queryable, Clone, Associations, ToSchema)]
 #[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
 #[diesel(belongs_to(BrawlerEntity, foreign_key = user_id))]
 pub struct MissionInvite {
@@ -12,7 +14,16 @@ pub struct MissionInvite {
     pub mission_id: i32,
     pub user_id: i32,
     pub status: String,
-    pub created_at: chrono::NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub invited_by: Option<i32>,
+    pub expires_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionInvite {
+    pub fn is_expired(&self, now: NaiveDateTime) -> bool {
+        self.expires_at <= now
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Insertable)]
@@ -21,13 +32,28 @@ pub struct NewMissionInvite {
     pub mission_id: i32,
     pub user_id: i32,
     pub status: String,
+    pub invited_by: Option<i32>,
+    pub expires_at: NaiveDateTime,
 }
 
-#[derive(Debug, Serialize, Deserialize, Queryable)]
+#[derive(Debug, Serialize, Deserialize, Queryable, ToSchema)]
 pub struct MissionInviteDetails {
     pub id: i32,
     pub mission_id: i32,
     pub mission_name: String,
     pub chief_name: String,
     pub status: String,
+    pub expires_at: NaiveDateTime,
+}
+
+/// An invite as the mission's chief sees it.
+#[derive(Debug, Serialize, Deserialize, Queryable, ToSchema)]
+pub struct SentMissionInvite {
+    pub id: i32,
+    pub user_id: i32,
+    pub user_display_name: String,
+    pub invited_by: Option<i32>,
+    pub status: String,
+    pub expires_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
 }
@@ -0,0 +1,31 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::entities::missions::MissionEntity,
+    infrastructure::database::schema::mission_join_requests,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
+#[diesel(table_name = mission_join_requests)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MissionJoinRequestEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub role_slot_id: Option<i32>,
+    pub status: String,
+    pub decided_by: Option<i32>,
+    pub decided_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_join_requests)]
+pub struct AddMissionJoinRequestEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub role_slot_id: Option<i32>,
+}
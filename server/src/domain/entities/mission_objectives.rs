@@ -0,0 +1,38 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::entities::missions::MissionEntity,
+    infrastructure::database::schema::mission_objectives,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
+#[diesel(table_name = mission_objectives)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MissionObjectiveEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub title: String,
+    pub position: i32,
+    pub is_mandatory: bool,
+    pub completed_by: Option<i32>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_objectives)]
+pub struct AddMissionObjectiveEntity {
+    pub mission_id: i32,
+    pub title: String,
+    pub is_mandatory: bool,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_objectives)]
+pub struct EditMissionObjectiveEntity {
+    pub title: Option<String>,
+    pub is_mandatory: Option<bool>,
+}
@@ -0,0 +1,13 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_ratings;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_ratings)]
+pub struct AddMissionRatingEntity {
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub tags: Vec<String>,
+}
@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::entities::missions::MissionEntity,
+    infrastructure::database::schema::mission_role_slots,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
+#[diesel(table_name = mission_role_slots)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MissionRoleSlotEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub label: String,
+    pub position: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_role_slots)]
+pub struct AddMissionRoleSlotEntity {
+    pub mission_id: i32,
+    pub label: String,
+    pub position: i32,
+}
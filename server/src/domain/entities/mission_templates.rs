@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::entities::brawlers::BrawlerEntity,
+    infrastructure::database::schema::mission_templates,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = owner_id))]
+#[diesel(table_name = mission_templates)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MissionTemplateEntity {
+    pub id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub max_crew: i32,
+    pub image_url: Option<String>,
+    pub recurrence: Option<String>,
+    pub duration_minutes: Option<i32>,
+    pub auto_invite: bool,
+    pub last_scheduled_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_templates)]
+pub struct AddMissionTemplateEntity {
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub max_crew: i32,
+    pub image_url: Option<String>,
+    pub recurrence: Option<String>,
+    pub duration_minutes: Option<i32>,
+    pub auto_invite: bool,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_templates)]
+pub struct EditMissionTemplateEntity {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub max_crew: Option<i32>,
+    pub image_url: Option<String>,
+    pub recurrence: Option<Option<String>>,
+    pub duration_minutes: Option<i32>,
+    pub auto_invite: Option<bool>,
+}
@@ -0,0 +1,10 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_waitlist;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_waitlist)]
+pub struct AddMissionWaitlistEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+}
@@ -21,6 +21,13 @@ pub struct MissionEntity {
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
+    pub scheduled_start_at: Option<NaiveDateTime>,
+    pub deadline_at: Option<NaiveDateTime>,
+    pub duration_minutes: Option<i32>,
+    pub objectives_required: bool,
+    pub join_policy: String,
+    pub finished_at: Option<NaiveDateTime>,
+    pub min_reputation: Option<f64>,
 }
 
 impl MissionEntity {
@@ -36,9 +43,20 @@ impl MissionEntity {
             max_crew: self.max_crew,
             crew_count,
             is_member,
+            is_co_chief: false,
             image_url: self.image_url.clone(),
             created_at: self.created_at,
             updated_at: self.updated_at,
+            scheduled_start_at: self.scheduled_start_at,
+            deadline_at: self.deadline_at,
+            duration_minutes: self.duration_minutes,
+            objectives_required: self.objectives_required,
+            objectives_total: 0,
+            objectives_done: 0,
+            objectives_progress: 0,
+            join_policy: self.join_policy.clone(),
+            finished_at: self.finished_at,
+            min_reputation: self.min_reputation,
         }
     }
 }
@@ -54,6 +72,13 @@ pub struct AddMissionEntity {
     pub max_crew: i32,
     pub image_url: Option<String>,
     pub image_public_id: Option<String>,
+    pub scheduled_start_at: Option<NaiveDateTime>,
+    pub deadline_at: Option<NaiveDateTime>,
+    pub duration_minutes: Option<i32>,
+    pub template_id: Option<i32>,
+    pub objectives_required: bool,
+    pub join_policy: String,
+    pub min_reputation: Option<f64>,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
@@ -66,4 +91,12 @@ pub struct EditMissionEntity {
     pub max_crew: Option<i32>,
     pub image_url: Option<String>,
     pub image_public_id: Option<String>,
+    pub scheduled_start_at: Option<NaiveDateTime>,
+    pub deadline_at: Option<NaiveDateTime>,
+    pub duration_minutes: Option<i32>,
+    pub reminder_sent_at: Option<Option<NaiveDateTime>>,
+    pub auto_start_checked_at: Option<Option<NaiveDateTime>>,
+    pub objectives_required: Option<bool>,
+    pub join_policy: Option<String>,
+    pub min_reputation: Option<Option<f64>>,
 }
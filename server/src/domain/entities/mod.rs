@@ -4,4 +4,15 @@ pub mod missions;
 pub mod achievements;
 pub mod notification;
 pub mod mission_messages;
-pub mod mission_invites;
\ No newline at end of file
+pub mod mission_invites;
+pub mod login_attempts;
+pub mod identities;
+pub mod api_keys;
+pub mod mission_templates;
+pub mod mission_objectives;
+pub mod mission_role_slots;
+pub mod mission_join_requests;
+pub mod mission_waitlist;
+pub mod mission_bans;
+pub mod mission_invite_links;
+pub mod mission_ratings;
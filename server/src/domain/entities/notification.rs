@@ -1,16 +1,23 @@
 // *เพิ่ม
 
 use serde::Serialize;
+use utoipa::ToSchema;
 use serde_json::Value;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub enum NotificationType {
     JoinMission,
     LeaveMission,
     MissionStatusUpdate,
+    MissionReminder,
+    JoinRequest,
+    JoinRequestDecision,
+    WaitlistOffer,
+    ChiefChange,
+    MissionInvite,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Notification {
     pub recipient_id: Option<i32>, // None for broadcast, Some for specific user
     pub title: String,
@@ -0,0 +1,84 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub type AppResult<T> = Result<T, AppError>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Error returned by repositories and use cases. Every variant except
+/// `Internal` carries a message that is safe to show to the client.
+#[derive(Debug)]
+pub enum AppError {
+    NotFound(String),
+    Forbidden(String),
+    Conflict(String),
+    Validation(Vec<FieldError>),
+    Unauthorized(String),
+    TooManyRequests(String),
+    Unavailable(String),
+    Internal(anyhow::Error),
+}
+
+impl AppError {
+    pub fn validation(field: impl Into<String>, message: impl Into<String>) -> Self {
+        AppError::Validation(vec![FieldError::new(field, message)])
+    }
+
+    /// Machine-readable code sent alongside the message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "not_found",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::Conflict(_) => "conflict",
+            AppError::Validation(_) => "validation_failed",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::TooManyRequests(_) => "too_many_requests",
+            AppError::Unavailable(_) => "unavailable",
+            AppError::Internal(_) => "internal_error",
+        }
+    }
+}
+
+impl Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppError::NotFound(message)
+            | AppError::Forbidden(message)
+            | AppError::Conflict(message)
+            | AppError::Unauthorized(message)
+            | AppError::TooManyRequests(message)
+            | AppError::Unavailable(message) => write!(f, "{}", message),
+            AppError::Validation(fields) => {
+                let messages: Vec<String> = fields
+                    .iter()
+                    .map(|field| format!("{}: {}", field.field, field.message))
+                    .collect();
+                write!(f, "Validation failed: {}", messages.join(", "))
+            }
+            AppError::Internal(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<anyhow::Error> for AppError {
+    fn from(e: anyhow::Error) -> Self {
+        AppError::Internal(e)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod entities;
+pub mod errors;
 pub mod repositories;
 pub mod value_objects;
 pub mod services;
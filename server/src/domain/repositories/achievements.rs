@@ -1,12 +1,18 @@
-use crate::domain::entities::achievements::{Achievement, BrawlerAchievement};
+use crate::domain::entities::achievements::{
+    Achievement, AddAchievementEntity, BrawlerAchievement, EditAchievementEntity,
+};
 use async_trait::async_trait;
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 
 
 #[async_trait]
 pub trait AchievementRepository: Send + Sync {
-    async fn get_all(&self) -> Result<Vec<Achievement>>;
-    async fn get_by_brawler_id(&self, brawler_id: i32) -> Result<Vec<(Achievement, Option<BrawlerAchievement>)>>;
-    async fn award_achievement(&self, brawler_id: i32, achievement_id: i32) -> Result<()>;
-    async fn check_and_award(&self, brawler_id: i32, condition_type: &str, current_value: i32) -> Result<Vec<String>>;
+    async fn get_all(&self) -> AppResult<Vec<Achievement>>;
+    async fn get_by_brawler_id(&self, brawler_id: i32) -> AppResult<Vec<(Achievement, Option<BrawlerAchievement>)>>;
+    async fn award_achievement(&self, brawler_id: i32, achievement_id: i32) -> AppResult<()>;
+    async fn revoke_achievement(&self, brawler_id: i32, achievement_id: i32) -> AppResult<()>;
+    async fn create(&self, add_achievement_entity: AddAchievementEntity) -> AppResult<i32>;
+    async fn update(&self, achievement_id: i32, edit_achievement_entity: EditAchievementEntity) -> AppResult<()>;
+    async fn delete(&self, achievement_id: i32) -> AppResult<()>;
+    async fn check_and_award(&self, brawler_id: i32, condition_type: &str, current_value: i32) -> AppResult<Vec<String>>;
 }
@@ -0,0 +1,15 @@
+use crate::domain::errors::AppResult;
+use async_trait::async_trait;
+
+use crate::domain::entities::api_keys::{ApiKeyEntity, NewApiKeyEntity};
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn create(&self, api_key: NewApiKeyEntity) -> AppResult<ApiKeyEntity>;
+    async fn find_by_id(&self, api_key_id: i32) -> AppResult<ApiKeyEntity>;
+    /// Only keys that have not been revoked.
+    async fn find_active_by_prefix(&self, prefix: String) -> AppResult<Option<ApiKeyEntity>>;
+    async fn find_by_brawlers(&self, brawler_ids: Vec<i32>) -> AppResult<Vec<ApiKeyEntity>>;
+    async fn revoke(&self, api_key_id: i32) -> AppResult<()>;
+    async fn touch(&self, api_key_id: i32) -> AppResult<()>;
+}
@@ -1,38 +1,61 @@
 use crate::{
     domain::{
-        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        entities::{
+            brawlers::{BrawlerEntity, RegisterBotEntity, RegisterBrawlerEntity},
+            missions::MissionEntity,
+        },
         value_objects::{
-            base64_img::Base64Img, brawler_model::{BrawlerModel, UpdateProfileModel}, MissionModel, mission_summary::MissionSummaryModel, uploaded_img::UploadedImg
+            base64_img::Base64Img, brawler_model::{AccountDeletion, BrawlerModel, BrawlerSort, UpdateProfileModel}, MissionModel,
+            mission_model::MissionSort, mission_summary::MissionSummaryModel,
+            pagination::{Page, PageRequest}, roles::Roles, uploaded_img::UploadedImg
         },
     },
-    infrastructure::{cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
+    infrastructure::cloudinary::UploadImageOptions,
 };
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait BrawlerRepository: Send + Sync {
-    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<Passport>;
-    async fn find_by_username(&self, username: String) -> Result<BrawlerEntity>;
-    async fn find_by_id(&self, id: i32) -> Result<BrawlerEntity>;
+    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> AppResult<BrawlerEntity>;
+    async fn register_bot(&self, register_bot_entity: RegisterBotEntity) -> AppResult<BrawlerEntity>;
+    async fn find_bots_by_owner(&self, owner_id: i32) -> AppResult<Vec<BrawlerEntity>>;
+    async fn find_by_username(&self, username: String) -> AppResult<BrawlerEntity>;
+    async fn find_by_id(&self, id: i32) -> AppResult<BrawlerEntity>;
     async fn upload_base64img(
         &self,
         user_id: i32,
         base64img: Base64Img,
         opt: UploadImageOptions,
-    ) -> Result<UploadedImg>;
+    ) -> AppResult<UploadedImg>;
 
     async fn update_profile(
         &self,
         user_id: i32,
         model: UpdateProfileModel,
-    ) -> Result<BrawlerEntity>;
+    ) -> AppResult<BrawlerEntity>;
 
-    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>>;
+    async fn get_missions(
+        &self,
+        brawler_id: i32,
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>>;
     // *เพิ่ม
-    async fn get_mission_summary(&self, brawler_id: i32) -> Result<MissionSummaryModel>;
-    async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
+    async fn get_mission_summary(&self, brawler_id: i32) -> AppResult<MissionSummaryModel>;
+    async fn crew_counting(&self, mission_id: i32) -> AppResult<u32>;
     // *เพิ่ม
-    async fn get_leaderboard(&self) -> Result<Vec<BrawlerModel>>;
-    async fn get_all_brawlers(&self) -> Result<Vec<BrawlerModel>>;
+    async fn get_leaderboard(&self) -> AppResult<Vec<BrawlerModel>>;
+    async fn get_all_brawlers(&self, page: &PageRequest<BrawlerSort>) -> AppResult<Page<BrawlerModel>>;
+    async fn list_brawlers(&self) -> AppResult<Vec<BrawlerEntity>>;
+    async fn set_disabled(&self, brawler_id: i32, disabled: bool) -> AppResult<()>;
+    async fn set_role(&self, brawler_id: i32, role: Roles) -> AppResult<()>;
+    /// `None` turns the calendar feed off.
+    async fn set_calendar_token_hash(&self, brawler_id: i32, token_hash: Option<String>) -> AppResult<()>;
+    async fn find_by_calendar_token_hash(&self, token_hash: String) -> AppResult<Option<BrawlerEntity>>;
+    /// Scheduled missions the brawler chiefs or has joined, soonest first.
+    async fn get_calendar_missions(&self, brawler_id: i32) -> AppResult<Vec<MissionEntity>>;
+    /// Anonymises and disables the account, handing each mission it leads to its
+    /// longest-standing active member. Missions nobody can take over are deleted unless finished,
+    /// and the account leaves the crew of every open or in-progress mission.
+    async fn delete_account(&self, brawler_id: i32) -> AppResult<AccountDeletion>;
 }
@@ -1,12 +1,26 @@
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 use async_trait::async_trait;
 
-use crate::domain::entities::crew_memberships::CrewMemberShips;
+use crate::domain::{
+    entities::crew_memberships::CrewMemberShips,
+    value_objects::mission_role_model::RoleSlotModel,
+};
 
 #[async_trait]
 pub trait CrewOperationRepository: Send + Sync {
-    async fn join(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
-    async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
+    /// Fails with a conflict when the crew is full, counting places held for waitlist offers
+    /// other than the joiner's own, or when the role slot is taken.
+    async fn join(&self, crew_member_ships: CrewMemberShips) -> AppResult<()>;
+    async fn leave(&self, crew_member_ships: CrewMemberShips) -> AppResult<()>;
     // *เพิ่ม
-    async fn is_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool>;
+    async fn is_member(&self, mission_id: i32, brawler_id: i32) -> AppResult<bool>;
+    /// Slots of a mission in lineup order, with whoever fills them.
+    async fn role_slots(&self, mission_id: i32) -> AppResult<Vec<RoleSlotModel>>;
+    /// Replaces the mission's slots with `labels`, keeping members in slots whose label survives.
+    async fn set_role_slots(&self, mission_id: i32, labels: Vec<String>) -> AppResult<()>;
+    /// Moves a crew member into `slot_id` (or out of any slot); an occupant swaps into the member's old slot.
+    async fn assign_role_slot(&self, mission_id: i32, brawler_id: i32, slot_id: Option<i32>) -> AppResult<()>;
+    async fn set_co_chief(&self, mission_id: i32, brawler_id: i32, is_co_chief: bool) -> AppResult<()>;
+    /// Makes a crew member the chief; the old chief takes their place (and role slot) in the crew.
+    async fn transfer_chief(&self, mission_id: i32, chief_id: i32, new_chief_id: i32) -> AppResult<()>;
 }
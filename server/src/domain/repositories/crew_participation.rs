@@ -1,4 +1,4 @@
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 use async_trait::async_trait;
 use diesel::{
     PgConnection,
@@ -11,19 +11,19 @@ use crate::domain::entities::crew_memberships::CrewMemberShips;
 #[async_trait]
 #[automock]
 pub trait CrewOperationRepository {
-    async fn join(&self, crew_memberships: CrewMemberShips) -> Result<()>;
-    async fn leave(&self, crew_memberships: CrewMemberShips) -> Result<()>;
+    async fn join(&self, crew_memberships: CrewMemberShips) -> AppResult<()>;
+    async fn leave(&self, crew_memberships: CrewMemberShips) -> AppResult<()>;
 
     //testing method
     fn for_insert_transaction_test(
         &self,
         conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
         crew_memberships: CrewMemberShips,
-    ) -> Result<()>;
+    ) -> AppResult<()>;
     fn for_delete_transaction_test(
         &self,
         conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
         crew_memberships: CrewMemberShips,
-    ) -> Result<()>;
+    ) -> AppResult<()>;
 
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+use crate::domain::errors::AppResult;
+use async_trait::async_trait;
+
+use crate::domain::entities::{
+    brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+    identities::{IdentityEntity, NewIdentityEntity},
+};
+
+#[async_trait]
+pub trait IdentityRepository: Send + Sync {
+    async fn find_by_provider_subject(&self, provider: String, subject: String) -> AppResult<Option<IdentityEntity>>;
+    async fn find_by_brawler(&self, brawler_id: i32) -> AppResult<Vec<IdentityEntity>>;
+    async fn link(&self, identity: NewIdentityEntity) -> AppResult<IdentityEntity>;
+    /// Creates the brawler and its first identity in one transaction.
+    /// `identity.brawler_id` is ignored and replaced by the new brawler's id.
+    async fn register_with_identity(
+        &self,
+        register_brawler_entity: RegisterBrawlerEntity,
+        identity: NewIdentityEntity,
+    ) -> AppResult<BrawlerEntity>;
+}
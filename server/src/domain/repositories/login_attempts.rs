@@ -0,0 +1,28 @@
+use crate::domain::errors::AppResult;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::login_attempts::{LoginAttemptEntity, NewLoginAttemptEntity},
+    value_objects::login_attempt_model::FailedLoginSummary,
+};
+
+/// Decides from the failures already counted against a username and (when known) an IP address
+/// whether another attempt may go ahead.
+pub type LoginAttemptGuard<'a> = dyn Fn(&FailedLoginSummary, Option<&FailedLoginSummary>) -> AppResult<()> + Sync + 'a;
+
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync {
+    /// Records `attempt` as a failure once `guard` accepts the failures after `since` for its
+    /// username and IP address (for the username, only those since its last successful login).
+    /// Attempts for the same username or IP address are serialized, so parallel guesses each see
+    /// the ones before them. Returns the id of the recorded attempt.
+    async fn record_failure(
+        &self,
+        attempt: NewLoginAttemptEntity,
+        since: NaiveDateTime,
+        guard: &LoginAttemptGuard<'_>,
+    ) -> AppResult<i32>;
+    async fn mark_succeeded(&self, attempt_id: i32) -> AppResult<()>;
+    async fn find_failed_by_brawler(&self, brawler_id: i32, limit: i64) -> AppResult<Vec<LoginAttemptEntity>>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::mission_bans::AddMissionBanEntity, errors::AppResult,
+    value_objects::ban_model::MissionBanModel,
+};
+
+#[async_trait]
+pub trait MissionBanRepository: Send + Sync {
+    /// Bans the brawler, replacing any earlier ban from the same mission.
+    async fn ban(&self, ban: AddMissionBanEntity) -> AppResult<()>;
+    /// The brawler's ban if it is still in force at `now`.
+    async fn find_active(&self, mission_id: i32, brawler_id: i32, now: NaiveDateTime) -> AppResult<Option<MissionBanModel>>;
+    /// Bans in force at `now`, newest first.
+    async fn find_by_mission(&self, mission_id: i32, now: NaiveDateTime) -> AppResult<Vec<MissionBanModel>>;
+    /// Fails with not found when the brawler is not banned.
+    async fn lift(&self, mission_id: i32, brawler_id: i32) -> AppResult<()>;
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::mission_invite_links::AddMissionInviteLinkEntity,
+    errors::AppResult,
+    value_objects::invite_link_model::{InviteLinkModel, InviteLinkPreviewModel, InviteLinkUseModel},
+};
+
+#[async_trait]
+pub trait MissionInviteLinkRepository: Send + Sync {
+    async fn create(&self, link: AddMissionInviteLinkEntity) -> AppResult<InviteLinkModel>;
+    async fn find_by_token_hash(&self, token_hash: String) -> AppResult<Option<InviteLinkModel>>;
+    /// Every link of the mission, revoked and lapsed ones included, newest first.
+    async fn find_by_mission(&self, mission_id: i32) -> AppResult<Vec<InviteLinkModel>>;
+    /// Brawlers who joined through the link, newest first.
+    async fn find_uses(&self, link_id: i32) -> AppResult<Vec<InviteLinkUseModel>>;
+    /// The mission behind a link that is still usable at `now`.
+    async fn preview(&self, token_hash: String, now: NaiveDateTime) -> AppResult<Option<InviteLinkPreviewModel>>;
+    /// Fails with not found when the link does not belong to the mission. Revoking twice keeps the first time.
+    async fn revoke(&self, link_id: i32, mission_id: i32, now: NaiveDateTime) -> AppResult<()>;
+    /// Counts the brawler against the link's use limit, failing when the link stopped being usable
+    /// in the meantime. A brawler who used the link before is not counted twice; returns whether
+    /// this use was counted.
+    async fn record_use(&self, link_id: i32, brawler_id: i32, now: NaiveDateTime) -> AppResult<bool>;
+    /// Undoes a counted use whose join did not go through.
+    async fn release_use(&self, link_id: i32, brawler_id: i32) -> AppResult<()>;
+}
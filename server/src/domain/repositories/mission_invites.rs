@@ -1,14 +1,31 @@
-use crate::domain::entities::mission_invites::{MissionInvite, NewMissionInvite, MissionInviteDetails};
-use anyhow::Result;
+use crate::domain::entities::mission_invites::{MissionInvite, NewMissionInvite, MissionInviteDetails, SentMissionInvite};
+use crate::domain::errors::AppResult;
+use crate::domain::value_objects::invite_model::{BulkInviteResultModel, InviteRecipient, InviteStatus};
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
 #[async_trait]
 pub trait MissionInviteRepository: Send + Sync {
-    async fn create(&self, invite: NewMissionInvite) -> Result<MissionInvite>;
-    async fn find_by_id(&self, id: i32) -> Result<Option<MissionInvite>>;
-    async fn find_invites_by_user(&self, user_id: i32) -> Result<Vec<MissionInvite>>;
-    async fn find_invites_details_by_user(&self, user_id: i32) -> Result<Vec<MissionInviteDetails>>;
-    async fn find_invites_by_mission(&self, mission_id: i32) -> Result<Vec<MissionInvite>>;
-    async fn update_status(&self, id: i32, status: String) -> Result<MissionInvite>;
-    async fn check_exists(&self, mission_id: i32, user_id: i32) -> Result<bool>;
+    async fn create(&self, invite: NewMissionInvite) -> AppResult<MissionInvite>;
+    async fn find_by_id(&self, id: i32) -> AppResult<Option<MissionInvite>>;
+    async fn find_invites_by_user(&self, user_id: i32) -> AppResult<Vec<MissionInvite>>;
+    /// Pending invites still open at `now`.
+    async fn find_invites_details_by_user(&self, user_id: i32, now: NaiveDateTime) -> AppResult<Vec<MissionInviteDetails>>;
+    /// Every invite of the mission, newest first.
+    async fn find_invites_by_mission(&self, mission_id: i32) -> AppResult<Vec<SentMissionInvite>>;
+    async fn update_status(&self, id: i32, status: InviteStatus) -> AppResult<MissionInvite>;
+    /// Whether a pending invite still open at `now` exists.
+    async fn check_exists(&self, mission_id: i32, user_id: i32, now: NaiveDateTime) -> AppResult<bool>;
+    /// Invites every recipient in one transaction, skipping members, banned brawlers and those
+    /// with a pending invite still open at `now`. Results follow the order of `recipients`.
+    async fn create_many(
+        &self,
+        mission_id: i32,
+        invited_by: i32,
+        recipients: Vec<InviteRecipient>,
+        expires_at: NaiveDateTime,
+        now: NaiveDateTime,
+    ) -> AppResult<Vec<BulkInviteResultModel>>;
+    /// Marks pending invites that lapsed by `now` as expired and returns how many.
+    async fn expire_invites(&self, now: NaiveDateTime) -> AppResult<usize>;
 }
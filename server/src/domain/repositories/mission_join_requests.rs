@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_join_requests::AddMissionJoinRequestEntity,
+    errors::AppResult,
+    value_objects::join_request_model::{JoinRequestModel, JoinRequestStatus},
+};
+
+#[async_trait]
+pub trait MissionJoinRequestRepository {
+    /// Fails with a conflict while the brawler already has a pending request for the mission.
+    async fn create(&self, request: AddMissionJoinRequestEntity) -> AppResult<i32>;
+    async fn find_one(&self, request_id: i32, mission_id: i32) -> AppResult<JoinRequestModel>;
+    /// Pending requests of a mission, oldest first.
+    async fn find_pending(&self, mission_id: i32) -> AppResult<Vec<JoinRequestModel>>;
+    async fn find_pending_by_brawler(&self, mission_id: i32, brawler_id: i32) -> AppResult<Option<JoinRequestModel>>;
+    /// Closes a pending request; `decided_by` is the chief, or `None` when the brawler withdrew.
+    async fn decide(&self, request_id: i32, status: JoinRequestStatus, decided_by: Option<i32>) -> AppResult<()>;
+}
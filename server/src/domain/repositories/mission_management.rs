@@ -1,4 +1,4 @@
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 use async_trait::async_trait;
 
 use crate::domain::{
@@ -9,14 +9,21 @@ use crate::infrastructure::cloudinary::UploadImageOptions;
 
 #[async_trait]
 pub trait MissionManagementRepository {
-    async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32>;
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
-    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
+    async fn add(&self, add_mission_entity: AddMissionEntity) -> AppResult<i32>;
+    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> AppResult<i32>;
+    async fn remove(&self, mission_id: i32, chief_id: i32) -> AppResult<()>;
+    async fn force_remove(&self, mission_id: i32) -> AppResult<()>;
+    /// Undoes `remove` if the mission was deleted less than `retention_days` ago.
+    async fn restore(&self, mission_id: i32, chief_id: i32, retention_days: i32) -> AppResult<()>;
+    async fn force_restore(&self, mission_id: i32, retention_days: i32) -> AppResult<()>;
+    /// Hard-deletes missions deleted more than `retention_days` ago, with their
+    /// messages, invites and memberships. Returns how many missions were removed.
+    async fn purge_deleted(&self, retention_days: i32) -> AppResult<usize>;
     async fn upload_image(
         &self,
         mission_id: i32,
         chief_id: i32,
         base64img: Base64Img,
         opt: UploadImageOptions,
-    ) -> Result<UploadedImg>;
+    ) -> AppResult<UploadedImg>;
 }
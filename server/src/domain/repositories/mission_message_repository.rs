@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 use crate::domain::{
     entities::mission_messages::{MissionMessageEntity, NewMissionMessageEntity},
     value_objects::mission_message_model::MissionMessageModel,
@@ -7,6 +7,6 @@ use crate::domain::{
 
 #[async_trait]
 pub trait MissionMessageRepository: Send + Sync {
-    async fn create(&self, entity: NewMissionMessageEntity) -> Result<MissionMessageEntity>;
-    async fn get_by_mission_id(&self, mission_id: i32) -> Result<Vec<MissionMessageModel>>;
+    async fn create(&self, entity: NewMissionMessageEntity) -> AppResult<MissionMessageEntity>;
+    async fn get_by_mission_id(&self, mission_id: i32) -> AppResult<Vec<MissionMessageModel>>;
 }
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_objectives::{AddMissionObjectiveEntity, EditMissionObjectiveEntity},
+    errors::AppResult,
+    value_objects::mission_objective_model::MissionObjectiveModel,
+};
+
+#[async_trait]
+pub trait MissionObjectiveRepository {
+    /// The mission's checklist in order.
+    async fn find_by_mission(&self, mission_id: i32) -> AppResult<Vec<MissionObjectiveModel>>;
+    async fn find_one(&self, objective_id: i32, mission_id: i32) -> AppResult<MissionObjectiveModel>;
+    /// Appends the objective to the end of the checklist.
+    async fn create(&self, objective: AddMissionObjectiveEntity) -> AppResult<i32>;
+    async fn edit(&self, objective_id: i32, mission_id: i32, objective: EditMissionObjectiveEntity) -> AppResult<()>;
+    /// Removes the objective and closes the gap it leaves in the order.
+    async fn remove(&self, objective_id: i32, mission_id: i32) -> AppResult<()>;
+    /// `objective_ids` must hold every objective of the mission exactly once.
+    async fn reorder(&self, mission_id: i32, objective_ids: Vec<i32>) -> AppResult<()>;
+    /// Marks the objective done by `completed_by`, or open again with `None`.
+    async fn set_completed(&self, objective_id: i32, mission_id: i32, completed_by: Option<i32>) -> AppResult<()>;
+}
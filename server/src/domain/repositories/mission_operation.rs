@@ -1,9 +1,21 @@
-use anyhow::Result;
+use crate::domain::{entities::missions::MissionEntity, errors::AppResult};
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
 #[async_trait]
 pub trait MissionOperationRepository {
-    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
+    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> AppResult<i32>;
+    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> AppResult<i32>;
+    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> AppResult<i32>;
+
+    /// Open missions starting at or before `before` whose reminder has not gone out.
+    async fn find_due_reminders(&self, before: NaiveDateTime) -> AppResult<Vec<MissionEntity>>;
+    async fn mark_reminder_sent(&self, mission_id: i32) -> AppResult<()>;
+    /// Open missions whose start has passed and that the scheduler has not tried to start yet.
+    async fn find_due_starts(&self, now: NaiveDateTime) -> AppResult<Vec<MissionEntity>>;
+    async fn mark_auto_start_checked(&self, mission_id: i32) -> AppResult<()>;
+    /// In-progress missions past their deadline.
+    async fn find_overdue(&self, now: NaiveDateTime) -> AppResult<Vec<MissionEntity>>;
+    /// Mandatory objectives of the mission not yet marked done.
+    async fn count_open_mandatory_objectives(&self, mission_id: i32) -> AppResult<i64>;
 }
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_ratings::AddMissionRatingEntity,
+    errors::AppResult,
+    value_objects::rating_model::{MissionRatingModel, PeerRatingModel, ReputationModel},
+};
+
+#[async_trait]
+pub trait MissionRatingRepository: Send + Sync {
+    /// Creates or replaces the rater's rating of the ratee for the mission and refreshes the
+    /// ratee's cached reputation.
+    async fn rate(&self, rating: AddMissionRatingEntity) -> AppResult<MissionRatingModel>;
+    /// The mission's chief and the crew it finished with, other than the rater, each with the
+    /// rater's rating if any.
+    async fn find_peers(&self, mission_id: i32, rater_id: i32) -> AppResult<Vec<PeerRatingModel>>;
+    /// Whether the brawler is the chief or was already in the crew when the mission finished.
+    async fn took_part(&self, mission_id: i32, brawler_id: i32) -> AppResult<bool>;
+    async fn reputation(&self, brawler_id: i32) -> AppResult<ReputationModel>;
+}
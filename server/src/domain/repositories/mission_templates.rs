@@ -0,0 +1,29 @@
+use crate::domain::errors::AppResult;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::entities::{
+    mission_templates::{AddMissionTemplateEntity, EditMissionTemplateEntity, MissionTemplateEntity},
+    missions::AddMissionEntity,
+};
+
+#[async_trait]
+pub trait MissionTemplateRepository: Send + Sync {
+    async fn create(&self, template: AddMissionTemplateEntity) -> AppResult<MissionTemplateEntity>;
+    async fn find_by_owner(&self, owner_id: i32) -> AppResult<Vec<MissionTemplateEntity>>;
+    /// Not found unless `owner_id` owns the template.
+    async fn find_owned(&self, template_id: i32, owner_id: i32) -> AppResult<MissionTemplateEntity>;
+    async fn edit(
+        &self,
+        template_id: i32,
+        owner_id: i32,
+        template: EditMissionTemplateEntity,
+    ) -> AppResult<MissionTemplateEntity>;
+    async fn remove(&self, template_id: i32, owner_id: i32) -> AppResult<()>;
+    async fn find_recurring(&self) -> AppResult<Vec<MissionTemplateEntity>>;
+    /// Creates the mission for one occurrence and advances the template's
+    /// `last_scheduled_at`. `None` when that occurrence already has a mission.
+    async fn schedule_occurrence(&self, template_id: i32, mission: AddMissionEntity) -> AppResult<Option<i32>>;
+    /// Crew of the latest occurrence starting before `before` that had any.
+    async fn previous_crew(&self, template_id: i32, before: NaiveDateTime) -> AppResult<Vec<i32>>;
+}
@@ -1,18 +1,31 @@
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 use async_trait::async_trait;
 
 use crate::domain::value_objects::{
-    brawler_model::BrawlerModel, mission_filter::MissionFilter, MissionModel,
+    brawler_model::BrawlerModel,
+    mission_filter::MissionFilter,
+    mission_model::MissionSort,
+    pagination::{Page, PageRequest},
+    MissionModel,
 };
 
 #[async_trait]
 pub trait MissionViewingRepository: Send + Sync {
-    async fn crew_counting(&self, mission_id: i32) -> Result<i64>;
-    async fn get_one(&self, mission_id: i32, brawler_id: i32) -> Result<MissionModel>;
-    async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>>;
-    async fn get_all(&self, filter: &MissionFilter, brawler_id: i32) -> Result<Vec<MissionModel>>;
+    async fn crew_counting(&self, mission_id: i32) -> AppResult<i64>;
+    async fn get_one(&self, mission_id: i32, brawler_id: i32) -> AppResult<MissionModel>;
+    async fn get_crew(&self, mission_id: i32) -> AppResult<Vec<BrawlerModel>>;
+    async fn get_all(
+        &self,
+        filter: &MissionFilter,
+        brawler_id: i32,
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>>;
     // *เพิ่ม
-    async fn get_joined_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>>;
+    async fn get_joined_missions(
+        &self,
+        brawler_id: i32,
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>>;
     // *เพิ่ม
-    async fn get_popular_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>>;
+    async fn get_popular_missions(&self, brawler_id: i32) -> AppResult<Vec<MissionModel>>;
 }
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::mission_waitlist::AddMissionWaitlistEntity,
+    errors::AppResult,
+    value_objects::waitlist_model::{WaitlistEntryModel, WaitlistStatus},
+};
+
+#[async_trait]
+pub trait MissionWaitlistRepository: Send + Sync {
+    /// Fails with a conflict while the brawler is already queued for the mission.
+    async fn enqueue(&self, entry: AddMissionWaitlistEntity) -> AppResult<()>;
+    /// Waiting and offered entries in queue order.
+    async fn find_by_mission(&self, mission_id: i32) -> AppResult<Vec<WaitlistEntryModel>>;
+    async fn find_active(&self, mission_id: i32, brawler_id: i32) -> AppResult<Option<WaitlistEntryModel>>;
+    /// Places held by offers still open at `now`, not counting `except_brawler_id`'s own.
+    async fn count_held_places(&self, mission_id: i32, except_brawler_id: i32, now: NaiveDateTime) -> AppResult<i64>;
+    async fn set_status(&self, entry_id: i32, status: WaitlistStatus) -> AppResult<()>;
+    /// Offers every free place of an open mission to the next waiting brawlers, holding each
+    /// until `expires_at`. Returns the new offers.
+    async fn offer_open_places(&self, mission_id: i32, now: NaiveDateTime, expires_at: NaiveDateTime) -> AppResult<Vec<WaitlistEntryModel>>;
+    /// Expires offers that lapsed by `now` and returns the missions they belonged to.
+    async fn expire_offers(&self, now: NaiveDateTime) -> AppResult<Vec<i32>>;
+}
@@ -12,4 +12,24 @@ pub use brawlers::BrawlerRepository;
 pub use achievements::AchievementRepository;
 pub use mission_message_repository::MissionMessageRepository;
 pub mod mission_invites;
-pub use mission_invites::MissionInviteRepository;
\ No newline at end of file
+pub use mission_invites::MissionInviteRepository;
+pub mod login_attempts;
+pub use login_attempts::LoginAttemptRepository;
+pub mod identities;
+pub use identities::IdentityRepository;
+pub mod api_keys;
+pub use api_keys::ApiKeyRepository;
+pub mod mission_templates;
+pub use mission_templates::MissionTemplateRepository;
+pub mod mission_objectives;
+pub use mission_objectives::MissionObjectiveRepository;
+pub mod mission_join_requests;
+pub use mission_join_requests::MissionJoinRequestRepository;
+pub mod mission_waitlist;
+pub use mission_waitlist::MissionWaitlistRepository;
+pub mod mission_bans;
+pub use mission_bans::MissionBanRepository;
+pub mod mission_invite_links;
+pub use mission_invite_links::MissionInviteLinkRepository;
+pub mod mission_ratings;
+pub use mission_ratings::MissionRatingRepository;
@@ -1,9 +1,24 @@
 // *เพิ่ม
 
 use chrono::NaiveDateTime;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::achievements::{AddAchievementEntity, EditAchievementEntity},
+        errors::FieldError,
+        value_objects::validation::{Validate, check_length, check_max_length},
+    },
+};
+
+/// Column widths of the `achievements` table.
+const ACHIEVEMENT_NAME_MAX_LEN: usize = 255;
+const ACHIEVEMENT_ICON_URL_MAX_LEN: usize = 255;
+const ACHIEVEMENT_CONDITION_TYPE_MAX_LEN: usize = 50;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct AchievementViewModel {
     pub id: i32,
     pub name: String,
@@ -12,3 +27,63 @@ pub struct AchievementViewModel {
     pub is_earned: bool,
     pub earned_at: Option<NaiveDateTime>,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AddAchievementModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub icon_url: Option<String>,
+    pub condition_type: Option<String>,
+    pub condition_value: Option<i32>,
+}
+
+impl AddAchievementModel {
+    pub fn to_entity(&self) -> AddAchievementEntity {
+        AddAchievementEntity {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            icon_url: self.icon_url.clone(),
+            condition_type: self.condition_type.clone(),
+            condition_value: self.condition_value,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct EditAchievementModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub icon_url: Option<String>,
+    pub condition_type: Option<String>,
+    pub condition_value: Option<i32>,
+}
+
+impl EditAchievementModel {
+    pub fn to_entity(&self) -> EditAchievementEntity {
+        EditAchievementEntity {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            icon_url: self.icon_url.clone(),
+            condition_type: self.condition_type.clone(),
+            condition_value: self.condition_value,
+        }
+    }
+}
+
+impl Validate for AddAchievementModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "name", &self.name, 1, ACHIEVEMENT_NAME_MAX_LEN);
+        check_max_length(errors, "icon_url", self.icon_url.as_deref(), ACHIEVEMENT_ICON_URL_MAX_LEN);
+        check_max_length(errors, "condition_type", self.condition_type.as_deref(), ACHIEVEMENT_CONDITION_TYPE_MAX_LEN);
+    }
+}
+
+impl Validate for EditAchievementModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if let Some(name) = self.name.as_deref() {
+            check_length(errors, "name", name, 1, ACHIEVEMENT_NAME_MAX_LEN);
+        }
+        check_max_length(errors, "icon_url", self.icon_url.as_deref(), ACHIEVEMENT_ICON_URL_MAX_LEN);
+        check_max_length(errors, "condition_type", self.condition_type.as_deref(), ACHIEVEMENT_CONDITION_TYPE_MAX_LEN);
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::brawlers::BrawlerEntity,
+        errors::FieldError,
+        value_objects::{roles::Roles, validation::Validate},
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AdminBrawlerModel {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub role: String,
+    pub disabled_at: Option<NaiveDateTime>,
+    pub mission_success_count: i32,
+    pub mission_join_count: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<BrawlerEntity> for AdminBrawlerModel {
+    fn from(entity: BrawlerEntity) -> Self {
+        Self {
+            id: entity.id,
+            username: entity.username,
+            display_name: entity.display_name,
+            role: entity.role,
+            disabled_at: entity.disabled_at,
+            mission_success_count: entity.mission_success_count,
+            mission_join_count: entity.mission_join_count,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateRoleModel {
+    pub role: Roles,
+}
+
+impl Validate for UpdateRoleModel {
+    /// `role` is an enum, so deserialising it is the whole check.
+    fn validate(&self, _limits: &ValidationEnv, _errors: &mut Vec<FieldError>) {}
+}
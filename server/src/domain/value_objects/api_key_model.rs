@@ -0,0 +1,144 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::{api_keys::ApiKeyEntity, brawlers::BrawlerEntity},
+        errors::FieldError,
+        value_objects::{
+            roles::Roles,
+            validation::{Validate, check_length},
+        },
+    },
+};
+
+/// Width of `api_keys.name`.
+const API_KEY_NAME_MAX_LEN: usize = 100;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyScopes {
+    ReadMissions,
+    PostChat,
+    ManageMissions,
+}
+
+impl Display for ApiKeyScopes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiKeyScopes::ReadMissions => write!(f, "read_missions"),
+            ApiKeyScopes::PostChat => write!(f, "post_chat"),
+            ApiKeyScopes::ManageMissions => write!(f, "manage_missions"),
+        }
+    }
+}
+
+impl FromStr for ApiKeyScopes {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read_missions" => Ok(ApiKeyScopes::ReadMissions),
+            "post_chat" => Ok(ApiKeyScopes::PostChat),
+            "manage_missions" => Ok(ApiKeyScopes::ManageMissions),
+            _ => Err(anyhow::anyhow!("Unknown API key scope: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddApiKeyModel {
+    pub name: String,
+    pub scopes: Vec<ApiKeyScopes>,
+    /// Issue the key for one of the caller's bots instead of the caller.
+    pub bot_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiKeyModel {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<ApiKeyEntity> for ApiKeyModel {
+    fn from(entity: ApiKeyEntity) -> Self {
+        Self {
+            id: entity.id,
+            brawler_id: entity.brawler_id,
+            name: entity.name,
+            prefix: entity.prefix,
+            scopes: entity.scopes,
+            last_used_at: entity.last_used_at,
+            revoked_at: entity.revoked_at,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+/// Returned only when a key is created; the plain key is never stored.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreatedApiKeyModel {
+    pub key: String,
+    pub api_key: ApiKeyModel,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddBotModel {
+    pub username: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BotModel {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub disabled_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<BrawlerEntity> for BotModel {
+    fn from(entity: BrawlerEntity) -> Self {
+        Self {
+            id: entity.id,
+            username: entity.username,
+            display_name: entity.display_name,
+            disabled_at: entity.disabled_at,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+/// The brawler behind a valid API key and what the key may do.
+#[derive(Debug, Clone)]
+pub struct ApiKeyPrincipal {
+    pub brawler_id: i32,
+    pub role: Roles,
+    pub scopes: Vec<ApiKeyScopes>,
+}
+
+impl Validate for AddApiKeyModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "name", &self.name, 1, API_KEY_NAME_MAX_LEN);
+        if self.scopes.is_empty() {
+            errors.push(FieldError::new("scopes", "must contain at least one scope"));
+        }
+    }
+}
+
+impl Validate for AddBotModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "username", &self.username, limits.username_min_len, limits.username_max_len);
+        check_length(errors, "display_name", &self.display_name, limits.display_name_min_len, limits.display_name_max_len);
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Integer, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::FieldError,
+        value_objects::validation::{Validate, check_max_length},
+    },
+};
+
+/// Width of `mission_bans.reason`.
+const BAN_REASON_MAX_LEN: usize = 255;
+
+/// A ban still in force.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct MissionBanModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub brawler_display_name: String,
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub banned_by: Option<i32>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub reason: Option<String>,
+    /// `null` for a ban without end.
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub expires_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+/// Optional part of a kick that also bars the brawler from coming back.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct BanModel {
+    pub reason: Option<String>,
+    /// When the ban ends; leave out to ban for good.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl BanModel {
+    pub fn trimmed_reason(&self) -> Option<String> {
+        self.reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|reason| !reason.is_empty())
+            .map(str::to_string)
+    }
+}
+
+impl Validate for BanModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_max_length(errors, "ban.reason", self.reason.as_deref(), BAN_REASON_MAX_LEN);
+        if matches!(self.expires_at, Some(at) if at <= Utc::now()) {
+            errors.push(FieldError::new("ban.expires_at", "must be in the future"));
+        }
+    }
+}
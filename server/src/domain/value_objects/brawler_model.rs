@@ -1,12 +1,23 @@
 use diesel::{
     prelude::QueryableByName,
-    sql_types::{Integer, Nullable, Varchar},
+    sql_types::{Bool, Double, Integer, Nullable, Varchar},
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::brawlers::RegisterBrawlerEntity,
+        errors::FieldError,
+        value_objects::{
+            pagination::{Cursor, CursorKind, SortDirection, SortField},
+            validation::{Validate, check_length},
+        },
+    },
+};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RegisterBrawlerModel {
     pub username: String,
     pub password: String,
@@ -23,12 +34,12 @@ impl RegisterBrawlerModel {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateProfileModel {
     pub display_name: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
 pub struct BrawlerModel {
     #[diesel(sql_type=Integer)]
     pub id: i32,
@@ -40,4 +51,98 @@ pub struct BrawlerModel {
     pub mission_success_count: i32,
     #[diesel(sql_type=Integer)]
     pub mission_join_count: i32,
+    /// Peer rating average from finished missions, pulled towards 3 while ratings are few.
+    #[diesel(sql_type=Double)]
+    pub reputation: f64,
+    #[diesel(sql_type=Integer)]
+    pub rating_count: i32,
+    /// Role slot held in the mission, only set in crew listings.
+    #[diesel(sql_type=Nullable<Integer>)]
+    pub role_slot_id: Option<i32>,
+    /// Label of that role slot.
+    #[diesel(sql_type=Nullable<Varchar>)]
+    pub role: Option<String>,
+    /// Helps the chief run the mission, only set in crew listings.
+    #[diesel(sql_type=Bool)]
+    pub is_co_chief: bool,
+}
+
+/// A mission passed to its longest-standing member when the chief deleted their account.
+#[derive(Debug, Clone)]
+pub struct ChiefHandover {
+    pub mission_id: i32,
+    pub mission_name: String,
+    pub new_chief_id: i32,
+}
+
+/// What closing an account changed in missions other brawlers still run.
+#[derive(Debug, Clone, Default)]
+pub struct AccountDeletion {
+    pub handovers: Vec<ChiefHandover>,
+    /// Open or in-progress missions where the account gave up a crew place.
+    pub vacated_mission_ids: Vec<i32>,
+}
+
+/// Sort fields for brawler listings.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BrawlerSort {
+    #[default]
+    DisplayName,
+    MissionSuccessCount,
+    MissionJoinCount,
+}
+
+impl SortField for BrawlerSort {
+    fn default_direction(self) -> SortDirection {
+        match self {
+            BrawlerSort::DisplayName => SortDirection::Asc,
+            _ => SortDirection::Desc,
+        }
+    }
+
+    fn cursor_kind(self) -> CursorKind {
+        match self {
+            BrawlerSort::DisplayName => CursorKind::Text,
+            BrawlerSort::MissionSuccessCount | BrawlerSort::MissionJoinCount => CursorKind::Integer,
+        }
+    }
+}
+
+impl BrawlerSort {
+    pub fn cursor(self, brawler: &BrawlerModel) -> Cursor {
+        let value = match self {
+            BrawlerSort::DisplayName => brawler.display_name.clone(),
+            BrawlerSort::MissionSuccessCount => brawler.mission_success_count.to_string(),
+            BrawlerSort::MissionJoinCount => brawler.mission_join_count.to_string(),
+        };
+        Cursor::new(value, brawler.id)
+    }
+}
+
+impl Validate for RegisterBrawlerModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "username", &self.username, limits.username_min_len, limits.username_max_len);
+        if self.username.chars().any(char::is_whitespace) {
+            errors.push(FieldError::new("username", "must not contain spaces"));
+        }
+        // Passwords are not trimmed, so count every character.
+        let password_len = self.password.chars().count();
+        if password_len < limits.password_min_len || password_len > limits.password_max_len {
+            errors.push(FieldError::new(
+                "password",
+                format!(
+                    "must be between {} and {} characters long",
+                    limits.password_min_len, limits.password_max_len
+                ),
+            ));
+        }
+        check_length(errors, "display_name", &self.display_name, limits.display_name_min_len, limits.display_name_max_len);
+    }
+}
+
+impl Validate for UpdateProfileModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "display_name", &self.display_name, limits.display_name_min_len, limits.display_name_max_len);
+    }
 }
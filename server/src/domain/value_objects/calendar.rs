@@ -0,0 +1,172 @@
+use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::missions::MissionEntity,
+        errors::FieldError,
+        value_objects::{
+            mission_statuses::MissionStatuses,
+            validation::{Validate, check_length},
+        },
+    },
+};
+
+/// Length given to events of missions with neither a deadline nor a duration.
+const DEFAULT_EVENT_MINUTES: i64 = 60;
+/// Far longer than any issued token; anything bigger is not worth hashing.
+const MAX_TOKEN_LEN: usize = 128;
+/// RFC 5545 content lines are folded at 75 octets.
+const MAX_LINE_OCTETS: usize = 75;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CalendarTokenModel {
+    /// Shown only once; issuing a new token invalidates the previous feed URL.
+    pub token: String,
+    /// Subscribe to this path on the API host.
+    pub feed_path: String,
+}
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarFeedQuery {
+    /// Token from `POST /api/brawler/calendar-token`.
+    pub token: String,
+}
+
+impl Validate for CalendarFeedQuery {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "token", &self.token, 1, MAX_TOKEN_LEN);
+    }
+}
+
+/// An iCalendar (RFC 5545) feed with one event per scheduled mission.
+pub fn render_feed(missions: &[MissionEntity]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//ASTRA//Missions//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "X-WR-CALNAME:ASTRA missions".to_string(),
+    ];
+
+    for mission in missions {
+        let Some(starts_at) = mission.scheduled_start_at else {
+            continue;
+        };
+        let ends_at = mission
+            .deadline_at
+            .or_else(|| {
+                mission
+                    .duration_minutes
+                    .map(|minutes| starts_at + Duration::minutes(i64::from(minutes)))
+            })
+            .unwrap_or(starts_at + Duration::minutes(DEFAULT_EVENT_MINUTES));
+
+        let mut description = format!("Status: {}", mission.status);
+        if let Some(details) = mission.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            description.push_str("\n\n");
+            description.push_str(details);
+        }
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:mission-{}@astra", mission.id));
+        lines.push(format!("DTSTAMP:{}", utc_stamp(mission.updated_at)));
+        lines.push(format!("LAST-MODIFIED:{}", utc_stamp(mission.updated_at)));
+        lines.push(format!("DTSTART:{}", utc_stamp(starts_at)));
+        lines.push(format!("DTEND:{}", utc_stamp(ends_at.max(starts_at))));
+        lines.push(format!("SUMMARY:{}", escape_text(&mission.name)));
+        lines.push(format!("DESCRIPTION:{}", escape_text(&description)));
+        if let Some(category) = mission.category.as_deref() {
+            lines.push(format!("CATEGORIES:{}", escape_text(category)));
+        }
+        lines.push(format!("STATUS:{}", event_status(&mission.status)));
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect::<String>()
+}
+
+fn event_status(status: &str) -> &'static str {
+    if status == MissionStatuses::Open.to_string() {
+        "TENTATIVE"
+    } else if status == MissionStatuses::Failed.to_string() {
+        "CANCELLED"
+    } else {
+        "CONFIRMED"
+    }
+}
+
+fn utc_stamp(at: NaiveDateTime) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+/// Splits a content line into CRLF-terminated pieces of at most 75 octets,
+/// never inside a UTF-8 character; continuation lines start with a space.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_text_escapes_separators_and_newlines() {
+        assert_eq!(escape_text(r"a\b;c,d"), r"a\\b\;c\,d");
+        assert_eq!(escape_text("one\r\ntwo\nthree\rfour"), r"one\ntwo\nthree\nfour");
+    }
+
+    #[test]
+    fn fold_keeps_short_lines_whole() {
+        assert_eq!(fold("SUMMARY:Raid"), "SUMMARY:Raid\r\n");
+    }
+
+    #[test]
+    fn fold_splits_long_lines_at_75_octets() {
+        let line = format!("DESCRIPTION:{}", "x".repeat(200));
+        let folded = fold(&line);
+
+        assert!(folded.ends_with("\r\n"));
+        let pieces: Vec<&str> = folded.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(pieces.len() > 1);
+        assert!(pieces.iter().all(|piece| piece.len() <= MAX_LINE_OCTETS));
+        assert!(pieces[1..].iter().all(|piece| piece.starts_with(' ')));
+        let unfolded: String = pieces.iter().enumerate().map(|(i, p)| if i == 0 { *p } else { &p[1..] }).collect();
+        assert_eq!(unfolded, line);
+    }
+
+    #[test]
+    fn fold_never_splits_a_character() {
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold(&line);
+
+        let pieces: Vec<&str> = folded.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(pieces.iter().all(|piece| piece.len() <= MAX_LINE_OCTETS));
+        assert_eq!(pieces.concat().replace(' ', ""), line.replace(' ', ""));
+    }
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Integer, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{errors::FieldError, value_objects::validation::Validate},
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AddInviteLinkModel {
+    /// How many brawlers may join through the link; leave out for no limit.
+    pub max_uses: Option<i32>,
+    /// When the link stops working; leave out to keep it until revoked.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Slot offered to whoever joins through the link, while it is still free.
+    pub role_slot_id: Option<i32>,
+}
+
+impl Validate for AddInviteLinkModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if matches!(self.max_uses, Some(max_uses) if max_uses <= 0) {
+            errors.push(FieldError::new("max_uses", "must be at least 1"));
+        }
+        if matches!(self.expires_at, Some(at) if at <= Utc::now()) {
+            errors.push(FieldError::new("expires_at", "must be in the future"));
+        }
+    }
+}
+
+/// An invite link as its mission's managers see it. The token is never part of it.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct InviteLinkModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub created_by: Option<i32>,
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub role_slot_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub role_label: Option<String>,
+    /// `null` for a link without a use limit.
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub max_uses: Option<i32>,
+    #[diesel(sql_type = Integer)]
+    pub use_count: i32,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub expires_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub revoked_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+impl InviteLinkModel {
+    pub fn is_expired(&self, now: NaiveDateTime) -> bool {
+        matches!(self.expires_at, Some(at) if at <= now)
+    }
+
+    pub fn is_used_up(&self) -> bool {
+        matches!(self.max_uses, Some(max_uses) if self.use_count >= max_uses)
+    }
+
+    pub fn is_usable(&self, now: NaiveDateTime) -> bool {
+        self.revoked_at.is_none() && !self.is_expired(now) && !self.is_used_up()
+    }
+}
+
+/// Returned only when a link is created; the plain token is never stored.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreatedInviteLinkModel {
+    pub token: String,
+    pub link: InviteLinkModel,
+}
+
+/// A brawler who joined through a link.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct InviteLinkUseModel {
+    #[diesel(sql_type = Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub brawler_display_name: String,
+    #[diesel(sql_type = Timestamp)]
+    pub used_at: NaiveDateTime,
+}
+
+/// What anyone holding a usable link may see before joining.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct InviteLinkPreviewModel {
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub mission_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub mission_status: String,
+    #[diesel(sql_type = Varchar)]
+    pub chief_display_name: String,
+    #[diesel(sql_type = BigInt)]
+    pub crew_count: i64,
+    #[diesel(sql_type = Integer)]
+    pub max_crew: i32,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub role_label: Option<String>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub expires_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::FieldError,
+        value_objects::validation::{Validate, check_length},
+    },
+};
+
+/// Most recipients one bulk invite may name.
+const BULK_INVITE_MAX_RECIPIENTS: usize = 50;
+/// Width of `brawlers.username`.
+const USERNAME_MAX_LEN: usize = 255;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InviteStatus {
+    Pending,
+    Accepted,
+    Rejected,
+    /// Withdrawn by the chief or a co-chief.
+    Cancelled,
+    /// Not answered before `expires_at`.
+    Expired,
+}
+
+impl Display for InviteStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InviteStatus::Pending => write!(f, "pending"),
+            InviteStatus::Accepted => write!(f, "accepted"),
+            InviteStatus::Rejected => write!(f, "rejected"),
+            InviteStatus::Cancelled => write!(f, "cancelled"),
+            InviteStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+/// Brawlers to invite at once, by id, by username or both.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct BulkInviteModel {
+    #[serde(default)]
+    pub user_ids: Vec<i32>,
+    #[serde(default)]
+    pub usernames: Vec<String>,
+}
+
+impl BulkInviteModel {
+    /// Ids first, then usernames, each in the order given.
+    pub fn recipients(&self) -> Vec<InviteRecipient> {
+        self.user_ids
+            .iter()
+            .map(|id| InviteRecipient::Id(*id))
+            .chain(self.usernames.iter().map(|name| InviteRecipient::Username(name.trim().to_string())))
+            .collect()
+    }
+}
+
+impl Validate for BulkInviteModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        let count = self.user_ids.len() + self.usernames.len();
+        if count == 0 {
+            errors.push(FieldError::new("user_ids", "name at least one brawler by id or username"));
+        } else if count > BULK_INVITE_MAX_RECIPIENTS {
+            errors.push(FieldError::new(
+                "user_ids",
+                format!("at most {BULK_INVITE_MAX_RECIPIENTS} brawlers per request"),
+            ));
+        }
+        if self.user_ids.iter().any(|id| *id <= 0) {
+            errors.push(FieldError::new("user_ids", "must be valid brawler ids"));
+        }
+        for username in &self.usernames {
+            check_length(errors, "usernames", username, 1, USERNAME_MAX_LEN);
+        }
+    }
+}
+
+/// A brawler as the inviter named them; serialized as the bare id or username.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum InviteRecipient {
+    Id(i32),
+    Username(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkInviteOutcome {
+    Invited,
+    AlreadyMember,
+    AlreadyInvited,
+    Banned,
+    NotFound,
+}
+
+/// What happened to one recipient of a bulk invite.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkInviteResultModel {
+    pub recipient: InviteRecipient,
+    pub outcome: BulkInviteOutcome,
+    /// `null` when no brawler matched.
+    pub user_id: Option<i32>,
+    pub username: Option<String>,
+    /// Set when the outcome is `invited`.
+    pub invite_id: Option<i32>,
+}
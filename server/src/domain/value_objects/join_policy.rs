@@ -0,0 +1,40 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Who may join a mission's crew.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinPolicy {
+    /// Anyone may join while there is room.
+    #[default]
+    Open,
+    /// Joining creates a request the chief approves or rejects.
+    Approval,
+    /// Only invited brawlers can get in.
+    InviteOnly,
+}
+
+impl Display for JoinPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinPolicy::Open => write!(f, "open"),
+            JoinPolicy::Approval => write!(f, "approval"),
+            JoinPolicy::InviteOnly => write!(f, "invite_only"),
+        }
+    }
+}
+
+impl FromStr for JoinPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "open" => Ok(JoinPolicy::Open),
+            "approval" => Ok(JoinPolicy::Approval),
+            "invite_only" => Ok(JoinPolicy::InviteOnly),
+            _ => Err(anyhow::anyhow!("Unknown join policy: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Integer, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+    /// Withdrawn by the brawler who asked.
+    Cancelled,
+}
+
+impl Display for JoinRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinRequestStatus::Pending => write!(f, "pending"),
+            JoinRequestStatus::Approved => write!(f, "approved"),
+            JoinRequestStatus::Rejected => write!(f, "rejected"),
+            JoinRequestStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// A join request together with who asked and the role they asked for.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct JoinRequestModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub brawler_display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub brawler_avatar_url: String,
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub role_slot_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub role: Option<String>,
+    #[diesel(sql_type = Varchar)]
+    pub status: String,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+impl JoinRequestModel {
+    pub fn is_pending(&self) -> bool {
+        self.status == JoinRequestStatus::Pending.to_string()
+    }
+}
+
+/// What a join attempt did.
+#[derive(Debug, Clone)]
+pub enum JoinOutcome {
+    Joined,
+    /// The mission needs approval; the chief has been asked.
+    Requested(JoinRequestModel),
+}
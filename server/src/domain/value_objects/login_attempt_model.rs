@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Nullable, Timestamp},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::login_attempts::LoginAttemptEntity;
+
+/// Failed attempts counted for a username or an IP address since the
+/// start of the lockout window (or the last successful login).
+#[derive(Debug, Clone, Default, PartialEq, QueryableByName)]
+pub struct FailedLoginSummary {
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub last_failed_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FailedLoginModel {
+    pub id: i32,
+    pub ip_address: Option<String>,
+    pub attempted_at: NaiveDateTime,
+}
+
+impl From<LoginAttemptEntity> for FailedLoginModel {
+    fn from(entity: LoginAttemptEntity) -> Self {
+        Self {
+            id: entity.id,
+            ip_address: entity.ip_address,
+            attempted_at: entity.attempted_at,
+        }
+    }
+}
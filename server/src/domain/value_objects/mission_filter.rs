@@ -1,10 +1,81 @@
-use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize, de::IntoDeserializer};
+use utoipa::{IntoParams, ToSchema};
 
-use crate::domain::value_objects::mission_statuses::MissionStatuses;
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::FieldError,
+        value_objects::{
+            mission_statuses::MissionStatuses,
+            validation::{Validate, check_max_length},
+        },
+    },
+};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct MissionFilter {
+    /// Substring of the mission name.
     pub name: Option<String>,
-    pub status: Option<MissionStatuses>,
-    pub category: Option<String>,
+    /// Full-text search over name and description. Without `sort`, results are ranked by relevance.
+    pub q: Option<String>,
+    /// Comma-separated, e.g. `Open,InProgress`.
+    #[serde(default, deserialize_with = "comma_separated")]
+    #[param(value_type = Option<Vec<MissionStatuses>>, style = Form, explode = false)]
+    pub status: Option<Vec<MissionStatuses>>,
+    /// Comma-separated.
+    #[serde(default, deserialize_with = "comma_separated")]
+    #[param(style = Form, explode = false)]
+    pub category: Option<Vec<String>>,
+    pub chief_id: Option<i32>,
+    /// Only missions whose crew is not full.
+    pub has_open_slots: Option<bool>,
+    /// Only missions the caller neither leads nor has joined.
+    pub joinable: Option<bool>,
+    /// Created on or after this day.
+    pub created_from: Option<NaiveDate>,
+    /// Created on or before this day.
+    pub created_to: Option<NaiveDate>,
+}
+
+impl MissionFilter {
+    pub fn search(&self) -> Option<&str> {
+        self.q.as_deref().map(str::trim).filter(|q| !q.is_empty())
+    }
+}
+
+impl Validate for MissionFilter {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_max_length(errors, "name", self.name.as_deref(), limits.max_search_len);
+        check_max_length(errors, "q", self.q.as_deref(), limits.max_search_len);
+        for category in self.category.iter().flatten() {
+            check_max_length(errors, "category", Some(category), limits.mission_category_max_len);
+        }
+        if let (Some(from), Some(to)) = (self.created_from, self.created_to) {
+            if from > to {
+                errors.push(FieldError::new("created_to", "must not be before created_from"));
+            }
+        }
+    }
+}
+
+/// `a,b,c` in a single query parameter; empty items are skipped.
+fn comma_separated<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let Some(raw) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    let values = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| T::deserialize(IntoDeserializer::<D::Error>::into_deserializer(value.to_string())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(if values.is_empty() { None } else { Some(values) })
 }
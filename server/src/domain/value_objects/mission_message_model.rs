@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use chrono::NaiveDateTime;
 use diesel::prelude::QueryableByName;
 use diesel::sql_types::{Integer, Text, Timestamp, Varchar, Nullable};
 
-#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
 pub struct MissionMessageModel {
     #[diesel(sql_type = Integer)]
     pub id: i32,
@@ -1,12 +1,22 @@
-use crate::domain::{
-    entities::missions::{AddMissionEntity, EditMissionEntity},
-    value_objects::mission_statuses::MissionStatuses,
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::missions::{AddMissionEntity, EditMissionEntity},
+        errors::{AppError, AppResult, FieldError},
+        value_objects::{
+            join_policy::JoinPolicy,
+            mission_statuses::MissionStatuses,
+            pagination::{Cursor, CursorKind, SortDirection, SortField},
+            validation::{Validate, check_length, check_max_length, check_range},
+        },
+    },
 };
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use diesel::{prelude::QueryableByName, sql_types::Bool};
-use diesel::sql_types::{BigInt, Int4, Nullable, Text, Timestamp, Varchar};
+use diesel::sql_types::{BigInt, Double, Int4, Nullable, Text, Timestamp, Varchar};
 use serde::{Deserialize, Serialize};
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+use utoipa::ToSchema;
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName, ToSchema)]
 pub struct MissionModel {
     #[diesel(sql_type = Int4)]
     pub id: i32,
@@ -29,20 +39,127 @@ pub struct MissionModel {
     // *เพิ่ม
     #[diesel(sql_type = Bool)]
     pub is_member: bool,
+    /// The viewer is a crew member the chief delegated management to.
+    #[diesel(sql_type = Bool)]
+    pub is_co_chief: bool,
     #[diesel(sql_type = Nullable<Varchar>)]
     pub image_url: Option<String>,
     #[diesel(sql_type = Timestamp)]
     pub created_at: NaiveDateTime,
     #[diesel(sql_type = Timestamp)]
     pub updated_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub scheduled_start_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub deadline_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub duration_minutes: Option<i32>,
+    /// Completing the mission needs every mandatory objective done.
+    #[diesel(sql_type = Bool)]
+    pub objectives_required: bool,
+    #[diesel(sql_type = BigInt)]
+    pub objectives_total: i64,
+    #[diesel(sql_type = BigInt)]
+    pub objectives_done: i64,
+    /// Share of objectives done, 0-100; 0 when the mission has none.
+    #[diesel(sql_type = Int4)]
+    pub objectives_progress: i32,
+    /// `open`, `approval` or `invite_only`.
+    #[diesel(sql_type = Varchar)]
+    pub join_policy: String,
+    /// When the mission last became `Completed` or `Failed`.
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub finished_at: Option<NaiveDateTime>,
+    /// Reputation a brawler needs to join on their own; direct invites from a manager skip it.
+    #[diesel(sql_type = Nullable<Double>)]
+    pub min_reputation: Option<f64>,
+}
+
+impl MissionModel {
+    /// Whether the viewer the model was loaded for may manage the mission.
+    pub fn can_manage(&self, brawler_id: i32) -> bool {
+        self.chief_id == brawler_id || self.is_co_chief
+    }
+
+    pub fn check_reputation(&self, reputation: f64) -> AppResult<()> {
+        match self.min_reputation {
+            Some(min) if reputation < min => Err(AppError::Forbidden(format!(
+                "Your reputation ({reputation:.2}) is below this mission's minimum of {min:.2}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Sort fields for mission listings.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionSort {
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+    Name,
+    CrewCount,
+}
+
+impl SortField for MissionSort {
+    fn default_direction(self) -> SortDirection {
+        match self {
+            MissionSort::Name => SortDirection::Asc,
+            _ => SortDirection::Desc,
+        }
+    }
+
+    fn cursor_kind(self) -> CursorKind {
+        match self {
+            MissionSort::CreatedAt | MissionSort::UpdatedAt => CursorKind::Timestamp,
+            MissionSort::Name => CursorKind::Text,
+            MissionSort::CrewCount => CursorKind::Integer,
+        }
+    }
+
+    /// A search without a sort pages by rank.
+    fn unsorted_cursor_kinds() -> Vec<CursorKind> {
+        vec![Self::default().cursor_kind(), CursorKind::Real]
+    }
+}
+
+impl MissionSort {
+    pub fn cursor(self, mission: &MissionModel) -> Cursor {
+        let value = match self {
+            MissionSort::CreatedAt => timestamp_value(mission.created_at),
+            MissionSort::UpdatedAt => timestamp_value(mission.updated_at),
+            MissionSort::Name => mission.name.clone(),
+            MissionSort::CrewCount => mission.crew_count.to_string(),
+        };
+        Cursor::new(value, mission.id)
+    }
+}
+
+/// Full microsecond precision, so the cursor compares equal to the stored timestamp.
+fn timestamp_value(timestamp: NaiveDateTime) -> String {
+    timestamp.format("%Y-%m-%dT%H:%M:%S%.6f").to_string()
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct AddMissionModel {
     pub name: String,
     pub description: Option<String>,
     pub category: Option<String>,
     pub max_crew: i32,
+    /// Crew are reminded before this and the mission starts itself if it has crew.
+    pub scheduled_start_at: Option<DateTime<Utc>>,
+    /// An in-progress mission still running at this time fails. Defaults to
+    /// `scheduled_start_at` plus `duration_minutes` when both are given.
+    pub deadline_at: Option<DateTime<Utc>>,
+    pub duration_minutes: Option<i32>,
+    /// Refuse to complete the mission while a mandatory objective is open.
+    #[serde(default)]
+    pub objectives_required: bool,
+    #[serde(default)]
+    pub join_policy: JoinPolicy,
+    /// Reputation, 1 to 5, brawlers need to join; leave out for none.
+    pub min_reputation: Option<f64>,
 }
 
 impl AddMissionModel {
@@ -56,16 +173,30 @@ impl AddMissionModel {
             chief_id,
             image_url: None,
             image_public_id: None,
+            scheduled_start_at: self.scheduled_start_at.map(|at| at.naive_utc()),
+            deadline_at: self.deadline_at.map(|at| at.naive_utc()),
+            duration_minutes: self.duration_minutes,
+            template_id: None,
+            objectives_required: self.objectives_required,
+            join_policy: self.join_policy.to_string(),
+            min_reputation: self.min_reputation,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct EditMissionModel {
     pub name: Option<String>,
     pub description: Option<String>,
     pub category: Option<String>,
     pub max_crew: Option<i32>,
+    pub scheduled_start_at: Option<DateTime<Utc>>,
+    pub deadline_at: Option<DateTime<Utc>>,
+    pub duration_minutes: Option<i32>,
+    pub objectives_required: Option<bool>,
+    pub join_policy: Option<JoinPolicy>,
+    /// 1 to 5; 0 removes the requirement.
+    pub min_reputation: Option<f64>,
 }
 
 impl EditMissionModel {
@@ -78,6 +209,50 @@ impl EditMissionModel {
             chief_id,
             image_url: None,
             image_public_id: None,
+            scheduled_start_at: self.scheduled_start_at.map(|at| at.naive_utc()),
+            deadline_at: self.deadline_at.map(|at| at.naive_utc()),
+            duration_minutes: self.duration_minutes,
+            // A new start time gets a new reminder and auto-start check.
+            reminder_sent_at: self.scheduled_start_at.map(|_| None),
+            auto_start_checked_at: self.scheduled_start_at.map(|_| None),
+            objectives_required: self.objectives_required,
+            join_policy: self.join_policy.map(|policy| policy.to_string()),
+            min_reputation: self.min_reputation.map(|min| Some(min).filter(|min| *min > 0.0)),
+        }
+    }
+}
+
+impl Validate for AddMissionModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "name", &self.name, limits.mission_name_min_len, limits.mission_name_max_len);
+        check_max_length(errors, "description", self.description.as_deref(), limits.mission_description_max_len);
+        check_max_length(errors, "category", self.category.as_deref(), limits.mission_category_max_len);
+        check_range(errors, "max_crew", self.max_crew, limits.min_crew, limits.max_crew);
+        if let Some(duration) = self.duration_minutes {
+            check_range(errors, "duration_minutes", duration, 1, limits.max_duration_minutes);
+        }
+        if matches!(self.min_reputation, Some(min) if !(1.0..=5.0).contains(&min)) {
+            errors.push(FieldError::new("min_reputation", "must be between 1 and 5"));
+        }
+    }
+}
+
+impl Validate for EditMissionModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        // A blank name means "leave unchanged".
+        if let Some(name) = self.name.as_deref().filter(|name| !name.trim().is_empty()) {
+            check_length(errors, "name", name, limits.mission_name_min_len, limits.mission_name_max_len);
+        }
+        check_max_length(errors, "description", self.description.as_deref(), limits.mission_description_max_len);
+        check_max_length(errors, "category", self.category.as_deref(), limits.mission_category_max_len);
+        if let Some(max_crew) = self.max_crew {
+            check_range(errors, "max_crew", max_crew, limits.min_crew, limits.max_crew);
+        }
+        if let Some(duration) = self.duration_minutes {
+            check_range(errors, "duration_minutes", duration, 1, limits.max_duration_minutes);
+        }
+        if matches!(self.min_reputation, Some(min) if min != 0.0 && !(1.0..=5.0).contains(&min)) {
+            errors.push(FieldError::new("min_reputation", "must be between 1 and 5, or 0 to remove it"));
         }
     }
 }
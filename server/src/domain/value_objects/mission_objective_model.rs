@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::mission_objectives::{
+            AddMissionObjectiveEntity, EditMissionObjectiveEntity, MissionObjectiveEntity,
+        },
+        errors::FieldError,
+        value_objects::validation::{Validate, check_length},
+    },
+};
+
+/// Width of `mission_objectives.title`.
+const OBJECTIVE_TITLE_MAX_LEN: usize = 255;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MissionObjectiveModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub title: String,
+    /// Zero-based place in the checklist.
+    pub position: i32,
+    pub is_mandatory: bool,
+    pub completed_by: Option<i32>,
+    pub completed_by_display_name: Option<String>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionObjectiveModel {
+    pub fn from_entity(entity: MissionObjectiveEntity, completed_by_display_name: Option<String>) -> Self {
+        Self {
+            id: entity.id,
+            mission_id: entity.mission_id,
+            title: entity.title,
+            position: entity.position,
+            is_mandatory: entity.is_mandatory,
+            completed_by: entity.completed_by,
+            completed_by_display_name,
+            completed_at: entity.completed_at,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.completed_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddMissionObjectiveModel {
+    pub title: String,
+    /// Mandatory objectives block completion when the mission requires them.
+    #[serde(default = "default_mandatory")]
+    pub is_mandatory: bool,
+}
+
+fn default_mandatory() -> bool {
+    true
+}
+
+impl AddMissionObjectiveModel {
+    pub fn to_entity(&self, mission_id: i32) -> AddMissionObjectiveEntity {
+        AddMissionObjectiveEntity {
+            mission_id,
+            title: self.title.trim().to_string(),
+            is_mandatory: self.is_mandatory,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EditMissionObjectiveModel {
+    pub title: Option<String>,
+    pub is_mandatory: Option<bool>,
+}
+
+impl EditMissionObjectiveModel {
+    pub fn to_entity(&self) -> EditMissionObjectiveEntity {
+        EditMissionObjectiveEntity {
+            title: self
+                .title
+                .as_deref()
+                .map(str::trim)
+                .filter(|title| !title.is_empty())
+                .map(str::to_string),
+            is_mandatory: self.is_mandatory,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReorderMissionObjectivesModel {
+    /// Every objective of the mission, in the new order.
+    pub objective_ids: Vec<i32>,
+}
+
+impl Validate for AddMissionObjectiveModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "title", &self.title, 1, OBJECTIVE_TITLE_MAX_LEN);
+    }
+}
+
+impl Validate for EditMissionObjectiveModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        // A blank title means "leave unchanged".
+        if let Some(title) = self.title.as_deref().filter(|title| !title.trim().is_empty()) {
+            check_length(errors, "title", title, 1, OBJECTIVE_TITLE_MAX_LEN);
+        }
+    }
+}
+
+impl Validate for ReorderMissionObjectivesModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        let unique = self.objective_ids.iter().collect::<HashSet<_>>();
+        if unique.len() != self.objective_ids.len() {
+            errors.push(FieldError::new("objective_ids", "must not repeat an objective"));
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Integer, Nullable, Varchar},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::{AppError, AppResult, FieldError},
+        value_objects::validation::{Validate, check_length},
+    },
+};
+
+/// Width of `mission_role_slots.label`.
+const ROLE_LABEL_MAX_LEN: usize = 50;
+
+/// A role slot together with the crew member filling it.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct RoleSlotModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub label: String,
+    /// Zero-based place in the lineup.
+    #[diesel(sql_type = Integer)]
+    pub position: i32,
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub brawler_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub brawler_display_name: Option<String>,
+}
+
+impl RoleSlotModel {
+    pub fn is_free(&self) -> bool {
+        self.brawler_id.is_none()
+    }
+
+    /// Resolves the slot a joiner asked for. While free slots remain one must be chosen;
+    /// once every slot is taken, extra members join without a role.
+    pub fn pick(slots: &[RoleSlotModel], slot_id: Option<i32>) -> AppResult<Option<&RoleSlotModel>> {
+        match slot_id {
+            Some(slot_id) => {
+                let slot = slots
+                    .iter()
+                    .find(|slot| slot.id == slot_id)
+                    .ok_or_else(|| AppError::validation("slot_id", "is not a role slot of this mission"))?;
+                if !slot.is_free() {
+                    return Err(AppError::Conflict("Role slot is already taken".to_string()));
+                }
+                Ok(Some(slot))
+            }
+            None if slots.iter().any(RoleSlotModel::is_free) => {
+                Err(AppError::validation("slot_id", "pick one of the free role slots"))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// The preferred slot while it is free, else the first free one; for joins where the
+    /// slot was chosen earlier and may have been taken since.
+    pub fn free_or_first_free(slots: &[RoleSlotModel], preferred: Option<i32>) -> Option<i32> {
+        preferred
+            .filter(|id| slots.iter().any(|slot| slot.id == *id && slot.is_free()))
+            .or_else(|| slots.iter().find(|slot| slot.is_free()).map(|slot| slot.id))
+    }
+}
+
+/// Ready-made slot sets for common games.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleSlotPreset {
+    LeagueOfLegends,
+}
+
+impl RoleSlotPreset {
+    pub fn labels(self) -> Vec<String> {
+        match self {
+            RoleSlotPreset::LeagueOfLegends => ["Top", "Jungle", "Mid", "ADC", "Support"]
+                .iter()
+                .map(|label| label.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Replaces a mission's slots. Existing slots whose label is kept also keep their member.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SetRoleSlotsModel {
+    /// Custom labels in lineup order; an empty list removes every slot.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Use a preset instead of custom labels.
+    pub preset: Option<RoleSlotPreset>,
+}
+
+impl SetRoleSlotsModel {
+    pub fn resolved_labels(&self) -> Vec<String> {
+        match self.preset {
+            Some(preset) => preset.labels(),
+            None => self.labels.iter().map(|label| label.trim().to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssignRoleSlotModel {
+    pub brawler_id: i32,
+    /// Target slot; `null` clears the member's role. A member already in the slot swaps out.
+    pub slot_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct JoinCrewQuery {
+    /// Role slot to take; required while the mission has free slots.
+    pub slot_id: Option<i32>,
+}
+
+impl Validate for SetRoleSlotsModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if self.preset.is_some() && !self.labels.is_empty() {
+            errors.push(FieldError::new("labels", "must be empty when a preset is given"));
+            return;
+        }
+        for label in &self.labels {
+            check_length(errors, "labels", label, 1, ROLE_LABEL_MAX_LEN);
+        }
+        let unique = self
+            .labels
+            .iter()
+            .map(|label| label.trim().to_lowercase())
+            .collect::<HashSet<_>>();
+        if unique.len() != self.labels.len() {
+            errors.push(FieldError::new("labels", "must not repeat a label"));
+        }
+        if self.labels.len() > limits.max_crew as usize {
+            errors.push(FieldError::new(
+                "labels",
+                format!("must not have more than {} slots", limits.max_crew),
+            ));
+        }
+    }
+}
+
+impl Validate for JoinCrewQuery {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if matches!(self.slot_id, Some(id) if id <= 0) {
+            errors.push(FieldError::new("slot_id", "must be positive"));
+        }
+    }
+}
+
+impl Validate for AssignRoleSlotModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if matches!(self.slot_id, Some(id) if id <= 0) {
+            errors.push(FieldError::new("slot_id", "must be positive"));
+        }
+    }
+}
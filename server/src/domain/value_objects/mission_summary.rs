@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use diesel::QueryableByName;
 use diesel::sql_types::BigInt;
 
-#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
 pub struct MissionSummaryModel {
     #[diesel(sql_type = BigInt)]
     pub created_count: i64,
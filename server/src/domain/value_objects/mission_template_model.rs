@@ -0,0 +1,185 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::mission_templates::{
+            AddMissionTemplateEntity, EditMissionTemplateEntity, MissionTemplateEntity,
+        },
+        errors::FieldError,
+        value_objects::{
+            recurrence::RecurrenceRule,
+            validation::{Validate, check_length, check_max_length, check_range},
+        },
+    },
+};
+
+/// Width of `missions.image_url`, which occurrences copy the image into.
+const TEMPLATE_IMAGE_URL_MAX_LEN: usize = 512;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MissionTemplateModel {
+    pub id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub max_crew: i32,
+    pub image_url: Option<String>,
+    pub recurrence: Option<String>,
+    pub duration_minutes: Option<i32>,
+    pub auto_invite: bool,
+    /// Start of the latest occurrence already created as a mission.
+    pub last_scheduled_at: Option<NaiveDateTime>,
+    /// Next start the rule produces after now, UTC.
+    pub next_occurrence_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionTemplateModel {
+    pub fn from_entity(entity: MissionTemplateEntity, now: NaiveDateTime) -> Self {
+        let next_occurrence_at = entity
+            .recurrence
+            .as_deref()
+            .and_then(|rule| rule.parse::<RecurrenceRule>().ok())
+            .and_then(|rule| rule.next_after(now));
+
+        Self {
+            id: entity.id,
+            owner_id: entity.owner_id,
+            name: entity.name,
+            description: entity.description,
+            category: entity.category,
+            max_crew: entity.max_crew,
+            image_url: entity.image_url,
+            recurrence: entity.recurrence,
+            duration_minutes: entity.duration_minutes,
+            auto_invite: entity.auto_invite,
+            last_scheduled_at: entity.last_scheduled_at,
+            next_occurrence_at,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddMissionTemplateModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub max_crew: i32,
+    pub image_url: Option<String>,
+    /// Weekly RRULE subset in UTC, e.g. `FREQ=WEEKLY;BYDAY=MO,TH;BYHOUR=19;BYMINUTE=30`.
+    /// Without it the template never schedules missions by itself.
+    pub recurrence: Option<String>,
+    pub duration_minutes: Option<i32>,
+    /// Invite the crew of the previous occurrence to each new one.
+    #[serde(default)]
+    pub auto_invite: bool,
+}
+
+impl AddMissionTemplateModel {
+    pub fn to_entity(&self, owner_id: i32) -> AddMissionTemplateEntity {
+        AddMissionTemplateEntity {
+            owner_id,
+            name: self.name.trim().to_string(),
+            description: self.description.clone(),
+            category: self.category.clone(),
+            max_crew: self.max_crew,
+            image_url: self.image_url.clone(),
+            recurrence: self.recurrence.as_deref().and_then(canonical_rule),
+            duration_minutes: self.duration_minutes,
+            auto_invite: self.auto_invite,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EditMissionTemplateModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub max_crew: Option<i32>,
+    pub image_url: Option<String>,
+    /// An empty string stops the template from recurring.
+    pub recurrence: Option<String>,
+    pub duration_minutes: Option<i32>,
+    pub auto_invite: Option<bool>,
+}
+
+impl EditMissionTemplateModel {
+    pub fn to_entity(&self) -> EditMissionTemplateEntity {
+        EditMissionTemplateEntity {
+            name: self
+                .name
+                .as_deref()
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string),
+            description: self.description.clone(),
+            category: self.category.clone(),
+            max_crew: self.max_crew,
+            image_url: self.image_url.clone(),
+            recurrence: self.recurrence.as_deref().map(canonical_rule),
+            duration_minutes: self.duration_minutes,
+            auto_invite: self.auto_invite,
+        }
+    }
+}
+
+impl Validate for AddMissionTemplateModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "name", &self.name, limits.mission_name_min_len, limits.mission_name_max_len);
+        check_max_length(errors, "description", self.description.as_deref(), limits.mission_description_max_len);
+        check_max_length(errors, "category", self.category.as_deref(), limits.mission_category_max_len);
+        check_range(errors, "max_crew", self.max_crew, limits.min_crew, limits.max_crew);
+        check_image_url(errors, self.image_url.as_deref());
+        check_recurrence(errors, self.recurrence.as_deref());
+        if let Some(duration) = self.duration_minutes {
+            check_range(errors, "duration_minutes", duration, 1, limits.max_duration_minutes);
+        }
+    }
+}
+
+impl Validate for EditMissionTemplateModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        // A blank name means "leave unchanged".
+        if let Some(name) = self.name.as_deref().filter(|name| !name.trim().is_empty()) {
+            check_length(errors, "name", name, limits.mission_name_min_len, limits.mission_name_max_len);
+        }
+        check_max_length(errors, "description", self.description.as_deref(), limits.mission_description_max_len);
+        check_max_length(errors, "category", self.category.as_deref(), limits.mission_category_max_len);
+        if let Some(max_crew) = self.max_crew {
+            check_range(errors, "max_crew", max_crew, limits.min_crew, limits.max_crew);
+        }
+        check_image_url(errors, self.image_url.as_deref());
+        check_recurrence(errors, self.recurrence.as_deref());
+        if let Some(duration) = self.duration_minutes {
+            check_range(errors, "duration_minutes", duration, 1, limits.max_duration_minutes);
+        }
+    }
+}
+
+/// Blank means no rule; anything else is stored in the form `RecurrenceRule` prints.
+fn canonical_rule(rule: &str) -> Option<String> {
+    rule.parse::<RecurrenceRule>().ok().map(|rule| rule.to_string())
+}
+
+fn check_recurrence(errors: &mut Vec<FieldError>, rule: Option<&str>) {
+    if let Some(rule) = rule.filter(|rule| !rule.trim().is_empty()) {
+        if let Err(message) = rule.parse::<RecurrenceRule>() {
+            errors.push(FieldError::new("recurrence", message));
+        }
+    }
+}
+
+fn check_image_url(errors: &mut Vec<FieldError>, image_url: Option<&str>) {
+    check_max_length(errors, "image_url", image_url, TEMPLATE_IMAGE_URL_MAX_LEN);
+    if image_url.is_some_and(|url| !url.starts_with("https://") && !url.starts_with("http://")) {
+        errors.push(FieldError::new("image_url", "must be an http(s) URL"));
+    }
+}
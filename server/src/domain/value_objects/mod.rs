@@ -1,10 +1,28 @@
 pub mod base64_img;
 pub mod brawler_model;
+pub mod calendar;
 pub mod mission_filter;
 pub mod mission_model;
 pub use mission_model::{MissionModel, AddMissionModel, EditMissionModel};
 pub mod mission_statuses;
 pub mod mission_summary;
+pub mod mission_template_model;
+pub mod pagination;
+pub mod recurrence;
 pub mod uploaded_img;
 pub mod achievement_model;
 pub mod mission_message_model;
+pub mod mission_objective_model;
+pub mod login_attempt_model;
+pub mod roles;
+pub mod admin_model;
+pub mod api_key_model;
+pub mod validation;
+pub mod mission_role_model;
+pub mod join_policy;
+pub mod join_request_model;
+pub mod waitlist_model;
+pub mod ban_model;
+pub mod invite_model;
+pub mod invite_link_model;
+pub mod rating_model;
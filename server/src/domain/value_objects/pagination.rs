@@ -0,0 +1,222 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::FieldError,
+        value_objects::validation::{Validate, check_range},
+    },
+};
+
+pub const DEFAULT_PAGE_LIMIT: i32 = 20;
+pub const MAX_PAGE_LIMIT: i32 = 100;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// A column a listing can be sorted by; also decides the direction used when none is given.
+pub trait SortField: Copy + Default {
+    fn default_direction(self) -> SortDirection;
+
+    /// What a cursor value for this column has to parse as.
+    fn cursor_kind(self) -> CursorKind;
+
+    /// Cursor kinds accepted when no sort is given. Listings that then fall back to another
+    /// order, like search rank, add the kind of that order.
+    fn unsorted_cursor_kinds() -> Vec<CursorKind> {
+        vec![Self::default().cursor_kind()]
+    }
+}
+
+/// The type of a sort column, as far as cursor values are concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorKind {
+    Text,
+    Integer,
+    Real,
+    Timestamp,
+}
+
+impl CursorKind {
+    pub fn accepts(self, value: &str) -> bool {
+        match self {
+            CursorKind::Text => true,
+            CursorKind::Integer => value.parse::<i64>().is_ok(),
+            CursorKind::Real => value.parse::<f64>().is_ok_and(f64::is_finite),
+            CursorKind::Timestamp => NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f").is_ok(),
+        }
+    }
+}
+
+/// Position after the last item of a page: its sort value and id, the id breaking ties.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursor {
+    pub value: String,
+    pub id: i32,
+}
+
+impl Cursor {
+    pub fn new(value: impl Into<String>, id: i32) -> Self {
+        Self {
+            value: value.into(),
+            id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(&(&self.value, self.id)).unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(encoded: &str) -> Option<Self> {
+        let json = URL_SAFE_NO_PAD.decode(encoded).ok()?;
+        let (value, id) = serde_json::from_slice::<(String, i32)>(&json).ok()?;
+        Some(Self { value, id })
+    }
+}
+
+/// Query parameters shared by paginated listings; `S` is the listing's sort field.
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageRequest<S: ToSchema> {
+    /// Items per page, 1 to 100. Defaults to 20.
+    pub limit: Option<i32>,
+    /// `next_cursor` of the previous page.
+    pub cursor: Option<String>,
+    #[param(inline)]
+    pub sort: Option<S>,
+    pub direction: Option<SortDirection>,
+}
+
+impl<S: SortField + ToSchema> PageRequest<S> {
+    pub fn limit(&self) -> i32 {
+        self.limit.unwrap_or(DEFAULT_PAGE_LIMIT)
+    }
+
+    pub fn sort(&self) -> S {
+        self.sort.unwrap_or_default()
+    }
+
+    pub fn direction(&self) -> SortDirection {
+        self.direction.unwrap_or_else(|| self.sort().default_direction())
+    }
+
+    /// Already checked by `validate`, so an undecodable cursor reads as the first page.
+    pub fn cursor(&self) -> Option<Cursor> {
+        self.cursor.as_deref().and_then(Cursor::decode)
+    }
+}
+
+impl<S: SortField + ToSchema> Validate for PageRequest<S> {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if let Some(limit) = self.limit {
+            check_range(errors, "limit", limit, 1, MAX_PAGE_LIMIT);
+        }
+        let Some(encoded) = self.cursor.as_deref() else {
+            return;
+        };
+        let Some(cursor) = Cursor::decode(encoded) else {
+            errors.push(FieldError::new("cursor", "is not a valid cursor"));
+            return;
+        };
+
+        let kinds = match self.sort {
+            Some(sort) => vec![sort.cursor_kind()],
+            None => S::unsorted_cursor_kinds(),
+        };
+        if !kinds.iter().any(|kind| kind.accepts(&cursor.value)) {
+            errors.push(FieldError::new("cursor", "does not match the sort order"));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Items matching the query across every page.
+    pub total: i64,
+    /// Pass as `cursor` to fetch the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// `rows` may hold one item past `limit`, fetched only to tell whether another page exists.
+    pub fn from_rows(mut rows: Vec<T>, limit: i32, total: i64, cursor_of: impl Fn(&T) -> Cursor) -> Self {
+        let limit = usize::try_from(limit).unwrap_or_default();
+        let next_cursor = if rows.len() > limit {
+            rows.truncate(limit);
+            rows.last().map(|last| cursor_of(last).encode())
+        } else {
+            None
+        };
+
+        Self {
+            items: rows,
+            total,
+            next_cursor,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::config_loader::get_validation_env, domain::value_objects::mission_model::MissionSort};
+
+    fn errors(page: &PageRequest<MissionSort>) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        page.validate(&get_validation_env(), &mut errors);
+        errors
+    }
+
+    fn page(cursor: String, sort: Option<MissionSort>) -> PageRequest<MissionSort> {
+        PageRequest { limit: None, cursor: Some(cursor), sort, direction: None }
+    }
+
+    #[test]
+    fn cursor_round_trips() {
+        let cursor = Cursor::new("2026-03-01T09:00:00.123456", 42);
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor.clone()));
+        assert!(errors(&page(cursor.encode(), None)).is_empty());
+        assert!(errors(&page(Cursor::new("Alpha, \"quoted\"", 7).encode(), Some(MissionSort::Name))).is_empty());
+    }
+
+    #[test]
+    fn tampered_cursor_is_rejected() {
+        let encoded = Cursor::new("12", 3).encode();
+        let tampered = format!("{}x", &encoded[..encoded.len() - 1]);
+
+        assert_eq!(Cursor::decode("not base64!"), None);
+        assert_eq!(Cursor::decode(&URL_SAFE_NO_PAD.encode(r#"{"value":"12"}"#)), None);
+        assert_eq!(errors(&page(tampered, Some(MissionSort::CrewCount))).len(), 1);
+    }
+
+    #[test]
+    fn cursor_value_must_match_the_sort_column() {
+        let count = Cursor::new("12", 3).encode();
+        let timestamp = Cursor::new("2026-03-01T09:00:00.000000", 3).encode();
+        let rank = Cursor::new("0.0607927", 3).encode();
+
+        assert!(errors(&page(count.clone(), Some(MissionSort::CrewCount))).is_empty());
+        assert_eq!(errors(&page(count, Some(MissionSort::CreatedAt)))[0].field, "cursor");
+        assert_eq!(errors(&page(timestamp, Some(MissionSort::CrewCount)))[0].field, "cursor");
+        // Unsorted searches page by rank.
+        assert!(errors(&page(rank.clone(), None)).is_empty());
+        assert_eq!(errors(&page(rank, Some(MissionSort::UpdatedAt))).len(), 1);
+    }
+}
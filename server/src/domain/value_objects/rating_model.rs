@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Array, BigInt, Bool, Integer, Nullable, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::FieldError,
+        value_objects::validation::{Validate, check_range},
+    },
+};
+
+/// Score every brawler starts from before anyone has rated them.
+pub const REPUTATION_PRIOR_SCORE: f64 = 3.0;
+/// How many ratings the starting score counts as, so a single rating cannot swing a reputation.
+pub const REPUTATION_PRIOR_WEIGHT: i64 = 5;
+
+/// Short labels a rating may carry next to its score.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RatingTag {
+    Shotcaller,
+    TeamPlayer,
+    Skilled,
+    Friendly,
+    Toxic,
+    NoShow,
+}
+
+impl Display for RatingTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RatingTag::Shotcaller => write!(f, "shotcaller"),
+            RatingTag::TeamPlayer => write!(f, "team-player"),
+            RatingTag::Skilled => write!(f, "skilled"),
+            RatingTag::Friendly => write!(f, "friendly"),
+            RatingTag::Toxic => write!(f, "toxic"),
+            RatingTag::NoShow => write!(f, "no-show"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RateBrawlerModel {
+    /// 1 to 5.
+    pub score: i32,
+    #[serde(default)]
+    pub tags: Vec<RatingTag>,
+}
+
+impl RateBrawlerModel {
+    /// The tags in the order given, each once.
+    pub fn tag_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for tag in self.tags.iter().map(ToString::to_string) {
+            if !names.contains(&tag) {
+                names.push(tag);
+            }
+        }
+        names
+    }
+}
+
+impl Validate for RateBrawlerModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_range(errors, "score", self.score, 1, 5);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct MissionRatingModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub mission_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub rater_id: Option<i32>,
+    #[diesel(sql_type = Integer)]
+    pub ratee_id: i32,
+    #[diesel(sql_type = Integer)]
+    pub score: i32,
+    #[diesel(sql_type = Array<Text>)]
+    pub tags: Vec<String>,
+    #[diesel(sql_type = Timestamp)]
+    pub updated_at: NaiveDateTime,
+}
+
+/// Someone the viewer shared a finished mission with, and how the viewer rated them so far.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct PeerRatingModel {
+    #[diesel(sql_type = Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub avatar_url: String,
+    #[diesel(sql_type = Bool)]
+    pub is_chief: bool,
+    /// `null` until the viewer rates them.
+    #[diesel(sql_type = Nullable<Integer>)]
+    pub score: Option<i32>,
+    #[diesel(sql_type = Array<Text>)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RatingSheetModel {
+    pub mission_id: i32,
+    /// Ratings can be given or changed until then.
+    pub closes_at: NaiveDateTime,
+    pub is_open: bool,
+    pub peers: Vec<PeerRatingModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct TagCountModel {
+    #[diesel(sql_type = Text)]
+    pub tag: String,
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReputationModel {
+    pub brawler_id: i32,
+    pub reputation: f64,
+    pub rating_count: i32,
+    /// Tags the brawler was given, most frequent first.
+    pub tags: Vec<TagCountModel>,
+}
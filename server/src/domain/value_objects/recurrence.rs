@@ -0,0 +1,126 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{Datelike, Duration, NaiveDateTime, NaiveTime, Weekday};
+
+const WEEKDAYS: [(Weekday, &str); 7] = [
+    (Weekday::Mon, "MO"),
+    (Weekday::Tue, "TU"),
+    (Weekday::Wed, "WE"),
+    (Weekday::Thu, "TH"),
+    (Weekday::Fri, "FR"),
+    (Weekday::Sat, "SA"),
+    (Weekday::Sun, "SU"),
+];
+
+/// The weekly subset of an iCalendar RRULE we schedule from, e.g.
+/// `FREQ=WEEKLY;BYDAY=MO,TH;BYHOUR=19;BYMINUTE=30`. Times are UTC;
+/// `BYHOUR` and `BYMINUTE` default to 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    days: Vec<Weekday>,
+    time: NaiveTime,
+}
+
+impl RecurrenceRule {
+    /// Occurrences in `(after, until]`, earliest first.
+    pub fn occurrences_between(&self, after: NaiveDateTime, until: NaiveDateTime) -> Vec<NaiveDateTime> {
+        after
+            .date()
+            .iter_days()
+            .take_while(|day| *day <= until.date())
+            .filter(|day| self.days.contains(&day.weekday()))
+            .map(|day| day.and_time(self.time))
+            .filter(|at| *at > after && *at <= until)
+            .collect()
+    }
+
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.occurrences_between(after, after + Duration::weeks(1))
+            .into_iter()
+            .next()
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rule = s.trim();
+        let rule = rule
+            .strip_prefix("RRULE:")
+            .or_else(|| rule.strip_prefix("rrule:"))
+            .unwrap_or(rule);
+
+        let mut weekly = false;
+        let mut days = Vec::new();
+        let mut hour = 0;
+        let mut minute = 0;
+
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("`{}` is not KEY=VALUE", part))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    if !value.eq_ignore_ascii_case("WEEKLY") {
+                        return Err("only FREQ=WEEKLY is supported".to_string());
+                    }
+                    weekly = true;
+                }
+                "BYDAY" => {
+                    for code in value.split(',') {
+                        let day = WEEKDAYS
+                            .iter()
+                            .find(|(_, name)| code.trim().eq_ignore_ascii_case(name))
+                            .map(|(day, _)| *day)
+                            .ok_or_else(|| format!("`{}` is not a weekday (MO..SU)", code))?;
+                        if !days.contains(&day) {
+                            days.push(day);
+                        }
+                    }
+                }
+                "BYHOUR" => hour = parse_in_range(value, "BYHOUR", 23)?,
+                "BYMINUTE" => minute = parse_in_range(value, "BYMINUTE", 59)?,
+                other => return Err(format!("{} is not supported", other)),
+            }
+        }
+
+        if !weekly {
+            return Err("FREQ=WEEKLY is required".to_string());
+        }
+        if days.is_empty() {
+            return Err("BYDAY needs at least one weekday".to_string());
+        }
+        days.sort_by_key(|day| day.num_days_from_monday());
+
+        let time = NaiveTime::from_hms_opt(hour, minute, 0).ok_or("invalid time of day")?;
+        Ok(Self { days, time })
+    }
+}
+
+impl Display for RecurrenceRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let days = self
+            .days
+            .iter()
+            .filter_map(|day| WEEKDAYS.iter().find(|(d, _)| d == day).map(|(_, name)| *name))
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "FREQ=WEEKLY;BYDAY={};BYHOUR={};BYMINUTE={}",
+            days,
+            self.time.format("%-H"),
+            self.time.format("%-M")
+        )
+    }
+}
+
+fn parse_in_range(value: &str, key: &str, max: u32) -> Result<u32, String> {
+    value
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|n| *n <= max)
+        .ok_or_else(|| format!("{} must be between 0 and {}", key, max))
+}
@@ -0,0 +1,43 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Roles are ordered: each role has every permission of the roles below it.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Roles {
+    #[default]
+    User,
+    Moderator,
+    Admin,
+}
+
+impl Roles {
+    pub fn satisfies(&self, required: Roles) -> bool {
+        *self >= required
+    }
+}
+
+impl Display for Roles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Roles::User => write!(f, "user"),
+            Roles::Moderator => write!(f, "moderator"),
+            Roles::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl FromStr for Roles {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user" => Ok(Roles::User),
+            "moderator" => Ok(Roles::Moderator),
+            "admin" => Ok(Roles::Admin),
+            _ => Err(anyhow::anyhow!("Unknown role: {}", s)),
+        }
+    }
+}
@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{errors::FieldError, value_objects::validation::Validate},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UploadedImg {
     pub url: String,
     pub public_id: String,
@@ -12,7 +18,15 @@ impl UploadedImg {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UploadBase64Img {
     pub base64_string: String,
 }
+
+impl Validate for UploadBase64Img {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if self.base64_string.trim().is_empty() {
+            errors.push(FieldError::new("base64_string", "is required"));
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::errors::{AppError, AppResult, FieldError},
+};
+
+/// Implemented by every request model. Collects all problems so the client can
+/// show them next to each field at once.
+pub trait Validate {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>);
+
+    fn validated(&self, limits: &ValidationEnv) -> AppResult<()> {
+        let mut errors = Vec::new();
+        self.validate(limits, &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::Validation(errors))
+        }
+    }
+}
+
+/// Length in characters of the trimmed value.
+pub fn check_length(errors: &mut Vec<FieldError>, field: &str, value: &str, min: usize, max: usize) {
+    let length = value.trim().chars().count();
+    if length < min {
+        let message = if min == 1 {
+            "is required".to_string()
+        } else {
+            format!("must be at least {} characters long", min)
+        };
+        errors.push(FieldError::new(field, message));
+    } else if length > max {
+        errors.push(FieldError::new(
+            field,
+            format!("must be at most {} characters long", max),
+        ));
+    }
+}
+
+pub fn check_max_length(errors: &mut Vec<FieldError>, field: &str, value: Option<&str>, max: usize) {
+    if let Some(value) = value {
+        check_length(errors, field, value, 0, max);
+    }
+}
+
+pub fn check_range(errors: &mut Vec<FieldError>, field: &str, value: i32, min: i32, max: i32) {
+    if value < min || value > max {
+        errors.push(FieldError::new(
+            field,
+            format!("must be between {} and {}", min, max),
+        ));
+    }
+}
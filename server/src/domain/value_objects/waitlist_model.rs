@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Integer, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::entities::notification::{Notification, NotificationType};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WaitlistStatus {
+    Waiting,
+    /// A place is held for the brawler until the offer expires.
+    Offered,
+    Joined,
+    Expired,
+    Left,
+}
+
+impl Display for WaitlistStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WaitlistStatus::Waiting => write!(f, "waiting"),
+            WaitlistStatus::Offered => write!(f, "offered"),
+            WaitlistStatus::Joined => write!(f, "joined"),
+            WaitlistStatus::Expired => write!(f, "expired"),
+            WaitlistStatus::Left => write!(f, "left"),
+        }
+    }
+}
+
+/// A queued brawler; `position` counts from 1 among waiting and offered entries.
+#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName, ToSchema)]
+pub struct WaitlistEntryModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
+    #[diesel(sql_type = Integer)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub mission_name: String,
+    #[diesel(sql_type = Integer)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub brawler_display_name: String,
+    #[diesel(sql_type = BigInt)]
+    pub position: i64,
+    #[diesel(sql_type = Varchar)]
+    pub status: String,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub offer_expires_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+impl WaitlistEntryModel {
+    pub fn is_offered(&self) -> bool {
+        self.status == WaitlistStatus::Offered.to_string()
+    }
+
+    pub fn offer_notification(&self) -> Notification {
+        Notification {
+            recipient_id: Some(self.brawler_id),
+            title: "A Place Opened Up".to_string(),
+            message: format!("A place opened up in {}. Confirm to join before it passes on.", self.mission_name),
+            notification_type: NotificationType::WaitlistOffer,
+            metadata: serde_json::json!({
+                "mission_id": self.mission_id,
+                "waitlist_id": self.id,
+                "offer_expires_at": self.offer_expires_at
+            }),
+        }
+    }
+}
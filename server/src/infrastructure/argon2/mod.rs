@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use anyhow::{Ok, Result};
 use argon2::{
     Argon2, PasswordHash, PasswordVerifier,
@@ -26,3 +28,15 @@ pub fn verify(password: String, hashed_password: String) -> Result<bool> {
         .is_ok();
     Ok(value)
 }
+
+/// A hash of a random password, for checking logins that have no real hash so they
+/// take as long as a wrong password. Computed once, then reused.
+pub fn dummy_hash() -> Result<String> {
+    static DUMMY: OnceLock<String> = OnceLock::new();
+    if let Some(dummy) = DUMMY.get() {
+        return Ok(dummy.clone());
+    }
+
+    let dummy = hash(SaltString::generate(&mut OsRng).to_string())?;
+    Ok(DUMMY.get_or_init(|| dummy).clone())
+}
@@ -4,7 +4,7 @@ use crate::{
 };
 use anyhow::{Context, Ok, Result};
 use chrono::Utc;
-use reqwest::multipart::{Form, Part};
+use reqwest::multipart::Form;
 use sha1::{Digest, Sha1};
 use std::collections::HashMap;
 use tracing::{debug, error};
@@ -0,0 +1,35 @@
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+
+use crate::domain::errors::AppError;
+
+impl From<DieselError> for AppError {
+    fn from(e: DieselError) -> Self {
+        match e {
+            DieselError::NotFound => AppError::NotFound("Resource not found".to_string()),
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                AppError::Conflict("Resource already exists".to_string())
+            }
+            e => AppError::Internal(e.into()),
+        }
+    }
+}
+
+impl From<diesel::r2d2::PoolError> for AppError {
+    fn from(e: diesel::r2d2::PoolError) -> Self {
+        AppError::Internal(e.into())
+    }
+}
+
+impl From<tokio::task::JoinError> for AppError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        AppError::Internal(e.into())
+    }
+}
+
+/// `map_err` adapter naming the missing row, e.g. `.map_err(not_found("Mission"))`.
+pub fn not_found(resource: &'static str) -> impl Fn(DieselError) -> AppError {
+    move |e| match e {
+        DieselError::NotFound => AppError::NotFound(format!("{} not found", resource)),
+        e => e.into(),
+    }
+}
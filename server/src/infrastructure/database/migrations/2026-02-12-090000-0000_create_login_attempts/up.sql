@@ -0,0 +1,12 @@
+CREATE TABLE login_attempts (
+    id SERIAL PRIMARY KEY,
+    username VARCHAR(255) NOT NULL,
+    brawler_id INTEGER REFERENCES brawlers(id) ON DELETE CASCADE,
+    ip_address VARCHAR(64),
+    succeeded BOOLEAN NOT NULL DEFAULT FALSE,
+    attempted_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_login_attempts_username ON login_attempts (username, attempted_at);
+CREATE INDEX idx_login_attempts_ip_address ON login_attempts (ip_address, attempted_at);
+CREATE INDEX idx_login_attempts_brawler_id ON login_attempts (brawler_id, attempted_at);
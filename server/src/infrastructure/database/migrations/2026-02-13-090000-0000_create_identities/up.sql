@@ -0,0 +1,11 @@
+CREATE TABLE identities (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    provider VARCHAR(255) NOT NULL,
+    subject VARCHAR(255) NOT NULL,
+    email VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    CONSTRAINT unique_identity_provider_subject UNIQUE (provider, subject)
+);
+
+CREATE INDEX idx_identities_brawler_id ON identities (brawler_id);
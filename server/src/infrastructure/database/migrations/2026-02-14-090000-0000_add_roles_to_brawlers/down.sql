@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_brawlers_role;
+
+ALTER TABLE brawlers
+    DROP COLUMN IF EXISTS disabled_at,
+    DROP COLUMN IF EXISTS role;
@@ -0,0 +1,6 @@
+ALTER TABLE brawlers
+    ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'user'
+        CHECK (role IN ('user', 'moderator', 'admin')),
+    ADD COLUMN disabled_at TIMESTAMP;
+
+CREATE INDEX idx_brawlers_role ON brawlers(role);
@@ -0,0 +1,7 @@
+DROP TABLE api_keys;
+
+DROP INDEX IF EXISTS idx_brawlers_bot_owner_id;
+
+ALTER TABLE brawlers
+    DROP COLUMN IF EXISTS bot_owner_id,
+    DROP COLUMN IF EXISTS is_bot;
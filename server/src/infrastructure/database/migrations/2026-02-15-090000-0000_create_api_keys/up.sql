@@ -0,0 +1,19 @@
+ALTER TABLE brawlers
+    ADD COLUMN is_bot BOOLEAN NOT NULL DEFAULT false,
+    ADD COLUMN bot_owner_id INTEGER REFERENCES brawlers(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_brawlers_bot_owner_id ON brawlers (bot_owner_id);
+
+CREATE TABLE api_keys (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    prefix VARCHAR(16) NOT NULL UNIQUE,
+    key_hash VARCHAR(64) NOT NULL,
+    scopes TEXT[] NOT NULL DEFAULT '{}',
+    last_used_at TIMESTAMP,
+    revoked_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_api_keys_brawler_id ON api_keys (brawler_id);
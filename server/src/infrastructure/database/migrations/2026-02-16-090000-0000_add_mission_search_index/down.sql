@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_missions_chief_id;
+DROP INDEX IF EXISTS idx_missions_status;
+DROP INDEX IF EXISTS idx_missions_search;
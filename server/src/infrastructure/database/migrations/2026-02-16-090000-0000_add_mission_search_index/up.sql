@@ -0,0 +1,7 @@
+CREATE INDEX idx_missions_search ON missions USING GIN (
+    (setweight(to_tsvector('simple', coalesce(name, '')), 'A')
+        || setweight(to_tsvector('simple', coalesce(description, '')), 'B'))
+);
+
+CREATE INDEX idx_missions_status ON missions (status);
+CREATE INDEX idx_missions_chief_id ON missions (chief_id);
@@ -0,0 +1,9 @@
+DROP INDEX IF EXISTS idx_missions_deadline_at;
+DROP INDEX IF EXISTS idx_missions_scheduled_start_at;
+
+ALTER TABLE missions
+    DROP COLUMN IF EXISTS auto_start_checked_at,
+    DROP COLUMN IF EXISTS reminder_sent_at,
+    DROP COLUMN IF EXISTS duration_minutes,
+    DROP COLUMN IF EXISTS deadline_at,
+    DROP COLUMN IF EXISTS scheduled_start_at;
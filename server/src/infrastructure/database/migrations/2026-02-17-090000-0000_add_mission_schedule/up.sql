@@ -0,0 +1,11 @@
+ALTER TABLE missions
+    ADD COLUMN scheduled_start_at TIMESTAMP,
+    ADD COLUMN deadline_at TIMESTAMP,
+    ADD COLUMN duration_minutes INTEGER,
+    ADD COLUMN reminder_sent_at TIMESTAMP,
+    ADD COLUMN auto_start_checked_at TIMESTAMP;
+
+CREATE INDEX idx_missions_scheduled_start_at ON missions (scheduled_start_at)
+    WHERE deleted_at IS NULL AND status = 'Open';
+CREATE INDEX idx_missions_deadline_at ON missions (deadline_at)
+    WHERE deleted_at IS NULL AND status = 'InProgress';
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_missions_template_occurrence;
+
+ALTER TABLE missions DROP COLUMN IF EXISTS template_id;
+
+DROP TABLE mission_templates;
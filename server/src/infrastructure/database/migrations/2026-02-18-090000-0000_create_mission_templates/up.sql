@@ -0,0 +1,29 @@
+CREATE TABLE mission_templates (
+    id SERIAL PRIMARY KEY,
+    owner_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    name VARCHAR NOT NULL,
+    description TEXT,
+    category VARCHAR,
+    max_crew INTEGER NOT NULL,
+    image_url VARCHAR,
+    -- Weekly RRULE subset, e.g. FREQ=WEEKLY;BYDAY=MO,TH;BYHOUR=19;BYMINUTE=30 (UTC).
+    recurrence VARCHAR,
+    duration_minutes INTEGER,
+    auto_invite BOOLEAN NOT NULL DEFAULT false,
+    -- Start of the latest occurrence turned into a mission.
+    last_scheduled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+SELECT diesel_manage_updated_at('mission_templates');
+
+CREATE INDEX idx_mission_templates_owner_id ON mission_templates (owner_id);
+
+ALTER TABLE missions
+    ADD COLUMN template_id INTEGER REFERENCES mission_templates(id) ON DELETE SET NULL;
+
+-- One mission per occurrence, however often the scheduler runs. Missions
+-- without a template have a NULL template_id and never collide.
+CREATE UNIQUE INDEX idx_missions_template_occurrence
+    ON missions (template_id, scheduled_start_at);
@@ -0,0 +1,2 @@
+ALTER TABLE brawlers
+    DROP COLUMN IF EXISTS calendar_token_hash;
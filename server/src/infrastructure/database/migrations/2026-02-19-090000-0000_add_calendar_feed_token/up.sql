@@ -0,0 +1,3 @@
+-- SHA-256 of the secret in a brawler's calendar feed URL; NULL when no feed is issued.
+ALTER TABLE brawlers
+    ADD COLUMN calendar_token_hash VARCHAR(64) UNIQUE;
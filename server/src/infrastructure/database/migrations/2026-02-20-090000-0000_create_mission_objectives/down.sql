@@ -0,0 +1,3 @@
+ALTER TABLE missions DROP COLUMN IF EXISTS objectives_required;
+
+DROP TABLE mission_objectives;
@@ -0,0 +1,20 @@
+CREATE TABLE mission_objectives (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    title VARCHAR(255) NOT NULL,
+    -- Zero-based order within the mission's checklist.
+    position INTEGER NOT NULL,
+    is_mandatory BOOLEAN NOT NULL DEFAULT true,
+    completed_by INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    completed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+SELECT diesel_manage_updated_at('mission_objectives');
+
+CREATE INDEX idx_mission_objectives_mission_id ON mission_objectives (mission_id, position);
+
+-- When set, a mission cannot be completed while a mandatory objective is open.
+ALTER TABLE missions
+    ADD COLUMN objectives_required BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_crew_memberships_role_slot;
+
+ALTER TABLE crew_memberships DROP COLUMN IF EXISTS role_slot_id;
+
+DROP TABLE mission_role_slots;
@@ -0,0 +1,17 @@
+CREATE TABLE mission_role_slots (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    -- e.g. Top, Jungle, Mid, ADC, Support, or any label the chief picks.
+    label VARCHAR(50) NOT NULL,
+    position INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mission_role_slots_mission_id ON mission_role_slots (mission_id, position);
+
+-- Removing a slot leaves its member in the crew without a role.
+ALTER TABLE crew_memberships
+    ADD COLUMN role_slot_id INTEGER REFERENCES mission_role_slots(id) ON DELETE SET NULL;
+
+-- One member per slot; members without a slot have a NULL role_slot_id and never collide.
+CREATE UNIQUE INDEX idx_crew_memberships_role_slot ON crew_memberships (role_slot_id);
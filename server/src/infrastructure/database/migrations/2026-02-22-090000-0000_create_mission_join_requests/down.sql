@@ -0,0 +1,3 @@
+DROP TABLE mission_join_requests;
+
+ALTER TABLE missions DROP COLUMN IF EXISTS join_policy;
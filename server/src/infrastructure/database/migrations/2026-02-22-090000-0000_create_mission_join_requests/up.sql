@@ -0,0 +1,26 @@
+-- open: anyone may join while there is room; approval: joins become requests
+-- the chief approves or rejects; invite_only: only invited brawlers get in.
+ALTER TABLE missions
+    ADD COLUMN join_policy VARCHAR(20) NOT NULL DEFAULT 'open'
+        CHECK (join_policy IN ('open', 'approval', 'invite_only'));
+
+CREATE TABLE mission_join_requests (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    -- Role slot the brawler asked for, if the mission has slots.
+    role_slot_id INTEGER REFERENCES mission_role_slots(id) ON DELETE SET NULL,
+    -- pending, approved, rejected or cancelled
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    decided_by INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    decided_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+SELECT diesel_manage_updated_at('mission_join_requests');
+
+-- A brawler has at most one open request per mission.
+CREATE UNIQUE INDEX idx_mission_join_requests_pending
+    ON mission_join_requests (mission_id, brawler_id)
+    WHERE status = 'pending';
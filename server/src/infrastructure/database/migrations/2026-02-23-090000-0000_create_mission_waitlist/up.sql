@@ -0,0 +1,21 @@
+CREATE TABLE mission_waitlist (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    -- waiting, offered (a place is held until offer_expires_at), joined, expired or left
+    status VARCHAR(20) NOT NULL DEFAULT 'waiting',
+    offered_at TIMESTAMP,
+    offer_expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+SELECT diesel_manage_updated_at('mission_waitlist');
+
+-- A brawler is in a mission's queue at most once.
+CREATE UNIQUE INDEX idx_mission_waitlist_active
+    ON mission_waitlist (mission_id, brawler_id)
+    WHERE status IN ('waiting', 'offered');
+
+CREATE INDEX idx_mission_waitlist_queue ON mission_waitlist (mission_id, created_at, id);
+CREATE INDEX idx_mission_waitlist_offer_expiry ON mission_waitlist (offer_expires_at) WHERE status = 'offered';
@@ -0,0 +1,2 @@
+ALTER TABLE crew_memberships
+    DROP COLUMN IF EXISTS is_co_chief;
@@ -0,0 +1,3 @@
+-- Crew members the chief delegates mission management to.
+ALTER TABLE crew_memberships
+    ADD COLUMN is_co_chief BOOLEAN NOT NULL DEFAULT FALSE;
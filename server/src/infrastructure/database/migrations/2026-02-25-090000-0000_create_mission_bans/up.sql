@@ -0,0 +1,16 @@
+CREATE TABLE mission_bans (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    banned_by INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    reason VARCHAR(255),
+    -- NULL bans for good; otherwise the ban stops applying at this time.
+    expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+SELECT diesel_manage_updated_at('mission_bans');
+
+-- Banning again replaces the previous ban.
+CREATE UNIQUE INDEX idx_mission_bans_brawler ON mission_bans (mission_id, brawler_id);
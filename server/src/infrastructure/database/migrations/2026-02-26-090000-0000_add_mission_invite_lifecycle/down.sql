@@ -0,0 +1,9 @@
+DROP INDEX idx_mission_invites_pending_expiry;
+DROP TRIGGER IF EXISTS set_updated_at ON mission_invites;
+
+UPDATE mission_invites SET status = 'rejected' WHERE status IN ('cancelled', 'expired');
+
+ALTER TABLE mission_invites
+    DROP COLUMN invited_by,
+    DROP COLUMN expires_at,
+    DROP COLUMN updated_at;
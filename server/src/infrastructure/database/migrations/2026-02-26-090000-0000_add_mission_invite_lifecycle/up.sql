@@ -0,0 +1,17 @@
+-- pending, accepted, rejected, cancelled (withdrawn by the inviter) or expired
+ALTER TABLE mission_invites
+    ADD COLUMN invited_by INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    ADD COLUMN expires_at TIMESTAMP,
+    ADD COLUMN updated_at TIMESTAMP NOT NULL DEFAULT now();
+
+UPDATE mission_invites SET expires_at = created_at + interval '72 hours';
+ALTER TABLE mission_invites
+    ALTER COLUMN expires_at SET DEFAULT now() + interval '72 hours',
+    ALTER COLUMN expires_at SET NOT NULL;
+
+-- Invites sent before this change that already lapsed.
+UPDATE mission_invites SET status = 'expired' WHERE status = 'pending' AND expires_at <= now();
+
+SELECT diesel_manage_updated_at('mission_invites');
+
+CREATE INDEX idx_mission_invites_pending_expiry ON mission_invites (expires_at) WHERE status = 'pending';
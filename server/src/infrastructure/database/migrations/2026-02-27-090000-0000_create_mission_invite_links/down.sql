@@ -0,0 +1,2 @@
+DROP TABLE mission_invite_link_uses;
+DROP TABLE mission_invite_links;
@@ -0,0 +1,30 @@
+CREATE TABLE mission_invite_links (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL REFERENCES missions(id) ON DELETE CASCADE,
+    -- SHA-256 of the token; the token itself is shown once, at creation.
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    created_by INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    -- Slot offered to whoever joins through the link, while it is free.
+    role_slot_id INTEGER REFERENCES mission_role_slots(id) ON DELETE SET NULL,
+    -- NULL allows any number of uses.
+    max_uses INTEGER CHECK (max_uses > 0),
+    use_count INTEGER NOT NULL DEFAULT 0,
+    expires_at TIMESTAMP,
+    revoked_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+SELECT diesel_manage_updated_at('mission_invite_links');
+
+CREATE INDEX idx_mission_invite_links_mission ON mission_invite_links (mission_id);
+
+CREATE TABLE mission_invite_link_uses (
+    id SERIAL PRIMARY KEY,
+    link_id INTEGER NOT NULL REFERENCES mission_invite_links(id) ON DELETE CASCADE,
+    brawler_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    used_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- A brawler counts once per link.
+CREATE UNIQUE INDEX idx_mission_invite_link_uses_brawler ON mission_invite_link_uses (link_id, brawler_id);
@@ -0,0 +1,9 @@
+DROP TABLE mission_ratings;
+
+ALTER TABLE brawlers
+    DROP COLUMN reputation,
+    DROP COLUMN rating_count;
+
+ALTER TABLE missions
+    DROP COLUMN finished_at,
+    DROP COLUMN min_reputation;
@@ -0,0 +1,30 @@
+-- When the mission last became Completed or Failed; opens the rating window.
+ALTER TABLE missions
+    ADD COLUMN finished_at TIMESTAMP,
+    ADD COLUMN min_reputation DOUBLE PRECISION CHECK (min_reputation BETWEEN 1 AND 5);
+
+UPDATE missions SET finished_at = updated_at WHERE status IN ('Completed', 'Failed');
+
+-- Kept in step with mission_ratings, like the mission counters.
+ALTER TABLE brawlers
+    ADD COLUMN reputation DOUBLE PRECISION NOT NULL DEFAULT 3,
+    ADD COLUMN rating_count INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE mission_ratings (
+    id SERIAL PRIMARY KEY,
+    -- Ratings outlive purged missions and keep counting towards reputation.
+    mission_id INTEGER REFERENCES missions(id) ON DELETE SET NULL,
+    rater_id INTEGER REFERENCES brawlers(id) ON DELETE SET NULL,
+    ratee_id INTEGER NOT NULL REFERENCES brawlers(id) ON DELETE CASCADE,
+    score INTEGER NOT NULL CHECK (score BETWEEN 1 AND 5),
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    CHECK (rater_id <> ratee_id)
+);
+
+SELECT diesel_manage_updated_at('mission_ratings');
+
+-- Rating someone again in the same mission replaces the earlier rating.
+CREATE UNIQUE INDEX idx_mission_ratings_pair ON mission_ratings (mission_id, rater_id, ratee_id);
+CREATE INDEX idx_mission_ratings_ratee ON mission_ratings (ratee_id);
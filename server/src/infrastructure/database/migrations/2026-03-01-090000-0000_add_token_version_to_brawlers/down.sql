@@ -0,0 +1 @@
+ALTER TABLE brawlers DROP COLUMN token_version;
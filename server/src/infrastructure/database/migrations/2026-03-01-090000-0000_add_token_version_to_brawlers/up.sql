@@ -0,0 +1,3 @@
+-- Bumped whenever the account is disabled, deleted or changes role; access tokens
+-- issued for an older version stop working.
+ALTER TABLE brawlers ADD COLUMN token_version INTEGER NOT NULL DEFAULT 0;
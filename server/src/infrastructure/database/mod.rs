@@ -1,3 +1,5 @@
+pub mod errors;
+pub mod pagination;
 pub mod postgresql_connection;
 pub mod repositories;
 pub mod schema;
@@ -0,0 +1,70 @@
+use diesel::{QueryableByName, sql_types::BigInt};
+
+use crate::domain::value_objects::{
+    brawler_model::BrawlerSort, mission_model::MissionSort, pagination::SortDirection,
+};
+
+/// An output column of a paginated query and the SQL type cursor values are cast to.
+#[derive(Debug, Clone, Copy)]
+pub struct SortColumn {
+    pub name: &'static str,
+    pub sql_type: &'static str,
+}
+
+impl SortColumn {
+    pub const fn new(name: &'static str, sql_type: &'static str) -> Self {
+        Self { name, sql_type }
+    }
+}
+
+pub fn mission_sort_column(sort: MissionSort) -> SortColumn {
+    match sort {
+        MissionSort::CreatedAt => SortColumn::new("created_at", "timestamp"),
+        MissionSort::UpdatedAt => SortColumn::new("updated_at", "timestamp"),
+        MissionSort::Name => SortColumn::new("name", "varchar"),
+        MissionSort::CrewCount => SortColumn::new("crew_count", "bigint"),
+    }
+}
+
+pub fn brawler_sort_column(sort: BrawlerSort) -> SortColumn {
+    match sort {
+        BrawlerSort::DisplayName => SortColumn::new("display_name", "varchar"),
+        BrawlerSort::MissionSuccessCount => SortColumn::new("mission_success_count", "int4"),
+        BrawlerSort::MissionJoinCount => SortColumn::new("mission_join_count", "int4"),
+    }
+}
+
+#[derive(Debug, QueryableByName)]
+pub struct TotalRow {
+    #[diesel(sql_type = BigInt)]
+    pub total: i64,
+}
+
+pub fn count_sql(base: &str) -> String {
+    format!("SELECT COUNT(*) AS total FROM ({base}) AS base")
+}
+
+/// Wraps `base`, which uses binds `$1..=$binds` and has no `ORDER BY`, into one keyset page.
+/// The next three binds are the cursor value (`Nullable<Text>`), the cursor id
+/// (`Nullable<Int4>`) and the row limit (`BigInt`); `base` must select an `id` column.
+pub fn keyset_sql(base: &str, binds: usize, column: SortColumn, direction: SortDirection) -> String {
+    let (comparison, order) = match direction {
+        SortDirection::Asc => (">", "ASC"),
+        SortDirection::Desc => ("<", "DESC"),
+    };
+    let value = binds + 1;
+    let id = binds + 2;
+    let limit = binds + 3;
+    let name = column.name;
+    let sql_type = column.sql_type;
+
+    format!(
+        r#"
+SELECT page.* FROM ({base}) AS page
+WHERE ${value}::text IS NULL
+   OR (page.{name}, page.id) {comparison} (${value}::{sql_type}, ${id})
+ORDER BY page.{name} {order}, page.id {order}
+LIMIT ${limit}
+"#
+    )
+}
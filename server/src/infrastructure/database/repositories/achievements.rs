@@ -1,10 +1,12 @@
 use crate::domain::{
-    entities::achievements::{Achievement, BrawlerAchievement},
+    entities::achievements::{
+        Achievement, AddAchievementEntity, BrawlerAchievement, EditAchievementEntity,
+    },
     repositories::achievements::AchievementRepository,
 };
 use crate::infrastructure::database::postgresql_connection::PgPoolSquad;
 use crate::schema::{achievements, brawler_achievements};
-use anyhow::Result;
+use crate::domain::errors::{AppError, AppResult};
 use async_trait::async_trait;
 use diesel::prelude::*;
 use std::sync::Arc;
@@ -21,14 +23,14 @@ impl AchievementRepositoryImpl {
 
 #[async_trait]
 impl AchievementRepository for AchievementRepositoryImpl {
-    async fn get_all(&self) -> Result<Vec<Achievement>> {
+    async fn get_all(&self) -> AppResult<Vec<Achievement>> {
         let mut conn = self.pool.get()?;
         let result = achievements::table
             .load::<Achievement>(&mut conn)?;
         Ok(result)
     }
 
-    async fn get_by_brawler_id(&self, brawler_id: i32) -> Result<Vec<(Achievement, Option<BrawlerAchievement>)>> {
+    async fn get_by_brawler_id(&self, brawler_id: i32) -> AppResult<Vec<(Achievement, Option<BrawlerAchievement>)>> {
         let mut conn = self.pool.get()?;
         
         // This is a LEFT JOIN to get all achievements and mark which ones the user has
@@ -43,7 +45,7 @@ impl AchievementRepository for AchievementRepositoryImpl {
         Ok(result)
     }
 
-    async fn award_achievement(&self, brawler_id_val: i32, achievement_id_val: i32) -> Result<()> {
+    async fn award_achievement(&self, brawler_id_val: i32, achievement_id_val: i32) -> AppResult<()> {
         let mut conn = self.pool.get()?;
         
         diesel::insert_into(brawler_achievements::table)
@@ -57,7 +59,66 @@ impl AchievementRepository for AchievementRepositoryImpl {
         Ok(())
     }
 
-    async fn check_and_award(&self, brawler_id_val: i32, condition_type_val: &str, current_value: i32) -> Result<Vec<String>> {
+    async fn revoke_achievement(&self, brawler_id_val: i32, achievement_id_val: i32) -> AppResult<()> {
+        let mut conn = self.pool.get()?;
+
+        diesel::delete(brawler_achievements::table)
+            .filter(brawler_achievements::brawler_id.eq(brawler_id_val))
+            .filter(brawler_achievements::achievement_id.eq(achievement_id_val))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn create(&self, add_achievement_entity: AddAchievementEntity) -> AppResult<i32> {
+        let mut conn = self.pool.get()?;
+
+        let id = diesel::insert_into(achievements::table)
+            .values(&add_achievement_entity)
+            .returning(achievements::id)
+            .get_result::<i32>(&mut conn)?;
+
+        Ok(id)
+    }
+
+    async fn update(&self, achievement_id: i32, edit_achievement_entity: EditAchievementEntity) -> AppResult<()> {
+        let mut conn = self.pool.get()?;
+
+        let updated = diesel::update(achievements::table)
+            .filter(achievements::id.eq(achievement_id))
+            .set(&edit_achievement_entity)
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(AppError::NotFound("Achievement not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, achievement_id: i32) -> AppResult<()> {
+        let mut conn = self.pool.get()?;
+
+        let deleted = conn.transaction::<_, AppError, _>(|conn| {
+            diesel::delete(brawler_achievements::table)
+                .filter(brawler_achievements::achievement_id.eq(achievement_id))
+                .execute(conn)?;
+
+            let deleted = diesel::delete(achievements::table)
+                .filter(achievements::id.eq(achievement_id))
+                .execute(conn)?;
+
+            Ok(deleted)
+        })?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Achievement not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn check_and_award(&self, brawler_id_val: i32, condition_type_val: &str, current_value: i32) -> AppResult<Vec<String>> {
         let mut conn = self.pool.get()?;
         
         // 1. Find potential achievements
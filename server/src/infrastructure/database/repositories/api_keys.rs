@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use crate::domain::errors::AppResult;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use diesel::{dsl::now, prelude::*};
+
+use crate::{
+    domain::{
+        entities::api_keys::{ApiKeyEntity, NewApiKeyEntity},
+        repositories::api_keys::ApiKeyRepository,
+    },
+    infrastructure::database::{errors::not_found, postgresql_connection::PgPoolSquad, schema::api_keys},
+};
+
+/// `last_used_at` is only rewritten once per interval so busy scripts don't write on every request.
+const LAST_USED_RESOLUTION_SECS: i64 = 60;
+
+pub struct ApiKeyPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl ApiKeyPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for ApiKeyPostgres {
+    async fn create(&self, api_key: NewApiKeyEntity) -> AppResult<ApiKeyEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(api_keys::table)
+            .values(&api_key)
+            .returning(ApiKeyEntity::as_returning())
+            .get_result::<ApiKeyEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_by_id(&self, api_key_id: i32) -> AppResult<ApiKeyEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = api_keys::table
+            .find(api_key_id)
+            .select(ApiKeyEntity::as_select())
+            .first::<ApiKeyEntity>(&mut conn)
+            .map_err(not_found("API key"))?;
+
+        Ok(result)
+    }
+
+    async fn find_active_by_prefix(&self, prefix: String) -> AppResult<Option<ApiKeyEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = api_keys::table
+            .filter(api_keys::prefix.eq(prefix))
+            .filter(api_keys::revoked_at.is_null())
+            .select(ApiKeyEntity::as_select())
+            .first::<ApiKeyEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn find_by_brawlers(&self, brawler_ids: Vec<i32>) -> AppResult<Vec<ApiKeyEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = api_keys::table
+            .filter(api_keys::brawler_id.eq_any(brawler_ids))
+            .order(api_keys::created_at.desc())
+            .select(ApiKeyEntity::as_select())
+            .load::<ApiKeyEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn revoke(&self, api_key_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(api_keys::table)
+            .filter(api_keys::id.eq(api_key_id))
+            .filter(api_keys::revoked_at.is_null())
+            .set(api_keys::revoked_at.eq(now))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn touch(&self, api_key_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let stale_before = Utc::now().naive_utc() - Duration::seconds(LAST_USED_RESOLUTION_SECS);
+        diesel::update(api_keys::table)
+            .filter(api_keys::id.eq(api_key_id))
+            .filter(
+                api_keys::last_used_at
+                    .is_null()
+                    .or(api_keys::last_used_at.lt(stale_before)),
+            )
+            .set(api_keys::last_used_at.eq(now))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
-use anyhow::{Ok, Result};
+use crate::domain::errors::{AppError, AppResult};
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::Utc;
 // use diesel::{
 //     ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into,
 //     query_dsl::methods::{FilterDsl, SelectDsl},
@@ -9,21 +9,26 @@ use diesel::{dsl::insert_into, prelude::*};
 use std::sync::Arc;
 
 use crate::{
-    config::config_loader::get_jwt_env,
     domain::{
-        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        entities::{
+            brawlers::{BrawlerEntity, RegisterBotEntity, RegisterBrawlerEntity},
+            missions::MissionEntity,
+        },
         repositories::BrawlerRepository,
         value_objects::{
-            base64_img::Base64Img, brawler_model::{BrawlerModel, UpdateProfileModel}, MissionModel,
-            mission_summary::MissionSummaryModel, uploaded_img::UploadedImg,
+            base64_img::Base64Img, brawler_model::{AccountDeletion, BrawlerModel, BrawlerSort, ChiefHandover, UpdateProfileModel}, MissionModel,
+            join_request_model::JoinRequestStatus, mission_model::MissionSort,
+            mission_statuses::MissionStatuses, mission_summary::MissionSummaryModel,
+            pagination::{Page, PageRequest}, roles::Roles, uploaded_img::UploadedImg,
+            waitlist_model::WaitlistStatus,
         },
     },
     infrastructure::{
         cloudinary::{self, UploadImageOptions},
-        database::postgresql_connection::PgPoolSquad,
-        jwt::{
-            generate_token,
-            jwt_model::{Claims, Passport},
+        database::{
+            errors::not_found,
+            pagination::{TotalRow, brawler_sort_column, count_sql, keyset_sql, mission_sort_column},
+            postgresql_connection::PgPoolSquad,
         },
     },
     schema as root_schema,
@@ -41,50 +46,61 @@ impl BrawlerPostgres {
 
 #[async_trait]
 impl BrawlerRepository for BrawlerPostgres {
-    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<Passport> {
+    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> AppResult<BrawlerEntity> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
-        let user_id = insert_into(root_schema::brawlers::table)
+        let brawler = insert_into(root_schema::brawlers::table)
             .values(&register_brawler_entity)
-            .returning(root_schema::brawlers::id)
-            .get_result::<i32>(&mut connection)?;
+            .returning(BrawlerEntity::as_returning())
+            .get_result::<BrawlerEntity>(&mut connection)?;
 
-        let display_name = register_brawler_entity.display_name;
+        Ok(brawler)
+    }
 
-        let jwt_env = get_jwt_env()?;
-        let claims = Claims {
-            sub: user_id.to_string(),
-            exp: (Utc::now() + Duration::days(jwt_env.ttl)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
-        let token = generate_token(jwt_env.secret, &claims)?;
-        Ok(Passport {
-            token,
-            display_name,
-            avatar_url: None,
-            mission_success_count: 0,
-            mission_join_count: 0,
-        })
+    async fn register_bot(&self, register_bot_entity: RegisterBotEntity) -> AppResult<BrawlerEntity> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let bot = insert_into(root_schema::brawlers::table)
+            .values(&register_bot_entity)
+            .returning(BrawlerEntity::as_returning())
+            .get_result::<BrawlerEntity>(&mut connection)?;
+
+        Ok(bot)
     }
 
-    async fn find_by_username(&self, username: String) -> Result<BrawlerEntity> {
+    async fn find_bots_by_owner(&self, owner_id: i32) -> AppResult<Vec<BrawlerEntity>> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let results = root_schema::brawlers::table
+            .filter(root_schema::brawlers::bot_owner_id.eq(owner_id))
+            .filter(root_schema::brawlers::is_bot.eq(true))
+            .order(root_schema::brawlers::created_at.asc())
+            .select(BrawlerEntity::as_select())
+            .load::<BrawlerEntity>(&mut connection)?;
+
+        Ok(results)
+    }
+
+    async fn find_by_username(&self, username: String) -> AppResult<BrawlerEntity> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
         let result = root_schema::brawlers::table
             .filter(root_schema::brawlers::username.eq(username))
             .select(BrawlerEntity::as_select())
-            .first::<BrawlerEntity>(&mut connection)?;
+            .first::<BrawlerEntity>(&mut connection)
+            .map_err(not_found("Brawler"))?;
 
         Ok(result)
     }
 
-    async fn find_by_id(&self, id: i32) -> Result<BrawlerEntity> {
+    async fn find_by_id(&self, id: i32) -> AppResult<BrawlerEntity> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
         let result = root_schema::brawlers::table
             .find(id)
             .select(BrawlerEntity::as_select())
-            .first::<BrawlerEntity>(&mut connection)?;
+            .first::<BrawlerEntity>(&mut connection)
+            .map_err(not_found("Brawler"))?;
 
         Ok(result)
     }
@@ -94,7 +110,7 @@ impl BrawlerRepository for BrawlerPostgres {
         user_id: i32,
         base64img: Base64Img,
         opt: UploadImageOptions,
-    ) -> Result<UploadedImg> {
+    ) -> AppResult<UploadedImg> {
         let uploaded_img = cloudinary::upload(base64img, opt).await?;
 
         let mut conn = Arc::clone(&self.db_pool).get()?;
@@ -114,72 +130,28 @@ impl BrawlerRepository for BrawlerPostgres {
         &self,
         user_id: i32,
         model: UpdateProfileModel,
-    ) -> Result<BrawlerEntity> {
+    ) -> AppResult<BrawlerEntity> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let (
-            id,
-            username,
-            password,
-            created_at,
-            updated_at,
-            display_name,
-            avatar_url,
-            avatar_public_id,
-            mission_success_count,
-            mission_join_count,
-        ) = diesel::update(root_schema::brawlers::table)
+        let result = diesel::update(root_schema::brawlers::table)
             .filter(root_schema::brawlers::id.eq(user_id))
             .set((
                 root_schema::brawlers::display_name.eq(model.display_name),
                 root_schema::brawlers::updated_at.eq(Utc::now().naive_utc()),
             ))
-            .returning((
-                root_schema::brawlers::id,
-                root_schema::brawlers::username,
-                root_schema::brawlers::password,
-                root_schema::brawlers::created_at,
-                root_schema::brawlers::updated_at,
-                root_schema::brawlers::display_name,
-                root_schema::brawlers::avatar_url,
-                root_schema::brawlers::avatar_public_id,
-                root_schema::brawlers::mission_success_count,
-                root_schema::brawlers::mission_join_count,
-            ))
-            .get_result::<(
-                i32,
-                String,
-                String,
-                chrono::NaiveDateTime,
-                chrono::NaiveDateTime,
-                String,
-                Option<String>,
-                Option<String>,
-                i32,
-                i32,
-            )>(&mut conn)?;
-
-        Ok(BrawlerEntity {
-            id,
-            username,
-            password,
-            created_at,
-            updated_at,
-            display_name,
-            avatar_url,
-            avatar_public_id,
-            mission_success_count,
-            mission_join_count,
-        })
+            .returning(BrawlerEntity::as_returning())
+            .get_result::<BrawlerEntity>(&mut conn)?;
+
+        Ok(result)
     }
 
     // *เพิ่ม
-    async fn get_leaderboard(&self) -> Result<Vec<BrawlerModel>> {
+    async fn get_leaderboard(&self) -> AppResult<Vec<BrawlerModel>> {
         use diesel::sql_query;
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let result = sql_query(
-            "SELECT id, display_name, COALESCE(avatar_url, '') as avatar_url, mission_success_count, mission_join_count 
+            "SELECT id, display_name, COALESCE(avatar_url, '') as avatar_url, mission_success_count, mission_join_count, reputation, rating_count, NULL::int4 AS role_slot_id, NULL::varchar AS role, false AS is_co_chief 
              FROM brawlers 
              ORDER BY mission_success_count DESC, mission_join_count DESC, id ASC 
              LIMIT 10",
@@ -189,12 +161,17 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(result)
     }
 
-    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+    async fn get_missions(
+        &self,
+        brawler_id: i32,
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>> {
+        use diesel::sql_types::{BigInt, Int4, Nullable, Text};
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         // Use a raw SQL query to select the MissionModel fields including
         // the chief's display name and the crew count.
-        let sql = r#"
+        let base = r#"
 SELECT
     missions.id,
     missions.name,
@@ -206,49 +183,73 @@ SELECT
     COALESCE(brawlers.display_name, '') AS chief_display_name,
     (SELECT COUNT(*) FROM crew_memberships WHERE crew_memberships.mission_id = missions.id) AS crew_count,
     false::bool AS is_member,
+    false::bool AS is_co_chief,
     missions.image_url,
     missions.created_at,
-    missions.updated_at
+    missions.updated_at,
+    missions.scheduled_start_at,
+    missions.deadline_at,
+    missions.duration_minutes,
+    missions.objectives_required,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = missions.id) AS objectives_total,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = missions.id AND mo.completed_at IS NOT NULL) AS objectives_done,
+    (SELECT COALESCE(100 * COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) / NULLIF(COUNT(*), 0), 0)::int4
+     FROM mission_objectives mo WHERE mo.mission_id = missions.id) AS objectives_progress,
+    missions.join_policy,
+    missions.finished_at,
+    missions.min_reputation
 FROM missions
 LEFT JOIN brawlers ON brawlers.id = missions.chief_id
 WHERE missions.deleted_at IS NULL
     AND missions.chief_id = $1
-ORDER BY missions.created_at DESC
         "#;
 
-        let results = diesel::sql_query(sql)
-            .bind::<diesel::sql_types::Int4, _>(brawler_id)
+        let total = diesel::sql_query(count_sql(base))
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<TotalRow>(&mut conn)?
+            .total;
+
+        let sort = page.sort();
+        let cursor = page.cursor();
+        let results = diesel::sql_query(keyset_sql(base, 1, mission_sort_column(sort), page.direction()))
+            .bind::<Int4, _>(brawler_id)
+            .bind::<Nullable<Text>, _>(cursor.as_ref().map(|c| c.value.clone()))
+            .bind::<Nullable<Int4>, _>(cursor.as_ref().map(|c| c.id))
+            .bind::<BigInt, _>(i64::from(page.limit()) + 1)
             .load::<MissionModel>(&mut conn)?;
 
-        Ok(results)
+        Ok(Page::from_rows(results, page.limit(), total, |m| sort.cursor(m)))
     }
 
-    async fn get_mission_summary(&self, brawler_id: i32) -> Result<MissionSummaryModel> {
+    async fn get_mission_summary(&self, brawler_id: i32) -> AppResult<MissionSummaryModel> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let sql = r#"
 SELECT
     (SELECT COUNT(*)::BIGINT FROM missions WHERE chief_id = $1 AND deleted_at IS NULL) AS created_count,
-    (SELECT COUNT(*)::BIGINT FROM crew_memberships WHERE brawler_id = $1) AS joined_count,
+    (SELECT COUNT(*)::BIGINT
+        FROM crew_memberships cm
+        INNER JOIN missions m ON m.id = cm.mission_id
+        WHERE cm.brawler_id = $1 AND m.deleted_at IS NULL) AS joined_count,
     (
         (SELECT COUNT(*)::BIGINT
         FROM missions m
         INNER JOIN crew_memberships cm ON m.id = cm.mission_id
-        WHERE cm.brawler_id = $1 AND m.status = 'Completed')
+        WHERE cm.brawler_id = $1 AND m.status = 'Completed' AND m.deleted_at IS NULL)
         +
         (SELECT COUNT(*)::BIGINT
         FROM missions m2
-        WHERE m2.chief_id = $1 AND m2.status = 'Completed')
+        WHERE m2.chief_id = $1 AND m2.status = 'Completed' AND m2.deleted_at IS NULL)
     ) AS completed_count,
     (
         (SELECT COUNT(*)::BIGINT
         FROM missions m
         INNER JOIN crew_memberships cm ON m.id = cm.mission_id
-        WHERE cm.brawler_id = $1 AND m.status = 'Failed')
+        WHERE cm.brawler_id = $1 AND m.status = 'Failed' AND m.deleted_at IS NULL)
         +
         (SELECT COUNT(*)::BIGINT
         FROM missions m3
-        WHERE m3.chief_id = $1 AND m3.status = 'Failed')
+        WHERE m3.chief_id = $1 AND m3.status = 'Failed' AND m3.deleted_at IS NULL)
     ) AS failed_count
         "#;
 
@@ -259,7 +260,7 @@ SELECT
         Ok(summary)
     }
 
-    async fn crew_counting(&self, mission_id: i32) -> Result<u32> {
+    async fn crew_counting(&self, mission_id: i32) -> AppResult<u32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let result = root_schema::crew_memberships::table
@@ -267,22 +268,257 @@ SELECT
             .count()
             .first::<i64>(&mut conn)?;
 
-        let count = u32::try_from(result)?;
+        let count = u32::try_from(result).map_err(anyhow::Error::from)?;
 
         Ok(count)
     }
 
-    async fn get_all_brawlers(&self) -> Result<Vec<BrawlerModel>> {
-        use diesel::sql_query;
+    async fn get_all_brawlers(&self, page: &PageRequest<BrawlerSort>) -> AppResult<Page<BrawlerModel>> {
+        use diesel::{
+            sql_query,
+            sql_types::{BigInt, Int4, Nullable, Text},
+        };
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = sql_query(
-            "SELECT id, display_name, COALESCE(avatar_url, '') as avatar_url, mission_success_count, mission_join_count 
-             FROM brawlers 
-             ORDER BY display_name ASC",
-        )
-        .load::<BrawlerModel>(&mut conn)?;
+        let base = "SELECT id, display_name, COALESCE(avatar_url, '') as avatar_url, mission_success_count, mission_join_count, reputation, rating_count, NULL::int4 AS role_slot_id, NULL::varchar AS role, false AS is_co_chief 
+             FROM brawlers";
+
+        let total = sql_query(count_sql(base)).get_result::<TotalRow>(&mut conn)?.total;
+
+        let sort = page.sort();
+        let cursor = page.cursor();
+        let result = sql_query(keyset_sql(base, 0, brawler_sort_column(sort), page.direction()))
+            .bind::<Nullable<Text>, _>(cursor.as_ref().map(|c| c.value.clone()))
+            .bind::<Nullable<Int4>, _>(cursor.as_ref().map(|c| c.id))
+            .bind::<BigInt, _>(i64::from(page.limit()) + 1)
+            .load::<BrawlerModel>(&mut conn)?;
+
+        Ok(Page::from_rows(result, page.limit(), total, |b| sort.cursor(b)))
+    }
+
+    async fn list_brawlers(&self) -> AppResult<Vec<BrawlerEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = root_schema::brawlers::table
+            .order(root_schema::brawlers::id.asc())
+            .select(BrawlerEntity::as_select())
+            .load::<BrawlerEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn set_disabled(&self, brawler_id: i32, disabled: bool) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let disabled_at = disabled.then(|| Utc::now().naive_utc());
+        // Disabling signs the account out everywhere; enabling it again does not bring old tokens back.
+        let version_bump = if disabled { 1 } else { 0 };
+        let updated = diesel::update(root_schema::brawlers::table)
+            .filter(root_schema::brawlers::id.eq(brawler_id))
+            .set((
+                root_schema::brawlers::disabled_at.eq(disabled_at),
+                root_schema::brawlers::token_version.eq(root_schema::brawlers::token_version + version_bump),
+                root_schema::brawlers::updated_at.eq(Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(AppError::NotFound("Brawler not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn set_role(&self, brawler_id: i32, role: Roles) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::update(root_schema::brawlers::table)
+            .filter(root_schema::brawlers::id.eq(brawler_id))
+            .set((
+                root_schema::brawlers::role.eq(role.to_string()),
+                root_schema::brawlers::token_version.eq(root_schema::brawlers::token_version + 1),
+                root_schema::brawlers::updated_at.eq(Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(AppError::NotFound("Brawler not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn set_calendar_token_hash(&self, brawler_id: i32, token_hash: Option<String>) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::update(root_schema::brawlers::table)
+            .filter(root_schema::brawlers::id.eq(brawler_id))
+            .set(root_schema::brawlers::calendar_token_hash.eq(token_hash))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(AppError::NotFound("Brawler not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_calendar_token_hash(&self, token_hash: String) -> AppResult<Option<BrawlerEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = root_schema::brawlers::table
+            .filter(root_schema::brawlers::calendar_token_hash.eq(token_hash))
+            .select(BrawlerEntity::as_select())
+            .first::<BrawlerEntity>(&mut conn)
+            .optional()?;
 
         Ok(result)
     }
+
+    async fn get_calendar_missions(&self, brawler_id: i32) -> AppResult<Vec<MissionEntity>> {
+        use crate::infrastructure::database::schema::{crew_memberships, missions};
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let joined = crew_memberships::table
+            .filter(crew_memberships::brawler_id.eq(brawler_id))
+            .select(crew_memberships::mission_id);
+
+        let results = missions::table
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::scheduled_start_at.is_not_null())
+            .filter(missions::chief_id.eq(brawler_id).or(missions::id.eq_any(joined)))
+            .order(missions::scheduled_start_at.asc())
+            .select(MissionEntity::as_select())
+            .load::<MissionEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn delete_account(&self, brawler_id: i32) -> AppResult<AccountDeletion> {
+        use crate::infrastructure::database::schema::{
+            api_keys, brawlers, crew_memberships, identities, mission_join_requests,
+            mission_waitlist, missions,
+        };
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now().naive_utc();
+        let finished = [
+            MissionStatuses::Completed.to_string(),
+            MissionStatuses::Failed.to_string(),
+        ];
+        let running = [
+            MissionStatuses::Open.to_string(),
+            MissionStatuses::InProgress.to_string(),
+        ];
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let exists = brawlers::table
+                .filter(brawlers::id.eq(brawler_id))
+                .filter(brawlers::disabled_at.is_null())
+                .select(brawlers::id)
+                .for_update()
+                .first::<i32>(conn)
+                .optional()?;
+            if exists.is_none() {
+                return Err(AppError::NotFound("Brawler not found".to_string()));
+            }
+
+            let led = missions::table
+                .filter(missions::chief_id.eq(brawler_id))
+                .filter(missions::deleted_at.is_null())
+                .select((missions::id, missions::name, missions::status))
+                .for_update()
+                .load::<(i32, String, String)>(conn)?;
+
+            let mut handovers = Vec::new();
+            let mut vacated_mission_ids = Vec::new();
+            for (mission_id, mission_name, status) in led {
+                let successor = crew_memberships::table
+                    .inner_join(brawlers::table)
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(brawlers::disabled_at.is_null())
+                    .order((crew_memberships::joined_at.asc(), crew_memberships::brawler_id.asc()))
+                    .select(crew_memberships::brawler_id)
+                    .first::<i32>(conn)
+                    .optional()?;
+
+                match successor {
+                    Some(new_chief_id) => {
+                        diesel::delete(crew_memberships::table)
+                            .filter(crew_memberships::mission_id.eq(mission_id))
+                            .filter(crew_memberships::brawler_id.eq(new_chief_id))
+                            .execute(conn)?;
+                        diesel::update(missions::table)
+                            .filter(missions::id.eq(mission_id))
+                            .set(missions::chief_id.eq(new_chief_id))
+                            .execute(conn)?;
+                        // The new chief's crew place is free again.
+                        if running.contains(&status) {
+                            vacated_mission_ids.push(mission_id);
+                        }
+                        handovers.push(ChiefHandover { mission_id, mission_name, new_chief_id });
+                    }
+                    // Finished missions stay as history under the anonymised chief.
+                    None if finished.contains(&status) => {}
+                    None => {
+                        diesel::update(missions::table)
+                            .filter(missions::id.eq(mission_id))
+                            .set(missions::deleted_at.eq(now))
+                            .execute(conn)?;
+                    }
+                }
+            }
+
+            // Finished crews stay as history; running ones get their place back.
+            let running_missions = missions::table
+                .filter(missions::status.eq_any(&running))
+                .filter(missions::deleted_at.is_null())
+                .select(missions::id);
+            let left = diesel::delete(crew_memberships::table)
+                .filter(crew_memberships::brawler_id.eq(brawler_id))
+                .filter(crew_memberships::mission_id.eq_any(running_missions))
+                .returning(crew_memberships::mission_id)
+                .get_results::<i32>(conn)?;
+            vacated_mission_ids.extend(left);
+
+            diesel::update(mission_waitlist::table)
+                .filter(mission_waitlist::brawler_id.eq(brawler_id))
+                .filter(mission_waitlist::status.eq(WaitlistStatus::Waiting.to_string()))
+                .set(mission_waitlist::status.eq(WaitlistStatus::Left.to_string()))
+                .execute(conn)?;
+            diesel::update(mission_join_requests::table)
+                .filter(mission_join_requests::brawler_id.eq(brawler_id))
+                .filter(mission_join_requests::status.eq(JoinRequestStatus::Pending.to_string()))
+                .set(mission_join_requests::status.eq(JoinRequestStatus::Cancelled.to_string()))
+                .execute(conn)?;
+            diesel::delete(identities::table.filter(identities::brawler_id.eq(brawler_id)))
+                .execute(conn)?;
+            diesel::delete(api_keys::table.filter(api_keys::brawler_id.eq(brawler_id)))
+                .execute(conn)?;
+
+            diesel::update(brawlers::table)
+                .filter(brawlers::bot_owner_id.eq(brawler_id))
+                .filter(brawlers::disabled_at.is_null())
+                .set((
+                    brawlers::disabled_at.eq(now),
+                    brawlers::token_version.eq(brawlers::token_version + 1),
+                ))
+                .execute(conn)?;
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set((
+                    brawlers::username.eq(format!("deleted-{}", brawler_id)),
+                    brawlers::password.eq(""),
+                    brawlers::display_name.eq("Deleted brawler"),
+                    brawlers::avatar_url.eq(None::<String>),
+                    brawlers::avatar_public_id.eq(None::<String>),
+                    brawlers::calendar_token_hash.eq(None::<String>),
+                    brawlers::disabled_at.eq(now),
+                    brawlers::token_version.eq(brawlers::token_version + 1),
+                    brawlers::updated_at.eq(now),
+                ))
+                .execute(conn)?;
+
+            Ok(AccountDeletion { handovers, vacated_mission_ids })
+        })
+    }
 }
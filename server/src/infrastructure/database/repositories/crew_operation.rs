@@ -1,16 +1,30 @@
-use anyhow::Result;
+use crate::domain::errors::{AppError, AppResult};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::{delete, exists}, insert_into, query_dsl::methods::FilterDsl, select};
-use std::sync::Arc;
+use diesel::{
+    Connection, ExpressionMethods, NullableExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+    dsl::{self, delete, exists},
+    insert_into,
+    result::{DatabaseErrorKind, Error as DieselError},
+    select,
+};
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     domain::{
-        entities::crew_memberships::CrewMemberShips,
+        entities::{crew_memberships::CrewMemberShips, mission_role_slots::AddMissionRoleSlotEntity},
         repositories::crew_operation::CrewOperationRepository,
+        value_objects::{mission_role_model::RoleSlotModel, waitlist_model::WaitlistStatus},
+    },
+    infrastructure::database::{
+        errors::not_found,
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, crew_memberships, mission_role_slots, mission_waitlist, missions},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{crew_memberships, brawlers}},
 };
 
+/// Unique index that keeps one member per role slot.
+const ROLE_SLOT_UNIQUE_INDEX: &str = "idx_crew_memberships_role_slot";
+
 pub struct CrewOperationPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -23,38 +37,58 @@ impl CrewOperationPostgres {
 
 #[async_trait]
 impl CrewOperationRepository for CrewOperationPostgres {
-    async fn join(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
+    async fn join(&self, crew_member_ships: CrewMemberShips) -> AppResult<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
+        let mission_id = crew_member_ships.mission_id;
         let brawler_id = crew_member_ships.brawler_id;
-        let result = insert_into(crew_memberships::table)
-            .values(crew_member_ships)
-            .execute(&mut conn);
-
-        match result {
-            Ok(_) => {
-                diesel::update(brawlers::table)
-                    .filter(brawlers::id.eq(brawler_id))
-                    .set(brawlers::mission_join_count.eq(brawlers::mission_join_count + 1))
-                    .execute(&mut conn)?;
-                Ok(())
-            },
-            Err(diesel::result::Error::DatabaseError(
-                diesel::result::DatabaseErrorKind::UniqueViolation,
-                _,
-            )) => Err(anyhow::anyhow!("Already joined")),
-            Err(e) => {
-                let err_msg = e.to_string();
-                println!("Database Insert Error: {}", err_msg);
-                if err_msg.contains("duplicate key") || err_msg.contains("UniqueViolation") {
-                     Err(anyhow::anyhow!("Already joined"))
-                } else {
-                     Err(e.into())
-                }
-            },
-        }
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            // Row lock shared with waitlist promotion, so racing joins, invite and link accepts
+            // cannot overfill the crew or take a place held for an offer.
+            let max_crew = missions::table
+                .filter(missions::id.eq(mission_id))
+                .select(missions::max_crew)
+                .for_update()
+                .first::<i32>(conn)
+                .map_err(not_found("Mission"))?;
+            let crew_count = crew_memberships::table
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .count()
+                .get_result::<i64>(conn)?;
+            let held = mission_waitlist::table
+                .filter(mission_waitlist::mission_id.eq(mission_id))
+                .filter(mission_waitlist::brawler_id.ne(brawler_id))
+                .filter(mission_waitlist::status.eq(WaitlistStatus::Offered.to_string()))
+                .filter(mission_waitlist::offer_expires_at.gt(dsl::now.nullable()))
+                .count()
+                .get_result::<i64>(conn)?;
+            if crew_count + held >= i64::from(max_crew) {
+                return Err(AppError::Conflict("Mission is full".to_string()));
+            }
+
+            insert_into(crew_memberships::table)
+                .values(crew_member_ships)
+                .execute(conn)
+                .map_err(|e| match e {
+                    DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+                        if info.constraint_name() == Some(ROLE_SLOT_UNIQUE_INDEX) =>
+                    {
+                        AppError::Conflict("Role slot is already taken".to_string())
+                    }
+                    DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                        AppError::Conflict("Already joined".to_string())
+                    }
+                    e => e.into(),
+                })?;
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set(brawlers::mission_join_count.eq(brawlers::mission_join_count + 1))
+                .execute(conn)?;
+            Ok(())
+        })
     }
 
-    async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
+    async fn leave(&self, crew_member_ships: CrewMemberShips) -> AppResult<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         delete(crew_memberships::table)
             .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
@@ -64,7 +98,7 @@ impl CrewOperationRepository for CrewOperationPostgres {
     }
 
     // *เพิ่ม
-    async fn is_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool> {
+    async fn is_member(&self, mission_id: i32, brawler_id: i32) -> AppResult<bool> {
     let mut conn = Arc::clone(&self.db_pool).get()?;
 
     let result = select(exists(
@@ -76,4 +110,172 @@ impl CrewOperationRepository for CrewOperationPostgres {
 
     Ok(result)
 }
+
+    async fn role_slots(&self, mission_id: i32) -> AppResult<Vec<RoleSlotModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let sql = r#"
+SELECT
+    rs.id,
+    rs.mission_id,
+    rs.label,
+    rs.position,
+    cm.brawler_id,
+    b.display_name AS brawler_display_name
+FROM mission_role_slots rs
+LEFT JOIN crew_memberships cm ON cm.role_slot_id = rs.id
+LEFT JOIN brawlers b ON b.id = cm.brawler_id
+WHERE rs.mission_id = $1
+ORDER BY rs.position ASC
+"#;
+        let slots = diesel::sql_query(sql)
+            .bind::<diesel::sql_types::Int4, _>(mission_id)
+            .load::<RoleSlotModel>(&mut conn)?;
+        Ok(slots)
+    }
+
+    async fn set_role_slots(&self, mission_id: i32, labels: Vec<String>) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing = mission_role_slots::table
+                .filter(mission_role_slots::mission_id.eq(mission_id))
+                .select((mission_role_slots::id, mission_role_slots::label))
+                .for_update()
+                .load::<(i32, String)>(conn)?;
+            let mut by_label = existing
+                .into_iter()
+                .map(|(id, label)| (label.to_lowercase(), id))
+                .collect::<HashMap<_, _>>();
+
+            for (position, label) in labels.iter().enumerate() {
+                match by_label.remove(&label.to_lowercase()) {
+                    Some(slot_id) => {
+                        diesel::update(mission_role_slots::table)
+                            .filter(mission_role_slots::id.eq(slot_id))
+                            .set((
+                                mission_role_slots::label.eq(label),
+                                mission_role_slots::position.eq(position as i32),
+                            ))
+                            .execute(conn)?;
+                    }
+                    None => {
+                        insert_into(mission_role_slots::table)
+                            .values(AddMissionRoleSlotEntity {
+                                mission_id,
+                                label: label.clone(),
+                                position: position as i32,
+                            })
+                            .execute(conn)?;
+                    }
+                }
+            }
+
+            // Members of dropped slots stay in the crew without a role (ON DELETE SET NULL).
+            let dropped = by_label.into_values().collect::<Vec<_>>();
+            delete(mission_role_slots::table)
+                .filter(mission_role_slots::id.eq_any(dropped))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn assign_role_slot(&self, mission_id: i32, brawler_id: i32, slot_id: Option<i32>) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let current = crew_memberships::table
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .filter(crew_memberships::brawler_id.eq(brawler_id))
+                .select(crew_memberships::role_slot_id)
+                .for_update()
+                .first::<Option<i32>>(conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound("Crew member not found".to_string()))?;
+
+            if current == slot_id {
+                return Ok(());
+            }
+
+            let occupant = match slot_id {
+                Some(slot_id) => crew_memberships::table
+                    .filter(crew_memberships::role_slot_id.eq(slot_id))
+                    .select(crew_memberships::brawler_id)
+                    .for_update()
+                    .first::<i32>(conn)
+                    .optional()?,
+                None => None,
+            };
+
+            // Free the member's slot first so the occupant can take it without tripping the unique index.
+            diesel::update(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .filter(crew_memberships::brawler_id.eq(brawler_id))
+                .set(crew_memberships::role_slot_id.eq(None::<i32>))
+                .execute(conn)?;
+
+            if let Some(occupant_id) = occupant {
+                diesel::update(crew_memberships::table)
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(crew_memberships::brawler_id.eq(occupant_id))
+                    .set(crew_memberships::role_slot_id.eq(current))
+                    .execute(conn)?;
+            }
+
+            diesel::update(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .filter(crew_memberships::brawler_id.eq(brawler_id))
+                .set(crew_memberships::role_slot_id.eq(slot_id))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn set_co_chief(&self, mission_id: i32, brawler_id: i32, is_co_chief: bool) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let updated = diesel::update(crew_memberships::table)
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .filter(crew_memberships::brawler_id.eq(brawler_id))
+            .set(crew_memberships::is_co_chief.eq(is_co_chief))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(AppError::NotFound("Crew member not found".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn transfer_chief(&self, mission_id: i32, chief_id: i32, new_chief_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let role_slot_id = delete(crew_memberships::table)
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .filter(crew_memberships::brawler_id.eq(new_chief_id))
+                .returning(crew_memberships::role_slot_id)
+                .get_result::<Option<i32>>(conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound("Crew member not found".to_string()))?;
+
+            let updated = diesel::update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::chief_id.eq(chief_id))
+                .set(missions::chief_id.eq(new_chief_id))
+                .execute(conn)?;
+            if updated == 0 {
+                return Err(AppError::NotFound("Mission not found".to_string()));
+            }
+
+            insert_into(crew_memberships::table)
+                .values(CrewMemberShips {
+                    mission_id,
+                    brawler_id: chief_id,
+                    role_slot_id,
+                })
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
 }
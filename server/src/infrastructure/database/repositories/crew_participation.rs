@@ -3,8 +3,8 @@ use diesel::{
     ExpressionMethods, RunQueryDsl, delete, insert_into
 };
 use std::sync::Arc;
-use crate::{domain::{entities::crew_memberships::CrewMemberShips, repositories::crew_operation::CrewOperationRepository}, infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{brawlers, crew_memberships}}};
-use anyhow::Result;
+use crate::{domain::{entities::crew_memberships::CrewMemberShips, repositories::crew_operation::CrewOperationRepository, value_objects::mission_role_model::RoleSlotModel}, infrastructure::database::{postgresql_connection::PgPoolSquad, repositories::crew_operation::CrewOperationPostgres, schema::{brawlers, crew_memberships}}};
+use crate::domain::errors::AppResult;
 use diesel::prelude::*;
 
 pub struct CrewPostgres {
@@ -22,7 +22,7 @@ impl CrewPostgres {
 impl CrewOperationRepository for CrewPostgres {
 
 
-    async fn join(&self, crew_memberships: CrewMemberShips) -> Result<()> {
+    async fn join(&self, crew_memberships: CrewMemberShips) -> AppResult<()> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
         let brawler_id = crew_memberships.brawler_id;
         
@@ -41,7 +41,7 @@ impl CrewOperationRepository for CrewPostgres {
         Ok(())
     }
 
-    async fn leave(&self, crew_memberships: CrewMemberShips) -> Result<()> {
+    async fn leave(&self, crew_memberships: CrewMemberShips) -> AppResult<()> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
         delete(crew_memberships::table)
             .filter(crew_memberships::brawler_id.eq(crew_memberships.brawler_id))
@@ -50,7 +50,7 @@ impl CrewOperationRepository for CrewPostgres {
         Ok(())
     }
 
-    async fn is_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool> {
+    async fn is_member(&self, mission_id: i32, brawler_id: i32) -> AppResult<bool> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
         let count = crew_memberships::table
             .filter(crew_memberships::mission_id.eq(mission_id))
@@ -60,4 +60,25 @@ impl CrewOperationRepository for CrewPostgres {
         
         Ok(count > 0)
     }
-}
\ No newline at end of file
+
+    // Role slots are shared with the main crew repository.
+    async fn role_slots(&self, mission_id: i32) -> AppResult<Vec<RoleSlotModel>> {
+        CrewOperationPostgres::new(Arc::clone(&self.db_pool)).role_slots(mission_id).await
+    }
+
+    async fn set_role_slots(&self, mission_id: i32, labels: Vec<String>) -> AppResult<()> {
+        CrewOperationPostgres::new(Arc::clone(&self.db_pool)).set_role_slots(mission_id, labels).await
+    }
+
+    async fn assign_role_slot(&self, mission_id: i32, brawler_id: i32, slot_id: Option<i32>) -> AppResult<()> {
+        CrewOperationPostgres::new(Arc::clone(&self.db_pool)).assign_role_slot(mission_id, brawler_id, slot_id).await
+    }
+
+    async fn set_co_chief(&self, mission_id: i32, brawler_id: i32, is_co_chief: bool) -> AppResult<()> {
+        CrewOperationPostgres::new(Arc::clone(&self.db_pool)).set_co_chief(mission_id, brawler_id, is_co_chief).await
+    }
+
+    async fn transfer_chief(&self, mission_id: i32, chief_id: i32, new_chief_id: i32) -> AppResult<()> {
+        CrewOperationPostgres::new(Arc::clone(&self.db_pool)).transfer_chief(mission_id, chief_id, new_chief_id).await
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::{
+            brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+            identities::{IdentityEntity, NewIdentityEntity},
+        },
+        repositories::identities::IdentityRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::identities},
+    schema::brawlers,
+};
+
+pub struct IdentityPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl IdentityPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl IdentityRepository for IdentityPostgres {
+    async fn find_by_provider_subject(&self, provider: String, subject: String) -> AppResult<Option<IdentityEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = identities::table
+            .filter(identities::provider.eq(provider))
+            .filter(identities::subject.eq(subject))
+            .select(IdentityEntity::as_select())
+            .first::<IdentityEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn find_by_brawler(&self, brawler_id: i32) -> AppResult<Vec<IdentityEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = identities::table
+            .filter(identities::brawler_id.eq(brawler_id))
+            .order(identities::created_at.asc())
+            .select(IdentityEntity::as_select())
+            .load::<IdentityEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn link(&self, identity: NewIdentityEntity) -> AppResult<IdentityEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(identities::table)
+            .values(&identity)
+            .returning(IdentityEntity::as_returning())
+            .get_result::<IdentityEntity>(&mut conn);
+
+        match result {
+            Ok(identity) => Ok(identity),
+            Err(diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            )) => Err(AppError::Conflict(
+                "Identity is already linked to a brawler".to_string(),
+            )),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn register_with_identity(
+        &self,
+        register_brawler_entity: RegisterBrawlerEntity,
+        identity: NewIdentityEntity,
+    ) -> AppResult<BrawlerEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let brawler = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let brawler = diesel::insert_into(brawlers::table)
+                .values(&register_brawler_entity)
+                .returning(BrawlerEntity::as_returning())
+                .get_result::<BrawlerEntity>(conn)?;
+
+            diesel::insert_into(identities::table)
+                .values(&NewIdentityEntity {
+                    brawler_id: brawler.id,
+                    ..identity
+                })
+                .execute(conn)?;
+
+            Ok(brawler)
+        })?;
+
+        Ok(brawler)
+    }
+}
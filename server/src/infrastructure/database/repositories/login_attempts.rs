@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::*,
+    sql_types::{Integer, Timestamp, Varchar},
+};
+
+use crate::{
+    domain::{
+        entities::login_attempts::{LoginAttemptEntity, NewLoginAttemptEntity},
+        repositories::login_attempts::{LoginAttemptGuard, LoginAttemptRepository},
+        value_objects::login_attempt_model::FailedLoginSummary,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::login_attempts},
+};
+
+pub struct LoginAttemptPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LoginAttemptPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn failures_by_username(conn: &mut PgConnection, username: &str, since: NaiveDateTime) -> QueryResult<FailedLoginSummary> {
+    // A successful login resets the streak, so only count failures after it.
+    let sql = r#"
+SELECT
+    COUNT(*) AS count,
+    MAX(la.attempted_at) AS last_failed_at
+FROM login_attempts la
+WHERE la.username = $1
+  AND la.succeeded = FALSE
+  AND la.attempted_at > $2
+  AND la.attempted_at > COALESCE(
+      (SELECT MAX(ok.attempted_at) FROM login_attempts ok
+       WHERE ok.username = $1 AND ok.succeeded = TRUE),
+      '-infinity'::timestamp
+  )
+"#;
+
+    diesel::sql_query(sql)
+        .bind::<Varchar, _>(username)
+        .bind::<Timestamp, _>(since)
+        .get_result::<FailedLoginSummary>(conn)
+}
+
+fn failures_by_ip(conn: &mut PgConnection, ip_address: &str, since: NaiveDateTime) -> QueryResult<FailedLoginSummary> {
+    let sql = r#"
+SELECT
+    COUNT(*) AS count,
+    MAX(la.attempted_at) AS last_failed_at
+FROM login_attempts la
+WHERE la.ip_address = $1
+  AND la.succeeded = FALSE
+  AND la.attempted_at > $2
+"#;
+
+    diesel::sql_query(sql)
+        .bind::<Varchar, _>(ip_address)
+        .bind::<Timestamp, _>(since)
+        .get_result::<FailedLoginSummary>(conn)
+}
+
+/// Transaction-scoped advisory lock on `key` within `namespace`, so usernames and IP addresses
+/// never share a lock. Usernames are always locked before IP addresses, which keeps two attempts
+/// from waiting on each other.
+fn lock_key(conn: &mut PgConnection, namespace: i32, key: &str) -> QueryResult<()> {
+    diesel::sql_query("SELECT pg_advisory_xact_lock($1, hashtext($2))")
+        .bind::<Integer, _>(namespace)
+        .bind::<Varchar, _>(key)
+        .execute(conn)?;
+    Ok(())
+}
+
+const USERNAME_LOCK: i32 = 1;
+const IP_ADDRESS_LOCK: i32 = 2;
+
+#[async_trait]
+impl LoginAttemptRepository for LoginAttemptPostgres {
+    async fn record_failure(
+        &self,
+        attempt: NewLoginAttemptEntity,
+        since: NaiveDateTime,
+        guard: &LoginAttemptGuard<'_>,
+    ) -> AppResult<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            lock_key(conn, USERNAME_LOCK, &attempt.username)?;
+            if let Some(ip_address) = &attempt.ip_address {
+                lock_key(conn, IP_ADDRESS_LOCK, ip_address)?;
+            }
+
+            let by_username = failures_by_username(conn, &attempt.username, since)?;
+            let by_ip = match &attempt.ip_address {
+                Some(ip_address) => Some(failures_by_ip(conn, ip_address, since)?),
+                None => None,
+            };
+            guard(&by_username, by_ip.as_ref())?;
+
+            let attempt_id = diesel::insert_into(login_attempts::table)
+                .values(&attempt)
+                .returning(login_attempts::id)
+                .get_result::<i32>(conn)?;
+            Ok(attempt_id)
+        })
+    }
+
+    async fn mark_succeeded(&self, attempt_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(login_attempts::table)
+            .filter(login_attempts::id.eq(attempt_id))
+            .set(login_attempts::succeeded.eq(true))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn find_failed_by_brawler(&self, brawler_id: i32, limit: i64) -> AppResult<Vec<LoginAttemptEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = login_attempts::table
+            .filter(login_attempts::brawler_id.eq(brawler_id))
+            .filter(login_attempts::succeeded.eq(false))
+            .order(login_attempts::attempted_at.desc())
+            .limit(limit)
+            .select(LoginAttemptEntity::as_select())
+            .load::<LoginAttemptEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+}
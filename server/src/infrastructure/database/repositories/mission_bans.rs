@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::*,
+    sql_types::{Int4, Timestamp},
+};
+
+use crate::{
+    domain::{
+        entities::mission_bans::AddMissionBanEntity,
+        repositories::mission_bans::MissionBanRepository,
+        value_objects::ban_model::MissionBanModel,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_bans},
+};
+
+/// Bans in force at `$1`; callers add their own filters on `mb`.
+const BAN_SELECT: &str = r#"
+SELECT
+    mb.id,
+    mb.mission_id,
+    mb.brawler_id,
+    b.display_name AS brawler_display_name,
+    mb.banned_by,
+    mb.reason,
+    mb.expires_at,
+    mb.created_at
+FROM mission_bans mb
+INNER JOIN brawlers b ON b.id = mb.brawler_id
+WHERE (mb.expires_at IS NULL OR mb.expires_at > $1)
+"#;
+
+pub struct MissionBanPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionBanPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionBanRepository for MissionBanPostgres {
+    async fn ban(&self, ban: AddMissionBanEntity) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::insert_into(mission_bans::table)
+            .values(&ban)
+            .on_conflict((mission_bans::mission_id, mission_bans::brawler_id))
+            .do_update()
+            .set((&ban, mission_bans::created_at.eq(diesel::dsl::now)))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn find_active(&self, mission_id: i32, brawler_id: i32, now: NaiveDateTime) -> AppResult<Option<MissionBanModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!("{BAN_SELECT} AND mb.mission_id = $2 AND mb.brawler_id = $3");
+        let ban = diesel::sql_query(sql)
+            .bind::<Timestamp, _>(now)
+            .bind::<Int4, _>(mission_id)
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<MissionBanModel>(&mut conn)
+            .optional()?;
+        Ok(ban)
+    }
+
+    async fn find_by_mission(&self, mission_id: i32, now: NaiveDateTime) -> AppResult<Vec<MissionBanModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!("{BAN_SELECT} AND mb.mission_id = $2 ORDER BY mb.created_at DESC, mb.id DESC");
+        let bans = diesel::sql_query(sql)
+            .bind::<Timestamp, _>(now)
+            .bind::<Int4, _>(mission_id)
+            .load::<MissionBanModel>(&mut conn)?;
+        Ok(bans)
+    }
+
+    async fn lift(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted = diesel::delete(mission_bans::table)
+            .filter(mission_bans::mission_id.eq(mission_id))
+            .filter(mission_bans::brawler_id.eq(brawler_id))
+            .execute(&mut conn)?;
+        if deleted == 0 {
+            return Err(AppError::NotFound("Ban not found".to_string()));
+        }
+        Ok(())
+    }
+}
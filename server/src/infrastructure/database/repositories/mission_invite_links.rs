@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::*,
+    sql_types::{Int4, Timestamp, Varchar},
+};
+
+use crate::{
+    domain::{
+        entities::mission_invite_links::AddMissionInviteLinkEntity,
+        repositories::mission_invite_links::MissionInviteLinkRepository,
+        value_objects::invite_link_model::{InviteLinkModel, InviteLinkPreviewModel, InviteLinkUseModel},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{mission_invite_link_uses, mission_invite_links},
+    },
+};
+
+/// Callers add their own `WHERE` on `l`.
+const LINK_SELECT: &str = r#"
+SELECT
+    l.id,
+    l.mission_id,
+    l.created_by,
+    l.role_slot_id,
+    rs.label AS role_label,
+    l.max_uses,
+    l.use_count,
+    l.expires_at,
+    l.revoked_at,
+    l.created_at
+FROM mission_invite_links l
+LEFT JOIN mission_role_slots rs ON rs.id = l.role_slot_id
+"#;
+
+pub struct MissionInviteLinkPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionInviteLinkPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionInviteLinkRepository for MissionInviteLinkPostgres {
+    async fn create(&self, link: AddMissionInviteLinkEntity) -> AppResult<InviteLinkModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let id = diesel::insert_into(mission_invite_links::table)
+            .values(&link)
+            .returning(mission_invite_links::id)
+            .get_result::<i32>(&mut conn)?;
+
+        let sql = format!("{LINK_SELECT} WHERE l.id = $1");
+        let link = diesel::sql_query(sql)
+            .bind::<Int4, _>(id)
+            .get_result::<InviteLinkModel>(&mut conn)?;
+        Ok(link)
+    }
+
+    async fn find_by_token_hash(&self, token_hash: String) -> AppResult<Option<InviteLinkModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!("{LINK_SELECT} WHERE l.token_hash = $1");
+        let link = diesel::sql_query(sql)
+            .bind::<Varchar, _>(token_hash)
+            .get_result::<InviteLinkModel>(&mut conn)
+            .optional()?;
+        Ok(link)
+    }
+
+    async fn find_by_mission(&self, mission_id: i32) -> AppResult<Vec<InviteLinkModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!("{LINK_SELECT} WHERE l.mission_id = $1 ORDER BY l.created_at DESC, l.id DESC");
+        let links = diesel::sql_query(sql)
+            .bind::<Int4, _>(mission_id)
+            .load::<InviteLinkModel>(&mut conn)?;
+        Ok(links)
+    }
+
+    async fn find_uses(&self, link_id: i32) -> AppResult<Vec<InviteLinkUseModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let uses = diesel::sql_query(
+            r#"
+SELECT u.brawler_id, b.display_name AS brawler_display_name, u.used_at
+FROM mission_invite_link_uses u
+INNER JOIN brawlers b ON b.id = u.brawler_id
+WHERE u.link_id = $1
+ORDER BY u.used_at DESC, u.id DESC
+"#,
+        )
+        .bind::<Int4, _>(link_id)
+        .load::<InviteLinkUseModel>(&mut conn)?;
+        Ok(uses)
+    }
+
+    async fn preview(&self, token_hash: String, now: NaiveDateTime) -> AppResult<Option<InviteLinkPreviewModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let preview = diesel::sql_query(
+            r#"
+SELECT
+    m.id AS mission_id,
+    m.name AS mission_name,
+    m.status AS mission_status,
+    COALESCE(b.display_name, '') AS chief_display_name,
+    (SELECT COUNT(*) FROM crew_memberships cm WHERE cm.mission_id = m.id) AS crew_count,
+    m.max_crew,
+    rs.label AS role_label,
+    l.expires_at
+FROM mission_invite_links l
+INNER JOIN missions m ON m.id = l.mission_id
+LEFT JOIN brawlers b ON b.id = m.chief_id
+LEFT JOIN mission_role_slots rs ON rs.id = l.role_slot_id
+WHERE l.token_hash = $1
+  AND m.deleted_at IS NULL
+  AND l.revoked_at IS NULL
+  AND (l.expires_at IS NULL OR l.expires_at > $2)
+  AND (l.max_uses IS NULL OR l.use_count < l.max_uses)
+"#,
+        )
+        .bind::<Varchar, _>(token_hash)
+        .bind::<Timestamp, _>(now)
+        .get_result::<InviteLinkPreviewModel>(&mut conn)
+        .optional()?;
+        Ok(preview)
+    }
+
+    async fn revoke(&self, link_id: i32, mission_id: i32, now: NaiveDateTime) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::sql_query(
+            "UPDATE mission_invite_links SET revoked_at = COALESCE(revoked_at, $3) WHERE id = $1 AND mission_id = $2",
+        )
+        .bind::<Int4, _>(link_id)
+        .bind::<Int4, _>(mission_id)
+        .bind::<Timestamp, _>(now)
+        .execute(&mut conn)?;
+        if updated == 0 {
+            return Err(AppError::NotFound("Invite link not found".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn record_use(&self, link_id: i32, brawler_id: i32, now: NaiveDateTime) -> AppResult<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            // Row lock so two brawlers racing for the last use cannot both get it.
+            let (max_uses, use_count, expires_at, revoked_at) = mission_invite_links::table
+                .filter(mission_invite_links::id.eq(link_id))
+                .select((
+                    mission_invite_links::max_uses,
+                    mission_invite_links::use_count,
+                    mission_invite_links::expires_at,
+                    mission_invite_links::revoked_at,
+                ))
+                .for_update()
+                .first::<(Option<i32>, i32, Option<NaiveDateTime>, Option<NaiveDateTime>)>(conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound("Invite link not found".to_string()))?;
+
+            if revoked_at.is_some() || matches!(expires_at, Some(at) if at <= now) {
+                return Err(AppError::Conflict("Invite link is no longer valid".to_string()));
+            }
+            if matches!(max_uses, Some(max_uses) if use_count >= max_uses) {
+                return Err(AppError::Conflict("Invite link has no uses left".to_string()));
+            }
+
+            let inserted = diesel::insert_into(mission_invite_link_uses::table)
+                .values((
+                    mission_invite_link_uses::link_id.eq(link_id),
+                    mission_invite_link_uses::brawler_id.eq(brawler_id),
+                    mission_invite_link_uses::used_at.eq(now),
+                ))
+                .on_conflict((mission_invite_link_uses::link_id, mission_invite_link_uses::brawler_id))
+                .do_nothing()
+                .execute(conn)?;
+            if inserted == 0 {
+                return Ok(false);
+            }
+
+            diesel::update(mission_invite_links::table)
+                .filter(mission_invite_links::id.eq(link_id))
+                .set(mission_invite_links::use_count.eq(mission_invite_links::use_count + 1))
+                .execute(conn)?;
+            Ok(true)
+        })
+    }
+
+    async fn release_use(&self, link_id: i32, brawler_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let deleted = diesel::delete(mission_invite_link_uses::table)
+                .filter(mission_invite_link_uses::link_id.eq(link_id))
+                .filter(mission_invite_link_uses::brawler_id.eq(brawler_id))
+                .execute(conn)?;
+            if deleted > 0 {
+                diesel::update(mission_invite_links::table)
+                    .filter(mission_invite_links::id.eq(link_id))
+                    .set(mission_invite_links::use_count.eq(mission_invite_links::use_count - 1))
+                    .execute(conn)?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -1,18 +1,20 @@
 use crate::{
     domain::{
-        entities::mission_invites::{MissionInvite, NewMissionInvite, MissionInviteDetails},
+        entities::mission_invites::{MissionInvite, NewMissionInvite, MissionInviteDetails, SentMissionInvite},
         repositories::mission_invites::MissionInviteRepository,
+        value_objects::invite_model::{BulkInviteOutcome, BulkInviteResultModel, InviteRecipient, InviteStatus},
     },
     infrastructure::database::{
         postgresql_connection::PgPoolSquad,
-        schema::{mission_invites, missions, brawlers},
+        schema::{crew_memberships, mission_bans, mission_invites, missions, brawlers},
     },
 };
-use anyhow::Result;
+use crate::domain::errors::{AppError, AppResult};
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use diesel::{
-    dsl::{insert_into, update},
-    ExpressionMethods, RunQueryDsl, QueryDsl, OptionalExtension, JoinOnDsl
+    dsl::{exists, insert_into, update},
+    BoolExpressionMethods, Connection, ExpressionMethods, RunQueryDsl, QueryDsl, OptionalExtension, JoinOnDsl
 };
 use std::sync::Arc;
 
@@ -28,7 +30,7 @@ impl MissionInvitePostgres {
 
 #[async_trait]
 impl MissionInviteRepository for MissionInvitePostgres {
-    async fn create(&self, invite: NewMissionInvite) -> Result<MissionInvite> {
+    async fn create(&self, invite: NewMissionInvite) -> AppResult<MissionInvite> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let result = insert_into(mission_invites::table)
             .values(invite)
@@ -36,7 +38,7 @@ impl MissionInviteRepository for MissionInvitePostgres {
         Ok(result)
     }
 
-    async fn find_by_id(&self, id: i32) -> Result<Option<MissionInvite>> {
+    async fn find_by_id(&self, id: i32) -> AppResult<Option<MissionInvite>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let result = mission_invites::table
             .find(id)
@@ -45,60 +47,186 @@ impl MissionInviteRepository for MissionInvitePostgres {
         Ok(result)
     }
 
-    async fn find_invites_by_user(&self, user_id: i32) -> Result<Vec<MissionInvite>> {
+    async fn find_invites_by_user(&self, user_id: i32) -> AppResult<Vec<MissionInvite>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let results = mission_invites::table
             .filter(mission_invites::user_id.eq(user_id))
-            .filter(mission_invites::status.eq("pending"))
+            .filter(mission_invites::status.eq(InviteStatus::Pending.to_string()))
             .load::<MissionInvite>(&mut conn)?;
         Ok(results)
     }
 
-    async fn find_invites_details_by_user(&self, user_id: i32) -> Result<Vec<MissionInviteDetails>> {
+    async fn find_invites_details_by_user(&self, user_id: i32, now: NaiveDateTime) -> AppResult<Vec<MissionInviteDetails>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let results = mission_invites::table
             .inner_join(missions::table.inner_join(brawlers::table))
             .filter(mission_invites::user_id.eq(user_id))
-            .filter(mission_invites::status.eq("pending"))
+            .filter(mission_invites::status.eq(InviteStatus::Pending.to_string()))
+            .filter(mission_invites::expires_at.gt(now))
+            .filter(missions::deleted_at.is_null())
+            .order(mission_invites::created_at.desc())
             .select((
                 mission_invites::id,
                 mission_invites::mission_id,
                 missions::name,
                 brawlers::display_name,
                 mission_invites::status,
+                mission_invites::expires_at,
             ))
             .load::<MissionInviteDetails>(&mut conn)?;
         
         Ok(results)
     }
 
-    async fn find_invites_by_mission(&self, mission_id: i32) -> Result<Vec<MissionInvite>> {
+    async fn find_invites_by_mission(&self, mission_id: i32) -> AppResult<Vec<SentMissionInvite>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let results = mission_invites::table
+            .inner_join(brawlers::table.on(brawlers::id.eq(mission_invites::user_id)))
             .filter(mission_invites::mission_id.eq(mission_id))
-            .load::<MissionInvite>(&mut conn)?;
+            .order((mission_invites::created_at.desc(), mission_invites::id.desc()))
+            .select((
+                mission_invites::id,
+                mission_invites::user_id,
+                brawlers::display_name,
+                mission_invites::invited_by,
+                mission_invites::status,
+                mission_invites::expires_at,
+                mission_invites::created_at,
+            ))
+            .load::<SentMissionInvite>(&mut conn)?;
         Ok(results)
     }
 
-    async fn update_status(&self, id: i32, status: String) -> Result<MissionInvite> {
+    async fn update_status(&self, id: i32, status: InviteStatus) -> AppResult<MissionInvite> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let result = update(mission_invites::table)
             .filter(mission_invites::id.eq(id))
-            .set(mission_invites::status.eq(status))
+            .set(mission_invites::status.eq(status.to_string()))
             .get_result::<MissionInvite>(&mut conn)?;
         Ok(result)
     }
 
-    async fn check_exists(&self, mission_id: i32, user_id: i32) -> Result<bool> {
+    async fn check_exists(&self, mission_id: i32, user_id: i32, now: NaiveDateTime) -> AppResult<bool> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let exists = diesel::select(diesel::dsl::exists(
             mission_invites::table
                 .filter(mission_invites::mission_id.eq(mission_id))
                 .filter(mission_invites::user_id.eq(user_id))
-                .filter(mission_invites::status.eq("pending")),
+                .filter(mission_invites::status.eq(InviteStatus::Pending.to_string()))
+                .filter(mission_invites::expires_at.gt(now)),
         ))
         .get_result(&mut conn)?;
         
         Ok(exists)
     }
+
+    async fn create_many(
+        &self,
+        mission_id: i32,
+        invited_by: i32,
+        recipients: Vec<InviteRecipient>,
+        expires_at: NaiveDateTime,
+        now: NaiveDateTime,
+    ) -> AppResult<Vec<BulkInviteResultModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            // Locking the mission keeps two batches from inviting the same brawler twice.
+            let chief_id = missions::table
+                .find(mission_id)
+                .select(missions::chief_id)
+                .for_update()
+                .first::<i32>(conn)?;
+
+            let mut results = Vec::with_capacity(recipients.len());
+            for recipient in recipients {
+                let brawler = match &recipient {
+                    InviteRecipient::Id(id) => brawlers::table
+                        .find(*id)
+                        .select((brawlers::id, brawlers::username))
+                        .first::<(i32, String)>(conn)
+                        .optional()?,
+                    InviteRecipient::Username(username) => brawlers::table
+                        .filter(brawlers::username.eq(username))
+                        .select((brawlers::id, brawlers::username))
+                        .first::<(i32, String)>(conn)
+                        .optional()?,
+                };
+                let Some((user_id, username)) = brawler else {
+                    results.push(BulkInviteResultModel {
+                        recipient,
+                        outcome: BulkInviteOutcome::NotFound,
+                        user_id: None,
+                        username: None,
+                        invite_id: None,
+                    });
+                    continue;
+                };
+
+                let is_member = user_id == chief_id
+                    || diesel::select(exists(
+                        crew_memberships::table
+                            .filter(crew_memberships::mission_id.eq(mission_id))
+                            .filter(crew_memberships::brawler_id.eq(user_id)),
+                    ))
+                    .get_result::<bool>(conn)?;
+                let is_banned = !is_member
+                    && diesel::select(exists(
+                        mission_bans::table
+                            .filter(mission_bans::mission_id.eq(mission_id))
+                            .filter(mission_bans::brawler_id.eq(user_id))
+                            .filter(mission_bans::expires_at.is_null().or(mission_bans::expires_at.gt(now))),
+                    ))
+                    .get_result::<bool>(conn)?;
+                let is_invited = !is_member
+                    && !is_banned
+                    && diesel::select(exists(
+                        mission_invites::table
+                            .filter(mission_invites::mission_id.eq(mission_id))
+                            .filter(mission_invites::user_id.eq(user_id))
+                            .filter(mission_invites::status.eq(InviteStatus::Pending.to_string()))
+                            .filter(mission_invites::expires_at.gt(now)),
+                    ))
+                    .get_result::<bool>(conn)?;
+
+                let (outcome, invite_id) = if is_member {
+                    (BulkInviteOutcome::AlreadyMember, None)
+                } else if is_banned {
+                    (BulkInviteOutcome::Banned, None)
+                } else if is_invited {
+                    (BulkInviteOutcome::AlreadyInvited, None)
+                } else {
+                    let invite = insert_into(mission_invites::table)
+                        .values(NewMissionInvite {
+                            mission_id,
+                            user_id,
+                            status: InviteStatus::Pending.to_string(),
+                            invited_by: Some(invited_by),
+                            expires_at,
+                        })
+                        .get_result::<MissionInvite>(conn)?;
+                    (BulkInviteOutcome::Invited, Some(invite.id))
+                };
+
+                results.push(BulkInviteResultModel {
+                    recipient,
+                    outcome,
+                    user_id: Some(user_id),
+                    username: Some(username),
+                    invite_id,
+                });
+            }
+            Ok(results)
+        })
+    }
+
+    async fn expire_invites(&self, now: NaiveDateTime) -> AppResult<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let expired = update(mission_invites::table)
+            .filter(mission_invites::status.eq(InviteStatus::Pending.to_string()))
+            .filter(mission_invites::expires_at.le(now))
+            .set(mission_invites::status.eq(InviteStatus::Expired.to_string()))
+            .execute(&mut conn)?;
+        Ok(expired)
+    }
 }
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use diesel::{
+    dsl,
+    prelude::*,
+    result::{DatabaseErrorKind, Error as DieselError},
+    sql_types::Int4,
+};
+
+use crate::{
+    domain::{
+        entities::mission_join_requests::AddMissionJoinRequestEntity,
+        repositories::mission_join_requests::MissionJoinRequestRepository,
+        value_objects::join_request_model::{JoinRequestModel, JoinRequestStatus},
+    },
+    infrastructure::database::{
+        errors::not_found,
+        postgresql_connection::PgPoolSquad,
+        schema::mission_join_requests,
+    },
+};
+
+const JOIN_REQUEST_SELECT: &str = r#"
+SELECT
+    r.id,
+    r.mission_id,
+    r.brawler_id,
+    b.display_name AS brawler_display_name,
+    COALESCE(b.avatar_url, '') AS brawler_avatar_url,
+    r.role_slot_id,
+    rs.label AS role,
+    r.status,
+    r.created_at
+FROM mission_join_requests r
+INNER JOIN brawlers b ON b.id = r.brawler_id
+LEFT JOIN mission_role_slots rs ON rs.id = r.role_slot_id
+"#;
+
+pub struct MissionJoinRequestPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionJoinRequestPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionJoinRequestRepository for MissionJoinRequestPostgres {
+    async fn create(&self, request: AddMissionJoinRequestEntity) -> AppResult<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::insert_into(mission_join_requests::table)
+            .values(&request)
+            .returning(mission_join_requests::id)
+            .get_result::<i32>(&mut conn)
+            .map_err(|e| match e {
+                DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                    AppError::Conflict("Join request already pending".to_string())
+                }
+                e => e.into(),
+            })
+    }
+
+    async fn find_one(&self, request_id: i32, mission_id: i32) -> AppResult<JoinRequestModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!("{JOIN_REQUEST_SELECT} WHERE r.id = $1 AND r.mission_id = $2");
+        diesel::sql_query(sql)
+            .bind::<Int4, _>(request_id)
+            .bind::<Int4, _>(mission_id)
+            .get_result::<JoinRequestModel>(&mut conn)
+            .map_err(not_found("Join request"))
+    }
+
+    async fn find_pending(&self, mission_id: i32) -> AppResult<Vec<JoinRequestModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!(
+            "{JOIN_REQUEST_SELECT} WHERE r.mission_id = $1 AND r.status = 'pending' ORDER BY r.created_at ASC, r.id ASC"
+        );
+        let requests = diesel::sql_query(sql)
+            .bind::<Int4, _>(mission_id)
+            .load::<JoinRequestModel>(&mut conn)?;
+        Ok(requests)
+    }
+
+    async fn find_pending_by_brawler(&self, mission_id: i32, brawler_id: i32) -> AppResult<Option<JoinRequestModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!("{JOIN_REQUEST_SELECT} WHERE r.mission_id = $1 AND r.brawler_id = $2 AND r.status = 'pending'");
+        let request = diesel::sql_query(sql)
+            .bind::<Int4, _>(mission_id)
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<JoinRequestModel>(&mut conn)
+            .optional()?;
+        Ok(request)
+    }
+
+    async fn decide(&self, request_id: i32, status: JoinRequestStatus, decided_by: Option<i32>) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::update(mission_join_requests::table)
+            .filter(mission_join_requests::id.eq(request_id))
+            .filter(mission_join_requests::status.eq(JoinRequestStatus::Pending.to_string()))
+            .set((
+                mission_join_requests::status.eq(status.to_string()),
+                mission_join_requests::decided_by.eq(decided_by),
+                mission_join_requests::decided_at.eq(dsl::now.nullable()),
+            ))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(AppError::Conflict("Join request is no longer pending".to_string()));
+        }
+        Ok(())
+    }
+}
@@ -8,16 +8,17 @@ use crate::{
         cloudinary::{UploadImageOptions},
         database::{
             postgresql_connection::PgPoolSquad,
-            schema::{crew_memberships, missions},
+            schema::{crew_memberships, mission_invites, mission_messages, missions},
         },
     },
     domain::value_objects::{base64_img::Base64Img, uploaded_img::UploadedImg}
 };
-use anyhow::{Ok, Result};
+use crate::domain::errors::{AppError, AppResult};
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use diesel::{
-    dsl::{delete, insert_into, now, update},
-    Connection, ExpressionMethods, RunQueryDsl,
+    dsl::{delete, insert_into, now, update, IntervalDsl},
+    Connection, ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl,
 };
 use std::sync::Arc;
 
@@ -33,7 +34,7 @@ impl MissionManagementPostgres {
 
 #[async_trait]
 impl MissionManagementRepository for MissionManagementPostgres {
-    async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32> {
+    async fn add(&self, add_mission_entity: AddMissionEntity) -> AppResult<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let result = insert_into(missions::table)
             .values(add_mission_entity)
@@ -42,7 +43,7 @@ impl MissionManagementRepository for MissionManagementPostgres {
         Ok(result)
     }
 
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
+    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> AppResult<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let result = update(missions::table)
             .filter(missions::id.eq(mission_id))
@@ -58,7 +59,7 @@ impl MissionManagementRepository for MissionManagementPostgres {
 
 
 
-    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+    async fn remove(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         update(missions::table)
@@ -73,13 +74,90 @@ impl MissionManagementRepository for MissionManagementPostgres {
         Ok(())
     }
 
+    async fn force_remove(&self, mission_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::deleted_at.is_null())
+            .set(missions::deleted_at.eq(now))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(AppError::NotFound("Mission not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn restore(&self, mission_id: i32, chief_id: i32, retention_days: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let restored = update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::chief_id.eq(chief_id))
+            .filter(missions::deleted_at.gt((now - retention_days.days()).nullable()))
+            .set(missions::deleted_at.eq(None::<NaiveDateTime>))
+            .execute(&mut conn)?;
+
+        if restored == 0 {
+            return Err(AppError::NotFound("Deleted mission not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn force_restore(&self, mission_id: i32, retention_days: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let restored = update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::deleted_at.gt((now - retention_days.days()).nullable()))
+            .set(missions::deleted_at.eq(None::<NaiveDateTime>))
+            .execute(&mut conn)?;
+
+        if restored == 0 {
+            return Err(AppError::NotFound("Deleted mission not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn purge_deleted(&self, retention_days: i32) -> AppResult<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let purged = conn.transaction::<usize, AppError, _>(|conn| {
+            let expired = missions::table
+                .filter(missions::deleted_at.le((now - retention_days.days()).nullable()))
+                .select(missions::id)
+                .load::<i32>(conn)?;
+
+            if expired.is_empty() {
+                return Ok(0);
+            }
+
+            delete(mission_messages::table.filter(mission_messages::mission_id.eq_any(&expired)))
+                .execute(conn)?;
+            delete(mission_invites::table.filter(mission_invites::mission_id.eq_any(&expired)))
+                .execute(conn)?;
+            delete(crew_memberships::table.filter(crew_memberships::mission_id.eq_any(&expired)))
+                .execute(conn)?;
+            let purged = delete(missions::table.filter(missions::id.eq_any(&expired)))
+                .execute(conn)?;
+
+            Ok(purged)
+        })?;
+
+        Ok(purged)
+    }
+
     async fn upload_image(
         &self,
         mission_id: i32,
         chief_id: i32,
         base64img: Base64Img,
         opt: UploadImageOptions,
-    ) -> Result<UploadedImg> {
+    ) -> AppResult<UploadedImg> {
         let uploaded_img = crate::infrastructure::cloudinary::upload(base64img, opt).await?;
 
         let mut conn = Arc::clone(&self.db_pool).get()?;
@@ -87,6 +165,7 @@ impl MissionManagementRepository for MissionManagementPostgres {
         update(missions::table)
             .filter(missions::id.eq(mission_id))
             .filter(missions::chief_id.eq(chief_id))
+            .filter(missions::deleted_at.is_null())
             .set((
                 missions::image_url.eq(uploaded_img.url.clone()),
                 missions::image_public_id.eq(uploaded_img.public_id.clone()),
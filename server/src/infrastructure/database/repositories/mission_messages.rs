@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use anyhow::Result;
+use crate::domain::errors::AppResult;
 use async_trait::async_trait;
 use diesel::prelude::*;
 
@@ -11,7 +11,7 @@ use crate::{
     },
     infrastructure::database::{
         postgresql_connection::PgPoolSquad,
-        schema::{brawlers, mission_messages},
+        schema::mission_messages,
     },
 };
 
@@ -27,7 +27,7 @@ impl MissionMessagePostgres {
 
 #[async_trait]
 impl MissionMessageRepository for MissionMessagePostgres {
-    async fn create(&self, entity: NewMissionMessageEntity) -> Result<MissionMessageEntity> {
+    async fn create(&self, entity: NewMissionMessageEntity) -> AppResult<MissionMessageEntity> {
         use crate::domain::entities::mission_messages::MissionMessageEntity;
 
         let mut conn = Arc::clone(&self.db_pool).get()?;
@@ -39,7 +39,7 @@ impl MissionMessageRepository for MissionMessagePostgres {
         Ok(result)
     }
 
-    async fn get_by_mission_id(&self, mission_id_val: i32) -> Result<Vec<MissionMessageModel>> {
+    async fn get_by_mission_id(&self, mission_id_val: i32) -> AppResult<Vec<MissionMessageModel>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
         
         // Using sql_query to join with brawlers and select into MissionMessageModel
@@ -54,8 +54,10 @@ impl MissionMessageRepository for MissionMessagePostgres {
                 mm.type as type_,
                 mm.created_at
             FROM mission_messages mm
+            INNER JOIN missions m ON m.id = mm.mission_id
             LEFT JOIN brawlers b ON mm.user_id = b.id
             WHERE mm.mission_id = $1
+              AND m.deleted_at IS NULL
             ORDER BY mm.created_at ASC
             LIMIT 100
         "#;
@@ -0,0 +1,179 @@
+use std::{collections::HashSet, sync::Arc};
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{dsl, prelude::*};
+
+use crate::{
+    domain::{
+        entities::mission_objectives::{
+            AddMissionObjectiveEntity, EditMissionObjectiveEntity, MissionObjectiveEntity,
+        },
+        repositories::mission_objectives::MissionObjectiveRepository,
+        value_objects::mission_objective_model::MissionObjectiveModel,
+    },
+    infrastructure::database::{
+        errors::not_found,
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, mission_objectives},
+    },
+};
+
+pub struct MissionObjectivePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionObjectivePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionObjectiveRepository for MissionObjectivePostgres {
+    async fn find_by_mission(&self, mission_id: i32) -> AppResult<Vec<MissionObjectiveModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows = mission_objectives::table
+            .left_join(brawlers::table)
+            .filter(mission_objectives::mission_id.eq(mission_id))
+            .order((mission_objectives::position.asc(), mission_objectives::id.asc()))
+            .select((MissionObjectiveEntity::as_select(), brawlers::display_name.nullable()))
+            .load::<(MissionObjectiveEntity, Option<String>)>(&mut conn)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(objective, display_name)| MissionObjectiveModel::from_entity(objective, display_name))
+            .collect())
+    }
+
+    async fn find_one(&self, objective_id: i32, mission_id: i32) -> AppResult<MissionObjectiveModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let (objective, display_name) = mission_objectives::table
+            .left_join(brawlers::table)
+            .filter(mission_objectives::id.eq(objective_id))
+            .filter(mission_objectives::mission_id.eq(mission_id))
+            .select((MissionObjectiveEntity::as_select(), brawlers::display_name.nullable()))
+            .first::<(MissionObjectiveEntity, Option<String>)>(&mut conn)
+            .map_err(not_found("Objective"))?;
+
+        Ok(MissionObjectiveModel::from_entity(objective, display_name))
+    }
+
+    async fn create(&self, objective: AddMissionObjectiveEntity) -> AppResult<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let objective_id = conn.transaction::<_, AppError, _>(|conn| {
+            let last = mission_objectives::table
+                .filter(mission_objectives::mission_id.eq(objective.mission_id))
+                .select(dsl::max(mission_objectives::position))
+                .first::<Option<i32>>(conn)?;
+
+            let objective_id = diesel::insert_into(mission_objectives::table)
+                .values((&objective, mission_objectives::position.eq(last.map_or(0, |p| p + 1))))
+                .returning(mission_objectives::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(objective_id)
+        })?;
+
+        Ok(objective_id)
+    }
+
+    async fn edit(&self, objective_id: i32, mission_id: i32, objective: EditMissionObjectiveEntity) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::update(mission_objectives::table)
+            .filter(mission_objectives::id.eq(objective_id))
+            .filter(mission_objectives::mission_id.eq(mission_id))
+            .set(&objective)
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(AppError::NotFound("Objective not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn remove(&self, objective_id: i32, mission_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let position = diesel::delete(mission_objectives::table)
+                .filter(mission_objectives::id.eq(objective_id))
+                .filter(mission_objectives::mission_id.eq(mission_id))
+                .returning(mission_objectives::position)
+                .get_result::<i32>(conn)
+                .map_err(not_found("Objective"))?;
+
+            diesel::update(mission_objectives::table)
+                .filter(mission_objectives::mission_id.eq(mission_id))
+                .filter(mission_objectives::position.gt(position))
+                .set(mission_objectives::position.eq(mission_objectives::position - 1))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    async fn reorder(&self, mission_id: i32, objective_ids: Vec<i32>) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let existing = mission_objectives::table
+                .filter(mission_objectives::mission_id.eq(mission_id))
+                .select(mission_objectives::id)
+                .for_update()
+                .load::<i32>(conn)?;
+
+            let listed = objective_ids.iter().copied().collect::<HashSet<_>>();
+            if existing.len() != objective_ids.len() || existing.iter().any(|id| !listed.contains(id)) {
+                return Err(AppError::validation(
+                    "objective_ids",
+                    "must list every objective of the mission exactly once",
+                ));
+            }
+
+            for (position, objective_id) in objective_ids.iter().enumerate() {
+                diesel::update(mission_objectives::table)
+                    .filter(mission_objectives::id.eq(objective_id))
+                    .set(mission_objectives::position.eq(position as i32))
+                    .execute(conn)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn set_completed(&self, objective_id: i32, mission_id: i32, completed_by: Option<i32>) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let target = mission_objectives::table
+            .filter(mission_objectives::id.eq(objective_id))
+            .filter(mission_objectives::mission_id.eq(mission_id));
+
+        let updated = match completed_by {
+            Some(brawler_id) => diesel::update(target)
+                .set((
+                    mission_objectives::completed_by.eq(brawler_id),
+                    mission_objectives::completed_at.eq(dsl::now.nullable()),
+                ))
+                .execute(&mut conn)?,
+            None => diesel::update(target)
+                .set((
+                    mission_objectives::completed_by.eq(None::<i32>),
+                    mission_objectives::completed_at.eq(None::<NaiveDateTime>),
+                ))
+                .execute(&mut conn)?,
+        };
+
+        if updated == 0 {
+            return Err(AppError::NotFound("Objective not found".to_string()));
+        }
+
+        Ok(())
+    }
+}
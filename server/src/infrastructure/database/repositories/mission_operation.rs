@@ -1,15 +1,25 @@
 use std::sync::Arc;
 
-use anyhow::{Context, Ok, Result};
+use crate::domain::errors::AppResult;
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::update};
+use chrono::NaiveDateTime;
+use diesel::{
+    Column, ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{self, update},
+    sql_types::{Nullable, Timestamp},
+};
 
 use crate::{
     domain::{
+        entities::missions::MissionEntity,
         repositories::mission_operation::MissionOperationRepository,
         value_objects::mission_statuses::MissionStatuses,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
+    infrastructure::database::{
+        errors::not_found,
+        postgresql_connection::PgPoolSquad,
+        schema::{mission_objectives, missions},
+    },
 };
 pub struct MissionOperationPostgres {
     db_pool: Arc<PgPoolSquad>,
@@ -25,30 +35,54 @@ impl MissionOperationPostgres {
         mission_id: i32,
         chief_id: i32,
         status: MissionStatuses,
-    ) -> Result<i32> {
+    ) -> AppResult<i32> {
         let db_pool = Arc::clone(&self.db_pool);
         let status_string = status.to_string();
-        let id = tokio::task::spawn_blocking(move || -> Result<i32> {
-            let mut conn = db_pool.get().context("Failed to get DB connection")?;
+        // Restarting a failed mission reopens it, so only a finishing status keeps a timestamp.
+        let finished_at = matches!(status, MissionStatuses::Completed | MissionStatuses::Failed)
+            .then(|| chrono::Utc::now().naive_utc());
+        let id = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = db_pool.get()?;
 
             update(missions::table)
                 .filter(missions::id.eq(mission_id))
                 .filter(missions::chief_id.eq(chief_id))
                 .filter(missions::deleted_at.is_null())
-                .set((missions::status.eq(status_string),))
+                .set((missions::status.eq(status_string), missions::finished_at.eq(finished_at)))
                 .returning(missions::id)
                 .get_result::<i32>(&mut conn)
-                .context("Failed to execute mission update query")
+                .map_err(not_found("Mission"))
         })
         .await??;
 
         Ok(id)
     }
+
+    /// Stamps one of the scheduler's bookkeeping columns with the current time.
+    async fn set_marker<C>(&self, mission_id: i32, column: C) -> AppResult<()>
+    where
+        C: Column<Table = missions::table, SqlType = Nullable<Timestamp>> + ExpressionMethods + Send + 'static,
+    {
+        let db_pool = Arc::clone(&self.db_pool);
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = db_pool.get()?;
+
+            update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set(column.eq(dsl::now.nullable()))
+                .execute(&mut conn)?;
+
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl MissionOperationRepository for MissionOperationPostgres {
-    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> AppResult<i32> {
         let result = self
             .set_status(mission_id, chief_id, MissionStatuses::InProgress)
             .await?;
@@ -56,14 +90,14 @@ impl MissionOperationRepository for MissionOperationPostgres {
         Ok(result)
     }
 
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> AppResult<i32> {
         let result = self
             .set_status(mission_id, chief_id, MissionStatuses::Completed)
             .await?;
 
         let db_pool = Arc::clone(&self.db_pool);
-        tokio::task::spawn_blocking(move || -> Result<()> {
-            let mut conn = db_pool.get().context("Failed to get DB connection")?;
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = db_pool.get()?;
             use crate::infrastructure::database::schema::{brawlers, crew_memberships};
             use diesel::prelude::*;
 
@@ -92,11 +126,97 @@ impl MissionOperationRepository for MissionOperationPostgres {
         Ok(result)
     }
 
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> AppResult<i32> {
         let result = self
             .set_status(mission_id, chief_id, MissionStatuses::Failed)
             .await?;
 
         Ok(result)
     }
+
+    async fn find_due_reminders(&self, before: NaiveDateTime) -> AppResult<Vec<MissionEntity>> {
+        let db_pool = Arc::clone(&self.db_pool);
+        let missions = tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionEntity>> {
+            let mut conn = db_pool.get()?;
+
+            let missions = missions::table
+                .filter(missions::deleted_at.is_null())
+                .filter(missions::status.eq(MissionStatuses::Open.to_string()))
+                .filter(missions::scheduled_start_at.le(before))
+                .filter(missions::reminder_sent_at.is_null())
+                .select(MissionEntity::as_select())
+                .load::<MissionEntity>(&mut conn)?;
+
+            Ok(missions)
+        })
+        .await??;
+
+        Ok(missions)
+    }
+
+    async fn mark_reminder_sent(&self, mission_id: i32) -> AppResult<()> {
+        self.set_marker(mission_id, missions::reminder_sent_at).await
+    }
+
+    async fn find_due_starts(&self, now: NaiveDateTime) -> AppResult<Vec<MissionEntity>> {
+        let db_pool = Arc::clone(&self.db_pool);
+        let missions = tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionEntity>> {
+            let mut conn = db_pool.get()?;
+
+            let missions = missions::table
+                .filter(missions::deleted_at.is_null())
+                .filter(missions::status.eq(MissionStatuses::Open.to_string()))
+                .filter(missions::scheduled_start_at.le(now))
+                .filter(missions::auto_start_checked_at.is_null())
+                .select(MissionEntity::as_select())
+                .load::<MissionEntity>(&mut conn)?;
+
+            Ok(missions)
+        })
+        .await??;
+
+        Ok(missions)
+    }
+
+    async fn mark_auto_start_checked(&self, mission_id: i32) -> AppResult<()> {
+        self.set_marker(mission_id, missions::auto_start_checked_at).await
+    }
+
+    async fn find_overdue(&self, now: NaiveDateTime) -> AppResult<Vec<MissionEntity>> {
+        let db_pool = Arc::clone(&self.db_pool);
+        let missions = tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionEntity>> {
+            let mut conn = db_pool.get()?;
+
+            let missions = missions::table
+                .filter(missions::deleted_at.is_null())
+                .filter(missions::status.eq(MissionStatuses::InProgress.to_string()))
+                .filter(missions::deadline_at.le(now))
+                .select(MissionEntity::as_select())
+                .load::<MissionEntity>(&mut conn)?;
+
+            Ok(missions)
+        })
+        .await??;
+
+        Ok(missions)
+    }
+
+    async fn count_open_mandatory_objectives(&self, mission_id: i32) -> AppResult<i64> {
+        let db_pool = Arc::clone(&self.db_pool);
+        let count = tokio::task::spawn_blocking(move || -> AppResult<i64> {
+            let mut conn = db_pool.get()?;
+
+            let count = mission_objectives::table
+                .filter(mission_objectives::mission_id.eq(mission_id))
+                .filter(mission_objectives::is_mandatory.eq(true))
+                .filter(mission_objectives::completed_at.is_null())
+                .count()
+                .get_result::<i64>(&mut conn)?;
+
+            Ok(count)
+        })
+        .await??;
+
+        Ok(count)
+    }
 }
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use diesel::{
+    dsl::exists,
+    prelude::*,
+    sql_types::{Array, Double, Int4, Text},
+};
+
+use crate::{
+    domain::{
+        entities::mission_ratings::AddMissionRatingEntity,
+        repositories::mission_ratings::MissionRatingRepository,
+        value_objects::rating_model::{
+            MissionRatingModel, PeerRatingModel, REPUTATION_PRIOR_SCORE, REPUTATION_PRIOR_WEIGHT,
+            ReputationModel, TagCountModel,
+        },
+    },
+    infrastructure::database::{
+        errors::not_found,
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, crew_memberships, missions},
+    },
+};
+
+pub struct MissionRatingPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionRatingPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionRatingRepository for MissionRatingPostgres {
+    async fn rate(&self, rating: AddMissionRatingEntity) -> AppResult<MissionRatingModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, AppError, _>(|conn| {
+            let saved = diesel::sql_query(
+                r#"
+INSERT INTO mission_ratings (mission_id, rater_id, ratee_id, score, tags)
+VALUES ($1, $2, $3, $4, $5)
+ON CONFLICT (mission_id, rater_id, ratee_id)
+DO UPDATE SET score = EXCLUDED.score, tags = EXCLUDED.tags
+RETURNING id, mission_id, rater_id, ratee_id, score, tags, updated_at
+"#,
+            )
+            .bind::<Int4, _>(rating.mission_id)
+            .bind::<Int4, _>(rating.rater_id)
+            .bind::<Int4, _>(rating.ratee_id)
+            .bind::<Int4, _>(rating.score)
+            .bind::<Array<Text>, _>(&rating.tags)
+            .get_result::<MissionRatingModel>(conn)?;
+
+            // Bayesian average: the prior keeps newcomers near the middle until ratings pile up.
+            diesel::sql_query(
+                r#"
+UPDATE brawlers b
+SET reputation = (r.total + $2 * $3) / (r.count + $3),
+    rating_count = r.count
+FROM (
+    SELECT COALESCE(SUM(score), 0)::FLOAT8 AS total, COUNT(*)::INT4 AS count
+    FROM mission_ratings
+    WHERE ratee_id = $1
+) r
+WHERE b.id = $1
+"#,
+            )
+            .bind::<Int4, _>(saved.ratee_id)
+            .bind::<Double, _>(REPUTATION_PRIOR_SCORE)
+            .bind::<Double, _>(REPUTATION_PRIOR_WEIGHT as f64)
+            .execute(conn)?;
+
+            Ok(saved)
+        })
+    }
+
+    async fn find_peers(&self, mission_id: i32, rater_id: i32) -> AppResult<Vec<PeerRatingModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let peers = diesel::sql_query(
+            r#"
+SELECT
+    b.id AS brawler_id,
+    b.display_name,
+    COALESCE(b.avatar_url, '') AS avatar_url,
+    b.id = m.chief_id AS is_chief,
+    r.score,
+    COALESCE(r.tags, '{}') AS tags
+FROM missions m
+INNER JOIN brawlers b ON b.id = m.chief_id
+    OR b.id IN (
+        SELECT cm.brawler_id FROM crew_memberships cm
+        WHERE cm.mission_id = m.id AND cm.joined_at <= m.finished_at
+    )
+LEFT JOIN mission_ratings r ON r.mission_id = m.id AND r.rater_id = $2 AND r.ratee_id = b.id
+WHERE m.id = $1 AND b.id <> $2
+ORDER BY is_chief DESC, b.display_name ASC, b.id ASC
+"#,
+        )
+        .bind::<Int4, _>(mission_id)
+        .bind::<Int4, _>(rater_id)
+        .load::<PeerRatingModel>(&mut conn)?;
+
+        Ok(peers)
+    }
+
+    async fn took_part(&self, mission_id: i32, brawler_id: i32) -> AppResult<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Brawlers who joined after the finish had no part in how it went.
+        let took_part = missions::table
+            .filter(missions::id.eq(mission_id))
+            .filter(
+                missions::chief_id.eq(brawler_id).or(exists(
+                    crew_memberships::table
+                        .filter(crew_memberships::mission_id.eq(missions::id))
+                        .filter(crew_memberships::brawler_id.eq(brawler_id))
+                        .filter(crew_memberships::joined_at.nullable().le(missions::finished_at)),
+                )),
+            )
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(took_part > 0)
+    }
+
+    async fn reputation(&self, brawler_id: i32) -> AppResult<ReputationModel> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let (reputation, rating_count) = brawlers::table
+            .filter(brawlers::id.eq(brawler_id))
+            .select((brawlers::reputation, brawlers::rating_count))
+            .first::<(f64, i32)>(&mut conn)
+            .map_err(not_found("Brawler"))?;
+
+        let tags = diesel::sql_query(
+            r#"
+SELECT tag, COUNT(*) AS count
+FROM mission_ratings, unnest(tags) AS tag
+WHERE ratee_id = $1
+GROUP BY tag
+ORDER BY count DESC, tag ASC
+"#,
+        )
+        .bind::<Int4, _>(brawler_id)
+        .load::<TagCountModel>(&mut conn)?;
+
+        Ok(ReputationModel {
+            brawler_id,
+            reputation,
+            rating_count,
+            tags,
+        })
+    }
+}
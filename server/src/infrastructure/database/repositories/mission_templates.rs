@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::{
+            mission_templates::{AddMissionTemplateEntity, EditMissionTemplateEntity, MissionTemplateEntity},
+            missions::AddMissionEntity,
+        },
+        repositories::mission_templates::MissionTemplateRepository,
+    },
+    infrastructure::database::{
+        errors::not_found,
+        postgresql_connection::PgPoolSquad,
+        schema::{crew_memberships, mission_templates, missions},
+    },
+};
+
+pub struct MissionTemplatePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionTemplatePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionTemplateRepository for MissionTemplatePostgres {
+    async fn create(&self, template: AddMissionTemplateEntity) -> AppResult<MissionTemplateEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(mission_templates::table)
+            .values(&template)
+            .returning(MissionTemplateEntity::as_returning())
+            .get_result::<MissionTemplateEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_by_owner(&self, owner_id: i32) -> AppResult<Vec<MissionTemplateEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = mission_templates::table
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .order(mission_templates::created_at.desc())
+            .select(MissionTemplateEntity::as_select())
+            .load::<MissionTemplateEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn find_owned(&self, template_id: i32, owner_id: i32) -> AppResult<MissionTemplateEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mission_templates::table
+            .filter(mission_templates::id.eq(template_id))
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .select(MissionTemplateEntity::as_select())
+            .first::<MissionTemplateEntity>(&mut conn)
+            .map_err(not_found("Mission template"))?;
+
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        template_id: i32,
+        owner_id: i32,
+        template: EditMissionTemplateEntity,
+    ) -> AppResult<MissionTemplateEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(mission_templates::table)
+            .filter(mission_templates::id.eq(template_id))
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .set(&template)
+            .returning(MissionTemplateEntity::as_returning())
+            .get_result::<MissionTemplateEntity>(&mut conn)
+            .map_err(not_found("Mission template"))?;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, template_id: i32, owner_id: i32) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted = diesel::delete(mission_templates::table)
+            .filter(mission_templates::id.eq(template_id))
+            .filter(mission_templates::owner_id.eq(owner_id))
+            .execute(&mut conn)?;
+
+        if deleted == 0 {
+            return Err(AppError::NotFound("Mission template not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn find_recurring(&self) -> AppResult<Vec<MissionTemplateEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = mission_templates::table
+            .filter(mission_templates::recurrence.is_not_null())
+            .order(mission_templates::id.asc())
+            .select(MissionTemplateEntity::as_select())
+            .load::<MissionTemplateEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn schedule_occurrence(&self, template_id: i32, mission: AddMissionEntity) -> AppResult<Option<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let starts_at = mission.scheduled_start_at;
+
+        let mission_id = conn.transaction::<_, AppError, _>(|conn| {
+            let mission_id = diesel::insert_into(missions::table)
+                .values(&mission)
+                .on_conflict((missions::template_id, missions::scheduled_start_at))
+                .do_nothing()
+                .returning(missions::id)
+                .get_result::<i32>(conn)
+                .optional()?;
+
+            diesel::update(mission_templates::table)
+                .filter(mission_templates::id.eq(template_id))
+                .filter(
+                    mission_templates::last_scheduled_at
+                        .is_null()
+                        .or(mission_templates::last_scheduled_at.lt(starts_at)),
+                )
+                .set(mission_templates::last_scheduled_at.eq(starts_at))
+                .execute(conn)?;
+
+            Ok(mission_id)
+        })?;
+
+        Ok(mission_id)
+    }
+
+    async fn previous_crew(&self, template_id: i32, before: NaiveDateTime) -> AppResult<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let previous = missions::table
+            .inner_join(crew_memberships::table)
+            .filter(missions::template_id.eq(template_id))
+            .filter(missions::scheduled_start_at.lt(before))
+            .filter(missions::deleted_at.is_null())
+            .order(missions::scheduled_start_at.desc())
+            .select(missions::id)
+            .first::<i32>(&mut conn)
+            .optional()?;
+
+        let Some(previous) = previous else {
+            return Ok(Vec::new());
+        };
+
+        let crew = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(previous))
+            .select(crew_memberships::brawler_id)
+            .load::<i32>(&mut conn)?;
+
+        Ok(crew)
+    }
+}
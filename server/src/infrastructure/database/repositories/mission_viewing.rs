@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use anyhow::Result;
+use crate::domain::errors::{AppError, AppResult};
 use async_trait::async_trait;
 use diesel::prelude::*;
 
@@ -9,12 +9,32 @@ use crate::{
         value_objects::{
             brawler_model::BrawlerModel,
             mission_filter::MissionFilter,
+            mission_model::MissionSort,
+            pagination::{Cursor, CursorKind, Page, PageRequest, SortDirection, SortField},
             MissionModel,
         },
     },
-    infrastructure::database::postgresql_connection::PgPoolSquad,
+    infrastructure::database::{
+        errors::not_found,
+        pagination::{SortColumn, TotalRow, count_sql, keyset_sql, mission_sort_column},
+        postgresql_connection::PgPoolSquad,
+    },
 };
 
+/// Weighted document matched by `q`; must stay identical to `idx_missions_search`.
+const SEARCH_VECTOR: &str = "(setweight(to_tsvector('simple', coalesce(m.name, '')), 'A') \
+    || setweight(to_tsvector('simple', coalesce(m.description, '')), 'B'))";
+
+const RANK_COLUMN: SortColumn = SortColumn::new("rank", "real");
+
+#[derive(QueryableByName)]
+struct RankedMission {
+    #[diesel(embed)]
+    mission: MissionModel,
+    #[diesel(sql_type = diesel::sql_types::Float4)]
+    rank: f32,
+}
+
 pub struct MissionViewingPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -28,11 +48,11 @@ impl MissionViewingPostgres {
 #[async_trait]
 impl MissionViewingRepository for MissionViewingPostgres {
 
-    async fn crew_counting(&self, mission_id: i32) -> Result<i64> {
+    async fn crew_counting(&self, mission_id: i32) -> AppResult<i64> {
         use crate::infrastructure::database::schema::crew_memberships;
 
         let db_pool = Arc::clone(&self.db_pool);
-        let count = tokio::task::spawn_blocking(move || -> Result<i64> {
+        let count = tokio::task::spawn_blocking(move || -> AppResult<i64> {
             let mut conn = db_pool.get()?;
             let count = crew_memberships::table
                 .filter(crew_memberships::mission_id.eq(mission_id))
@@ -45,11 +65,11 @@ impl MissionViewingRepository for MissionViewingPostgres {
         Ok(count)
     }
 
-    async fn get_one(&self, mission_id: i32, brawler_id: i32) -> Result<MissionModel> {
+    async fn get_one(&self, mission_id: i32, brawler_id: i32) -> AppResult<MissionModel> {
         use diesel::sql_types::Int4;
 
         let db_pool = Arc::clone(&self.db_pool);
-        let mission = tokio::task::spawn_blocking(move || -> Result<MissionModel> {
+        let mission = tokio::task::spawn_blocking(move || -> AppResult<MissionModel> {
             let mut conn = db_pool.get()?;
 
             let sql = r#"
@@ -68,23 +88,44 @@ SELECT
         WHERE cm2.mission_id = m.id
           AND cm2.brawler_id = $2
     ) AS is_member,
+    EXISTS (
+        SELECT 1 FROM crew_memberships cm3
+        WHERE cm3.mission_id = m.id
+          AND cm3.brawler_id = $2
+          AND cm3.is_co_chief
+    ) AS is_co_chief,
     m.image_url,
     m.created_at,
-    m.updated_at
+    m.updated_at,
+    m.scheduled_start_at,
+    m.deadline_at,
+    m.duration_minutes,
+    m.objectives_required,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = m.id) AS objectives_total,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = m.id AND mo.completed_at IS NOT NULL) AS objectives_done,
+    (SELECT COALESCE(100 * COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) / NULLIF(COUNT(*), 0), 0)::int4
+     FROM mission_objectives mo WHERE mo.mission_id = m.id) AS objectives_progress,
+    m.join_policy,
+    m.finished_at,
+    m.min_reputation
 FROM missions m
 LEFT JOIN brawlers b ON b.id = m.chief_id
 LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
 WHERE m.id = $1
+  AND m.deleted_at IS NULL
 GROUP BY
     m.id, b.display_name, m.name, m.description, m.category, m.max_crew,
-    m.status, m.chief_id, m.image_url, m.created_at, m.updated_at
+    m.status, m.chief_id, m.image_url, m.created_at, m.updated_at,
+    m.scheduled_start_at, m.deadline_at, m.duration_minutes, m.objectives_required, m.join_policy,
+    m.finished_at, m.min_reputation
 LIMIT 1
 "#;
 
             let mission = diesel::sql_query(sql)
                 .bind::<Int4, _>(mission_id)
                 .bind::<Int4, _>(brawler_id)
-                .get_result::<MissionModel>(&mut conn)?;
+                .get_result::<MissionModel>(&mut conn)
+                .map_err(not_found("Mission"))?;
             
             Ok(mission)
         })
@@ -97,16 +138,19 @@ LIMIT 1
         &self,
         mission_filter: &MissionFilter,
         brawler_id: i32,
-    ) -> Result<Vec<MissionModel>> {
-        use diesel::sql_types::{Nullable, Varchar, Int4};
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>> {
+        use diesel::sql_types::{Array, BigInt, Bool, Date, Int4, Nullable, Text, Varchar};
 
         let db_pool = Arc::clone(&self.db_pool);
         let mission_filter = mission_filter.clone();
+        let page = page.clone();
 
-        let rows = tokio::task::spawn_blocking(move || -> Result<Vec<MissionModel>> {
+        let page = tokio::task::spawn_blocking(move || -> AppResult<Page<MissionModel>> {
             let mut conn = db_pool.get()?;
 
-            let sql = r#"
+            let base = format!(
+                r#"
 SELECT
     m.id,
     m.name,
@@ -122,56 +166,141 @@ SELECT
         WHERE cm2.mission_id = m.id
           AND cm2.brawler_id = $3
     ) AS is_member,
+    EXISTS (
+        SELECT 1 FROM crew_memberships cm3
+        WHERE cm3.mission_id = m.id
+          AND cm3.brawler_id = $3
+          AND cm3.is_co_chief
+    ) AS is_co_chief,
     m.image_url,
     m.created_at,
-    m.updated_at
+    m.updated_at,
+    m.scheduled_start_at,
+    m.deadline_at,
+    m.duration_minutes,
+    m.objectives_required,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = m.id) AS objectives_total,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = m.id AND mo.completed_at IS NOT NULL) AS objectives_done,
+    (SELECT COALESCE(100 * COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) / NULLIF(COUNT(*), 0), 0)::int4
+     FROM mission_objectives mo WHERE mo.mission_id = m.id) AS objectives_progress,
+    m.join_policy,
+    m.finished_at,
+    m.min_reputation,
+    CASE WHEN $5::text IS NULL THEN 0
+         ELSE ts_rank({SEARCH_VECTOR}, websearch_to_tsquery('simple', $5))
+    END::real AS rank
 FROM missions m
 LEFT JOIN brawlers b ON b.id = m.chief_id
 LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
-WHERE ($1::varchar IS NULL OR m.status = $1)
+WHERE m.deleted_at IS NULL
+  AND ($1::text[] IS NULL OR m.status = ANY($1))
   AND ($2::varchar IS NULL OR m.name ILIKE $2)
-  AND ($4::varchar IS NULL OR m.category = $4)
-  -- AND m.chief_id <> $3
-  -- AND NOT EXISTS (
-  --  SELECT 1 FROM crew_memberships cm3
-  --  WHERE cm3.mission_id = m.id
-  --    AND cm3.brawler_id = $3
-  -- )
+  AND ($4::text[] IS NULL OR m.category = ANY($4))
+  AND ($5::text IS NULL OR {SEARCH_VECTOR} @@ websearch_to_tsquery('simple', $5))
+  AND ($6::int4 IS NULL OR m.chief_id = $6)
+  AND ($9::date IS NULL OR m.created_at >= $9)
+  AND ($10::date IS NULL OR m.created_at < $10 + 1)
+  AND (NOT $8 OR (
+      m.chief_id <> $3
+      AND NOT EXISTS (
+        SELECT 1 FROM crew_memberships cm3
+        WHERE cm3.mission_id = m.id
+          AND cm3.brawler_id = $3
+      )
+  ))
 GROUP BY
     m.id, b.display_name, m.name, m.description, m.category, m.max_crew,
-    m.status, m.chief_id, m.image_url, m.created_at, m.updated_at
-ORDER BY m.created_at DESC
-"#;
+    m.status, m.chief_id, m.image_url, m.created_at, m.updated_at,
+    m.scheduled_start_at, m.deadline_at, m.duration_minutes, m.objectives_required, m.join_policy,
+    m.finished_at, m.min_reputation
+HAVING NOT $7 OR COUNT(cm.brawler_id) < m.max_crew
+"#
+            );
 
-            let rows = diesel::sql_query(sql)
-                .bind::<Nullable<Varchar>, _>(
-                    mission_filter.status.as_ref().map(|s| s.to_string()),
-                )
-                .bind::<Nullable<Varchar>, _>(
-                    mission_filter.name.as_ref().map(|n| format!("%{}%", n)),
-                )
+            let statuses = mission_filter
+                .status
+                .as_ref()
+                .map(|statuses| statuses.iter().map(|s| s.to_string()).collect::<Vec<_>>());
+            let name = mission_filter.name.as_ref().map(|n| format!("%{}%", n));
+            let search = mission_filter.search().map(str::to_string);
+            let has_open_slots = mission_filter.has_open_slots.unwrap_or(false);
+            let joinable = mission_filter.joinable.unwrap_or(false);
+
+            let total = diesel::sql_query(count_sql(&base))
+                .bind::<Nullable<Array<Text>>, _>(statuses.clone())
+                .bind::<Nullable<Varchar>, _>(name.clone())
                 .bind::<Int4, _>(brawler_id)
-                .bind::<Nullable<Varchar>, _>(
-                    mission_filter.category.as_ref().map(|c| c.to_string()),
-                )
-                .load::<MissionModel>(&mut conn)?;
-            
-            Ok(rows)
+                .bind::<Nullable<Array<Text>>, _>(mission_filter.category.clone())
+                .bind::<Nullable<Text>, _>(search.clone())
+                .bind::<Nullable<Int4>, _>(mission_filter.chief_id)
+                .bind::<Bool, _>(has_open_slots)
+                .bind::<Bool, _>(joinable)
+                .bind::<Nullable<Date>, _>(mission_filter.created_from)
+                .bind::<Nullable<Date>, _>(mission_filter.created_to)
+                .get_result::<TotalRow>(&mut conn)?
+                .total;
+
+            // A search without an explicit sort is ordered by relevance.
+            let by_rank = search.is_some() && page.sort.is_none();
+            let (column, direction) = if by_rank {
+                (RANK_COLUMN, page.direction.unwrap_or(SortDirection::Desc))
+            } else {
+                (mission_sort_column(page.sort()), page.direction())
+            };
+
+            let sort = page.sort();
+            let cursor = page.cursor();
+            // `validate` can not tell whether an unsorted page is ranked, so check the cursor here.
+            let kind = if by_rank { CursorKind::Real } else { sort.cursor_kind() };
+            if cursor.as_ref().is_some_and(|cursor| !kind.accepts(&cursor.value)) {
+                return Err(AppError::validation("cursor", "does not match the sort order"));
+            }
+            let rows = diesel::sql_query(keyset_sql(&base, 10, column, direction))
+                .bind::<Nullable<Array<Text>>, _>(statuses)
+                .bind::<Nullable<Varchar>, _>(name)
+                .bind::<Int4, _>(brawler_id)
+                .bind::<Nullable<Array<Text>>, _>(mission_filter.category)
+                .bind::<Nullable<Text>, _>(search)
+                .bind::<Nullable<Int4>, _>(mission_filter.chief_id)
+                .bind::<Bool, _>(has_open_slots)
+                .bind::<Bool, _>(joinable)
+                .bind::<Nullable<Date>, _>(mission_filter.created_from)
+                .bind::<Nullable<Date>, _>(mission_filter.created_to)
+                .bind::<Nullable<Text>, _>(cursor.as_ref().map(|c| c.value.clone()))
+                .bind::<Nullable<Int4>, _>(cursor.as_ref().map(|c| c.id))
+                .bind::<BigInt, _>(i64::from(page.limit()) + 1)
+                .load::<RankedMission>(&mut conn)?;
+
+            let page = Page::from_rows(rows, page.limit(), total, |row| {
+                if by_rank {
+                    Cursor::new(row.rank.to_string(), row.mission.id)
+                } else {
+                    sort.cursor(&row.mission)
+                }
+            });
+
+            Ok(page.map(|row| row.mission))
         })
         .await??;
 
-        Ok(rows)
+        Ok(page)
     }
 
     // *เพิ่ม
-    async fn get_joined_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
-        use diesel::sql_types::Int4;
+    async fn get_joined_missions(
+        &self,
+        brawler_id: i32,
+        page: &PageRequest<MissionSort>,
+    ) -> AppResult<Page<MissionModel>> {
+        use diesel::sql_types::{BigInt, Int4, Nullable, Text};
 
         let db_pool = Arc::clone(&self.db_pool);
-        let rows = tokio::task::spawn_blocking(move || -> Result<Vec<MissionModel>> {
+        let page = page.clone();
+
+        let page = tokio::task::spawn_blocking(move || -> AppResult<Page<MissionModel>> {
             let mut conn = db_pool.get()?;
 
-            let sql = r#"
+            let base = r#"
 SELECT
     m.id,
     m.name,
@@ -183,34 +312,59 @@ SELECT
     COALESCE(b.display_name, '') AS chief_display_name,
     COUNT(cm.brawler_id) AS crew_count,
     TRUE AS is_member,
+    BOOL_OR(cm_join.is_co_chief) AS is_co_chief,
     m.image_url,
     m.created_at,
-    m.updated_at
+    m.updated_at,
+    m.scheduled_start_at,
+    m.deadline_at,
+    m.duration_minutes,
+    m.objectives_required,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = m.id) AS objectives_total,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = m.id AND mo.completed_at IS NOT NULL) AS objectives_done,
+    (SELECT COALESCE(100 * COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) / NULLIF(COUNT(*), 0), 0)::int4
+     FROM mission_objectives mo WHERE mo.mission_id = m.id) AS objectives_progress,
+    m.join_policy,
+    m.finished_at,
+    m.min_reputation
 FROM missions m
 INNER JOIN crew_memberships cm_join ON m.id = cm_join.mission_id
 LEFT JOIN brawlers b ON b.id = m.chief_id
 LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
 WHERE cm_join.brawler_id = $1
+  AND m.deleted_at IS NULL
 GROUP BY
     m.id, b.display_name, m.name, m.description, m.category, m.max_crew,
-    m.status, m.chief_id, m.image_url, m.created_at, m.updated_at
-ORDER BY m.created_at DESC
+    m.status, m.chief_id, m.image_url, m.created_at, m.updated_at,
+    m.scheduled_start_at, m.deadline_at, m.duration_minutes, m.objectives_required, m.join_policy,
+    m.finished_at, m.min_reputation
 "#;
 
+            let total = diesel::sql_query(count_sql(base))
+                .bind::<Int4, _>(brawler_id)
+                .get_result::<TotalRow>(&mut conn)?
+                .total;
+
+            let sort = page.sort();
+            let cursor = page.cursor();
+            let sql = keyset_sql(base, 1, mission_sort_column(sort), page.direction());
             let rows = diesel::sql_query(sql)
                 .bind::<Int4, _>(brawler_id)
+                .bind::<Nullable<Text>, _>(cursor.as_ref().map(|c| c.value.clone()))
+                .bind::<Nullable<Int4>, _>(cursor.as_ref().map(|c| c.id))
+                .bind::<BigInt, _>(i64::from(page.limit()) + 1)
                 .load::<MissionModel>(&mut conn)?;
-            
-            Ok(rows)
+
+            Ok(Page::from_rows(rows, page.limit(), total, |m| sort.cursor(m)))
         })
         .await??;
 
-        Ok(rows)
+        Ok(page)
     }
 
-    async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
+    async fn get_crew(&self, mission_id: i32) -> AppResult<Vec<BrawlerModel>> {
         let db_pool = Arc::clone(&self.db_pool);
-        let list = tokio::task::spawn_blocking(move || -> Result<Vec<BrawlerModel>> {
+        let list = tokio::task::spawn_blocking(move || -> AppResult<Vec<BrawlerModel>> {
             let mut conn = db_pool.get()?;
             let sql = r#"
 SELECT 
@@ -221,20 +375,28 @@ SELECT
         (SELECT COUNT(cm_s.mission_id)::INTEGER 
         FROM crew_memberships cm_s
         INNER JOIN missions m_s ON cm_s.mission_id = m_s.id
-        WHERE cm_s.brawler_id = b.id AND m_s.status = 'Completed')
+        WHERE cm_s.brawler_id = b.id AND m_s.status = 'Completed' AND m_s.deleted_at IS NULL)
         +
         (SELECT COUNT(m_s2.id)::INTEGER
         FROM missions m_s2
-        WHERE m_s2.chief_id = b.id AND m_s2.status = 'Completed')
+        WHERE m_s2.chief_id = b.id AND m_s2.status = 'Completed' AND m_s2.deleted_at IS NULL)
     ) AS mission_success_count,
     (
         SELECT COUNT(cm_j.mission_id)::INTEGER 
         FROM crew_memberships cm_j
-        WHERE cm_j.brawler_id = b.id
-    ) AS mission_join_count
+        INNER JOIN missions m_j ON cm_j.mission_id = m_j.id
+        WHERE cm_j.brawler_id = b.id AND m_j.deleted_at IS NULL
+    ) AS mission_join_count,
+    b.reputation,
+    b.rating_count,
+    cm.role_slot_id,
+    rs.label AS role,
+    cm.is_co_chief
 FROM brawlers b
 INNER JOIN crew_memberships cm ON b.id = cm.brawler_id
+LEFT JOIN mission_role_slots rs ON rs.id = cm.role_slot_id
 WHERE cm.mission_id = $1
+ORDER BY rs.position ASC NULLS LAST, cm.joined_at ASC
 "#;
             let list = diesel::sql_query(sql)
                 .bind::<diesel::sql_types::Int4, _>(mission_id)
@@ -247,11 +409,11 @@ WHERE cm.mission_id = $1
     }
 
     // *เพิ่ม
-    async fn get_popular_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+    async fn get_popular_missions(&self, brawler_id: i32) -> AppResult<Vec<MissionModel>> {
         use diesel::sql_types::Int4;
 
         let db_pool = Arc::clone(&self.db_pool);
-        let rows = tokio::task::spawn_blocking(move || -> Result<Vec<MissionModel>> {
+        let rows = tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionModel>> {
             let mut conn = db_pool.get()?;
 
             let sql = r#"
@@ -270,16 +432,36 @@ SELECT
         WHERE cm2.mission_id = m.id
           AND cm2.brawler_id = $1
     ) AS is_member,
+    EXISTS (
+        SELECT 1 FROM crew_memberships cm3
+        WHERE cm3.mission_id = m.id
+          AND cm3.brawler_id = $1
+          AND cm3.is_co_chief
+    ) AS is_co_chief,
     m.image_url,
     m.created_at,
-    m.updated_at
+    m.updated_at,
+    m.scheduled_start_at,
+    m.deadline_at,
+    m.duration_minutes,
+    m.objectives_required,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = m.id) AS objectives_total,
+    (SELECT COUNT(*) FROM mission_objectives mo WHERE mo.mission_id = m.id AND mo.completed_at IS NOT NULL) AS objectives_done,
+    (SELECT COALESCE(100 * COUNT(*) FILTER (WHERE mo.completed_at IS NOT NULL) / NULLIF(COUNT(*), 0), 0)::int4
+     FROM mission_objectives mo WHERE mo.mission_id = m.id) AS objectives_progress,
+    m.join_policy,
+    m.finished_at,
+    m.min_reputation
 FROM missions m
 LEFT JOIN brawlers b ON b.id = m.chief_id
 LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
-WHERE m.status != 'Completed' AND m.status != 'Failed'
+WHERE m.deleted_at IS NULL
+  AND m.status != 'Completed' AND m.status != 'Failed'
 GROUP BY
     m.id, b.display_name, m.name, m.description, m.category, m.max_crew,
-    m.status, m.chief_id, m.image_url, m.created_at, m.updated_at
+    m.status, m.chief_id, m.image_url, m.created_at, m.updated_at,
+    m.scheduled_start_at, m.deadline_at, m.duration_minutes, m.objectives_required, m.join_policy,
+    m.finished_at, m.min_reputation
 ORDER BY crew_count DESC, m.updated_at DESC
 LIMIT 6
 "#;
@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use crate::domain::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::*,
+    result::{DatabaseErrorKind, Error as DieselError},
+    sql_types::{Array, Int4},
+};
+
+use crate::{
+    domain::{
+        entities::mission_waitlist::AddMissionWaitlistEntity,
+        repositories::mission_waitlist::MissionWaitlistRepository,
+        value_objects::{
+            join_policy::JoinPolicy,
+            mission_statuses::MissionStatuses,
+            waitlist_model::{WaitlistEntryModel, WaitlistStatus},
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{crew_memberships, mission_waitlist, missions},
+    },
+};
+
+/// Active entries with their place in the queue; callers filter on `q`.
+const WAITLIST_SELECT: &str = r#"
+SELECT q.* FROM (
+    SELECT
+        w.id,
+        w.mission_id,
+        m.name AS mission_name,
+        w.brawler_id,
+        b.display_name AS brawler_display_name,
+        ROW_NUMBER() OVER (PARTITION BY w.mission_id ORDER BY w.created_at, w.id) AS position,
+        w.status,
+        w.offer_expires_at,
+        w.created_at
+    FROM mission_waitlist w
+    INNER JOIN missions m ON m.id = w.mission_id
+    INNER JOIN brawlers b ON b.id = w.brawler_id
+    WHERE w.status IN ('waiting', 'offered')
+) q
+"#;
+
+pub struct MissionWaitlistPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionWaitlistPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn active_statuses() -> [String; 2] {
+    [WaitlistStatus::Waiting.to_string(), WaitlistStatus::Offered.to_string()]
+}
+
+#[async_trait]
+impl MissionWaitlistRepository for MissionWaitlistPostgres {
+    async fn enqueue(&self, entry: AddMissionWaitlistEntity) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::insert_into(mission_waitlist::table)
+            .values(&entry)
+            .execute(&mut conn)
+            .map_err(|e| match e {
+                DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                    AppError::Conflict("Already on the waitlist".to_string())
+                }
+                e => e.into(),
+            })?;
+        Ok(())
+    }
+
+    async fn find_by_mission(&self, mission_id: i32) -> AppResult<Vec<WaitlistEntryModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!("{WAITLIST_SELECT} WHERE q.mission_id = $1 ORDER BY q.position");
+        let entries = diesel::sql_query(sql)
+            .bind::<Int4, _>(mission_id)
+            .load::<WaitlistEntryModel>(&mut conn)?;
+        Ok(entries)
+    }
+
+    async fn find_active(&self, mission_id: i32, brawler_id: i32) -> AppResult<Option<WaitlistEntryModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let sql = format!("{WAITLIST_SELECT} WHERE q.mission_id = $1 AND q.brawler_id = $2");
+        let entry = diesel::sql_query(sql)
+            .bind::<Int4, _>(mission_id)
+            .bind::<Int4, _>(brawler_id)
+            .get_result::<WaitlistEntryModel>(&mut conn)
+            .optional()?;
+        Ok(entry)
+    }
+
+    async fn count_held_places(&self, mission_id: i32, except_brawler_id: i32, now: NaiveDateTime) -> AppResult<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let held = mission_waitlist::table
+            .filter(mission_waitlist::mission_id.eq(mission_id))
+            .filter(mission_waitlist::brawler_id.ne(except_brawler_id))
+            .filter(mission_waitlist::status.eq(WaitlistStatus::Offered.to_string()))
+            .filter(mission_waitlist::offer_expires_at.gt(now))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+        Ok(held)
+    }
+
+    async fn set_status(&self, entry_id: i32, status: WaitlistStatus) -> AppResult<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(mission_waitlist::table)
+            .filter(mission_waitlist::id.eq(entry_id))
+            .set(mission_waitlist::status.eq(status.to_string()))
+            .execute(&mut conn)?;
+        Ok(())
+    }
+
+    async fn offer_open_places(&self, mission_id: i32, now: NaiveDateTime, expires_at: NaiveDateTime) -> AppResult<Vec<WaitlistEntryModel>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let offered_ids = conn.transaction::<_, AppError, _>(|conn| {
+            // Serialises promotions of the same mission.
+            let mission = missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .select((missions::max_crew, missions::status, missions::join_policy))
+                .for_update()
+                .first::<(i32, String, String)>(conn)
+                .optional()?;
+            let Some((max_crew, status, join_policy)) = mission else {
+                return Ok(Vec::new());
+            };
+
+            // Brawlers who got in some other way leave the queue.
+            diesel::update(mission_waitlist::table)
+                .filter(mission_waitlist::mission_id.eq(mission_id))
+                .filter(mission_waitlist::status.eq_any(active_statuses()))
+                .filter(
+                    mission_waitlist::brawler_id.eq_any(
+                        crew_memberships::table
+                            .filter(crew_memberships::mission_id.eq(mission_id))
+                            .select(crew_memberships::brawler_id),
+                    ),
+                )
+                .set(mission_waitlist::status.eq(WaitlistStatus::Joined.to_string()))
+                .execute(conn)?;
+
+            let joinable = status == MissionStatuses::Open.to_string()
+                || status == MissionStatuses::InProgress.to_string()
+                || status == MissionStatuses::Failed.to_string();
+            if !joinable || join_policy != JoinPolicy::Open.to_string() {
+                return Ok(Vec::new());
+            }
+
+            let crew_count = crew_memberships::table
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .count()
+                .get_result::<i64>(conn)?;
+            let held = mission_waitlist::table
+                .filter(mission_waitlist::mission_id.eq(mission_id))
+                .filter(mission_waitlist::status.eq(WaitlistStatus::Offered.to_string()))
+                .filter(mission_waitlist::offer_expires_at.gt(now))
+                .count()
+                .get_result::<i64>(conn)?;
+            let places = max_crew as i64 - crew_count - held;
+            if places <= 0 {
+                return Ok(Vec::new());
+            }
+
+            let next = mission_waitlist::table
+                .filter(mission_waitlist::mission_id.eq(mission_id))
+                .filter(mission_waitlist::status.eq(WaitlistStatus::Waiting.to_string()))
+                .order((mission_waitlist::created_at.asc(), mission_waitlist::id.asc()))
+                .limit(places)
+                .select(mission_waitlist::id)
+                .load::<i32>(conn)?;
+
+            diesel::update(mission_waitlist::table)
+                .filter(mission_waitlist::id.eq_any(&next))
+                .set((
+                    mission_waitlist::status.eq(WaitlistStatus::Offered.to_string()),
+                    mission_waitlist::offered_at.eq(now),
+                    mission_waitlist::offer_expires_at.eq(expires_at),
+                ))
+                .execute(conn)?;
+
+            Ok(next)
+        })?;
+
+        if offered_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let sql = format!("{WAITLIST_SELECT} WHERE q.id = ANY($1) ORDER BY q.position");
+        let offers = diesel::sql_query(sql)
+            .bind::<Array<Int4>, _>(offered_ids)
+            .load::<WaitlistEntryModel>(&mut conn)?;
+        Ok(offers)
+    }
+
+    async fn expire_offers(&self, now: NaiveDateTime) -> AppResult<Vec<i32>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut mission_ids = diesel::update(mission_waitlist::table)
+            .filter(mission_waitlist::status.eq(WaitlistStatus::Offered.to_string()))
+            .filter(mission_waitlist::offer_expires_at.le(now))
+            .set(mission_waitlist::status.eq(WaitlistStatus::Expired.to_string()))
+            .returning(mission_waitlist::mission_id)
+            .get_results::<i32>(&mut conn)?;
+        mission_ids.sort_unstable();
+        mission_ids.dedup();
+        Ok(mission_ids)
+    }
+}
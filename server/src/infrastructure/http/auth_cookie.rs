@@ -0,0 +1,45 @@
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+
+use crate::config::config_model::AuthCookieEnv;
+
+/// Holds the OIDC `state` of a login this browser started, so a callback carrying
+/// someone else's state is refused.
+const OIDC_STATE_COOKIE: &str = "oidc_state";
+
+/// Adds the access token cookie when cookie auth is enabled; otherwise returns the jar untouched.
+pub fn with_token(jar: CookieJar, cookie_env: &AuthCookieEnv, token: &str) -> CookieJar {
+    if !cookie_env.enabled {
+        return jar;
+    }
+
+    jar.add(
+        Cookie::build((cookie_env.name.clone(), token.to_string()))
+            .path("/")
+            .http_only(true)
+            .secure(cookie_env.secure)
+            .same_site(SameSite::Strict),
+    )
+}
+
+pub fn without_token(jar: CookieJar, cookie_env: &AuthCookieEnv) -> CookieJar {
+    jar.remove(Cookie::build(cookie_env.name.clone()).path("/"))
+}
+
+/// Set whether or not cookie auth is enabled; the callback always checks it.
+pub fn with_oidc_state(jar: CookieJar, cookie_env: &AuthCookieEnv, state: &str) -> CookieJar {
+    jar.add(
+        Cookie::build((OIDC_STATE_COOKIE, state.to_string()))
+            .path("/")
+            .http_only(true)
+            .secure(cookie_env.secure)
+            .same_site(SameSite::Lax),
+    )
+}
+
+pub fn oidc_state(jar: &CookieJar) -> Option<String> {
+    jar.get(OIDC_STATE_COOKIE).map(|cookie| cookie.value().to_string())
+}
+
+pub fn without_oidc_state(jar: CookieJar) -> CookieJar {
+    jar.remove(Cookie::build(OIDC_STATE_COOKIE).path("/"))
+}
@@ -1,12 +1,67 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::domain::errors::{AppError, FieldError};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
+    pub code: String,
     pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldError>,
 }
 
 impl ErrorResponse {
-    pub fn new(message: String) -> Self {
-        Self { message }
+    pub fn new(code: &str, message: String) -> Self {
+        Self {
+            code: code.to_string(),
+            message,
+            fields: Vec::new(),
+        }
+    }
+}
+
+impl AppError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        let body = match self {
+            // Database and library errors stay in the logs.
+            AppError::Internal(e) => {
+                tracing::error!("Internal error: {:?}", e);
+                ErrorResponse::new("internal_error", "Internal server error".to_string())
+            }
+            AppError::Unavailable(ref message) => {
+                tracing::warn!("Upstream unavailable: {}", message);
+                ErrorResponse::new(self.code(), "Service temporarily unavailable".to_string())
+            }
+            AppError::Validation(fields) => ErrorResponse {
+                code: "validation_failed".to_string(),
+                message: "Validation failed".to_string(),
+                fields,
+            },
+            e => ErrorResponse::new(e.code(), e.to_string()),
+        };
+
+        (status, Json(body)).into_response()
     }
 }
@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{FromRequestParts, OptionalFromRequestParts},
+    http::{Extensions, HeaderMap, header, request::Parts},
+};
+use axum_extra::extract::CookieJar;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    config::config_model::AuthCookieEnv,
+    domain::{
+        errors::{AppError, AppResult},
+        value_objects::{
+            api_key_model::{ApiKeyPrincipal, ApiKeyScopes},
+            roles::Roles,
+        },
+    },
+    infrastructure::{
+        http::middlewares::auth::SessionAuthenticator,
+        jwt::{JwtKeys, jwt_model::Claims},
+    },
+};
+
+/// The authenticated caller, taken from a verified access token whose brawler is
+/// still enabled; the role is the brawler's current one, not the token's.
+///
+/// Use `CurrentBrawler` on routes that require a login and `Option<CurrentBrawler>`
+/// on public routes that only personalise their response. A present but invalid
+/// token is rejected in both cases.
+#[derive(Debug, Clone)]
+pub struct CurrentBrawler {
+    pub id: i32,
+    pub role: Roles,
+    pub session_id: Option<String>,
+    /// `None` for API keys, which stay valid until revoked.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Set when authenticated with an API key; `None` means a full user session.
+    pub scopes: Option<Vec<ApiKeyScopes>>,
+}
+
+impl CurrentBrawler {
+    pub fn from_claims(claims: Claims) -> AppResult<Self> {
+        let id = claims.sub.parse::<i32>().map_err(|_| invalid_token())?;
+        let expires_at =
+            DateTime::from_timestamp(claims.exp as i64, 0).ok_or_else(invalid_token)?;
+
+        Ok(Self {
+            id,
+            role: claims.role,
+            session_id: claims.sid,
+            expires_at: Some(expires_at),
+            scopes: None,
+        })
+    }
+
+    pub fn from_api_key(principal: ApiKeyPrincipal) -> Self {
+        Self {
+            id: principal.brawler_id,
+            role: principal.role,
+            session_id: None,
+            expires_at: None,
+            scopes: Some(principal.scopes),
+        }
+    }
+
+    pub async fn authenticate(extensions: &Extensions, token: &str) -> AppResult<Self> {
+        let claims = jwt_keys(extensions)?
+            .verify_token(token)
+            .map_err(|_| invalid_token())?;
+        let token_version = claims.ver;
+        let mut current = Self::from_claims(claims)?;
+
+        current.role = session_authenticator(extensions)?
+            .authenticate_session(current.id, token_version)
+            .await?;
+
+        Ok(current)
+    }
+}
+
+fn invalid_token() -> AppError {
+    AppError::Unauthorized("Invalid or expired token".to_string())
+}
+
+pub fn missing_credentials() -> AppError {
+    AppError::Unauthorized("Authentication required".to_string())
+}
+
+pub fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.to_string())
+}
+
+/// Bearer header first, then the auth cookie when cookie auth is enabled.
+pub fn request_token(headers: &HeaderMap, extensions: &Extensions) -> Option<String> {
+    bearer_token(headers).or_else(|| {
+        let cookie_env = extensions
+            .get::<AuthCookieEnv>()
+            .filter(|cookie_env| cookie_env.enabled)?;
+        CookieJar::from_headers(headers)
+            .get(&cookie_env.name)
+            .map(|cookie| cookie.value().to_string())
+    })
+}
+
+pub fn jwt_keys(extensions: &Extensions) -> AppResult<Arc<JwtKeys>> {
+    extensions.get::<Arc<JwtKeys>>().cloned().ok_or_else(|| {
+        AppError::Internal(anyhow::anyhow!(
+            "JWT keys are missing from the request extensions"
+        ))
+    })
+}
+
+pub fn session_authenticator(extensions: &Extensions) -> AppResult<Arc<SessionAuthenticator>> {
+    extensions.get::<Arc<SessionAuthenticator>>().cloned().ok_or_else(|| {
+        AppError::Internal(anyhow::anyhow!(
+            "Session authenticator is missing from the request extensions"
+        ))
+    })
+}
+
+impl<S> FromRequestParts<S> for CurrentBrawler
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // Already resolved by the `auth` middleware.
+        if let Some(current) = parts.extensions.get::<CurrentBrawler>() {
+            return Ok(current.clone());
+        }
+
+        let token = request_token(&parts.headers, &parts.extensions).ok_or_else(missing_credentials)?;
+        let current = Self::authenticate(&parts.extensions, &token).await?;
+        parts.extensions.insert(current.clone());
+
+        Ok(current)
+    }
+}
+
+impl<S> OptionalFromRequestParts<S> for CurrentBrawler
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        if let Some(current) = parts.extensions.get::<CurrentBrawler>() {
+            return Ok(Some(current.clone()));
+        }
+
+        let Some(token) = request_token(&parts.headers, &parts.extensions) else {
+            return Ok(None);
+        };
+        let current = Self::authenticate(&parts.extensions, &token).await?;
+        parts.extensions.insert(current.clone());
+
+        Ok(Some(current))
+    }
+}
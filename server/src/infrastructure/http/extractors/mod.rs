@@ -0,0 +1,5 @@
+pub mod current_brawler;
+pub mod validated;
+
+pub use current_brawler::CurrentBrawler;
+pub use validated::{ValidatedJson, ValidatedQuery};
@@ -0,0 +1,67 @@
+use axum::{
+    Json,
+    extract::{FromRequest, FromRequestParts, Query, Request, rejection::QueryRejection},
+    http::{Extensions, request::Parts},
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::AppError,
+        value_objects::validation::Validate,
+    },
+};
+
+/// `Json<T>` that also runs `T::validate`; malformed bodies and rule violations
+/// are both answered with 422 and per-field errors.
+#[derive(Debug, Clone)]
+pub struct ValidatedJson<T>(pub T);
+
+/// `Query<T>` counterpart of `ValidatedJson`.
+#[derive(Debug, Clone)]
+pub struct ValidatedQuery<T>(pub T);
+
+fn validation_env(extensions: &Extensions) -> Result<ValidationEnv, AppError> {
+    extensions.get::<ValidationEnv>().cloned().ok_or_else(|| {
+        AppError::Internal(anyhow::anyhow!(
+            "Validation limits are missing from the request extensions"
+        ))
+    })
+}
+
+impl<S, T> FromRequest<S> for ValidatedJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Validate,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let limits = validation_env(req.extensions())?;
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|rejection| AppError::validation("body", rejection.body_text()))?;
+
+        value.validated(&limits)?;
+        Ok(Self(value))
+    }
+}
+
+impl<S, T> FromRequestParts<S> for ValidatedQuery<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Validate,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let limits = validation_env(&parts.extensions)?;
+        let Query(value) = Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection: QueryRejection| AppError::validation("query", rejection.body_text()))?;
+
+        value.validated(&limits)?;
+        Ok(Self(value))
+    }
+}
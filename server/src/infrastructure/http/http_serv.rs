@@ -2,10 +2,7 @@ use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::{Ok, Result};
 use axum::{
-    Extension, Router, extract::DefaultBodyLimit, http::{
-        Method, StatusCode,
-        header::{AUTHORIZATION, CONTENT_TYPE, HeaderValue},
-    }
+    Extension, Router, extract::DefaultBodyLimit, http::StatusCode,
 };
 use tokio::{net::TcpListener, sync::broadcast};
 use tower_http::{
@@ -17,13 +14,26 @@ use tower_http::{
 use tracing::info;
 
 use crate::{
-    config::config_model::DotEnvyConfig,
+    config::{
+        config_loader::{get_auth_cookie_env, get_jwt_env, get_trusted_proxy_env, get_validation_env},
+        config_model::DotEnvyConfig,
+    },
     domain::{entities::notification::Notification,
+        errors::AppError,
         services::notification_service::{NotificationService},
     },
     infrastructure::{
-        database::postgresql_connection::PgPoolSquad,
-        http::routers::{self},
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{api_keys::ApiKeyPostgres, brawlers::BrawlerPostgres, login_attempts::LoginAttemptPostgres},
+        },
+        http::{
+            middlewares::{api_keys::ApiKeyAuthenticator, auth::SessionAuthenticator},
+            routers::{self},
+        },
+        jobs,
+        jwt::JwtKeys,
+        stream_tickets::StreamTicketStore,
         services::{notification_service::NotificationServiceImpl}},
     application::services::mission_realtime::MissionRealtimeService,
 };
@@ -35,9 +45,12 @@ fn api_serve(
     notification_service: Arc<dyn NotificationService>,
     tx: broadcast::Sender<Notification>,
     realtime_service: Arc<MissionRealtimeService>,
+    jwt_keys: Arc<JwtKeys>,
+    api_keys: Arc<ApiKeyAuthenticator>,
 ) -> Router {
     Router::new()
-        .nest("/brawler", routers::brawlers::routes(Arc::clone(&db_pool)))
+        .merge(routers::api_docs::routes())
+        .nest("/brawler", routers::brawlers::routes(Arc::clone(&db_pool), Arc::clone(&jwt_keys), Arc::clone(&notification_service)))
         .nest(
             "/view",
             routers::mission_viewing::routes(Arc::clone(&db_pool)),
@@ -61,12 +74,26 @@ fn api_serve(
         )
         .nest(
             "/mission-management",
-            routers::mission_management::routes(Arc::clone(&db_pool)),
+            routers::mission_management::routes(Arc::clone(&db_pool), Arc::clone(&notification_service)),
+        )
+        .nest(
+            "/mission-objectives",
+            routers::mission_objectives::routes(Arc::clone(&db_pool), Arc::clone(&realtime_service)),
+        )
+        .nest(
+            "/mission-templates",
+            routers::mission_templates::routes(Arc::clone(&db_pool), Arc::clone(&notification_service), Arc::clone(&realtime_service)),
         )
         .nest(
             "/authentication",
-            routers::authentication::routes(Arc::clone(&db_pool)),
+            routers::authentication::routes(Arc::clone(&db_pool), Arc::clone(&jwt_keys)),
+        )
+        .nest(
+            "/authentication/oidc",
+            routers::oidc_authentication::routes(Arc::clone(&db_pool), Arc::clone(&jwt_keys)),
         )
+        .nest("/admin", routers::admin::routes(Arc::clone(&db_pool)))
+        .nest("/api-keys", routers::api_keys::routes(api_keys))
         .nest(
             "/achievements",
             routers::achievements::routes(Arc::clone(&db_pool)),
@@ -77,16 +104,50 @@ fn api_serve(
         )
         .nest(
             "/mission-invites",
-            routers::mission_invites::routes(Arc::clone(&db_pool), Arc::clone(&realtime_service)),
+            routers::mission_invites::routes(Arc::clone(&db_pool), Arc::clone(&notification_service), Arc::clone(&realtime_service)),
         )
+        .nest("/mission-ratings", routers::mission_ratings::routes(Arc::clone(&db_pool)))
         .nest("/util", routers::default_router::routes())
-        .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
+        .fallback(|| async { AppError::NotFound("API not found".to_string()) })
 }
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
     let (tx, _rx) = broadcast::channel(100);
     let notification_svc: Arc<dyn NotificationService> = Arc::new(NotificationServiceImpl::new(tx.clone()));
     let realtime_svc = Arc::new(MissionRealtimeService::new());
+    let jwt_keys = Arc::new(JwtKeys::from_env(&get_jwt_env()?));
+    let stream_tickets = StreamTicketStore::new();
+    let sessions = Arc::new(SessionAuthenticator::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(LoginAttemptPostgres::new(Arc::clone(&db_pool))),
+        Arc::clone(&jwt_keys),
+    ));
+    let api_keys = Arc::new(ApiKeyAuthenticator::new(
+        Arc::new(ApiKeyPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+    ));
+
+    jobs::mission_purge::spawn(Arc::clone(&db_pool), Arc::clone(&notification_svc));
+    jobs::mission_scheduler::spawn(
+        Arc::clone(&db_pool),
+        Arc::clone(&notification_svc),
+        Arc::clone(&realtime_svc),
+    );
+    jobs::mission_recurrence::spawn(
+        Arc::clone(&db_pool),
+        Arc::clone(&notification_svc),
+        Arc::clone(&realtime_svc),
+    );
+    jobs::mission_invites::spawn(
+        Arc::clone(&db_pool),
+        Arc::clone(&notification_svc),
+        Arc::clone(&realtime_svc),
+    );
+    jobs::mission_waitlist::spawn(
+        Arc::clone(&db_pool),
+        Arc::clone(&notification_svc),
+        Arc::clone(&realtime_svc),
+    );
 
     let dir = "statics";
     let index_path = format!("{dir}/index.html");
@@ -105,8 +166,15 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
     };
 
     let app = Router::new()
-        .nest("/api", api_serve(db_pool, notification_svc, tx, realtime_svc))
+        .nest("/api", api_serve(db_pool, notification_svc, tx, realtime_svc, Arc::clone(&jwt_keys), Arc::clone(&api_keys)))
         .fallback_service(static_service)
+        .layer(Extension(jwt_keys))
+        .layer(Extension(stream_tickets))
+        .layer(Extension(api_keys))
+        .layer(Extension(sessions))
+        .layer(Extension(get_auth_cookie_env()))
+        .layer(Extension(get_trusted_proxy_env()))
+        .layer(Extension(get_validation_env()))
         .layer(DefaultBodyLimit::disable())
         .layer(tower_http::timeout::TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
@@ -124,7 +192,7 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Res
     let listener = TcpListener::bind(addr).await?;
 
     info!("Server start on port {}", config.server.port);
-    axum::serve(listener, app)
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(shutdown_signal())
         .await?;
 
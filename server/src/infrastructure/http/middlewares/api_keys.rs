@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::Extensions,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{
+    application::use_cases::api_keys::ApiKeyUseCase,
+    domain::{
+        errors::{AppError, AppResult},
+        value_objects::api_key_model::ApiKeyScopes,
+    },
+    infrastructure::{
+        database::repositories::{api_keys::ApiKeyPostgres, brawlers::BrawlerPostgres},
+        http::extractors::CurrentBrawler,
+    },
+};
+
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+pub type ApiKeyAuthenticator = ApiKeyUseCase<ApiKeyPostgres, BrawlerPostgres>;
+
+/// Scope an API key needs for the current route, set by `allow_api_key_scope`.
+#[derive(Debug, Clone, Copy)]
+pub struct AllowedApiKeyScope(pub ApiKeyScopes);
+
+/// Opens a router to API keys holding `scope`. Layer it outside `auth`; routes
+/// without it only accept user sessions.
+pub async fn allow_api_key_scope(
+    State(scope): State<ApiKeyScopes>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    req.extensions_mut().insert(AllowedApiKeyScope(scope));
+    next.run(req).await
+}
+
+pub async fn authenticate_api_key(
+    extensions: &Extensions,
+    key: &str,
+) -> AppResult<CurrentBrawler> {
+    let AllowedApiKeyScope(required) = extensions
+        .get::<AllowedApiKeyScope>()
+        .copied()
+        .ok_or_else(|| {
+            AppError::Forbidden("API keys are not accepted on this route".to_string())
+        })?;
+
+    let authenticator = extensions
+        .get::<Arc<ApiKeyAuthenticator>>()
+        .cloned()
+        .ok_or_else(|| {
+            AppError::Internal(anyhow::anyhow!(
+                "API key authenticator is missing from the request extensions"
+            ))
+        })?;
+
+    let principal = authenticator.authenticate(key).await?;
+    if !principal.scopes.contains(&required) {
+        return Err(AppError::Forbidden(format!(
+            "API key lacks the {} scope",
+            required
+        )));
+    }
+
+    Ok(CurrentBrawler::from_api_key(principal))
+}
@@ -1,46 +1,76 @@
 use axum::{
     extract::Request,
-    http::{StatusCode, header},
+    http::{Extensions, HeaderMap},
     middleware::Next,
     response::Response,
 };
 
-use crate::{config::config_loader::get_jwt_env, infrastructure::jwt::verify_token};
-
-pub async fn auth(mut req: Request, next: Next) -> Result<Response, StatusCode> {
-    let token = if let Some(header) = req.headers().get(header::AUTHORIZATION) {
-        header
-            .to_str()
-            .ok()
-            .and_then(|value| value.strip_prefix("Bearer "))
-            .map(|s| s.to_string())
-    } else {
-        // Fallback: Check for token in query parameters (for EventSource)
-        req.uri()
-            .query()
-            .and_then(|q| {
-                q.split('&')
-                    .find(|param| param.starts_with("token="))
-                    .map(|param| param.trim_start_matches("token=").to_string())
-            })
+use crate::{
+    application::use_cases::authentication::AuthenticationUseCase,
+    domain::errors::{AppError, AppResult},
+    infrastructure::{
+        database::repositories::{brawlers::BrawlerPostgres, login_attempts::LoginAttemptPostgres},
+        http::{
+            extractors::{
+                CurrentBrawler,
+                current_brawler::{missing_credentials, request_token},
+            },
+            middlewares::api_keys::{API_KEY_HEADER, authenticate_api_key},
+        },
+        stream_tickets::StreamTicketStore,
+    },
+};
+
+/// Checks access tokens against the current state of their brawler.
+pub type SessionAuthenticator = AuthenticationUseCase<BrawlerPostgres, LoginAttemptPostgres>;
+
+async fn authenticate(headers: &HeaderMap, extensions: &Extensions) -> AppResult<CurrentBrawler> {
+    let token = request_token(headers, extensions).ok_or_else(missing_credentials)?;
+
+    CurrentBrawler::authenticate(extensions, &token).await
+}
+
+pub async fn auth(mut req: Request, next: Next) -> AppResult<Response> {
+    let api_key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+
+    let current = match api_key {
+        Some(key) => authenticate_api_key(req.extensions(), &key).await?,
+        None => authenticate(req.headers(), req.extensions()).await?,
     };
 
-    let token = token.ok_or(StatusCode::UNAUTHORIZED)?;
-    
-    let jwt_env = get_jwt_env().map_err(|e| {
-        eprintln!(">>> ERROR: Failed to load JWT config: {}", e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })?;
-    let secret = jwt_env.secret;
+    req.extensions_mut().insert(current);
 
-    let claims = verify_token(secret, token).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    Ok(next.run(req).await)
+}
 
-    let user_id = claims
-        .sub
-        .parse::<i32>()
-        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+/// `auth` for `EventSource`/WebSocket endpoints, which additionally accept a
+/// single-use `ticket` query parameter from `POST /api/authentication/stream-ticket`.
+pub async fn stream_auth(mut req: Request, next: Next) -> AppResult<Response> {
+    let ticket = req.uri().query().and_then(|q| {
+        q.split('&')
+            .find_map(|param| param.strip_prefix("ticket="))
+            .map(|ticket| ticket.to_string())
+    });
+
+    let current = match ticket {
+        Some(ticket) => {
+            let tickets = req.extensions().get::<StreamTicketStore>().ok_or_else(|| {
+                AppError::Internal(anyhow::anyhow!(
+                    "Stream ticket store is missing from the request extensions"
+                ))
+            })?;
+            tickets.redeem(&ticket).ok_or_else(|| {
+                AppError::Unauthorized("Invalid or expired stream ticket".to_string())
+            })?
+        }
+        None => authenticate(req.headers(), req.extensions()).await?,
+    };
 
-    req.extensions_mut().insert(user_id);
+    req.extensions_mut().insert(current);
 
     Ok(next.run(req).await)
 }
@@ -0,0 +1,32 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{
+    domain::{
+        errors::{AppError, AppResult},
+        value_objects::roles::Roles,
+    },
+    infrastructure::http::extractors::{CurrentBrawler, current_brawler::missing_credentials},
+};
+
+/// Must be layered inside `auth`, which puts the caller into the request extensions.
+pub async fn require_role(
+    State(required): State<Roles>,
+    req: Request,
+    next: Next,
+) -> AppResult<Response> {
+    let role = req
+        .extensions()
+        .get::<CurrentBrawler>()
+        .map(|current| current.role)
+        .ok_or_else(missing_credentials)?;
+
+    if !role.satisfies(required) {
+        return Err(AppError::Forbidden("Insufficient role".to_string()));
+    }
+
+    Ok(next.run(req).await)
+}
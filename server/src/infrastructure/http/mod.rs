@@ -1,3 +1,7 @@
+pub mod auth_cookie;
+pub mod error_response;
+pub mod extractors;
 pub mod http_serv;
 pub mod middlewares;
+pub mod openapi;
 pub mod routers;
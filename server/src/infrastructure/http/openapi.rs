@@ -0,0 +1,262 @@
+use utoipa::{
+    OpenApi,
+    openapi::{
+        self, ContentBuilder, Ref, ResponseBuilder,
+        path::Operation,
+        security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+    },
+};
+
+use crate::{
+    domain::value_objects::pagination::SortDirection,
+    infrastructure::http::{
+        error_response::ErrorResponse,
+        middlewares::api_keys::API_KEY_HEADER,
+        routers,
+    },
+};
+
+/// Every router, nested under the same prefixes as `http_serv::api_serve`.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "ASTRA API",
+        description = "Missions, crews and brawlers. Errors share the `ErrorResponse` body."
+    ),
+    nest(
+        (path = "/api", api = routers::api_docs::ApiDoc, tags = ["docs"]),
+        (path = "/api/brawler", api = routers::brawlers::ApiDoc, tags = ["brawler"]),
+        (path = "/api/view", api = routers::mission_viewing::ApiDoc, tags = ["mission-viewing"]),
+        (path = "/api/mission", api = routers::mission_operation::ApiDoc, tags = ["mission-operation"]),
+        (path = "/api/crew", api = routers::crew_operation::ApiDoc, tags = ["crew"]),
+        (path = "/api/mission-chat", api = routers::mission_chat::ApiDoc, tags = ["mission-chat"]),
+        (path = "/api/ws/mission", api = routers::mission_ws::ApiDoc, tags = ["mission-chat"]),
+        (path = "/api/mission-management", api = routers::mission_management::ApiDoc, tags = ["mission-management"]),
+        (path = "/api/mission-objectives", api = routers::mission_objectives::ApiDoc, tags = ["mission-objectives"]),
+        (path = "/api/mission-templates", api = routers::mission_templates::ApiDoc, tags = ["mission-templates"]),
+        (path = "/api/authentication", api = routers::authentication::ApiDoc, tags = ["authentication"]),
+        (path = "/api/authentication/oidc", api = routers::oidc_authentication::ApiDoc, tags = ["authentication"]),
+        (path = "/api/admin", api = routers::admin::ApiDoc, tags = ["admin"]),
+        (path = "/api/api-keys", api = routers::api_keys::ApiDoc, tags = ["api-keys"]),
+        (path = "/api/achievements", api = routers::achievements::ApiDoc, tags = ["achievements"]),
+        (path = "/api/notifications", api = routers::notifications::ApiDoc, tags = ["notifications"]),
+        (path = "/api/mission-invites", api = routers::mission_invites::ApiDoc, tags = ["mission-invites"]),
+        (path = "/api/mission-ratings", api = routers::mission_ratings::ApiDoc, tags = ["mission-ratings"]),
+        (path = "/api/util", api = routers::default_router::ApiDoc, tags = ["util"]),
+    ),
+    components(schemas(ErrorResponse, SortDirection)),
+)]
+pub struct ApiDoc;
+
+/// The served document: `ApiDoc` plus the security schemes the paths refer to
+/// and the shared error body on every operation.
+pub fn document(cookie_name: &str) -> openapi::OpenApi {
+    let mut doc = ApiDoc::openapi();
+
+    let components = doc.components.get_or_insert_with(Default::default);
+    components.add_security_scheme(
+        "bearer_auth",
+        SecurityScheme::Http(
+            HttpBuilder::new()
+                .scheme(HttpAuthScheme::Bearer)
+                .bearer_format("JWT")
+                .build(),
+        ),
+    );
+    components.add_security_scheme(
+        "cookie_auth",
+        SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::with_description(
+            cookie_name,
+            "Set by login, register and the OIDC callback when cookie auth is enabled",
+        ))),
+    );
+    components.add_security_scheme(
+        "api_key",
+        SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+            API_KEY_HEADER,
+            "Accepted only on routes that list a scope",
+        ))),
+    );
+    components.add_security_scheme(
+        "stream_ticket",
+        SecurityScheme::ApiKey(ApiKey::Query(ApiKeyValue::with_description(
+            "ticket",
+            "Single-use ticket from POST /api/authentication/stream-ticket",
+        ))),
+    );
+
+    let error = ResponseBuilder::new()
+        .description("Error")
+        .content(
+            "application/json",
+            ContentBuilder::new()
+                .schema(Some(Ref::from_schema_name("ErrorResponse")))
+                .build(),
+        )
+        .build();
+
+    for item in doc.paths.paths.values_mut() {
+        for operation in operations(item) {
+            operation
+                .responses
+                .responses
+                .entry("default".to_string())
+                .or_insert_with(|| error.clone().into());
+        }
+    }
+
+    doc
+}
+
+fn operations(item: &mut openapi::PathItem) -> impl Iterator<Item = &mut Operation> {
+    [
+        item.get.as_mut(),
+        item.put.as_mut(),
+        item.post.as_mut(),
+        item.delete.as_mut(),
+        item.patch.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, fs, path::Path};
+
+    use super::*;
+
+    const METHODS: [&str; 5] = ["get", "put", "post", "delete", "patch"];
+
+    /// The text between the parenthesis at `open` and its match.
+    fn call_args(source: &str, open: usize) -> &str {
+        let mut depth = 0;
+        for (i, c) in source[open..].char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return &source[open + 1..open + i];
+                    }
+                }
+                _ => {}
+            }
+        }
+        panic!("unbalanced parenthesis in router source");
+    }
+
+    fn calls<'a>(source: &'a str, name: &str) -> Vec<&'a str> {
+        source
+            .match_indices(name)
+            .map(|(at, _)| call_args(source, at + name.len() - 1))
+            .collect()
+    }
+
+    fn first_literal(args: &str) -> Option<&str> {
+        let start = args.find('"')? + 1;
+        let end = start + args[start..].find('"')?;
+        Some(&args[start..end])
+    }
+
+    fn read_source(relative: &str) -> String {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(relative);
+        fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e))
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn normalize(path: &str) -> String {
+        match path.trim_end_matches('/') {
+            "" => "/".to_string(),
+            trimmed => trimmed.to_string(),
+        }
+    }
+
+    /// `METHOD /path` for every route mounted by `api_serve`, read from the router sources.
+    fn mounted_routes() -> BTreeSet<String> {
+        let server = read_source("src/infrastructure/http/http_serv.rs");
+        let api_serve = &server[server.find("fn api_serve").expect("api_serve not found")..];
+        let api_serve = &api_serve[..api_serve.find("\n}").unwrap()];
+
+        let mut mounts: Vec<(&str, &str)> = Vec::new();
+        for args in calls(api_serve, ".nest(") {
+            let prefix = first_literal(args).expect("nest without a prefix");
+            mounts.push((prefix, args));
+        }
+        for args in calls(api_serve, ".merge(") {
+            mounts.push(("", args));
+        }
+
+        let mut routes = BTreeSet::new();
+        for (prefix, args) in mounts {
+            let module = args
+                .split("routers::")
+                .nth(1)
+                .and_then(|rest| rest.split("::").next())
+                .unwrap_or_else(|| panic!("cannot tell which router is mounted at {:?}", prefix));
+            let source = read_source(&format!("src/infrastructure/http/routers/{}.rs", module));
+
+            for route in calls(&source, ".route(") {
+                let path = first_literal(route).expect("route without a path");
+                for method in METHODS {
+                    let pattern = format!("{}(", method);
+                    let used = route.match_indices(&pattern).any(|(at, _)| {
+                        !route[..at]
+                            .chars()
+                            .next_back()
+                            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == ':')
+                    });
+                    if used {
+                        routes.insert(format!(
+                            "{} {}",
+                            method.to_uppercase(),
+                            normalize(&format!("/api{}{}", prefix, path))
+                        ));
+                    }
+                }
+            }
+        }
+        routes
+    }
+
+    fn documented_routes() -> BTreeSet<String> {
+        let mut doc = document("access_token");
+        let mut routes = BTreeSet::new();
+        for (path, item) in doc.paths.paths.iter_mut() {
+            let methods = [
+                ("GET", item.get.is_some()),
+                ("PUT", item.put.is_some()),
+                ("POST", item.post.is_some()),
+                ("DELETE", item.delete.is_some()),
+                ("PATCH", item.patch.is_some()),
+            ];
+            for (method, present) in methods {
+                if present {
+                    routes.insert(format!("{} {}", method, normalize(path)));
+                }
+            }
+        }
+        routes
+    }
+
+    #[test]
+    fn every_route_is_documented() {
+        let mounted = mounted_routes();
+        let documented = documented_routes();
+        assert!(!mounted.is_empty(), "no routes found in the router sources");
+
+        let undocumented: Vec<_> = mounted.difference(&documented).collect();
+        assert!(
+            undocumented.is_empty(),
+            "routes missing a #[utoipa::path] in their router's ApiDoc: {:?}",
+            undocumented
+        );
+
+        let stale: Vec<_> = documented.difference(&mounted).collect();
+        assert!(stale.is_empty(), "documented routes that are not mounted: {:?}", stale);
+    }
+}
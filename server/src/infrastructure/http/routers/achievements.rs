@@ -1,35 +1,47 @@
 use std::sync::Arc;
 use axum::{
-    extract::{State, Extension},
+    extract::{State},
     response::IntoResponse,
-    http::StatusCode,
     Json,
     Router,
     routing::get,
     middleware,
 };
+use utoipa::OpenApi;
 use crate::{
     application::use_cases::achievements::AchievementUseCase,
-    domain::repositories::AchievementRepository,
+    domain::{
+        repositories::AchievementRepository, value_objects::achievement_model::AchievementViewModel,
+    },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::achievements::AchievementRepositoryImpl,
         },
-        http::middlewares::auth::auth,
+        http::{extractors::CurrentBrawler, middlewares::auth::auth},
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(get_my_achievements))]
+pub struct ApiDoc;
+
+#[utoipa::path(
+    get,
+    path = "",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Every achievement and whether the caller has earned it", body = [AchievementViewModel])),
+)]
 pub async fn get_my_achievements<T>(
     State(use_case): State<Arc<AchievementUseCase<T>>>,
-    Extension(user_id): Extension<i32>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
 ) -> impl IntoResponse
 where
     T: AchievementRepository + Send + Sync,
 {
     match use_case.get_my_achievements(user_id).await {
         Ok(achievements) => Json(achievements).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
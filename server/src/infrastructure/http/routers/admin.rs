@@ -0,0 +1,368 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, patch, post},
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::admin::AdminUseCase,
+    domain::{
+        entities::achievements::Achievement,
+        repositories::{
+            AchievementRepository, BrawlerRepository,
+            mission_management::MissionManagementRepository,
+        },
+        value_objects::{
+            achievement_model::{AddAchievementModel, EditAchievementModel},
+            admin_model::{AdminBrawlerModel, UpdateRoleModel},
+            roles::Roles,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres,
+                mission_management::MissionManagementPostgres,
+            },
+        },
+        http::{
+            extractors::{CurrentBrawler, ValidatedJson},
+            middlewares::{auth::auth, roles::require_role},
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_brawlers,
+    disable_brawler,
+    enable_brawler,
+    update_role,
+    force_delete_mission,
+    restore_mission,
+    list_achievements,
+    add_achievement,
+    edit_achievement,
+    remove_achievement,
+    award_achievement,
+    revoke_achievement,
+))]
+pub struct ApiDoc;
+
+type AdminState<T1, T2, T3> = State<Arc<AdminUseCase<T1, T2, T3>>>;
+
+#[utoipa::path(
+    get,
+    path = "/users",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Every brawler with moderation details", body = [AdminBrawlerModel])),
+)]
+pub async fn list_brawlers<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.list_brawlers().await {
+        Ok(brawlers) => (StatusCode::OK, Json(brawlers)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/users/{brawler_id}/disable",
+    params(("brawler_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Brawler disabled")),
+)]
+pub async fn disable_brawler<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, role, .. }: CurrentBrawler,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.set_disabled(user_id, role, brawler_id, true).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/users/{brawler_id}/enable",
+    params(("brawler_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Brawler enabled")),
+)]
+pub async fn enable_brawler<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, role, .. }: CurrentBrawler,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.set_disabled(user_id, role, brawler_id, false).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/users/{brawler_id}/role",
+    params(("brawler_id" = i32, Path)),
+    request_body = UpdateRoleModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Role updated")),
+)]
+pub async fn update_role<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(brawler_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<UpdateRoleModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.set_role(user_id, brawler_id, model.role).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/missions/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Mission deleted")),
+)]
+pub async fn force_delete_mission<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.force_delete_mission(mission_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/missions/{mission_id}/restore",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Mission restored; only within the retention window")),
+)]
+pub async fn restore_mission<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.restore_mission(mission_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/achievements",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Every achievement", body = [Achievement])),
+)]
+pub async fn list_achievements<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.list_achievements().await {
+        Ok(achievements) => (StatusCode::OK, Json(achievements)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/achievements",
+    request_body = AddAchievementModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 201, description = "Achievement created", body = Object, example = json!({ "achievement_id": 1 }))),
+)]
+pub async fn add_achievement<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    ValidatedJson(model): ValidatedJson<AddAchievementModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.add_achievement(model).await {
+        Ok(achievement_id) => (
+            StatusCode::CREATED,
+            Json(serde_json::json!({ "achievement_id": achievement_id })),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/achievements/{achievement_id}",
+    params(("achievement_id" = i32, Path)),
+    request_body = EditAchievementModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Achievement updated")),
+)]
+pub async fn edit_achievement<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    Path(achievement_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<EditAchievementModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.edit_achievement(achievement_id, model).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/achievements/{achievement_id}",
+    params(("achievement_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Achievement removed")),
+)]
+pub async fn remove_achievement<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    Path(achievement_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.remove_achievement(achievement_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/achievements/{achievement_id}/award/{brawler_id}",
+    params(("achievement_id" = i32, Path), ("brawler_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Achievement awarded")),
+)]
+pub async fn award_achievement<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    Path((achievement_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.award_achievement(achievement_id, brawler_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/achievements/{achievement_id}/award/{brawler_id}",
+    params(("achievement_id" = i32, Path), ("brawler_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Achievement revoked")),
+)]
+pub async fn revoke_achievement<T1, T2, T3>(
+    State(user_case): AdminState<T1, T2, T3>,
+    Path((achievement_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+{
+    match user_case.revoke_achievement(achievement_id, brawler_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let mission_management_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
+    let achievement_repository = AchievementRepositoryImpl::new(db_pool);
+    let user_case = Arc::new(AdminUseCase::new(
+        Arc::new(brawler_repository),
+        Arc::new(mission_management_repository),
+        Arc::new(achievement_repository),
+    ));
+
+    // Moderators handle day-to-day moderation; admins also manage roles and achievements.
+    let moderator_routes = Router::new()
+        .route("/users", get(list_brawlers))
+        .route("/users/{brawler_id}/disable", patch(disable_brawler))
+        .route("/users/{brawler_id}/enable", patch(enable_brawler))
+        .route("/missions/{mission_id}", delete(force_delete_mission))
+        .route("/missions/{mission_id}/restore", post(restore_mission))
+        .route_layer(middleware::from_fn_with_state(Roles::Moderator, require_role));
+
+    let admin_routes = Router::new()
+        .route("/users/{brawler_id}/role", patch(update_role))
+        .route("/achievements", get(list_achievements).post(add_achievement))
+        .route(
+            "/achievements/{achievement_id}",
+            patch(edit_achievement).delete(remove_achievement),
+        )
+        .route(
+            "/achievements/{achievement_id}/award/{brawler_id}",
+            post(award_achievement).delete(revoke_achievement),
+        )
+        .route_layer(middleware::from_fn_with_state(Roles::Admin, require_role));
+
+    moderator_routes
+        .merge(admin_routes)
+        .route_layer(middleware::from_fn(auth))
+        .with_state(user_case)
+}
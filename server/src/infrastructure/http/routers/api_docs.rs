@@ -0,0 +1,22 @@
+use axum::{Extension, Json, Router, response::IntoResponse, routing::get};
+use utoipa::OpenApi;
+
+use crate::{config::config_model::AuthCookieEnv, infrastructure::http::openapi};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_openapi))]
+pub struct ApiDoc;
+
+/// The docs UI under `statics/api-docs` reads this document.
+#[utoipa::path(
+    get,
+    path = "/openapi.json",
+    responses((status = 200, description = "This OpenAPI document", body = Object)),
+)]
+pub async fn get_openapi(Extension(cookie_env): Extension<AuthCookieEnv>) -> impl IntoResponse {
+    Json(openapi::document(&cookie_env.name))
+}
+
+pub fn routes() -> Router {
+    Router::new().route("/openapi.json", get(get_openapi))
+}
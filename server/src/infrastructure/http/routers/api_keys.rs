@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get},
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::api_keys::ApiKeyUseCase,
+    domain::{
+        repositories::{ApiKeyRepository, BrawlerRepository},
+        value_objects::api_key_model::{
+            AddApiKeyModel, AddBotModel, ApiKeyModel, BotModel, CreatedApiKeyModel,
+        },
+    },
+    infrastructure::{
+        database::repositories::{api_keys::ApiKeyPostgres, brawlers::BrawlerPostgres},
+        http::{
+            extractors::{CurrentBrawler, ValidatedJson},
+            middlewares::{api_keys::ApiKeyAuthenticator, auth::auth},
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_keys, add_key, revoke_key, get_bots, add_bot))]
+pub struct ApiDoc;
+
+type ApiKeyState<T1, T2> = State<Arc<ApiKeyUseCase<T1, T2>>>;
+
+#[utoipa::path(
+    get,
+    path = "",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Keys of the caller and of the caller's bots", body = [ApiKeyModel])),
+)]
+pub async fn get_keys<T1, T2>(
+    State(user_case): ApiKeyState<T1, T2>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+) -> impl IntoResponse
+where
+    T1: ApiKeyRepository + Send + Sync,
+    T2: BrawlerRepository + Send + Sync,
+{
+    match user_case.get_keys(user_id).await {
+        Ok(keys) => (StatusCode::OK, Json(keys)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = AddApiKeyModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 201, description = "The new key; the plain key is only shown once", body = CreatedApiKeyModel)),
+)]
+pub async fn add_key<T1, T2>(
+    State(user_case): ApiKeyState<T1, T2>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    ValidatedJson(model): ValidatedJson<AddApiKeyModel>,
+) -> impl IntoResponse
+where
+    T1: ApiKeyRepository + Send + Sync,
+    T2: BrawlerRepository + Send + Sync,
+{
+    match user_case.add_key(user_id, model).await {
+        Ok(created) => (StatusCode::CREATED, Json(created)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    params(("id" = i32, Path, description = "API key id")),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Key revoked")),
+)]
+pub async fn revoke_key<T1, T2>(
+    State(user_case): ApiKeyState<T1, T2>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(api_key_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: ApiKeyRepository + Send + Sync,
+    T2: BrawlerRepository + Send + Sync,
+{
+    match user_case.revoke_key(user_id, api_key_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/bots",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Bots owned by the caller", body = [BotModel])),
+)]
+pub async fn get_bots<T1, T2>(
+    State(user_case): ApiKeyState<T1, T2>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+) -> impl IntoResponse
+where
+    T1: ApiKeyRepository + Send + Sync,
+    T2: BrawlerRepository + Send + Sync,
+{
+    match user_case.get_bots(user_id).await {
+        Ok(bots) => (StatusCode::OK, Json(bots)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/bots",
+    request_body = AddBotModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 201, description = "The new bot", body = BotModel)),
+)]
+pub async fn add_bot<T1, T2>(
+    State(user_case): ApiKeyState<T1, T2>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    ValidatedJson(model): ValidatedJson<AddBotModel>,
+) -> impl IntoResponse
+where
+    T1: ApiKeyRepository + Send + Sync,
+    T2: BrawlerRepository + Send + Sync,
+{
+    match user_case.add_bot(user_id, model).await {
+        Ok(bot) => (StatusCode::CREATED, Json(bot)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+/// Key management is not opened to API keys themselves, so a leaked key cannot mint more keys.
+pub fn routes(api_keys: Arc<ApiKeyAuthenticator>) -> Router {
+    Router::new()
+        .route(
+            "/",
+            get(get_keys::<ApiKeyPostgres, BrawlerPostgres>)
+                .post(add_key::<ApiKeyPostgres, BrawlerPostgres>),
+        )
+        .route("/{id}", delete(revoke_key::<ApiKeyPostgres, BrawlerPostgres>))
+        .route(
+            "/bots",
+            get(get_bots::<ApiKeyPostgres, BrawlerPostgres>)
+                .post(add_bot::<ApiKeyPostgres, BrawlerPostgres>),
+        )
+        .route_layer(middleware::from_fn(auth))
+        .with_state(api_keys)
+}
@@ -1,48 +1,215 @@
-use std::sync::Arc;
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    Extension, Json, Router,
+    extract::{ConnectInfo, State},
+    http::{HeaderMap, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum_extra::extract::CookieJar;
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::authentication::AuthenticationUseCase,
+    config::config_model::{AuthCookieEnv, TrustedProxyEnv},
+    domain::{
+        repositories::{brawlers::BrawlerRepository, login_attempts::LoginAttemptRepository},
+        value_objects::login_attempt_model::FailedLoginModel,
+    },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
-        jwt::authentication_model::{LoginModel, RecoverPasswordModel},
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{brawlers::BrawlerPostgres, login_attempts::LoginAttemptPostgres},
+        },
+        http::{auth_cookie, extractors::{CurrentBrawler, ValidatedJson}, middlewares::auth::auth},
+        jwt::{
+            JwtKeys,
+            authentication_model::{LoginModel, RecoverPasswordModel, StreamTicketModel},
+            jwt_model::Passport,
+        },
+        stream_tickets::{STREAM_TICKET_TTL, StreamTicketStore},
     },
 };
 
-pub async fn login<T>(
-    State(user_case): State<Arc<AuthenticationUseCase<T>>>,
-    Json(model): Json<LoginModel>,
+#[derive(OpenApi)]
+#[openapi(paths(login, logout, get_failed_logins, issue_stream_ticket))]
+pub struct ApiDoc;
+
+/// The socket address, unless it belongs to a trusted proxy. Then `X-Forwarded-For` is read
+/// from the right and the first hop that is not itself a trusted proxy is the client.
+fn client_ip(headers: &HeaderMap, addr: SocketAddr, trusted: &TrustedProxyEnv) -> IpAddr {
+    let peer = addr.ip();
+    if !trusted.proxies.contains(&peer) {
+        return peer;
+    }
+
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|hop| hop.trim().parse::<IpAddr>())
+        .collect::<Result<Vec<_>, _>>()
+        .ok()
+        .and_then(|hops| hops.into_iter().rev().find(|hop| !trusted.proxies.contains(hop)))
+        .unwrap_or(peer)
+}
+
+#[utoipa::path(
+    post,
+    path = "/login",
+    request_body = LoginModel,
+    responses((status = 200, description = "Signed in; also sets the auth cookie", body = Passport)),
+)]
+pub async fn login<T1, T2>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(cookie_env): Extension<AuthCookieEnv>,
+    Extension(trusted_proxies): Extension<TrustedProxyEnv>,
+    jar: CookieJar,
+    headers: HeaderMap,
+    ValidatedJson(model): ValidatedJson<LoginModel>,
 ) -> impl IntoResponse
 where
-    T: crate::domain::repositories::brawlers::BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: LoginAttemptRepository + Send + Sync,
 {
-    match user_case.login(model).await {
-        Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
+    match user_case.login(model, Some(client_ip(&headers, addr, &trusted_proxies).to_string())).await {
+        Ok(passport) => (
+            auth_cookie::with_token(jar, &cookie_env, &passport.token),
+            Json(passport),
+        )
+            .into_response(),
 
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn recover_password<T>(
-    State(user_case): State<Arc<AuthenticationUseCase<T>>>,
-    Json(model): Json<RecoverPasswordModel>,
+pub async fn recover_password<T1, T2>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+    ValidatedJson(model): ValidatedJson<RecoverPasswordModel>,
 ) -> impl IntoResponse
 where
-    T: crate::domain::repositories::brawlers::BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: LoginAttemptRepository + Send + Sync,
 {
     match user_case.recover_password(model).await {
         Ok(msg) => (StatusCode::OK, Json(serde_json::json!({ "message": msg }))).into_response(),
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let user_case = AuthenticationUseCase::new(Arc::new(repository));
+#[utoipa::path(
+    get,
+    path = "/failed-logins",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Failed sign-ins on the caller's account", body = [FailedLoginModel])),
+)]
+pub async fn get_failed_logins<T1, T2>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+    CurrentBrawler { id: brawler_id, .. }: CurrentBrawler,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: LoginAttemptRepository + Send + Sync,
+{
+    match user_case.get_failed_logins(brawler_id).await {
+        Ok(attempts) => (StatusCode::OK, Json(attempts)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/logout",
+    responses((status = 204, description = "Auth cookie cleared")),
+)]
+pub async fn logout(
+    Extension(cookie_env): Extension<AuthCookieEnv>,
+    jar: CookieJar,
+) -> impl IntoResponse {
+    (auth_cookie::without_token(jar, &cookie_env), StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/stream-ticket",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 201, description = "A single-use ticket for stream endpoints", body = StreamTicketModel)),
+)]
+pub async fn issue_stream_ticket(
+    current: CurrentBrawler,
+    Extension(tickets): Extension<StreamTicketStore>,
+) -> impl IntoResponse {
+    let ticket = tickets.issue(current);
+
+    (
+        StatusCode::CREATED,
+        Json(StreamTicketModel {
+            ticket,
+            expires_in: STREAM_TICKET_TTL.as_secs(),
+        }),
+    )
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, jwt_keys: Arc<JwtKeys>) -> Router {
+    let repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let login_attempt_repository = LoginAttemptPostgres::new(Arc::clone(&db_pool));
+    let user_case = AuthenticationUseCase::new(
+        Arc::new(repository),
+        Arc::new(login_attempt_repository),
+        jwt_keys,
+    );
+
+    let protected_routes = Router::new()
+        .route("/failed-logins", get(get_failed_logins))
+        .route("/stream-ticket", post(issue_stream_ticket))
+        .route_layer(middleware::from_fn(auth));
 
     Router::new()
+        .merge(protected_routes)
         .route("/login", post(login))
+        .route("/logout", post(logout))
         // .route("/recover-password", post(recover_password))
         .with_state(Arc::new(user_case))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forwarded(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", value.parse().unwrap());
+        headers
+    }
+
+    fn trusted(proxies: &[&str]) -> TrustedProxyEnv {
+        TrustedProxyEnv { proxies: proxies.iter().map(|ip| ip.parse().unwrap()).collect() }
+    }
+
+    #[test]
+    fn forwarded_header_is_ignored_from_untrusted_peers() {
+        let peer: SocketAddr = "203.0.113.9:5000".parse().unwrap();
+        let ip = client_ip(&forwarded("198.51.100.1"), peer, &trusted(&["10.0.0.1"]));
+        assert_eq!(ip, peer.ip());
+    }
+
+    #[test]
+    fn trusted_proxy_hops_are_skipped_from_the_right() {
+        let peer: SocketAddr = "10.0.0.1:5000".parse().unwrap();
+        let proxies = trusted(&["10.0.0.1", "10.0.0.2"]);
+
+        let ip = client_ip(&forwarded("1.1.1.1, 198.51.100.1, 10.0.0.2"), peer, &proxies);
+        assert_eq!(ip, "198.51.100.1".parse::<IpAddr>().unwrap());
+
+        let ip = client_ip(&forwarded("not-an-ip"), peer, &proxies);
+        assert_eq!(ip, peer.ip());
+    }
+}
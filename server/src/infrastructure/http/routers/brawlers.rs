@@ -3,26 +3,69 @@ use std::sync::Arc;
 use axum::{
     Extension, Json, Router,
     extract::State,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::IntoResponse,
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
 };
 
+use axum_extra::extract::CookieJar;
+use utoipa::OpenApi;
+
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
+    config::config_model::AuthCookieEnv,
     domain::{
-        repositories::BrawlerRepository,
-        value_objects::{brawler_model::{RegisterBrawlerModel, UpdateProfileModel}, uploaded_img::UploadBase64Img},
+        repositories::{BrawlerRepository, mission_waitlist::MissionWaitlistRepository},
+        services::notification_service::NotificationService,
+        value_objects::{
+            MissionModel,
+            brawler_model::{BrawlerModel, BrawlerSort, RegisterBrawlerModel, UpdateProfileModel},
+            calendar::{CalendarFeedQuery, CalendarTokenModel},
+            mission_model::MissionSort,
+            mission_summary::MissionSummaryModel,
+            pagination::{Page, PageRequest},
+            uploaded_img::{UploadBase64Img, UploadedImg},
+        },
     },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
-        http::middlewares::auth::auth,
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{brawlers::BrawlerPostgres, mission_waitlist::MissionWaitlistPostgres},
+        },
+        http::{auth_cookie, extractors::{CurrentBrawler, ValidatedJson, ValidatedQuery}, middlewares::auth::auth},
+        jwt::{JwtKeys, jwt_model::Passport},
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let user_case = BrawlersUseCase::new(Arc::new(repository));
+#[derive(OpenApi)]
+#[openapi(paths(
+    register,
+    upload_avatar,
+    update_profile,
+    get_missions,
+    get_mission_summary,
+    get_leaderboard,
+    get_all_brawlers,
+    rotate_calendar_token,
+    revoke_calendar_token,
+    get_calendar_feed,
+    delete_account,
+))]
+pub struct ApiDoc;
+
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    jwt_keys: Arc<JwtKeys>,
+    notification_service: Arc<dyn NotificationService>,
+) -> Router {
+    let repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let waitlist_repository = MissionWaitlistPostgres::new(db_pool);
+    let user_case = BrawlersUseCase::new(
+        Arc::new(repository),
+        Arc::new(waitlist_repository),
+        jwt_keys,
+        notification_service,
+    );
 
     let protected_routes = Router::new()
         .route("/avatar", post(upload_avatar))
@@ -31,75 +74,123 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/mission-summary", get(get_mission_summary))
         .route("/leaderboard", get(get_leaderboard))
         .route("/all", get(get_all_brawlers))
+        .route("/calendar-token", post(rotate_calendar_token).delete(revoke_calendar_token))
+        .route("/me", delete(delete_account))
         .route_layer(axum::middleware::from_fn(auth));
 
     Router::new()
         .merge(protected_routes)
         .route("/register", post(register))
+        .route("/calendar.ics", get(get_calendar_feed))
         .with_state(Arc::new(user_case))
 }
 
 // *เพิ่ม
-pub async fn get_leaderboard<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
+#[utoipa::path(
+    get,
+    path = "/leaderboard",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Top ten brawlers by completed missions", body = [BrawlerModel])),
+)]
+pub async fn get_leaderboard<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
     match user_case.get_leaderboard().await {
         Ok(leaderboard) => (StatusCode::OK, Json(leaderboard)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn get_missions<T>(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
-    Extension(brawler_id): Extension<i32>,
+#[utoipa::path(
+    get,
+    path = "/my-missions",
+    params(PageRequest<MissionSort>),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "A page of missions the caller leads", body = Page<MissionModel>)),
+)]
+pub async fn get_missions<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    CurrentBrawler { id: brawler_id, .. }: CurrentBrawler,
+    ValidatedQuery(page): ValidatedQuery<PageRequest<MissionSort>>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
-    match brawlers_use_case.get_missions(brawler_id).await {
+    match brawlers_use_case.get_missions(brawler_id, &page).await {
         Ok(missions) => (StatusCode::OK, Json(missions)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 // *เพิ่ม
-pub async fn get_mission_summary<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
-    Extension(brawler_id): Extension<i32>,
+#[utoipa::path(
+    get,
+    path = "/mission-summary",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Mission counts for the caller", body = MissionSummaryModel)),
+)]
+pub async fn get_mission_summary<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    CurrentBrawler { id: brawler_id, .. }: CurrentBrawler,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
     match user_case.get_mission_summary(brawler_id).await {
         Ok(summary) => (StatusCode::OK, Json(summary)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn register<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
-    Json(model): Json<RegisterBrawlerModel>,
+#[utoipa::path(
+    post,
+    path = "/register",
+    request_body = RegisterBrawlerModel,
+    responses((status = 201, description = "Registered and signed in; also sets the auth cookie", body = Passport)),
+)]
+pub async fn register<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    Extension(cookie_env): Extension<AuthCookieEnv>,
+    jar: CookieJar,
+    ValidatedJson(model): ValidatedJson<RegisterBrawlerModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
     match user_case.register(model).await {
-        Ok(passport) => (StatusCode::CREATED, Json(passport)).into_response(),
+        Ok(passport) => (
+            StatusCode::CREATED,
+            auth_cookie::with_token(jar, &cookie_env, &passport.token),
+            Json(passport),
+        )
+            .into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn upload_avatar<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
-    Extension(user_id): Extension<i32>,
-    Json(model): Json<UploadBase64Img>,
+#[utoipa::path(
+    post,
+    path = "/avatar",
+    request_body = UploadBase64Img,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "The uploaded avatar", body = UploadedImg)),
+)]
+pub async fn upload_avatar<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    ValidatedJson(model): ValidatedJson<UploadBase64Img>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
     match user_case
         .upload_base64img(user_id, model.base64_string)
@@ -107,32 +198,144 @@ where
     {
         Ok(upload_img) => (StatusCode::OK, Json(upload_img)).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn get_all_brawlers<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
+#[utoipa::path(
+    get,
+    path = "/all",
+    params(PageRequest<BrawlerSort>),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "A page of brawlers", body = Page<BrawlerModel>)),
+)]
+pub async fn get_all_brawlers<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    ValidatedQuery(page): ValidatedQuery<PageRequest<BrawlerSort>>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
-    match user_case.get_all_brawlers().await {
+    match user_case.get_all_brawlers(&page).await {
         Ok(brawlers) => (StatusCode::OK, Json(brawlers)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn update_profile<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
-    Extension(user_id): Extension<i32>,
-    Json(model): Json<UpdateProfileModel>,
+#[utoipa::path(
+    put,
+    path = "/profile",
+    request_body = UpdateProfileModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "The refreshed passport", body = Passport)),
+)]
+pub async fn update_profile<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    ValidatedJson(model): ValidatedJson<UpdateProfileModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
 {
     match user_case.update_profile(user_id, model).await {
         Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/calendar-token",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 201, description = "A new calendar feed token; the previous feed URL stops working", body = CalendarTokenModel)),
+)]
+pub async fn rotate_calendar_token<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.rotate_calendar_token(user_id).await {
+        Ok(token) => (StatusCode::CREATED, Json(token)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/calendar-token",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Calendar feed disabled")),
+)]
+pub async fn revoke_calendar_token<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.revoke_calendar_token(user_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
     }
-}
\ No newline at end of file
+}
+
+#[utoipa::path(
+    delete,
+    path = "/me",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Account closed and auth cookie cleared; led missions pass to their longest-standing member")),
+)]
+pub async fn delete_account<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    Extension(cookie_env): Extension<AuthCookieEnv>,
+    jar: CookieJar,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.delete_account(user_id).await {
+        Ok(_) => (auth_cookie::without_token(jar, &cookie_env), StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+/// Calendar clients cannot send auth headers, so the feed token in the query is the credential.
+#[utoipa::path(
+    get,
+    path = "/calendar.ics",
+    params(CalendarFeedQuery),
+    responses(
+        (status = 200, description = "iCalendar feed of scheduled missions the brawler leads or crews", body = String, content_type = "text/calendar"),
+        (status = 404, description = "Unknown or revoked token"),
+    ),
+)]
+pub async fn get_calendar_feed<T1, T2>(
+    State(user_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    ValidatedQuery(query): ValidatedQuery<CalendarFeedQuery>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.calendar_feed(&query.token).await {
+        Ok(feed) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+                (header::CONTENT_DISPOSITION, "inline; filename=\"astra-missions.ics\""),
+                (header::CACHE_CONTROL, "private, max-age=300"),
+            ],
+            feed,
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -6,19 +6,30 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, post},
+    routing::{delete, get, patch, post, put},
 };
 use serde_json::json;
+use utoipa::{OpenApi, ToSchema};
 
 use crate::{
     application::use_cases::crew_operation::CrewOperationUseCase,
+    config::config_model::ValidationEnv,
     domain::{
-        entities::mission_messages::NewMissionMessageEntity,
+        errors::FieldError,
         repositories::{
             crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
-            AchievementRepository, BrawlerRepository, mission_message_repository::MissionMessageRepository,
+            AchievementRepository, BrawlerRepository, MissionBanRepository, MissionJoinRequestRepository,
+            MissionWaitlistRepository,
+            mission_message_repository::MissionMessageRepository,
         },
         services::notification_service::NotificationService,
+        value_objects::{
+            ban_model::{BanModel, MissionBanModel},
+            join_request_model::{JoinOutcome, JoinRequestModel},
+            mission_role_model::{AssignRoleSlotModel, JoinCrewQuery, RoleSlotModel, SetRoleSlotsModel},
+            waitlist_model::WaitlistEntryModel,
+            validation::Validate,
+        },
     },
     infrastructure::{
         database::{
@@ -26,18 +37,55 @@ use crate::{
             repositories::{
                 crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres,
                 achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres,
-                mission_messages::MissionMessagePostgres,
+                mission_messages::MissionMessagePostgres, mission_join_requests::MissionJoinRequestPostgres,
+                mission_waitlist::MissionWaitlistPostgres, mission_bans::MissionBanPostgres,
             },
         },
-        http::middlewares::auth::auth,
-        services::mission_websocket_service::MissionWebSocketService,
+        http::{extractors::{CurrentBrawler, ValidatedJson, ValidatedQuery}, middlewares::auth::auth},
     },
 };
 
-pub async fn join<T1, T2, T3, T4, T5>(
-    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4, T5>>>,
-    Extension(user_id): Extension<i32>,
+#[derive(OpenApi)]
+#[openapi(paths(
+    join,
+    leave,
+    kick,
+    add_co_chief,
+    remove_co_chief,
+    transfer_chief,
+    get_role_slots,
+    set_role_slots,
+    assign_role_slot,
+    get_join_requests,
+    cancel_join_request,
+    approve_join_request,
+    reject_join_request,
+    get_waitlist,
+    join_waitlist,
+    leave_waitlist,
+    confirm_waitlist_offer,
+    get_bans,
+    lift_ban,
+))]
+pub struct ApiDoc;
+
+type CrewState<T1, T2, T3, T4, T5, T6, T7, T8> = State<Arc<CrewOperationUseCase<T1, T2, T3, T4, T5, T6, T7, T8>>>;
+
+#[utoipa::path(
+    post,
+    path = "/join/{mission_id}",
+    params(("mission_id" = i32, Path), JoinCrewQuery),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses(
+        (status = 200, description = "Joined the mission", body = Object, example = json!({ "message": "Join Mission_id:1 completed" })),
+        (status = 202, description = "The mission needs approval; a join request was sent to the chief", body = JoinRequestModel),
+    ),
+)]
+pub async fn join<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
+    ValidatedQuery(query): ValidatedQuery<JoinCrewQuery>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
@@ -45,36 +93,32 @@ where
     T3: AchievementRepository + Send + Sync,
     T4: BrawlerRepository + Send + Sync,
     T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
 {
-    match user_case.join(mission_id, user_id).await {
-        Ok(_) => (
+    match user_case.join(mission_id, user_id, query.slot_id).await {
+        Ok(JoinOutcome::Requested(request)) => (StatusCode::ACCEPTED, Json(request)).into_response(),
+        Ok(JoinOutcome::Joined) => (
             StatusCode::OK,
             Json(json!({ "message": format!("Join Mission_id:{} completed", mission_id) })),
         )
         .into_response(),
 
-        Err(e) => {
-            let error_message = e.to_string();
-            let status = if error_message.contains("Already joined") {
-                StatusCode::CONFLICT
-            } else if error_message.contains("Mission is full") {
-                StatusCode::CONFLICT
-            } else if error_message.contains("Mission is not joinable") {
-                StatusCode::BAD_REQUEST
-            } else if error_message.contains("The Chief can not join") {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-
-            (status, Json(json!({ "message": error_message }))).into_response()
-        }
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn leave<T1, T2, T3, T4, T5>(
-    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4, T5>>>,
-    Extension(user_id): Extension<i32>,
+#[utoipa::path(
+    delete,
+    path = "/leave/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Left the mission", body = Object, example = json!({ "message": "Leave Mission_id:1 completed" }))),
+)]
+pub async fn leave<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
@@ -83,6 +127,9 @@ where
     T3: AchievementRepository + Send + Sync,
     T4: BrawlerRepository + Send + Sync,
     T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
 {
     match user_case.leave(mission_id, user_id).await {
         Ok(_) => (
@@ -91,24 +138,55 @@ where
         )
         .into_response(),
 
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({ "message": e.to_string() })),
-        )
-            .into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, ToSchema)]
 pub struct KickModel {
     member_id: i32,
+    /// Also ban the member from the mission.
+    ban: Option<BanModel>,
 }
 
-pub async fn kick<T1, T2, T3, T4, T5>(
-    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3, T4, T5>>>,
-    Extension(user_id): Extension<i32>,
+impl Validate for KickModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if self.member_id <= 0 {
+            errors.push(FieldError::new("member_id", "must be a valid brawler id"));
+        }
+        if let Some(ban) = &self.ban {
+            ban.validate(limits, errors);
+        }
+    }
+}
+
+#[derive(serde::Deserialize, ToSchema)]
+pub struct TransferChiefModel {
+    /// Crew member who becomes the chief.
+    brawler_id: i32,
+}
+
+impl Validate for TransferChiefModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if self.brawler_id <= 0 {
+            errors.push(FieldError::new("brawler_id", "must be a valid brawler id"));
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/kick/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    request_body = KickModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Member removed from the crew, and banned when `ban` is given", body = Object, example = json!({ "message": "Member kicked" }))),
+)]
+pub async fn kick<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
-    Json(model): Json<KickModel>,
+    ValidatedJson(model): ValidatedJson<KickModel>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
@@ -116,26 +194,480 @@ where
     T3: AchievementRepository + Send + Sync,
     T4: BrawlerRepository + Send + Sync,
     T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
 {
     match user_case
-        .kick_crew(mission_id, user_id, model.member_id)
+        .kick_crew(mission_id, user_id, model.member_id, model.ban)
         .await
     {
         Ok(_) => (StatusCode::OK, Json(json!({ "message": "Member kicked" }))).into_response(),
-        Err(e) => {
-            let error_message = e.to_string();
-            let status = if error_message.contains("Only the Chief can kick members") {
-                StatusCode::FORBIDDEN
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, Json(json!({ "message": error_message }))).into_response()
-        }
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/co-chiefs/{mission_id}/{brawler_id}",
+    params(("mission_id" = i32, Path), ("brawler_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Crew member promoted to co-chief")),
+)]
+pub async fn add_co_chief<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.set_co_chief(mission_id, user_id, brawler_id, true).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/co-chiefs/{mission_id}/{brawler_id}",
+    params(("mission_id" = i32, Path), ("brawler_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Co-chief demoted to plain crew")),
+)]
+pub async fn remove_co_chief<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.set_co_chief(mission_id, user_id, brawler_id, false).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/chief/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    request_body = TransferChiefModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Mission handed over; the old chief stays on as crew", body = Object, example = json!({ "message": "Chief transferred" }))),
+)]
+pub async fn transfer_chief<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<TransferChiefModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.transfer_chief(mission_id, user_id, model.brawler_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "message": "Chief transferred" }))).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/roles/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "The mission's role slots in lineup order", body = [RoleSlotModel])),
+)]
+pub async fn get_role_slots<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.get_role_slots(mission_id, user_id).await {
+        Ok(slots) => (StatusCode::OK, Json(slots)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/roles/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    request_body = SetRoleSlotsModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Role slots replaced", body = [RoleSlotModel])),
+)]
+pub async fn set_role_slots<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<SetRoleSlotsModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.set_role_slots(mission_id, user_id, model).await {
+        Ok(slots) => (StatusCode::OK, Json(slots)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/roles/{mission_id}/assign",
+    params(("mission_id" = i32, Path)),
+    request_body = AssignRoleSlotModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Member moved to the slot", body = [RoleSlotModel])),
+)]
+pub async fn assign_role_slot<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<AssignRoleSlotModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.assign_role_slot(mission_id, user_id, model).await {
+        Ok(slots) => (StatusCode::OK, Json(slots)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/requests/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Pending join requests, oldest first", body = [JoinRequestModel])),
+)]
+pub async fn get_join_requests<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.get_join_requests(mission_id, user_id).await {
+        Ok(requests) => (StatusCode::OK, Json(requests)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/requests/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Own pending join request withdrawn")),
+)]
+pub async fn cancel_join_request<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.cancel_join_request(mission_id, user_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/requests/{mission_id}/{request_id}/approve",
+    params(("mission_id" = i32, Path), ("request_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Requester added to the crew", body = Object, example = json!({ "message": "Join request approved" }))),
+)]
+pub async fn approve_join_request<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, request_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.approve_join_request(mission_id, user_id, request_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "message": "Join request approved" }))).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/requests/{mission_id}/{request_id}/reject",
+    params(("mission_id" = i32, Path), ("request_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Join request declined", body = Object, example = json!({ "message": "Join request rejected" }))),
+)]
+pub async fn reject_join_request<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, request_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.reject_join_request(mission_id, user_id, request_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "message": "Join request rejected" }))).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/waitlist/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Queued brawlers in order", body = [WaitlistEntryModel])),
+)]
+pub async fn get_waitlist<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.get_waitlist(mission_id, user_id).await {
+        Ok(entries) => (StatusCode::OK, Json(entries)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/waitlist/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 201, description = "Queued for the next free place", body = WaitlistEntryModel)),
+)]
+pub async fn join_waitlist<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.join_waitlist(mission_id, user_id).await {
+        Ok(entry) => (StatusCode::CREATED, Json(entry)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/waitlist/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Left the waitlist; a pending offer passes to the next brawler")),
+)]
+pub async fn leave_waitlist<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.leave_waitlist(mission_id, user_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/waitlist/{mission_id}/confirm",
+    params(("mission_id" = i32, Path), JoinCrewQuery),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Took the offered place", body = Object, example = json!({ "message": "Join Mission_id:1 completed" }))),
+)]
+pub async fn confirm_waitlist_offer<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedQuery(query): ValidatedQuery<JoinCrewQuery>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.confirm_waitlist_offer(mission_id, user_id, query.slot_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(json!({ "message": format!("Join Mission_id:{} completed", mission_id) })),
+        )
+        .into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 use crate::application::services::mission_realtime::MissionRealtimeService;
 
+#[utoipa::path(
+    get,
+    path = "/bans/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Bans in force, newest first", body = [MissionBanModel])),
+)]
+pub async fn get_bans<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.get_bans(mission_id, user_id).await {
+        Ok(bans) => (StatusCode::OK, Json(bans)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/bans/{mission_id}/{brawler_id}",
+    params(("mission_id" = i32, Path), ("brawler_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Ban lifted; the brawler may join again")),
+)]
+pub async fn lift_ban<T1, T2, T3, T4, T5, T6, T7, T8>(
+    State(user_case): CrewState<T1, T2, T3, T4, T5, T6, T7, T8>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: AchievementRepository + Send + Sync,
+    T4: BrawlerRepository + Send + Sync,
+    T5: MissionMessageRepository + Send + Sync,
+    T6: MissionJoinRequestRepository + Send + Sync,
+    T7: MissionWaitlistRepository + Send + Sync,
+    T8: MissionBanRepository + Send + Sync,
+{
+    match user_case.lift_ban(mission_id, user_id, brawler_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
 pub fn routes(
     db_pool: Arc<PgPoolSquad>,
     notification_service: Arc<dyn NotificationService>,
@@ -147,6 +679,9 @@ pub fn routes(
     // Wrap in Arc here to share with Extension
     let brawler_repository = Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool)));
     let mission_message_repository = MissionMessagePostgres::new(Arc::clone(&db_pool));
+    let join_request_repository = MissionJoinRequestPostgres::new(Arc::clone(&db_pool));
+    let waitlist_repository = MissionWaitlistPostgres::new(Arc::clone(&db_pool));
+    let ban_repository = MissionBanPostgres::new(Arc::clone(&db_pool));
     
     let user_case = CrewOperationUseCase::new(
         Arc::new(crew_operation_repository),
@@ -154,6 +689,9 @@ pub fn routes(
         Arc::new(achievement_repository),
         Arc::clone(&brawler_repository),
         Arc::new(mission_message_repository),
+        Arc::new(join_request_repository),
+        Arc::new(waitlist_repository),
+        Arc::new(ban_repository),
         notification_service,
         realtime_service,
     );
@@ -162,15 +700,64 @@ pub fn routes(
     Router::new()
         .route(
             "/join/{mission_id}",
-            post(join::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres>),
+            post(join::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
         )
         .route(
             "/leave/{mission_id}",
-            delete(leave::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres>),
+            delete(leave::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
         )
         .route(
             "/kick/{mission_id}",
-            post(kick::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres>),
+            post(kick::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/co-chiefs/{mission_id}/{brawler_id}",
+            put(add_co_chief::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>)
+                .delete(remove_co_chief::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/chief/{mission_id}",
+            patch(transfer_chief::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/roles/{mission_id}",
+            get(get_role_slots::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>)
+                .put(set_role_slots::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/roles/{mission_id}/assign",
+            patch(assign_role_slot::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/requests/{mission_id}",
+            get(get_join_requests::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>)
+                .delete(cancel_join_request::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/requests/{mission_id}/{request_id}/approve",
+            patch(approve_join_request::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/requests/{mission_id}/{request_id}/reject",
+            patch(reject_join_request::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/waitlist/{mission_id}",
+            get(get_waitlist::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>)
+                .post(join_waitlist::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>)
+                .delete(leave_waitlist::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/waitlist/{mission_id}/confirm",
+            post(confirm_waitlist_offer::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/bans/{mission_id}",
+            get(get_bans::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
+        )
+        .route(
+            "/bans/{mission_id}/{brawler_id}",
+            delete(lift_ban::<CrewOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres, MissionJoinRequestPostgres, MissionWaitlistPostgres, MissionBanPostgres>),
         )
         .layer(Extension(brawler_repository))
         .route_layer(middleware::from_fn(auth))
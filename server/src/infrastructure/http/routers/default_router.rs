@@ -1,9 +1,26 @@
 use axum::{Router, extract::Path, http::StatusCode, response::IntoResponse, routing::get};
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(paths(health_check, make_error))]
+pub struct ApiDoc;
+
+#[utoipa::path(
+    get,
+    path = "/health-check",
+    responses((status = 200, description = "The server is up", body = String, content_type = "text/plain")),
+)]
 
 pub async fn health_check() -> impl IntoResponse {
     (StatusCode::OK, " All Right, I'am Good").into_response()
 }
 
+#[utoipa::path(
+    get,
+    path = "/make-error/{code}",
+    params(("code" = u16, Path, description = "Status code to answer with")),
+    responses((status = "default", description = "The requested status, with the code as the body", body = String, content_type = "text/plain")),
+)]
 pub async fn make_error(Path(code): Path<u16>) -> impl IntoResponse {
     // pub async fn make_error() -> impl IntoResponse {
     // let code = 401;
@@ -4,52 +4,93 @@ use axum::{
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
-    Router, Extension, middleware,
+    Router, middleware,
 };
 use serde::Deserialize;
+use utoipa::{OpenApi, ToSchema};
 
 use crate::{
     application::use_cases::mission_chat::MissionChatUseCase,
-    domain::repositories::mission_message_repository::MissionMessageRepository,
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::FieldError,
+        repositories::{mission_message_repository::MissionMessageRepository, MissionBanRepository},
+        value_objects::{
+            api_key_model::ApiKeyScopes,
+            mission_message_model::MissionMessageModel,
+            validation::{Validate, check_length},
+        },
+    },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
-            repositories::mission_messages::MissionMessagePostgres,
+            repositories::{mission_bans::MissionBanPostgres, mission_messages::MissionMessagePostgres},
+        },
+        http::{
+            extractors::{CurrentBrawler, ValidatedJson},
+            middlewares::{api_keys::allow_api_key_scope, auth::auth},
         },
-        http::middlewares::auth::auth,
     },
 };
 
-#[derive(Deserialize)]
+#[derive(OpenApi)]
+#[openapi(paths(get_messages, send_message))]
+pub struct ApiDoc;
+
+#[derive(Deserialize, ToSchema)]
 pub struct SendMessageDto {
     pub content: String,
 }
 
-pub async fn get_messages<T>(
-    State(use_case): State<Arc<MissionChatUseCase<T>>>,
+impl Validate for SendMessageDto {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "content", &self.content, 1, limits.max_message_len);
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{mission_id}/messages",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["post_chat"])),
+    responses((status = 200, description = "Chat history of the mission", body = [MissionMessageModel])),
+)]
+pub async fn get_messages<T1, T2>(
+    State(use_case): State<Arc<MissionChatUseCase<T1, T2>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T: MissionMessageRepository + Send + Sync,
+    T1: MissionMessageRepository + Send + Sync,
+    T2: MissionBanRepository + Send + Sync,
 {
-    match use_case.get_messages(mission_id).await {
+    match use_case.get_messages(mission_id, user_id).await {
         Ok(messages) => (StatusCode::OK, Json(messages)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn send_message<T>(
-    State(use_case): State<Arc<MissionChatUseCase<T>>>,
-    Extension(user_id): Extension<i32>,
+#[utoipa::path(
+    post,
+    path = "/{mission_id}/messages",
+    params(("mission_id" = i32, Path)),
+    request_body = SendMessageDto,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["post_chat"])),
+    responses((status = 201, description = "Message posted")),
+)]
+pub async fn send_message<T1, T2>(
+    State(use_case): State<Arc<MissionChatUseCase<T1, T2>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
-    Json(body): Json<SendMessageDto>,
+    ValidatedJson(body): ValidatedJson<SendMessageDto>,
 ) -> impl IntoResponse
 where
-    T: MissionMessageRepository + Send + Sync,
+    T1: MissionMessageRepository + Send + Sync,
+    T2: MissionBanRepository + Send + Sync,
 {
     match use_case.send_message(mission_id, user_id, body.content).await {
         Ok(_) => StatusCode::CREATED.into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -57,11 +98,16 @@ use crate::application::services::mission_realtime::MissionRealtimeService;
 
 pub fn routes(db_pool: Arc<PgPoolSquad>, realtime_service: Arc<MissionRealtimeService>) -> Router {
     let repository = MissionMessagePostgres::new(Arc::clone(&db_pool));
-    let use_case = MissionChatUseCase::new(Arc::new(repository), realtime_service);
+    let ban_repository = MissionBanPostgres::new(Arc::clone(&db_pool));
+    let use_case = MissionChatUseCase::new(Arc::new(repository), Arc::new(ban_repository), realtime_service);
 
     Router::new()
-        .route("/{mission_id}/messages", get(get_messages::<MissionMessagePostgres>))
-        .route("/{mission_id}/messages", post(send_message::<MissionMessagePostgres>))
+        .route("/{mission_id}/messages", get(get_messages::<MissionMessagePostgres, MissionBanPostgres>))
+        .route("/{mission_id}/messages", post(send_message::<MissionMessagePostgres, MissionBanPostgres>))
         .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn_with_state(
+            ApiKeyScopes::PostChat,
+            allow_api_key_scope,
+        ))
         .with_state(Arc::new(use_case))
 }
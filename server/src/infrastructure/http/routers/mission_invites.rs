@@ -1,7 +1,7 @@
 use axum::{
-    extract::{Path, State, Extension},
+    extract::{Path, State},
     response::IntoResponse,
-    routing::{get, post},
+    routing::{delete, get, post},
     Router,
     middleware,
     Json,
@@ -9,91 +9,326 @@ use axum::{
 };
 use std::sync::Arc;
 use serde::Deserialize;
+use utoipa::{OpenApi, ToSchema};
 
 use crate::{
     application::use_cases::mission_invites::MissionInviteUseCase,
+    config::config_model::ValidationEnv,
+    domain::{
+        entities::mission_invites::{MissionInvite, MissionInviteDetails, SentMissionInvite},
+        errors::FieldError,
+        services::notification_service::NotificationService,
+        value_objects::{
+            invite_link_model::{AddInviteLinkModel, CreatedInviteLinkModel, InviteLinkModel, InviteLinkPreviewModel, InviteLinkUseModel},
+            invite_model::{BulkInviteModel, BulkInviteResultModel},
+            mission_role_model::JoinCrewQuery,
+            validation::Validate,
+        },
+    },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres,
+                achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres, mission_bans::MissionBanPostgres,
                 crew_operation::CrewOperationPostgres,
+                mission_invite_links::MissionInviteLinkPostgres,
+                mission_waitlist::MissionWaitlistPostgres,
                 mission_invites::MissionInvitePostgres,
                 mission_messages::MissionMessagePostgres,
                 mission_viewing::MissionViewingPostgres,
             },
         },
-        http::middlewares::auth::auth,
+        http::{extractors::{CurrentBrawler, ValidatedJson, ValidatedQuery}, middlewares::auth::auth},
     },
 };
 
-#[derive(Deserialize)]
+#[derive(OpenApi)]
+#[openapi(paths(
+    invite_user,
+    invite_users,
+    get_mission_invites,
+    cancel_invite,
+    accept_invite,
+    decline_invite,
+    get_my_invites,
+    create_invite_link,
+    get_invite_links,
+    get_invite_link_uses,
+    revoke_invite_link,
+    preview_invite_link,
+    accept_invite_link
+))]
+pub struct ApiDoc;
+
+#[derive(Deserialize, ToSchema)]
 pub struct InviteUserPayload {
     user_id: i32,
 }
 
-async fn invite_user(
+impl Validate for InviteUserPayload {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if self.user_id <= 0 {
+            errors.push(FieldError::new("user_id", "must be a valid brawler id"));
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/mission/{mission_id}/invite",
+    params(("mission_id" = i32, Path)),
+    request_body = InviteUserPayload,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Invite sent; it expires after the configured time", body = MissionInvite)),
+)]
+pub async fn invite_user(
     State(use_case): State<Arc<MissionInviteUseCase>>,
-    Extension(user_id): Extension<i32>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
-    Json(payload): Json<InviteUserPayload>,
+    ValidatedJson(payload): ValidatedJson<InviteUserPayload>,
 ) -> impl IntoResponse {
     match use_case
         .invite(mission_id, user_id, payload.user_id)
         .await
     {
         Ok(invite) => (AxumStatusCode::OK, Json(invite)).into_response(),
-        Err(e) => (AxumStatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/mission/{mission_id}/invites",
+    params(("mission_id" = i32, Path)),
+    request_body = BulkInviteModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Outcome per recipient, in the order given (ids before usernames)", body = [BulkInviteResultModel])),
+)]
+pub async fn invite_users(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedJson(payload): ValidatedJson<BulkInviteModel>,
+) -> impl IntoResponse {
+    match use_case.invite_many(mission_id, user_id, payload).await {
+        Ok(results) => (AxumStatusCode::OK, Json(results)).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-async fn accept_invite(
+#[utoipa::path(
+    get,
+    path = "/mission/{mission_id}/invites",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Every invite of the mission, newest first (chief or co-chief)", body = [SentMissionInvite])),
+)]
+pub async fn get_mission_invites(
     State(use_case): State<Arc<MissionInviteUseCase>>,
-    Extension(user_id): Extension<i32>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.get_mission_invites(mission_id, user_id).await {
+        Ok(invites) => (AxumStatusCode::OK, Json(invites)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/invite/{invite_id}",
+    params(("invite_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Pending invite withdrawn (chief or co-chief)")),
+)]
+pub async fn cancel_invite(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(invite_id): Path<i32>,
 ) -> impl IntoResponse {
-    match use_case.accept(invite_id, user_id).await {
+    match use_case.cancel(invite_id, user_id).await {
+        Ok(_) => AxumStatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/invite/{invite_id}/accept",
+    params(("invite_id" = i32, Path), JoinCrewQuery),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Invite accepted and mission joined", body = Object, example = json!({ "message": "Invite accepted" }))),
+)]
+pub async fn accept_invite(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(invite_id): Path<i32>,
+    ValidatedQuery(query): ValidatedQuery<JoinCrewQuery>,
+) -> impl IntoResponse {
+    match use_case.accept(invite_id, user_id, query.slot_id).await {
         Ok(_) => (AxumStatusCode::OK, Json(serde_json::json!({"message": "Invite accepted"}))).into_response(),
-        Err(e) => {
-            eprintln!("Failed to accept invite {}: {}", invite_id, e);
-            (AxumStatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e.to_string()}))).into_response()
-        },
+        Err(e) => e.into_response(),
     }
 }
 
-async fn decline_invite(
+#[utoipa::path(
+    post,
+    path = "/invite/{invite_id}/decline",
+    params(("invite_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Invite declined", body = Object, example = json!({ "message": "Invite declined" }))),
+)]
+pub async fn decline_invite(
     State(use_case): State<Arc<MissionInviteUseCase>>,
-    Extension(user_id): Extension<i32>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(invite_id): Path<i32>,
 ) -> impl IntoResponse {
     match use_case.decline(invite_id, user_id).await {
         Ok(_) => (AxumStatusCode::OK, Json(serde_json::json!({"message": "Invite declined"}))).into_response(),
-        Err(e) => {
-            eprintln!("Failed to decline invite {}: {}", invite_id, e);
-            (AxumStatusCode::BAD_REQUEST, Json(serde_json::json!({"error": e.to_string()}))).into_response()
-        },
+        Err(e) => e.into_response(),
     }
 }
 
-async fn get_my_invites(
+#[utoipa::path(
+    get,
+    path = "/my-invites",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Pending invites for the caller", body = [MissionInviteDetails])),
+)]
+pub async fn get_my_invites(
     State(use_case): State<Arc<MissionInviteUseCase>>,
-    Extension(user_id): Extension<i32>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
 ) -> impl IntoResponse {
     match use_case.get_my_pending_invites(user_id).await {
         Ok(invites) => (AxumStatusCode::OK, Json(invites)).into_response(),
-        Err(e) => (AxumStatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/mission/{mission_id}/links",
+    params(("mission_id" = i32, Path)),
+    request_body = AddInviteLinkModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 201, description = "Link created (chief or co-chief); the token is only shown here", body = CreatedInviteLinkModel)),
+)]
+pub async fn create_invite_link(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedJson(payload): ValidatedJson<AddInviteLinkModel>,
+) -> impl IntoResponse {
+    match use_case.create_link(mission_id, user_id, payload).await {
+        Ok(created) => (AxumStatusCode::CREATED, Json(created)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/mission/{mission_id}/links",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Every invite link of the mission, newest first (chief or co-chief)", body = [InviteLinkModel])),
+)]
+pub async fn get_invite_links(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.get_links(mission_id, user_id).await {
+        Ok(links) => (AxumStatusCode::OK, Json(links)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/mission/{mission_id}/links/{link_id}/uses",
+    params(("mission_id" = i32, Path), ("link_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Brawlers who joined through the link (chief or co-chief)", body = [InviteLinkUseModel])),
+)]
+pub async fn get_invite_link_uses(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, link_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match use_case.get_link_uses(mission_id, link_id, user_id).await {
+        Ok(uses) => (AxumStatusCode::OK, Json(uses)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/mission/{mission_id}/links/{link_id}",
+    params(("mission_id" = i32, Path), ("link_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 204, description = "Link revoked (chief or co-chief)")),
+)]
+pub async fn revoke_invite_link(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, link_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match use_case.revoke_link(mission_id, link_id, user_id).await {
+        Ok(_) => AxumStatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/link/{token}",
+    params(("token" = String, Path)),
+    responses((status = 200, description = "Mission behind a usable invite link; no sign-in needed", body = InviteLinkPreviewModel)),
+)]
+pub async fn preview_invite_link(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    Path(token): Path<String>,
+) -> impl IntoResponse {
+    match use_case.preview_link(&token).await {
+        Ok(preview) => (AxumStatusCode::OK, Json(preview)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/link/{token}/accept",
+    params(("token" = String, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Mission joined through the link", body = Object, example = json!({ "message": "Invite link accepted" }))),
+)]
+pub async fn accept_invite_link(
+    State(use_case): State<Arc<MissionInviteUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(token): Path<String>,
+) -> impl IntoResponse {
+    match use_case.accept_link(&token, user_id).await {
+        Ok(_) => (AxumStatusCode::OK, Json(serde_json::json!({"message": "Invite link accepted"}))).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 use crate::application::services::mission_realtime::MissionRealtimeService;
 
-pub fn routes(db_pool: Arc<PgPoolSquad>, realtime_service: Arc<MissionRealtimeService>) -> Router {
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<dyn NotificationService>,
+    realtime_service: Arc<MissionRealtimeService>,
+) -> Router {
     let invite_repo = Arc::new(MissionInvitePostgres::new(Arc::clone(&db_pool)));
     let mission_repo = Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool)));
     let crew_repo = Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool)));
     let brawler_repo = Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool)));
     let message_repo = Arc::new(MissionMessagePostgres::new(Arc::clone(&db_pool)));
     let achievement_repo = Arc::new(AchievementRepositoryImpl::new(Arc::clone(&db_pool)));
+    let ban_repo = Arc::new(MissionBanPostgres::new(Arc::clone(&db_pool)));
+    let link_repo = Arc::new(MissionInviteLinkPostgres::new(Arc::clone(&db_pool)));
+    let waitlist_repo = Arc::new(MissionWaitlistPostgres::new(Arc::clone(&db_pool)));
 
     let use_case = Arc::new(MissionInviteUseCase::new(
         invite_repo,
@@ -102,14 +337,28 @@ pub fn routes(db_pool: Arc<PgPoolSquad>, realtime_service: Arc<MissionRealtimeSe
         brawler_repo,
         message_repo,
         achievement_repo,
+        ban_repo,
+        link_repo,
+        waitlist_repo,
+        notification_service,
         realtime_service,
     ));
 
-    Router::new()
+    let protected_routes = Router::new()
         .route("/mission/{mission_id}/invite", post(invite_user))
+        .route("/mission/{mission_id}/invites", post(invite_users).get(get_mission_invites))
+        .route("/mission/{mission_id}/links", post(create_invite_link).get(get_invite_links))
+        .route("/mission/{mission_id}/links/{link_id}", delete(revoke_invite_link))
+        .route("/mission/{mission_id}/links/{link_id}/uses", get(get_invite_link_uses))
+        .route("/invite/{invite_id}", delete(cancel_invite))
         .route("/invite/{invite_id}/accept", post(accept_invite))
         .route("/invite/{invite_id}/decline", post(decline_invite))
+        .route("/link/{token}/accept", post(accept_invite_link))
         .route("/my-invites", get(get_my_invites))
-        .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn(auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/link/{token}", get(preview_invite_link))
         .with_state(use_case)
 }
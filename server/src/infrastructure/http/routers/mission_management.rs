@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Json, Router,
+    Json, Router,
     extract::{Path, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
     routing::{delete, patch, post},
 };
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::mission_management::MissionManagementUseCase,
@@ -15,8 +16,13 @@ use crate::{
         repositories::{
             mission_management::MissionManagementRepository,
             mission_viewing::MissionViewingRepository,
+            mission_waitlist::MissionWaitlistRepository,
+        },
+        services::notification_service::NotificationService,
+        value_objects::{
+            AddMissionModel, EditMissionModel, api_key_model::ApiKeyScopes,
+            uploaded_img::{UploadBase64Img, UploadedImg},
         },
-        value_objects::{AddMissionModel, EditMissionModel},
     },
     infrastructure::{
         database::{
@@ -24,20 +30,36 @@ use crate::{
             repositories::{
                 mission_management::MissionManagementPostgres,
                 mission_viewing::MissionViewingPostgres,
+                mission_waitlist::MissionWaitlistPostgres,
             },
         },
-        http::middlewares::auth::auth,
+        http::{
+            extractors::{CurrentBrawler, ValidatedJson},
+            middlewares::{api_keys::allow_api_key_scope, auth::auth},
+        },
     },
 };
 
-pub async fn add<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
-    Extension(user_id): Extension<i32>,
-    Json(model): Json<AddMissionModel>,
+#[derive(OpenApi)]
+#[openapi(paths(add, edit, remove, restore, upload_image))]
+pub struct ApiDoc;
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = AddMissionModel,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 201, description = "Mission created", body = Object, example = json!({ "mission_id": 1 }))),
+)]
+pub async fn add<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    ValidatedJson(model): ValidatedJson<AddMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionWaitlistRepository + Send + Sync,
 {
     match user_case.add(user_id, model).await {
         Ok(mission_id) => {
@@ -47,19 +69,28 @@ where
             (StatusCode::CREATED, axum::Json(json_value)).into_response()
         }
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn edit<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
-    Extension(user_id): Extension<i32>,
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    params(("id" = i32, Path, description = "Mission id")),
+    request_body = EditMissionModel,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Mission updated", body = String, content_type = "text/plain")),
+)]
+pub async fn edit<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
-    Json(model): Json<EditMissionModel>,
+    ValidatedJson(model): ValidatedJson<EditMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionWaitlistRepository + Send + Sync,
 {
     match user_case.edit(mission_id, user_id, model).await {
         Ok(mission_id) => (
@@ -68,18 +99,26 @@ where
         )
             .into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn remove<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
-    Extension(user_id): Extension<i32>,
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    params(("id" = i32, Path, description = "Mission id")),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Mission removed", body = String, content_type = "text/plain")),
+)]
+pub async fn remove<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionWaitlistRepository + Send + Sync,
 {
     match user_case.remove(mission_id, user_id).await {
         Ok(_) => (
@@ -88,49 +127,99 @@ where
         )
             .into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
 }
 }
 
-pub async fn upload_image<T1, T2>(
-    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
-    Extension(user_id): Extension<i32>,
+#[utoipa::path(
+    post,
+    path = "/{id}/restore",
+    params(("id" = i32, Path, description = "Mission id")),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Mission restored; only within the retention window", body = String, content_type = "text/plain")),
+)]
+pub async fn restore<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
-    Json(model): Json<crate::domain::value_objects::uploaded_img::UploadBase64Img>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.restore(mission_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Restore mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/image",
+    params(("id" = i32, Path, description = "Mission id")),
+    request_body = UploadBase64Img,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "The uploaded mission image", body = UploadedImg)),
+)]
+pub async fn upload_image<T1, T2, T3>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2, T3>>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<UploadBase64Img>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionWaitlistRepository + Send + Sync,
 {
     match user_case
         .upload_image(mission_id, user_id, model.base64_string)
         .await
     {
         Ok(uploaded_img) => (StatusCode::OK, Json(uploaded_img)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(db_pool: Arc<PgPoolSquad>, notification_service: Arc<dyn NotificationService>) -> Router {
     let mission_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let user_case =
-        MissionManagementUseCase::new(Arc::new(mission_repository), Arc::new(viewing_repositiory));
+    let waitlist_repository = MissionWaitlistPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionManagementUseCase::new(
+        Arc::new(mission_repository),
+        Arc::new(viewing_repositiory),
+        Arc::new(waitlist_repository),
+        notification_service,
+    );
 
     Router::new()
-        .route("/", post(add::<MissionManagementPostgres, MissionViewingPostgres>))
+        .route("/", post(add::<MissionManagementPostgres, MissionViewingPostgres, MissionWaitlistPostgres>))
         .route(
             "/{id}",
-            patch(edit::<MissionManagementPostgres, MissionViewingPostgres>),
+            patch(edit::<MissionManagementPostgres, MissionViewingPostgres, MissionWaitlistPostgres>),
         )
         .route(
             "/{id}",
-            delete(remove::<MissionManagementPostgres, MissionViewingPostgres>),
+            delete(remove::<MissionManagementPostgres, MissionViewingPostgres, MissionWaitlistPostgres>),
+        )
+        .route(
+            "/{id}/restore",
+            post(restore::<MissionManagementPostgres, MissionViewingPostgres, MissionWaitlistPostgres>),
         )
         .route(
             "/{id}/image",
-            post(upload_image::<MissionManagementPostgres, MissionViewingPostgres>),
+            post(upload_image::<MissionManagementPostgres, MissionViewingPostgres, MissionWaitlistPostgres>),
         )
         .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn_with_state(
+            ApiKeyScopes::ManageMissions,
+            allow_api_key_scope,
+        ))
         .with_state(Arc::new(user_case))
 }
\ No newline at end of file
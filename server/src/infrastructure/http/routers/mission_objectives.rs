@@ -0,0 +1,251 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, put},
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::{
+        services::mission_realtime::MissionRealtimeService,
+        use_cases::mission_objectives::MissionObjectiveUseCase,
+    },
+    domain::{
+        repositories::{
+            mission_message_repository::MissionMessageRepository,
+            mission_objectives::MissionObjectiveRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            api_key_model::ApiKeyScopes,
+            mission_objective_model::{
+                AddMissionObjectiveModel, EditMissionObjectiveModel, MissionObjectiveModel,
+                ReorderMissionObjectivesModel,
+            },
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_messages::MissionMessagePostgres, mission_objectives::MissionObjectivePostgres,
+                mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::{
+            extractors::{CurrentBrawler, ValidatedJson},
+            middlewares::{api_keys::allow_api_key_scope, auth::auth},
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_objectives,
+    add_objective,
+    reorder_objectives,
+    edit_objective,
+    remove_objective,
+    complete_objective,
+    reopen_objective,
+))]
+pub struct ApiDoc;
+
+type ObjectiveState<T1, T2, T3> = State<Arc<MissionObjectiveUseCase<T1, T2, T3>>>;
+
+#[utoipa::path(
+    get,
+    path = "/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "The mission's objectives in checklist order", body = [MissionObjectiveModel])),
+)]
+pub async fn get_objectives<T1, T2, T3>(
+    State(user_case): ObjectiveState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    match user_case.get_all(mission_id, user_id).await {
+        Ok(objectives) => (StatusCode::OK, Json(objectives)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    request_body = AddMissionObjectiveModel,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 201, description = "Objective appended to the checklist; chief only", body = MissionObjectiveModel)),
+)]
+pub async fn add_objective<T1, T2, T3>(
+    State(user_case): ObjectiveState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<AddMissionObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    match user_case.add(mission_id, user_id, model).await {
+        Ok(objective) => (StatusCode::CREATED, Json(objective)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/{mission_id}/order",
+    params(("mission_id" = i32, Path)),
+    request_body = ReorderMissionObjectivesModel,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "The checklist in its new order; chief only", body = [MissionObjectiveModel])),
+)]
+pub async fn reorder_objectives<T1, T2, T3>(
+    State(user_case): ObjectiveState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<ReorderMissionObjectivesModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    match user_case.reorder(mission_id, user_id, model).await {
+        Ok(objectives) => (StatusCode::OK, Json(objectives)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{mission_id}/{objective_id}",
+    params(("mission_id" = i32, Path), ("objective_id" = i32, Path)),
+    request_body = EditMissionObjectiveModel,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Objective updated; chief only", body = MissionObjectiveModel)),
+)]
+pub async fn edit_objective<T1, T2, T3>(
+    State(user_case): ObjectiveState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+    ValidatedJson(model): ValidatedJson<EditMissionObjectiveModel>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    match user_case.edit(mission_id, objective_id, user_id, model).await {
+        Ok(objective) => (StatusCode::OK, Json(objective)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{mission_id}/{objective_id}",
+    params(("mission_id" = i32, Path), ("objective_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 204, description = "Objective removed; chief only")),
+)]
+pub async fn remove_objective<T1, T2, T3>(
+    State(user_case): ObjectiveState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    match user_case.remove(mission_id, objective_id, user_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{mission_id}/{objective_id}/complete",
+    params(("mission_id" = i32, Path), ("objective_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Objective marked done by the caller; chief or crew", body = MissionObjectiveModel)),
+)]
+pub async fn complete_objective<T1, T2, T3>(
+    State(user_case): ObjectiveState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    match user_case.complete(mission_id, objective_id, user_id).await {
+        Ok(objective) => (StatusCode::OK, Json(objective)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{mission_id}/{objective_id}/reopen",
+    params(("mission_id" = i32, Path), ("objective_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Objective marked open again; chief or crew", body = MissionObjectiveModel)),
+)]
+pub async fn reopen_objective<T1, T2, T3>(
+    State(user_case): ObjectiveState<T1, T2, T3>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, objective_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionObjectiveRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: MissionMessageRepository + Send + Sync,
+{
+    match user_case.reopen(mission_id, objective_id, user_id).await {
+        Ok(objective) => (StatusCode::OK, Json(objective)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, realtime_service: Arc<MissionRealtimeService>) -> Router {
+    let user_case = MissionObjectiveUseCase::new(
+        Arc::new(MissionObjectivePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionMessagePostgres::new(db_pool)),
+        realtime_service,
+    );
+
+    Router::new()
+        .route("/{mission_id}", get(get_objectives).post(add_objective))
+        .route("/{mission_id}/order", put(reorder_objectives))
+        .route(
+            "/{mission_id}/{objective_id}",
+            patch(edit_objective).delete(remove_objective),
+        )
+        .route("/{mission_id}/{objective_id}/complete", patch(complete_objective))
+        .route("/{mission_id}/{objective_id}/reopen", patch(reopen_objective))
+        .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn_with_state(
+            ApiKeyScopes::ManageMissions,
+            allow_api_key_scope,
+        ))
+        .with_state(Arc::new(user_case))
+}
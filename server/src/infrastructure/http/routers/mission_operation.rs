@@ -1,14 +1,14 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
+    Router,
     extract::{Path, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
     routing::patch,
 };
-use serde_json::json;
+use utoipa::OpenApi;
 
 use crate::{
     application::use_cases::mission_operation::MissionOperationUseCase,
@@ -18,6 +18,7 @@ use crate::{
             AchievementRepository, BrawlerRepository, mission_message_repository::MissionMessageRepository,
         },
         services::notification_service::NotificationService,
+        value_objects::api_key_model::ApiKeyScopes,
     },
     infrastructure::{
         database::{
@@ -30,13 +31,29 @@ use crate::{
                 mission_messages::MissionMessagePostgres,
             },
         },
-        http::middlewares::auth::auth,
+        http::{
+            extractors::CurrentBrawler,
+            middlewares::{api_keys::allow_api_key_scope, auth::auth},
+        },
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(in_progress, to_completed, to_failed))]
+pub struct ApiDoc;
+
+type MissionOperationState<T1, T2, T3, T4, T5> = State<Arc<MissionOperationUseCase<T1, T2, T3, T4, T5>>>;
+
+#[utoipa::path(
+    patch,
+    path = "/in-progress/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Mission started; the body is the mission id", body = String, content_type = "text/plain")),
+)]
 pub async fn in_progress<T1, T2, T3, T4, T5>(
-    State(user_case): State<Arc<MissionOperationUseCase<T1, T2, T3, T4, T5>>>,
-    Extension(user_id): Extension<i32>,
+    State(user_case): MissionOperationState<T1, T2, T3, T4, T5>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
@@ -48,13 +65,20 @@ where
 {
     match user_case.in_progress(mission_id, user_id).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
+#[utoipa::path(
+    patch,
+    path = "/to-completed/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Mission completed; the body is the mission id", body = String, content_type = "text/plain")),
+)]
 pub async fn to_completed<T1, T2, T3, T4, T5>(
-    State(user_case): State<Arc<MissionOperationUseCase<T1, T2, T3, T4, T5>>>,
-    Extension(user_id): Extension<i32>,
+    State(user_case): MissionOperationState<T1, T2, T3, T4, T5>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
@@ -66,13 +90,20 @@ where
 {
     match user_case.to_completed(mission_id, user_id).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
+#[utoipa::path(
+    patch,
+    path = "/to-failed/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Mission failed; the body is the mission id", body = String, content_type = "text/plain")),
+)]
 pub async fn to_failed<T1, T2, T3, T4, T5>(
-    State(user_case): State<Arc<MissionOperationUseCase<T1, T2, T3, T4, T5>>>,
-    Extension(user_id): Extension<i32>,
+    State(user_case): MissionOperationState<T1, T2, T3, T4, T5>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
@@ -84,7 +115,7 @@ where
 {
     match user_case.to_failed(mission_id, user_id).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -116,5 +147,9 @@ pub fn routes(
         .route("/to-completed/{mission_id}", patch(to_completed::<MissionOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres>))
         .route("/to-failed/{mission_id}", patch(to_failed::<MissionOperationPostgres, MissionViewingPostgres, AchievementRepositoryImpl, BrawlerPostgres, MissionMessagePostgres>))
         .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn_with_state(
+            ApiKeyScopes::ManageMissions,
+            allow_api_key_scope,
+        ))
         .with_state(Arc::new(user_case))
 }
@@ -0,0 +1,96 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    routing::{get, put},
+    Router,
+    middleware,
+    Json,
+    http::StatusCode as AxumStatusCode,
+};
+use std::sync::Arc;
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::mission_ratings::MissionRatingUseCase,
+    domain::value_objects::rating_model::{MissionRatingModel, RateBrawlerModel, RatingSheetModel, ReputationModel},
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{mission_ratings::MissionRatingPostgres, mission_viewing::MissionViewingPostgres},
+        },
+        http::{extractors::{CurrentBrawler, ValidatedJson}, middlewares::auth::auth},
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_rating_sheet, rate_brawler, get_reputation))]
+pub struct ApiDoc;
+
+#[utoipa::path(
+    get,
+    path = "/mission/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Everyone the viewer can rate for a finished mission, with the viewer's ratings so far", body = RatingSheetModel)),
+)]
+pub async fn get_rating_sheet(
+    State(use_case): State<Arc<MissionRatingUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.get_sheet(mission_id, user_id).await {
+        Ok(sheet) => (AxumStatusCode::OK, Json(sheet)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/mission/{mission_id}/brawler/{brawler_id}",
+    params(("mission_id" = i32, Path), ("brawler_id" = i32, Path)),
+    request_body = RateBrawlerModel,
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Rating saved, replacing an earlier one; only while the rating window is open", body = MissionRatingModel)),
+)]
+pub async fn rate_brawler(
+    State(use_case): State<Arc<MissionRatingUseCase>>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+    ValidatedJson(model): ValidatedJson<RateBrawlerModel>,
+) -> impl IntoResponse {
+    match use_case.rate(mission_id, user_id, brawler_id, model).await {
+        Ok(rating) => (AxumStatusCode::OK, Json(rating)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/brawler/{brawler_id}",
+    params(("brawler_id" = i32, Path)),
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "The brawler's reputation and the tags they were given", body = ReputationModel)),
+)]
+pub async fn get_reputation(
+    State(use_case): State<Arc<MissionRatingUseCase>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse {
+    match use_case.get_reputation(brawler_id).await {
+        Ok(reputation) => (AxumStatusCode::OK, Json(reputation)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let rating_repo = Arc::new(MissionRatingPostgres::new(Arc::clone(&db_pool)));
+    let mission_repo = Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool)));
+
+    let use_case = Arc::new(MissionRatingUseCase::new(rating_repo, mission_repo));
+
+    Router::new()
+        .route("/mission/{mission_id}", get(get_rating_sheet))
+        .route("/mission/{mission_id}/brawler/{brawler_id}", put(rate_brawler))
+        .route("/brawler/{brawler_id}", get(get_reputation))
+        .route_layer(middleware::from_fn(auth))
+        .with_state(use_case)
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    application::{
+        services::mission_realtime::MissionRealtimeService,
+        use_cases::{mission_invites::MissionInviteUseCase, mission_templates::MissionTemplateUseCase},
+    },
+    domain::{
+        repositories::MissionTemplateRepository,
+        services::notification_service::NotificationService,
+        value_objects::{
+            api_key_model::ApiKeyScopes,
+            mission_template_model::{
+                AddMissionTemplateModel, EditMissionTemplateModel, MissionTemplateModel,
+            },
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres, mission_bans::MissionBanPostgres,
+                mission_invite_links::MissionInviteLinkPostgres,
+                mission_waitlist::MissionWaitlistPostgres,
+                crew_operation::CrewOperationPostgres, mission_invites::MissionInvitePostgres,
+                mission_messages::MissionMessagePostgres, mission_templates::MissionTemplatePostgres,
+                mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::{
+            extractors::{CurrentBrawler, ValidatedJson},
+            middlewares::{api_keys::allow_api_key_scope, auth::auth},
+        },
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_templates, add_template, get_template, edit_template, remove_template))]
+pub struct ApiDoc;
+
+type TemplateState<T> = State<Arc<MissionTemplateUseCase<T>>>;
+
+#[utoipa::path(
+    get,
+    path = "",
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Templates owned by the caller", body = [MissionTemplateModel])),
+)]
+pub async fn get_templates<T>(
+    State(user_case): TemplateState<T>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+) -> impl IntoResponse
+where
+    T: MissionTemplateRepository + Send + Sync,
+{
+    match user_case.get_all(user_id).await {
+        Ok(templates) => (StatusCode::OK, Json(templates)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = AddMissionTemplateModel,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 201, description = "Template created; a recurrence rule starts scheduling missions", body = MissionTemplateModel)),
+)]
+pub async fn add_template<T>(
+    State(user_case): TemplateState<T>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    ValidatedJson(model): ValidatedJson<AddMissionTemplateModel>,
+) -> impl IntoResponse
+where
+    T: MissionTemplateRepository + Send + Sync,
+{
+    match user_case.add(user_id, model).await {
+        Ok(template) => (StatusCode::CREATED, Json(template)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    params(("id" = i32, Path, description = "Template id")),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "The template", body = MissionTemplateModel)),
+)]
+pub async fn get_template<T>(
+    State(user_case): TemplateState<T>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionTemplateRepository + Send + Sync,
+{
+    match user_case.get_one(template_id, user_id).await {
+        Ok(template) => (StatusCode::OK, Json(template)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    params(("id" = i32, Path, description = "Template id")),
+    request_body = EditMissionTemplateModel,
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 200, description = "Template updated; missions already scheduled keep their details", body = MissionTemplateModel)),
+)]
+pub async fn edit_template<T>(
+    State(user_case): TemplateState<T>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(template_id): Path<i32>,
+    ValidatedJson(model): ValidatedJson<EditMissionTemplateModel>,
+) -> impl IntoResponse
+where
+    T: MissionTemplateRepository + Send + Sync,
+{
+    match user_case.edit(template_id, user_id, model).await {
+        Ok(template) => (StatusCode::OK, Json(template)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    params(("id" = i32, Path, description = "Template id")),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["manage_missions"])),
+    responses((status = 204, description = "Template removed; its missions are kept")),
+)]
+pub async fn remove_template<T>(
+    State(user_case): TemplateState<T>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionTemplateRepository + Send + Sync,
+{
+    match user_case.remove(template_id, user_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<dyn NotificationService>,
+    realtime_service: Arc<MissionRealtimeService>,
+) -> Router {
+    let invite_use_case = Arc::new(MissionInviteUseCase::new(
+        Arc::new(MissionInvitePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionMessagePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementRepositoryImpl::new(Arc::clone(&db_pool))),
+        Arc::new(MissionBanPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionInviteLinkPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionWaitlistPostgres::new(Arc::clone(&db_pool))),
+        notification_service,
+        realtime_service,
+    ));
+    let user_case = MissionTemplateUseCase::new(
+        Arc::new(MissionTemplatePostgres::new(db_pool)),
+        invite_use_case,
+    );
+
+    Router::new()
+        .route(
+            "/",
+            get(get_templates::<MissionTemplatePostgres>).post(add_template::<MissionTemplatePostgres>),
+        )
+        .route(
+            "/{id}",
+            get(get_template::<MissionTemplatePostgres>)
+                .patch(edit_template::<MissionTemplatePostgres>)
+                .delete(remove_template::<MissionTemplatePostgres>),
+        )
+        .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn_with_state(
+            ApiKeyScopes::ManageMissions,
+            allow_api_key_scope,
+        ))
+        .with_state(Arc::new(user_case))
+}
@@ -1,43 +1,75 @@
 use std::sync::Arc;
 
 use axum::{
-    Json, Router, Extension,
-    extract::{Path, Query, State},
+    Json, Router,
+    extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
     middleware,
     routing::get,
 };
 
+use utoipa::OpenApi;
+
 use crate::{
     application::use_cases::mission_viewing::MissionViewingUseCase,
     domain::{
         repositories::mission_viewing::MissionViewingRepository,
-        value_objects::mission_filter::MissionFilter,
+        value_objects::{
+            MissionModel, api_key_model::ApiKeyScopes, brawler_model::BrawlerModel,
+            mission_filter::MissionFilter, mission_model::MissionSort,
+            mission_statuses::MissionStatuses, pagination::{Page, PageRequest},
+        },
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
         },
-        http::middlewares::auth::auth,
+        http::{
+            extractors::{CurrentBrawler, ValidatedQuery},
+            middlewares::{api_keys::allow_api_key_scope, auth::auth},
+        },
     },
 };
 
+#[derive(OpenApi)]
+#[openapi(
+    paths(get_one, get_crew, get_all, get_joined, get_popular),
+    components(schemas(MissionStatuses)),
+)]
+pub struct ApiDoc;
+
+#[utoipa::path(
+    get,
+    path = "/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security((), ("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "The mission", body = MissionModel)),
+)]
 pub async fn get_one<T>(
     State(user_case): State<Arc<MissionViewingUseCase<T>>>,
-    Extension(brawler_id): Extension<i32>,
+    current: Option<CurrentBrawler>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
+    // Anonymous callers see every mission as not joined.
+    let brawler_id = current.map(|current| current.id).unwrap_or_default();
+
     match user_case.get_one(mission_id, brawler_id).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/crew/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    responses((status = 200, description = "Crew members of the mission", body = [BrawlerModel])),
+)]
 pub async fn get_crew<T>(
     State(user_case): State<Arc<MissionViewingUseCase<T>>>,
     Path(mission_id): Path<i32>,
@@ -48,48 +80,74 @@ where
     match user_case.get_crew(mission_id).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/filter",
+    params(MissionFilter, PageRequest<MissionSort>),
+    security((), ("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "A page of missions matching the filter", body = Page<MissionModel>)),
+)]
 pub async fn get_all<T>(
     State(user_case): State<Arc<MissionViewingUseCase<T>>>,
-    Extension(brawler_id): Extension<i32>,
-    filter: Query<MissionFilter>,
+    current: Option<CurrentBrawler>,
+    ValidatedQuery(filter): ValidatedQuery<MissionFilter>,
+    ValidatedQuery(page): ValidatedQuery<PageRequest<MissionSort>>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    match user_case.get_all(&filter, brawler_id).await {
+    let brawler_id = current.map(|current| current.id).unwrap_or_default();
+
+    match user_case.get_all(&filter, brawler_id, &page).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/joined",
+    params(PageRequest<MissionSort>),
+    security(("bearer_auth" = []), ("cookie_auth" = []), ("api_key" = ["read_missions"])),
+    responses((status = 200, description = "A page of missions the caller has joined", body = Page<MissionModel>)),
+)]
 pub async fn get_joined<T>(
     State(user_case): State<Arc<MissionViewingUseCase<T>>>,
-    Extension(brawler_id): Extension<i32>,
+    CurrentBrawler { id: brawler_id, .. }: CurrentBrawler,
+    ValidatedQuery(page): ValidatedQuery<PageRequest<MissionSort>>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    match user_case.get_joined_missions(brawler_id).await {
+    match user_case.get_joined_missions(brawler_id, &page).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/popular",
+    security((), ("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Missions with the most crew members", body = [MissionModel])),
+)]
 pub async fn get_popular<T>(
     State(user_case): State<Arc<MissionViewingUseCase<T>>>,
-    Extension(brawler_id): Extension<i32>,
+    current: Option<CurrentBrawler>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
+    let brawler_id = current.map(|current| current.id).unwrap_or_default();
+
     match user_case.get_popular_missions(brawler_id).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -97,12 +155,19 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
     let user_case = MissionViewingUseCase::new(Arc::new(viewing_repositiory));
 
+    let protected_routes = Router::new()
+        .route("/joined", get(get_joined::<MissionViewingPostgres>))
+        .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn_with_state(
+            ApiKeyScopes::ReadMissions,
+            allow_api_key_scope,
+        ));
+
     Router::new()
+        .merge(protected_routes)
         .route("/popular", get(get_popular::<MissionViewingPostgres>))
         .route("/filter", get(get_all::<MissionViewingPostgres>))
-        .route("/joined", get(get_joined::<MissionViewingPostgres>))
         .route("/crew/{mission_id}", get(get_crew::<MissionViewingPostgres>))
         .route("/{mission_id}", get(get_one::<MissionViewingPostgres>))
-        .route_layer(middleware::from_fn(auth))
         .with_state(Arc::new(user_case))
 }
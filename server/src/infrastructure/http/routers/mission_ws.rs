@@ -13,32 +13,63 @@ use axum::{
 use futures::{sink::SinkExt, stream::StreamExt};
 use serde_json::json;
 use chrono;
+use utoipa::OpenApi;
 
 use crate::{
+    config::config_model::ValidationEnv,
     domain::{
         entities::mission_messages::NewMissionMessageEntity,
-        repositories::mission_message_repository::MissionMessageRepository,
+        errors::AppError,
+        repositories::{mission_message_repository::MissionMessageRepository, MissionBanRepository},
+        value_objects::validation::Validate,
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
-            repositories::mission_messages::MissionMessagePostgres,
+            repositories::{mission_bans::MissionBanPostgres, mission_messages::MissionMessagePostgres},
+        },
+        http::{
+            extractors::CurrentBrawler, middlewares::auth::stream_auth,
+            routers::mission_chat::SendMessageDto,
         },
-        services::mission_websocket_service::MissionWebSocketService,
-        http::middlewares::auth::auth,
     },
 };
 
 use crate::application::services::mission_realtime::{MissionRealtimeService, ChatMessage};
 
+#[derive(OpenApi)]
+#[openapi(paths(ws_handler))]
+pub struct ApiDoc;
+
+/// Clients send `SendMessageDto` frames and receive `ChatMessage` frames for the mission.
+#[utoipa::path(
+    get,
+    path = "/{mission_id}",
+    params(("mission_id" = i32, Path)),
+    security(("stream_ticket" = []), ("bearer_auth" = []), ("cookie_auth" = [])),
+    responses(
+        (status = 101, description = "Switched to the mission chat WebSocket"),
+        (status = 403, description = "The brawler is banned from the mission"),
+    ),
+)]
 pub async fn ws_handler(
     ws: WebSocketUpgrade,
     Path(mission_id): Path<i32>,
-    Extension(user_id): Extension<i32>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
     State(db_pool): State<Arc<PgPoolSquad>>,
     Extension(realtime_service): Extension<Arc<MissionRealtimeService>>,
+    Extension(limits): Extension<ValidationEnv>,
 ) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| handle_socket(socket, mission_id, user_id, realtime_service, db_pool))
+    let banned = MissionBanPostgres::new(Arc::clone(&db_pool))
+        .find_active(mission_id, user_id, chrono::Utc::now().naive_utc())
+        .await;
+    match banned {
+        Ok(None) => {}
+        Ok(Some(_)) => return AppError::Forbidden("Banned from this mission".to_string()).into_response(),
+        Err(e) => return e.into_response(),
+    }
+
+    ws.on_upgrade(move |socket| handle_socket(socket, mission_id, user_id, realtime_service, db_pool, limits))
 }
 
 async fn handle_socket(
@@ -47,7 +78,10 @@ async fn handle_socket(
     user_id: i32,
     realtime_service: Arc<MissionRealtimeService>,
     db_pool: Arc<PgPoolSquad>,
+    limits: ValidationEnv,
 ) {
+    // Subscribed before anything is served, so a kick racing the upgrade still closes the socket.
+    let evicted = realtime_service.evicted(mission_id, user_id);
     let (mut sender, mut receiver) = socket.split();
 
     let tx = realtime_service.get_channel(mission_id);
@@ -78,6 +112,11 @@ async fn handle_socket(
         while let Some(Ok(msg)) = receiver.next().await {
             if let Message::Text(text) = msg {
                 let content = text.clone();
+                // Same rules as `POST /mission-chat/{id}/messages`; invalid frames are dropped.
+                let message = SendMessageDto { content: content.to_string() };
+                if message.validated(&limits).is_err() {
+                    continue;
+                }
                 let entity = NewMissionMessageEntity {
                     mission_id,
                     user_id: Some(user_id),
@@ -86,7 +125,7 @@ async fn handle_socket(
                 };
 
                 match message_repo.create(entity).await {
-                    Ok(saved_msg) => {
+                    Ok(_) => {
                          // Broadcast via structured service
                         let broadcast_msg = ChatMessage {
                             mission_id,
@@ -110,12 +149,17 @@ async fn handle_socket(
     tokio::select! {
         _ = (&mut send_task) => recv_task.abort(),
         _ = (&mut recv_task) => send_task.abort(),
+        // Kicked or banned: stop relaying either way, which drops the connection.
+        _ = evicted => {
+            send_task.abort();
+            recv_task.abort();
+        }
     };
 }
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     Router::new()
         .route("/{mission_id}", get(ws_handler))
-        .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn(stream_auth))
         .with_state(db_pool)
 }
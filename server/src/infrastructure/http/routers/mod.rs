@@ -1,14 +1,21 @@
 pub mod achievements;
+pub mod admin;
+pub mod api_docs;
+pub mod api_keys;
 pub mod authentication;
 pub mod brawlers;
 pub mod crew_operation;
 pub mod default;
 pub mod default_router;
 pub mod mission_management;
+pub mod mission_objectives;
 pub mod mission_operation;
 pub mod mission_viewing;
 pub mod notifications;
+pub mod oidc_authentication;
 pub mod mission_chat;
 pub mod mission_ws;
 pub mod mission_invites;
+pub mod mission_ratings;
 
+pub mod mission_templates;
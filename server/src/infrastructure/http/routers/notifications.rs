@@ -2,7 +2,7 @@ use axum::{
     extract::State,
     response::sse::{Event, KeepAlive, Sse},
     routing::get,
-    Router, Extension, middleware,
+    Router, middleware,
 };
 use futures::stream::Stream;
 use std::{convert::Infallible, time::Duration};
@@ -10,22 +10,33 @@ use tokio::sync::broadcast;
 use tokio_stream::wrappers::BroadcastStream;
 use tokio_stream::StreamExt;
 use tracing::info;
+use utoipa::OpenApi;
 
 use crate::{
     domain::entities::notification::Notification,
-    infrastructure::http::middlewares::auth::auth,
+    infrastructure::http::{extractors::CurrentBrawler, middlewares::auth::stream_auth},
 };
 
+#[derive(OpenApi)]
+#[openapi(paths(sse_handler))]
+pub struct ApiDoc;
+
 pub fn routes(tx: broadcast::Sender<Notification>) -> Router {
     Router::new()
         .route("/events", get(sse_handler))
-        .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn(stream_auth))
         .with_state(tx)
 }
 
-async fn sse_handler(
+#[utoipa::path(
+    get,
+    path = "/events",
+    security(("stream_ticket" = []), ("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Server-sent events, one `Notification` per event", content_type = "text/event-stream", body = Notification)),
+)]
+pub async fn sse_handler(
     State(tx): State<broadcast::Sender<Notification>>,
-    Extension(user_id): Extension<i32>,
+    CurrentBrawler { id: user_id, .. }: CurrentBrawler,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
     info!("SSE connection established for user: {}", user_id);
     let rx = tx.subscribe();
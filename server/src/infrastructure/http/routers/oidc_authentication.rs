@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use axum_extra::extract::CookieJar;
+use utoipa::OpenApi;
+
+use crate::{
+    application::use_cases::oidc_authentication::OidcAuthenticationUseCase,
+    config::config_model::AuthCookieEnv,
+    domain::repositories::{brawlers::BrawlerRepository, identities::IdentityRepository},
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{brawlers::BrawlerPostgres, identities::IdentityPostgres},
+        },
+        http::{auth_cookie, extractors::{CurrentBrawler, ValidatedJson}, middlewares::auth::auth},
+        jwt::{
+            JwtKeys,
+            authentication_model::{OidcAuthorizationModel, OidcCallbackModel},
+            jwt_model::Passport,
+        },
+        oidc::pending_login::PendingLoginStore,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(authorize, link, callback))]
+pub struct ApiDoc;
+
+#[utoipa::path(
+    get,
+    path = "/authorize",
+    responses((status = 200, description = "Where to send the browser to sign in", body = OidcAuthorizationModel)),
+)]
+pub async fn authorize<T1, T2>(
+    State(user_case): State<Arc<OidcAuthenticationUseCase<T1, T2>>>,
+    Extension(cookie_env): Extension<AuthCookieEnv>,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: IdentityRepository + Send + Sync,
+{
+    match user_case.authorize(None).await {
+        Ok((model, state)) => (
+            StatusCode::OK,
+            auth_cookie::with_oidc_state(jar, &cookie_env, &state),
+            Json(model),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/link",
+    security(("bearer_auth" = []), ("cookie_auth" = [])),
+    responses((status = 200, description = "Where to send the browser to link an identity to the caller", body = OidcAuthorizationModel)),
+)]
+pub async fn link<T1, T2>(
+    State(user_case): State<Arc<OidcAuthenticationUseCase<T1, T2>>>,
+    CurrentBrawler { id: brawler_id, .. }: CurrentBrawler,
+    Extension(cookie_env): Extension<AuthCookieEnv>,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: IdentityRepository + Send + Sync,
+{
+    match user_case.authorize(Some(brawler_id)).await {
+        Ok((model, state)) => (
+            StatusCode::OK,
+            auth_cookie::with_oidc_state(jar, &cookie_env, &state),
+            Json(model),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/callback",
+    request_body = OidcCallbackModel,
+    responses((status = 200, description = "Signed in; also sets the auth cookie", body = Passport)),
+)]
+pub async fn callback<T1, T2>(
+    State(user_case): State<Arc<OidcAuthenticationUseCase<T1, T2>>>,
+    Extension(cookie_env): Extension<AuthCookieEnv>,
+    jar: CookieJar,
+    ValidatedJson(model): ValidatedJson<OidcCallbackModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: IdentityRepository + Send + Sync,
+{
+    let browser_state = auth_cookie::oidc_state(&jar);
+    let jar = auth_cookie::without_oidc_state(jar);
+    match user_case.callback(model, browser_state.as_deref()).await {
+        Ok(passport) => (
+            auth_cookie::with_token(jar, &cookie_env, &passport.token),
+            Json(passport),
+        )
+            .into_response(),
+        Err(e) => (jar, e).into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, jwt_keys: Arc<JwtKeys>) -> Router {
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let identity_repository = IdentityPostgres::new(Arc::clone(&db_pool));
+    let user_case = OidcAuthenticationUseCase::new(
+        Arc::new(brawler_repository),
+        Arc::new(identity_repository),
+        PendingLoginStore::new(),
+        jwt_keys,
+    );
+
+    let protected_routes = Router::new()
+        .route("/link", get(link))
+        .route_layer(middleware::from_fn(auth));
+
+    Router::new()
+        .merge(protected_routes)
+        .route("/authorize", get(authorize))
+        .route("/callback", post(callback))
+        .with_state(Arc::new(user_case))
+}
@@ -0,0 +1,60 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::{
+    application::{
+        services::mission_realtime::MissionRealtimeService,
+        use_cases::mission_invites::MissionInviteUseCase,
+    },
+    config::config_loader::get_mission_invite_env,
+    domain::services::notification_service::NotificationService,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres,
+            crew_operation::CrewOperationPostgres, mission_bans::MissionBanPostgres,
+            mission_invite_links::MissionInviteLinkPostgres,
+            mission_waitlist::MissionWaitlistPostgres,
+            mission_invites::MissionInvitePostgres, mission_messages::MissionMessagePostgres,
+            mission_viewing::MissionViewingPostgres,
+        },
+    },
+};
+
+/// Expires invites nobody answered in time.
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<dyn NotificationService>,
+    realtime_service: Arc<MissionRealtimeService>,
+) {
+    let every = Duration::from_secs(get_mission_invite_env().interval_secs.max(1));
+    let use_case = MissionInviteUseCase::new(
+        Arc::new(MissionInvitePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionMessagePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementRepositoryImpl::new(Arc::clone(&db_pool))),
+        Arc::new(MissionBanPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionInviteLinkPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionWaitlistPostgres::new(db_pool)),
+        notification_service,
+        realtime_service,
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match use_case.expire_invites().await {
+                Ok(0) => {}
+                Ok(expired) => info!("Expired {} mission invites", expired),
+                Err(e) => error!("Mission invite expiry failed: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,42 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::mission_management::MissionManagementUseCase,
+    config::config_loader::get_mission_retention_env,
+    domain::services::notification_service::NotificationService,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            mission_management::MissionManagementPostgres, mission_viewing::MissionViewingPostgres,
+            mission_waitlist::MissionWaitlistPostgres,
+        },
+    },
+};
+
+/// Hard-deletes soft-deleted missions once they fall out of the retention window.
+pub fn spawn(db_pool: Arc<PgPoolSquad>, notification_service: Arc<dyn NotificationService>) {
+    let every = Duration::from_secs(get_mission_retention_env().purge_interval_secs.max(1));
+    let use_case = MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionWaitlistPostgres::new(db_pool)),
+        notification_service,
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match use_case.purge_expired().await {
+                Ok(0) => {}
+                Ok(purged) => info!("Purged {} deleted missions", purged),
+                Err(e) => error!("Mission purge failed: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,64 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::{
+    application::{
+        services::mission_realtime::MissionRealtimeService,
+        use_cases::{mission_invites::MissionInviteUseCase, mission_templates::MissionTemplateUseCase},
+    },
+    config::config_loader::get_mission_recurrence_env,
+    domain::services::notification_service::NotificationService,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres, mission_bans::MissionBanPostgres,
+            mission_invite_links::MissionInviteLinkPostgres,
+            mission_waitlist::MissionWaitlistPostgres,
+            crew_operation::CrewOperationPostgres, mission_invites::MissionInvitePostgres,
+            mission_messages::MissionMessagePostgres, mission_templates::MissionTemplatePostgres,
+            mission_viewing::MissionViewingPostgres,
+        },
+    },
+};
+
+/// Creates missions for upcoming occurrences of recurring templates.
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<dyn NotificationService>,
+    realtime_service: Arc<MissionRealtimeService>,
+) {
+    let every = Duration::from_secs(get_mission_recurrence_env().interval_secs.max(1));
+    let invite_use_case = Arc::new(MissionInviteUseCase::new(
+        Arc::new(MissionInvitePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionMessagePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementRepositoryImpl::new(Arc::clone(&db_pool))),
+        Arc::new(MissionBanPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionInviteLinkPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionWaitlistPostgres::new(Arc::clone(&db_pool))),
+        notification_service,
+        realtime_service,
+    ));
+    let use_case = MissionTemplateUseCase::new(
+        Arc::new(MissionTemplatePostgres::new(db_pool)),
+        invite_use_case,
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match use_case.materialise_upcoming().await {
+                Ok(0) => {}
+                Ok(created) => info!("Scheduled {} missions from templates", created),
+                Err(e) => error!("Mission recurrence failed: {}", e),
+            }
+        }
+    });
+}
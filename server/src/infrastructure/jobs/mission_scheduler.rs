@@ -0,0 +1,63 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::{
+    application::{
+        services::mission_realtime::MissionRealtimeService,
+        use_cases::mission_operation::MissionOperationUseCase,
+    },
+    config::config_loader::get_mission_schedule_env,
+    domain::services::notification_service::NotificationService,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres,
+            mission_messages::MissionMessagePostgres, mission_operation::MissionOperationPostgres,
+            mission_viewing::MissionViewingPostgres,
+        },
+    },
+};
+
+/// Sends start reminders, starts missions at their scheduled time and fails them past their deadline.
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<dyn NotificationService>,
+    realtime_service: Arc<MissionRealtimeService>,
+) {
+    let every = Duration::from_secs(get_mission_schedule_env().interval_secs.max(1));
+    let use_case = MissionOperationUseCase::new(
+        Arc::new(MissionOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementRepositoryImpl::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionMessagePostgres::new(db_pool)),
+        notification_service,
+        realtime_service,
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match use_case.send_start_reminders().await {
+                Ok(0) => {}
+                Ok(sent) => info!("Sent start reminders for {} missions", sent),
+                Err(e) => error!("Mission reminders failed: {}", e),
+            }
+            match use_case.start_due_missions().await {
+                Ok(0) => {}
+                Ok(started) => info!("Started {} scheduled missions", started),
+                Err(e) => error!("Scheduled mission start failed: {}", e),
+            }
+            match use_case.fail_overdue_missions().await {
+                Ok(0) => {}
+                Ok(failed) => info!("Failed {} missions past their deadline", failed),
+                Err(e) => error!("Mission deadline check failed: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,57 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::{
+    application::{
+        services::mission_realtime::MissionRealtimeService,
+        use_cases::crew_operation::CrewOperationUseCase,
+    },
+    config::config_loader::get_mission_waitlist_env,
+    domain::services::notification_service::NotificationService,
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres,
+            crew_operation::CrewOperationPostgres, mission_join_requests::MissionJoinRequestPostgres,
+            mission_messages::MissionMessagePostgres, mission_viewing::MissionViewingPostgres,
+            mission_bans::MissionBanPostgres, mission_waitlist::MissionWaitlistPostgres,
+        },
+    },
+};
+
+/// Expires unanswered waitlist offers and passes their places to the next brawlers in line.
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    notification_service: Arc<dyn NotificationService>,
+    realtime_service: Arc<MissionRealtimeService>,
+) {
+    let every = Duration::from_secs(get_mission_waitlist_env().interval_secs.max(1));
+    let use_case = CrewOperationUseCase::new(
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(AchievementRepositoryImpl::new(Arc::clone(&db_pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionMessagePostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionJoinRequestPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionWaitlistPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionBanPostgres::new(db_pool)),
+        notification_service,
+        realtime_service,
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match use_case.expire_waitlist_offers().await {
+                Ok(0) => {}
+                Ok(missions) => info!("Passed on lapsed waitlist offers for {} missions", missions),
+                Err(e) => error!("Waitlist offer expiry failed: {}", e),
+            }
+        }
+    });
+}
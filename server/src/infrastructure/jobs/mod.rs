@@ -0,0 +1,5 @@
+pub mod mission_invites;
+pub mod mission_purge;
+pub mod mission_recurrence;
+pub mod mission_scheduler;
+pub mod mission_waitlist;
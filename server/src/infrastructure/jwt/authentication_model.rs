@@ -1,12 +1,65 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::{
+    config::config_model::ValidationEnv,
+    domain::{
+        errors::FieldError,
+        value_objects::validation::{Validate, check_length},
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct LoginModel {
     pub username: String,
     pub password: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RecoverPasswordModel {
     pub username: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OidcCallbackModel {
+    pub code: String,
+    pub state: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OidcAuthorizationModel {
+    pub authorization_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StreamTicketModel {
+    pub ticket: String,
+    pub expires_in: u64,
+}
+
+/// Only presence is checked here; length rules would leak which accounts exist.
+impl Validate for LoginModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "username", &self.username, 1, limits.username_max_len);
+        if self.password.is_empty() {
+            errors.push(FieldError::new("password", "is required"));
+        }
+    }
+}
+
+impl Validate for RecoverPasswordModel {
+    fn validate(&self, limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        check_length(errors, "username", &self.username, 1, limits.username_max_len);
+    }
+}
+
+impl Validate for OidcCallbackModel {
+    fn validate(&self, _limits: &ValidationEnv, errors: &mut Vec<FieldError>) {
+        if self.code.is_empty() {
+            errors.push(FieldError::new("code", "is required"));
+        }
+        if self.state.is_empty() {
+            errors.push(FieldError::new("state", "is required"));
+        }
+    }
+}
@@ -3,11 +3,12 @@ use anyhow::Result;
 use chrono::Duration;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use crate::config::config_loader::get_jwt_env;
-use crate::infrastructure::jwt::generate_token;
+use crate::domain::{entities::brawlers::BrawlerEntity, value_objects::roles::Roles};
+use crate::infrastructure::{jwt::JwtKeys, secure_token};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Passport {
     // pub token_type: String,
     pub token: String,
@@ -16,23 +17,28 @@ pub struct Passport {
     pub avatar_url: Option<String>,
     pub mission_success_count: i32,
     pub mission_join_count: i32,
+    pub role: Roles,
 }
 
 impl Passport {
-    pub fn new(user_id: i32, display_name: String, avatar_url: Option<String>, mission_success_count: i32, mission_join_count: i32) -> Result<Self> {
-        let jwt_env = get_jwt_env()?;
+    pub fn new(jwt_keys: &JwtKeys, brawler: BrawlerEntity) -> Result<Self> {
+        let role: Roles = brawler.role.parse()?;
         let claims = Claims {
-            sub: user_id.to_string(),
-            exp: (Utc::now() + Duration::days(jwt_env.ttl)).timestamp() as usize,
+            sub: brawler.id.to_string(),
+            exp: (Utc::now() + Duration::days(jwt_keys.ttl)).timestamp() as usize,
             iat: Utc::now().timestamp() as usize,
+            role,
+            sid: Some(secure_token::generate(16)),
+            ver: brawler.token_version,
         };
-        let token = generate_token(jwt_env.secret, &claims)?;
+        let token = jwt_keys.generate_token(&claims)?;
         Ok(Self {
             token,
-            display_name,
-            avatar_url,
-            mission_success_count,
-            mission_join_count,
+            display_name: brawler.display_name,
+            avatar_url: brawler.avatar_url,
+            mission_success_count: brawler.mission_success_count,
+            mission_join_count: brawler.mission_join_count,
+            role,
         })
     }
 }
@@ -42,4 +48,12 @@ pub struct Claims {
     pub sub: String,
     pub exp: usize,
     pub iat: usize,
+    #[serde(default)]
+    pub role: Roles,
+    /// Session id, fresh for every issued token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    /// The brawler's `token_version` when the token was issued.
+    #[serde(default)]
+    pub ver: i32,
 }
@@ -1,25 +1,64 @@
 pub mod authentication_model;
 pub mod jwt_model;
 
+use std::collections::HashMap;
+
 use anyhow::{Ok, Result};
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode};
 
-pub fn generate_token(secret: String, claims: &jwt_model::Claims) -> Result<String> {
-    let token = encode(
-        &Header::default(),
-        claims,
-        &EncodingKey::from_secret(secret.as_ref()),
-    )?;
+use crate::config::config_model::JwtEnv;
 
-    Ok(token)
+/// Signing key plus every key still accepted for verification, indexed by `kid`.
+/// Built once at startup and shared through app state.
+pub struct JwtKeys {
+    active_kid: String,
+    encoding_key: EncodingKey,
+    decoding_keys: HashMap<String, DecodingKey>,
+    pub ttl: i64,
 }
 
-pub fn verify_token(secret: String, token: String) -> Result<jwt_model::Claims> {
-    let token = decode::<jwt_model::Claims>(
-        &token,
-        &DecodingKey::from_secret(secret.as_ref()),
-        &Validation::default(),
-    )?;
+impl JwtKeys {
+    pub fn from_env(jwt_env: &JwtEnv) -> Self {
+        let mut decoding_keys = HashMap::new();
+        for (kid, secret) in &jwt_env.previous_secrets {
+            decoding_keys.insert(kid.clone(), DecodingKey::from_secret(secret.as_ref()));
+        }
+        decoding_keys.insert(
+            jwt_env.kid.clone(),
+            DecodingKey::from_secret(jwt_env.secret.as_ref()),
+        );
+
+        Self {
+            active_kid: jwt_env.kid.clone(),
+            encoding_key: EncodingKey::from_secret(jwt_env.secret.as_ref()),
+            decoding_keys,
+            ttl: jwt_env.ttl,
+        }
+    }
+
+    pub fn generate_token(&self, claims: &jwt_model::Claims) -> Result<String> {
+        let header = Header {
+            kid: Some(self.active_kid.clone()),
+            ..Default::default()
+        };
+
+        let token = encode(&header, claims, &self.encoding_key)?;
+
+        Ok(token)
+    }
+
+    pub fn verify_token(&self, token: &str) -> Result<jwt_model::Claims> {
+        // Tokens issued before key ids were introduced carry no `kid`; they were signed with the active secret.
+        let kid = decode_header(token)?
+            .kid
+            .unwrap_or_else(|| self.active_kid.clone());
+        let key = self
+            .decoding_keys
+            .get(&kid)
+            .ok_or_else(|| anyhow::anyhow!("Unknown signing key: {}", kid))?;
+
+        let token = decode::<jwt_model::Claims>(token, key, &Validation::default())?;
 
-    Ok(token.claims)
+        Ok(token.claims)
+    }
 }
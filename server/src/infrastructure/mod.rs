@@ -2,5 +2,9 @@ pub mod argon2;
 pub mod cloudinary;
 pub mod database;
 pub mod http;
+pub mod jobs;
 pub mod jwt;
+pub mod oidc;
+pub mod secure_token;
 pub mod services;
+pub mod stream_tickets;
@@ -0,0 +1,178 @@
+pub mod pending_login;
+
+use anyhow::{Context, Result};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header, jwk::JwkSet};
+use reqwest::Url;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use sha2::{Digest, Sha256};
+use tracing::{debug, error};
+
+use crate::config::config_model::OidcEnv;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+    #[serde(default)]
+    pub id_token_signing_alg_values_supported: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenResponse {
+    pub id_token: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub exp: usize,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub preferred_username: Option<String>,
+}
+
+async fn get_json<T: DeserializeOwned>(url: &str) -> Result<T> {
+    let response = reqwest::Client::new()
+        .get(url)
+        .send()
+        .await
+        .with_context(|| format!("Failed to connect to OIDC provider: {}", url))?;
+
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        error!("OIDC request to {} failed. Status: {}, Response: {}", url, status, text);
+        return Err(anyhow::anyhow!("OIDC request failed (Status {})", status));
+    }
+
+    serde_json::from_str(&text).with_context(|| format!("Failed to parse OIDC response from {}", url))
+}
+
+pub async fn discover(oidc_env: &OidcEnv) -> Result<ProviderMetadata> {
+    let url = format!("{}/.well-known/openid-configuration", oidc_env.issuer_url);
+    debug!("Fetching OIDC discovery document: {}", url);
+
+    let metadata: ProviderMetadata = get_json(&url).await?;
+    if metadata.issuer.trim_end_matches('/') != oidc_env.issuer_url {
+        return Err(anyhow::anyhow!("OIDC discovery issuer does not match configured issuer"));
+    }
+
+    Ok(metadata)
+}
+
+/// S256 code challenge for the given PKCE verifier (RFC 7636).
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+pub fn authorization_url(
+    metadata: &ProviderMetadata,
+    oidc_env: &OidcEnv,
+    state: &str,
+    nonce: &str,
+    code_verifier: &str,
+) -> Result<String> {
+    let url = Url::parse_with_params(
+        &metadata.authorization_endpoint,
+        &[
+            ("response_type", "code"),
+            ("client_id", oidc_env.client_id.as_str()),
+            ("redirect_uri", oidc_env.redirect_url.as_str()),
+            ("scope", oidc_env.scopes.as_str()),
+            ("state", state),
+            ("nonce", nonce),
+            ("code_challenge", pkce_challenge(code_verifier).as_str()),
+            ("code_challenge_method", "S256"),
+        ],
+    )?;
+
+    Ok(url.to_string())
+}
+
+pub async fn exchange_code(
+    metadata: &ProviderMetadata,
+    oidc_env: &OidcEnv,
+    code: &str,
+    code_verifier: &str,
+) -> Result<TokenResponse> {
+    let response = reqwest::Client::new()
+        .post(&metadata.token_endpoint)
+        .basic_auth(&oidc_env.client_id, Some(&oidc_env.client_secret))
+        .form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", oidc_env.redirect_url.as_str()),
+            ("client_id", oidc_env.client_id.as_str()),
+            ("code_verifier", code_verifier),
+        ])
+        .send()
+        .await
+        .context("Failed to connect to OIDC token endpoint")?;
+
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        error!("OIDC token exchange failed. Status: {}, Response: {}", status, text);
+        return Err(anyhow::anyhow!("OIDC token exchange failed (Status {})", status));
+    }
+
+    serde_json::from_str(&text).context("Failed to parse OIDC token response")
+}
+
+/// Algorithms an ID token may be signed with: the configured list, else the provider's
+/// advertised list, else RS256 as OIDC requires. HMAC is never accepted, since its key
+/// would be the client secret rather than one the provider publishes.
+pub fn allowed_algorithms(metadata: &ProviderMetadata, oidc_env: &OidcEnv) -> Vec<Algorithm> {
+    let names = if oidc_env.id_token_signing_algs.is_empty() {
+        &metadata.id_token_signing_alg_values_supported
+    } else {
+        &oidc_env.id_token_signing_algs
+    };
+    if names.is_empty() {
+        return vec![Algorithm::RS256];
+    }
+
+    names
+        .iter()
+        .filter_map(|name| name.parse().ok())
+        .filter(|alg| !matches!(alg, Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512))
+        .collect()
+}
+
+pub async fn verify_id_token(
+    metadata: &ProviderMetadata,
+    oidc_env: &OidcEnv,
+    id_token: &str,
+    expected_nonce: &str,
+) -> Result<IdTokenClaims> {
+    let header = decode_header(id_token)?;
+    let algorithms = allowed_algorithms(metadata, oidc_env);
+    if !algorithms.contains(&header.alg) {
+        return Err(anyhow::anyhow!("ID token algorithm {:?} is not allowed", header.alg));
+    }
+
+    let jwks: JwkSet = get_json(&metadata.jwks_uri).await?;
+    let jwk = match header.kid.as_deref() {
+        Some(kid) => jwks.find(kid),
+        None => jwks.keys.first(),
+    }
+    .ok_or_else(|| anyhow::anyhow!("No matching signing key for ID token"))?;
+    let key = DecodingKey::from_jwk(jwk)?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.algorithms = algorithms;
+    validation.set_audience(&[&oidc_env.client_id]);
+    validation.set_issuer(&[&metadata.issuer]);
+
+    let claims = decode::<IdTokenClaims>(id_token, &key, &validation)?.claims;
+    if claims.nonce.as_deref() != Some(expected_nonce) {
+        return Err(anyhow::anyhow!("ID token nonce mismatch"));
+    }
+
+    Ok(claims)
+}
@@ -0,0 +1,55 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+const PENDING_LOGIN_TTL: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug, Clone)]
+pub struct PendingLogin {
+    pub code_verifier: String,
+    pub nonce: String,
+    /// Set when an already signed-in brawler is linking a new identity.
+    pub link_brawler_id: Option<i32>,
+    created_at: Instant,
+}
+
+impl PendingLogin {
+    pub fn new(code_verifier: String, nonce: String, link_brawler_id: Option<i32>) -> Self {
+        Self {
+            code_verifier,
+            nonce,
+            link_brawler_id,
+            created_at: Instant::now(),
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.created_at.elapsed() > PENDING_LOGIN_TTL
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct PendingLoginStore {
+    // Map state -> pending authorization request
+    logins: Arc<Mutex<HashMap<String, PendingLogin>>>,
+}
+
+impl PendingLoginStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&self, state: String, pending: PendingLogin) {
+        let mut logins = self.logins.lock().unwrap();
+        logins.retain(|_, login| !login.is_expired());
+        logins.insert(state, pending);
+    }
+
+    /// Removes the pending login so a `state` can only be redeemed once.
+    pub fn take(&self, state: &str) -> Option<PendingLogin> {
+        let mut logins = self.logins.lock().unwrap();
+        logins.remove(state).filter(|login| !login.is_expired())
+    }
+}
@@ -0,0 +1,37 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// URL-safe random token built from `num_bytes` bytes of OS randomness.
+pub fn generate(num_bytes: usize) -> String {
+    let mut bytes = vec![0u8; num_bytes];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// SHA-256 digest of a token for storage; random tokens don't need a slow password hash.
+pub fn hash(token: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Whether `token` hashes to `stored_hash`, compared in constant time so the check does not leak
+/// how much of the hash matched.
+pub fn matches(token: &str, stored_hash: &str) -> bool {
+    hash(token).as_bytes().ct_eq(stored_hash.as_bytes()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_only_the_hash_of_the_same_token() {
+        let token = generate(32);
+        let stored_hash = hash(&token);
+
+        assert!(matches(&token, &stored_hash));
+        assert!(!matches(&generate(32), &stored_hash));
+        assert!(!matches(&token, &stored_hash[1..]));
+    }
+}
@@ -7,6 +7,12 @@ pub struct MissionWebSocketService {
     rooms: Arc<RwLock<HashMap<i32, broadcast::Sender<String>>>>,
 }
 
+impl Default for MissionWebSocketService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MissionWebSocketService {
     pub fn new() -> Self {
         Self {
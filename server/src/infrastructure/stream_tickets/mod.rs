@@ -0,0 +1,59 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::infrastructure::{http::extractors::CurrentBrawler, secure_token};
+
+pub const STREAM_TICKET_TTL: Duration = Duration::from_secs(30);
+
+struct StreamTicket {
+    brawler: CurrentBrawler,
+    created_at: Instant,
+}
+
+impl StreamTicket {
+    fn is_expired(&self) -> bool {
+        self.created_at.elapsed() > STREAM_TICKET_TTL
+    }
+}
+
+/// Short-lived, single-use tickets that stand in for the access token on
+/// `EventSource`/WebSocket URLs, where request headers cannot be set.
+#[derive(Clone, Default)]
+pub struct StreamTicketStore {
+    // Map ticket -> brawler it was issued to
+    tickets: Arc<Mutex<HashMap<String, StreamTicket>>>,
+}
+
+impl StreamTicketStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn issue(&self, brawler: CurrentBrawler) -> String {
+        let ticket = secure_token::generate(32);
+
+        let mut tickets = self.tickets.lock().unwrap();
+        tickets.retain(|_, ticket| !ticket.is_expired());
+        tickets.insert(
+            ticket.clone(),
+            StreamTicket {
+                brawler,
+                created_at: Instant::now(),
+            },
+        );
+
+        ticket
+    }
+
+    /// Removes the ticket so it can only be redeemed once.
+    pub fn redeem(&self, ticket: &str) -> Option<CurrentBrawler> {
+        let mut tickets = self.tickets.lock().unwrap();
+        tickets
+            .remove(ticket)
+            .filter(|ticket| !ticket.is_expired())
+            .map(|ticket| ticket.brawler)
+    }
+}
@@ -4,7 +4,7 @@ use server::{
     config::config_loader,
     infrastructure::{database::postgresql_connection, http::http_serv::start},
 };
-use tracing::{error, info};
+use tracing::error;
 
 use std::io::{self, Write};
 
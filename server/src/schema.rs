@@ -15,6 +15,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    api_keys (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 16]
+        prefix -> Varchar,
+        #[max_length = 64]
+        key_hash -> Varchar,
+        scopes -> Array<Text>,
+        last_used_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     brawler_achievements (brawler_id, achievement_id) {
         brawler_id -> Int4,
@@ -40,6 +57,16 @@ diesel::table! {
         avatar_public_id -> Nullable<Varchar>,
         mission_success_count -> Int4,
         mission_join_count -> Int4,
+        #[max_length = 20]
+        role -> Varchar,
+        disabled_at -> Nullable<Timestamp>,
+        is_bot -> Bool,
+        bot_owner_id -> Nullable<Int4>,
+        #[max_length = 64]
+        calendar_token_hash -> Nullable<Varchar>,
+        reputation -> Float8,
+        rating_count -> Int4,
+        token_version -> Int4,
     }
 }
 
@@ -48,6 +75,35 @@ diesel::table! {
         mission_id -> Int4,
         brawler_id -> Int4,
         joined_at -> Timestamp,
+        role_slot_id -> Nullable<Int4>,
+        is_co_chief -> Bool,
+    }
+}
+
+diesel::table! {
+    identities (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 255]
+        provider -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    login_attempts (id) {
+        id -> Int4,
+        #[max_length = 255]
+        username -> Varchar,
+        brawler_id -> Nullable<Int4>,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        succeeded -> Bool,
+        attempted_at -> Timestamp,
     }
 }
 
@@ -64,6 +120,46 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mission_bans (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        banned_by -> Nullable<Int4>,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_invite_link_uses (id) {
+        id -> Int4,
+        link_id -> Int4,
+        brawler_id -> Int4,
+        used_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_invite_links (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        created_by -> Nullable<Int4>,
+        role_slot_id -> Nullable<Int4>,
+        max_uses -> Nullable<Int4>,
+        use_count -> Int4,
+        expires_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     mission_invites (id) {
         id -> Int4,
@@ -72,6 +168,95 @@ diesel::table! {
         #[max_length = 50]
         status -> Varchar,
         created_at -> Timestamp,
+        invited_by -> Nullable<Int4>,
+        expires_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_join_requests (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        role_slot_id -> Nullable<Int4>,
+        #[max_length = 20]
+        status -> Varchar,
+        decided_by -> Nullable<Int4>,
+        decided_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_objectives (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        #[max_length = 255]
+        title -> Varchar,
+        position -> Int4,
+        is_mandatory -> Bool,
+        completed_by -> Nullable<Int4>,
+        completed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_ratings (id) {
+        id -> Int4,
+        mission_id -> Nullable<Int4>,
+        rater_id -> Nullable<Int4>,
+        ratee_id -> Int4,
+        score -> Int4,
+        tags -> Array<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_role_slots (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        #[max_length = 50]
+        label -> Varchar,
+        position -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_templates (id) {
+        id -> Int4,
+        owner_id -> Int4,
+        name -> Varchar,
+        description -> Nullable<Text>,
+        category -> Nullable<Varchar>,
+        max_crew -> Int4,
+        image_url -> Nullable<Varchar>,
+        recurrence -> Nullable<Varchar>,
+        duration_minutes -> Nullable<Int4>,
+        auto_invite -> Bool,
+        last_scheduled_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_waitlist (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 20]
+        status -> Varchar,
+        offered_at -> Nullable<Timestamp>,
+        offer_expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
@@ -93,25 +278,67 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        scheduled_start_at -> Nullable<Timestamp>,
+        deadline_at -> Nullable<Timestamp>,
+        duration_minutes -> Nullable<Int4>,
+        reminder_sent_at -> Nullable<Timestamp>,
+        auto_start_checked_at -> Nullable<Timestamp>,
+        template_id -> Nullable<Int4>,
+        objectives_required -> Bool,
+        #[max_length = 20]
+        join_policy -> Varchar,
+        finished_at -> Nullable<Timestamp>,
+        min_reputation -> Nullable<Float8>,
     }
 }
 
+diesel::joinable!(api_keys -> brawlers (brawler_id));
 diesel::joinable!(brawler_achievements -> achievements (achievement_id));
 diesel::joinable!(brawler_achievements -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
+diesel::joinable!(crew_memberships -> mission_role_slots (role_slot_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(identities -> brawlers (brawler_id));
+diesel::joinable!(login_attempts -> brawlers (brawler_id));
+diesel::joinable!(mission_join_requests -> missions (mission_id));
+diesel::joinable!(mission_join_requests -> mission_role_slots (role_slot_id));
 diesel::joinable!(mission_messages -> brawlers (user_id));
 diesel::joinable!(mission_messages -> missions (mission_id));
+diesel::joinable!(mission_objectives -> brawlers (completed_by));
+diesel::joinable!(mission_objectives -> missions (mission_id));
+diesel::joinable!(mission_ratings -> missions (mission_id));
+diesel::joinable!(mission_role_slots -> missions (mission_id));
+diesel::joinable!(mission_templates -> brawlers (owner_id));
+diesel::joinable!(mission_bans -> missions (mission_id));
+diesel::joinable!(mission_invite_link_uses -> brawlers (brawler_id));
+diesel::joinable!(mission_invite_link_uses -> mission_invite_links (link_id));
+diesel::joinable!(mission_invite_links -> mission_role_slots (role_slot_id));
+diesel::joinable!(mission_invite_links -> missions (mission_id));
 diesel::joinable!(mission_invites -> brawlers (user_id));
 diesel::joinable!(mission_invites -> missions (mission_id));
+diesel::joinable!(mission_waitlist -> brawlers (brawler_id));
+diesel::joinable!(mission_waitlist -> missions (mission_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(missions -> mission_templates (template_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     achievements,
+    api_keys,
     brawler_achievements,
     brawlers,
     crew_memberships,
+    identities,
+    login_attempts,
+    mission_bans,
+    mission_invite_link_uses,
+    mission_invite_links,
     mission_invites,
+    mission_join_requests,
     mission_messages,
+    mission_objectives,
+    mission_ratings,
+    mission_role_slots,
+    mission_templates,
+    mission_waitlist,
     missions,
 );
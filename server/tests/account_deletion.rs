@@ -0,0 +1,59 @@
+mod common;
+
+use std::sync::Arc;
+
+use server::{
+    application::use_cases::brawlers::BrawlersUseCase,
+    config::config_model::JwtEnv,
+    domain::{
+        repositories::mission_viewing::MissionViewingRepository,
+        value_objects::waitlist_model::WaitlistStatus,
+    },
+    infrastructure::{
+        database::repositories::{
+            brawlers::BrawlerPostgres, mission_viewing::MissionViewingPostgres,
+            mission_waitlist::MissionWaitlistPostgres,
+        },
+        jwt::JwtKeys,
+        services::notification_service::NotificationServiceImpl,
+    },
+};
+
+#[tokio::test]
+async fn deleted_members_place_goes_to_the_waitlist() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let viewing = MissionViewingPostgres::new(Arc::clone(&pool));
+    let (tx, _) = tokio::sync::broadcast::channel(64);
+    let brawlers = BrawlersUseCase::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(&pool))),
+        Arc::new(MissionWaitlistPostgres::new(Arc::clone(&pool))),
+        Arc::new(JwtKeys::from_env(&JwtEnv {
+            secret: "account-deletion-test-secret".to_string(),
+            ttl: 1,
+            kid: "test".to_string(),
+            previous_secrets: Vec::new(),
+        })),
+        Arc::new(NotificationServiceImpl::new(tx)),
+    );
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let waiting = common::brawler(&pool).await;
+    let open_id = common::mission(&pool, chief.id, "Open", 1);
+    let finished_id = common::mission(&pool, chief.id, "Open", 1);
+
+    crew.join(open_id, member.id, None).await.unwrap();
+    crew.join(finished_id, member.id, None).await.unwrap();
+    common::execute(&pool, &format!("UPDATE missions SET status = 'Completed' WHERE id = {finished_id}"));
+    crew.join_waitlist(open_id, waiting.id).await.unwrap();
+
+    brawlers.delete_account(member.id).await.unwrap();
+
+    assert!(viewing.get_crew(open_id).await.unwrap().is_empty());
+    // Finished crews are kept as history.
+    assert_eq!(viewing.get_crew(finished_id).await.unwrap().len(), 1);
+
+    let waitlist = crew.get_waitlist(open_id, chief.id).await.unwrap();
+    let entry = waitlist.iter().find(|entry| entry.brawler_id == waiting.id).unwrap();
+    assert_eq!(entry.status, WaitlistStatus::Offered.to_string());
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use server::{
+    domain::{
+        repositories::mission_invites::MissionInviteRepository,
+        value_objects::{
+            ban_model::BanModel,
+            invite_model::{BulkInviteOutcome, InviteRecipient},
+        },
+    },
+    infrastructure::database::repositories::mission_invites::MissionInvitePostgres,
+};
+
+#[tokio::test]
+async fn each_recipient_gets_its_own_outcome_in_order() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let invites = MissionInvitePostgres::new(Arc::clone(&pool));
+    let chief = common::brawler(&pool).await;
+    let by_id = common::brawler(&pool).await;
+    let by_name = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let banned = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+    crew.join(mission_id, member.id, None).await.unwrap();
+    crew.join(mission_id, banned.id, None).await.unwrap();
+    crew.kick_crew(mission_id, chief.id, banned.id, Some(BanModel::default())).await.unwrap();
+
+    let now = Utc::now().naive_utc();
+    let expires_at = now + Duration::days(1);
+    let recipients = vec![
+        InviteRecipient::Id(by_id.id),
+        InviteRecipient::Username(by_name.username.clone()),
+        InviteRecipient::Id(member.id),
+        InviteRecipient::Id(chief.id),
+        InviteRecipient::Id(banned.id),
+        InviteRecipient::Username(common::unique("nobody")),
+    ];
+    let results = invites
+        .create_many(mission_id, chief.id, recipients.clone(), expires_at, now)
+        .await
+        .unwrap();
+
+    let outcomes: Vec<BulkInviteOutcome> = results.iter().map(|result| result.outcome).collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            BulkInviteOutcome::Invited,
+            BulkInviteOutcome::Invited,
+            BulkInviteOutcome::AlreadyMember,
+            BulkInviteOutcome::AlreadyMember,
+            BulkInviteOutcome::Banned,
+            BulkInviteOutcome::NotFound,
+        ]
+    );
+    assert_eq!(results.iter().map(|result| result.recipient.clone()).collect::<Vec<_>>(), recipients);
+    assert_eq!(results[1].user_id, Some(by_name.id));
+    assert!(results[0].invite_id.is_some() && results[2].invite_id.is_none());
+
+    // Inviting the same brawlers again does not pile up pending invites.
+    let again = invites
+        .create_many(mission_id, chief.id, recipients[..2].to_vec(), expires_at, now)
+        .await
+        .unwrap();
+    assert!(again.iter().all(|result| result.outcome == BulkInviteOutcome::AlreadyInvited));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_batches_invite_a_brawler_once() {
+    let pool = require_db!();
+    let invites = Arc::new(MissionInvitePostgres::new(Arc::clone(&pool)));
+    let chief = common::brawler(&pool).await;
+    let invitee = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+    let now = Utc::now().naive_utc();
+
+    let batches: Vec<_> = (0..4)
+        .map(|_| {
+            let invites = Arc::clone(&invites);
+            let recipients = vec![InviteRecipient::Id(invitee.id)];
+            tokio::spawn(async move {
+                invites
+                    .create_many(mission_id, chief.id, recipients, now + Duration::days(1), now)
+                    .await
+            })
+        })
+        .collect();
+    let mut invited = 0;
+    for batch in batches {
+        let results = batch.await.unwrap().unwrap();
+        invited += results.iter().filter(|result| result.outcome == BulkInviteOutcome::Invited).count();
+    }
+
+    assert_eq!(invited, 1);
+    assert_eq!(invites.find_invites_by_mission(mission_id).await.unwrap().len(), 1);
+}
@@ -0,0 +1,157 @@
+//! Shared setup for the database tests. They run against the database in `DATABASE_URL`
+//! and are skipped when it is not set. Every test creates its own brawlers and missions
+//! under unique names, so they can share one database and run in parallel.
+#![allow(dead_code)]
+
+use std::sync::{
+    Arc, OnceLock,
+    atomic::{AtomicU32, Ordering},
+};
+
+use diesel::{RunQueryDsl, sql_query, sql_types::Int4};
+use server::{
+    application::{
+        services::mission_realtime::MissionRealtimeService,
+        use_cases::{crew_operation::CrewOperationUseCase, mission_invites::MissionInviteUseCase},
+    },
+    domain::{
+        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        repositories::BrawlerRepository,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::{PgPoolSquad, establish_connection},
+            repositories::{
+                achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres,
+                crew_operation::CrewOperationPostgres, mission_bans::MissionBanPostgres,
+                mission_invite_links::MissionInviteLinkPostgres, mission_invites::MissionInvitePostgres,
+                mission_join_requests::MissionJoinRequestPostgres, mission_messages::MissionMessagePostgres,
+                mission_viewing::MissionViewingPostgres, mission_waitlist::MissionWaitlistPostgres,
+            },
+        },
+        services::notification_service::NotificationServiceImpl,
+    },
+};
+
+pub type CrewOperation = CrewOperationUseCase<
+    CrewOperationPostgres,
+    MissionViewingPostgres,
+    AchievementRepositoryImpl,
+    BrawlerPostgres,
+    MissionMessagePostgres,
+    MissionJoinRequestPostgres,
+    MissionWaitlistPostgres,
+    MissionBanPostgres,
+>;
+
+/// Returns the migrated pool, or skips the calling test when no database is configured.
+#[macro_export]
+macro_rules! require_db {
+    () => {
+        match common::pool() {
+            Some(pool) => pool,
+            None => {
+                eprintln!("DATABASE_URL is not set, skipping");
+                return;
+            }
+        }
+    };
+}
+
+pub fn pool() -> Option<Arc<PgPoolSquad>> {
+    static POOL: OnceLock<Option<Arc<PgPoolSquad>>> = OnceLock::new();
+    POOL.get_or_init(|| {
+        dotenvy::dotenv().ok();
+        let url = std::env::var("DATABASE_URL").ok()?;
+        Some(Arc::new(establish_connection(&url).expect("failed to connect to DATABASE_URL")))
+    })
+    .clone()
+}
+
+pub fn unique(prefix: &str) -> String {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .subsec_nanos();
+    format!(
+        "{prefix}-{}-{nanos}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+pub async fn brawler(pool: &Arc<PgPoolSquad>) -> BrawlerEntity {
+    let username = unique("brawler");
+    BrawlerPostgres::new(Arc::clone(pool))
+        .register(RegisterBrawlerEntity {
+            display_name: username.clone(),
+            username,
+            password: "not-a-hash".to_string(),
+        })
+        .await
+        .expect("failed to register brawler")
+}
+
+/// Inserts a mission straight into the table; returns its id.
+pub fn mission(pool: &Arc<PgPoolSquad>, chief_id: i32, status: &str, max_crew: i32) -> i32 {
+    #[derive(diesel::QueryableByName)]
+    struct Id {
+        #[diesel(sql_type = Int4)]
+        id: i32,
+    }
+
+    let mut conn = pool.get().unwrap();
+    sql_query("INSERT INTO missions (name, status, chief_id, max_crew) VALUES ($1, $2, $3, $4) RETURNING id")
+        .bind::<diesel::sql_types::Varchar, _>(unique("mission"))
+        .bind::<diesel::sql_types::Varchar, _>(status)
+        .bind::<Int4, _>(chief_id)
+        .bind::<Int4, _>(max_crew)
+        .get_result::<Id>(&mut conn)
+        .expect("failed to insert mission")
+        .id
+}
+
+/// Runs raw SQL for setup that has no repository method.
+pub fn execute(pool: &Arc<PgPoolSquad>, sql: &str) {
+    use diesel::connection::SimpleConnection;
+    pool.get().unwrap().batch_execute(sql).expect("setup SQL failed");
+}
+
+pub fn crew_operation(pool: &Arc<PgPoolSquad>) -> CrewOperation {
+    crew_operation_with(pool, Arc::new(MissionRealtimeService::new()))
+}
+
+/// `crew_operation` broadcasting through the given live chat service.
+pub fn crew_operation_with(pool: &Arc<PgPoolSquad>, realtime_service: Arc<MissionRealtimeService>) -> CrewOperation {
+    let (tx, _) = tokio::sync::broadcast::channel(64);
+    CrewOperationUseCase::new(
+        Arc::new(CrewOperationPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(pool))),
+        Arc::new(AchievementRepositoryImpl::new(Arc::clone(pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionMessagePostgres::new(Arc::clone(pool))),
+        Arc::new(MissionJoinRequestPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionWaitlistPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionBanPostgres::new(Arc::clone(pool))),
+        Arc::new(NotificationServiceImpl::new(tx)),
+        realtime_service,
+    )
+}
+
+pub fn mission_invites(pool: &Arc<PgPoolSquad>) -> MissionInviteUseCase {
+    let (tx, _) = tokio::sync::broadcast::channel(64);
+    MissionInviteUseCase::new(
+        Arc::new(MissionInvitePostgres::new(Arc::clone(pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(pool))),
+        Arc::new(CrewOperationPostgres::new(Arc::clone(pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionMessagePostgres::new(Arc::clone(pool))),
+        Arc::new(AchievementRepositoryImpl::new(Arc::clone(pool))),
+        Arc::new(MissionBanPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionInviteLinkPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionWaitlistPostgres::new(Arc::clone(pool))),
+        Arc::new(NotificationServiceImpl::new(tx)),
+        Arc::new(MissionRealtimeService::new()),
+    )
+}
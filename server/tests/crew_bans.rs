@@ -0,0 +1,95 @@
+mod common;
+
+use server::domain::{
+    errors::AppError,
+    repositories::mission_viewing::MissionViewingRepository,
+    value_objects::ban_model::BanModel,
+};
+use server::infrastructure::database::repositories::mission_viewing::MissionViewingPostgres;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn kick_requires_a_crew_member_and_never_targets_the_chief() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let chief = common::brawler(&pool).await;
+    let co_chief = common::brawler(&pool).await;
+    let outsider = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+
+    crew.join(mission_id, co_chief.id, None).await.unwrap();
+    crew.set_co_chief(mission_id, chief.id, co_chief.id, true).await.unwrap();
+
+    let ban = Some(BanModel::default());
+    assert!(matches!(
+        crew.kick_crew(mission_id, co_chief.id, chief.id, ban.clone()).await,
+        Err(AppError::Forbidden(_))
+    ));
+    assert!(matches!(
+        crew.kick_crew(mission_id, chief.id, outsider.id, ban).await,
+        Err(AppError::NotFound(_))
+    ));
+
+    // Neither attempt may leave a ban behind.
+    assert!(crew.get_bans(mission_id, chief.id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn banned_member_can_not_rejoin_until_the_ban_is_lifted() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let viewing = MissionViewingPostgres::new(Arc::clone(&pool));
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+
+    crew.join(mission_id, member.id, None).await.unwrap();
+    crew.kick_crew(mission_id, chief.id, member.id, Some(BanModel::default())).await.unwrap();
+    assert!(viewing.get_crew(mission_id).await.unwrap().is_empty());
+
+    assert!(matches!(crew.join(mission_id, member.id, None).await, Err(AppError::Forbidden(_))));
+    assert!(matches!(crew.join_waitlist(mission_id, member.id).await, Err(AppError::Forbidden(_))));
+
+    crew.lift_ban(mission_id, chief.id, member.id).await.unwrap();
+    crew.join(mission_id, member.id, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn kick_without_ban_lets_the_member_back() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+
+    crew.join(mission_id, member.id, None).await.unwrap();
+    crew.kick_crew(mission_id, chief.id, member.id, None).await.unwrap();
+    crew.join(mission_id, member.id, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn timed_ban_stops_applying_once_it_runs_out() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+
+    crew.join(mission_id, member.id, None).await.unwrap();
+    let ban = BanModel {
+        reason: Some("cool off".to_string()),
+        expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+    };
+    crew.kick_crew(mission_id, chief.id, member.id, Some(ban)).await.unwrap();
+    assert!(matches!(crew.join(mission_id, member.id, None).await, Err(AppError::Forbidden(_))));
+
+    common::execute(
+        &pool,
+        &format!(
+            "UPDATE mission_bans SET expires_at = now() - interval '1 minute'
+             WHERE mission_id = {mission_id} AND brawler_id = {}",
+            member.id
+        ),
+    );
+    crew.join(mission_id, member.id, None).await.unwrap();
+}
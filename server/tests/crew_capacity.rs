@@ -0,0 +1,81 @@
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use server::{
+    domain::{
+        entities::{crew_memberships::CrewMemberShips, mission_waitlist::AddMissionWaitlistEntity},
+        errors::AppError,
+        repositories::{
+            crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+            mission_waitlist::MissionWaitlistRepository,
+        },
+    },
+    infrastructure::database::repositories::{
+        crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres,
+        mission_waitlist::MissionWaitlistPostgres,
+    },
+};
+
+fn membership(mission_id: i32, brawler_id: i32) -> CrewMemberShips {
+    CrewMemberShips { mission_id, brawler_id, role_slot_id: None }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn racing_joins_do_not_overfill_the_crew() {
+    let pool = require_db!();
+    let crew = Arc::new(common::crew_operation(&pool));
+    let viewing = MissionViewingPostgres::new(Arc::clone(&pool));
+    let chief = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 2);
+
+    let mut joins = Vec::new();
+    for _ in 0..8 {
+        let brawler = common::brawler(&pool).await;
+        let crew = Arc::clone(&crew);
+        joins.push(tokio::spawn(async move { crew.join(mission_id, brawler.id, None).await }));
+    }
+    let mut joined = 0;
+    for join in joins {
+        match join.await.unwrap() {
+            Ok(_) => joined += 1,
+            Err(AppError::Conflict(_)) => {}
+            Err(e) => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    assert_eq!(joined, 2);
+    assert_eq!(viewing.crew_counting(mission_id).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn places_held_for_waitlist_offers_are_not_taken() {
+    let pool = require_db!();
+    let crew_repo = CrewOperationPostgres::new(Arc::clone(&pool));
+    let waitlist = MissionWaitlistPostgres::new(Arc::clone(&pool));
+    let chief = common::brawler(&pool).await;
+    let offered = common::brawler(&pool).await;
+    let outsider = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 1);
+    waitlist
+        .enqueue(AddMissionWaitlistEntity { mission_id, brawler_id: offered.id })
+        .await
+        .unwrap();
+    let now = Utc::now().naive_utc();
+    waitlist
+        .offer_open_places(mission_id, now, now + Duration::minutes(30))
+        .await
+        .unwrap();
+
+    // Even past the use case's own room check, the repository keeps the held place.
+    assert!(matches!(
+        crew_repo.join(membership(mission_id, outsider.id)).await,
+        Err(AppError::Conflict(_))
+    ));
+    crew_repo.join(membership(mission_id, offered.id)).await.unwrap();
+    assert!(matches!(
+        crew_repo.join(membership(mission_id, offered.id)).await,
+        Err(AppError::Conflict(_))
+    ));
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use server::{
+    domain::{
+        entities::mission_invite_links::AddMissionInviteLinkEntity,
+        errors::AppError,
+        repositories::{crew_operation::CrewOperationRepository, mission_invite_links::MissionInviteLinkRepository},
+        value_objects::invite_link_model::InviteLinkModel,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{crew_operation::CrewOperationPostgres, mission_invite_links::MissionInviteLinkPostgres},
+        },
+        secure_token,
+    },
+};
+
+async fn link(
+    links: &MissionInviteLinkPostgres,
+    pool: &Arc<PgPoolSquad>,
+    max_uses: Option<i32>,
+    expires_at: Option<NaiveDateTime>,
+) -> InviteLinkModel {
+    let chief = common::brawler(pool).await;
+    let mission_id = common::mission(pool, chief.id, "Open", 10);
+    links
+        .create(AddMissionInviteLinkEntity {
+            mission_id,
+            token_hash: common::unique("token"),
+            created_by: Some(chief.id),
+            role_slot_id: None,
+            max_uses,
+            expires_at,
+        })
+        .await
+        .unwrap()
+}
+
+async fn use_count(links: &MissionInviteLinkPostgres, link: &InviteLinkModel) -> i32 {
+    let all = links.find_by_mission(link.mission_id).await.unwrap();
+    all.iter().find(|other| other.id == link.id).unwrap().use_count
+}
+
+#[tokio::test]
+async fn uses_are_counted_once_per_brawler_and_can_be_released() {
+    let pool = require_db!();
+    let links = MissionInviteLinkPostgres::new(Arc::clone(&pool));
+    let link = link(&links, &pool, Some(2), None).await;
+    let first = common::brawler(&pool).await;
+    let second = common::brawler(&pool).await;
+    let third = common::brawler(&pool).await;
+    let now = Utc::now().naive_utc();
+
+    assert!(links.record_use(link.id, first.id, now).await.unwrap());
+    assert!(!links.record_use(link.id, first.id, now).await.unwrap());
+    assert_eq!(use_count(&links, &link).await, 1);
+
+    assert!(links.record_use(link.id, second.id, now).await.unwrap());
+    assert!(matches!(links.record_use(link.id, third.id, now).await, Err(AppError::Conflict(_))));
+
+    // A join that fell through gives its use back.
+    links.release_use(link.id, second.id).await.unwrap();
+    links.release_use(link.id, second.id).await.unwrap();
+    assert_eq!(use_count(&links, &link).await, 1);
+    assert!(links.record_use(link.id, third.id, now).await.unwrap());
+    assert_eq!(links.find_uses(link.id).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn expired_and_revoked_links_cannot_be_used() {
+    let pool = require_db!();
+    let links = MissionInviteLinkPostgres::new(Arc::clone(&pool));
+    let now = Utc::now().naive_utc();
+    let brawler = common::brawler(&pool).await;
+
+    let expired = link(&links, &pool, None, Some(now - Duration::minutes(1))).await;
+    assert!(matches!(links.record_use(expired.id, brawler.id, now).await, Err(AppError::Conflict(_))));
+
+    let revoked = link(&links, &pool, None, None).await;
+    links.revoke(revoked.id, revoked.mission_id, now).await.unwrap();
+    assert!(matches!(links.record_use(revoked.id, brawler.id, now).await, Err(AppError::Conflict(_))));
+
+    assert!(matches!(links.record_use(-1, brawler.id, now).await, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn racing_brawlers_cannot_exceed_the_use_limit() {
+    let pool = require_db!();
+    let links = Arc::new(MissionInviteLinkPostgres::new(Arc::clone(&pool)));
+    let link = link(&links, &pool, Some(3), None).await;
+    let now = Utc::now().naive_utc();
+
+    let mut attempts = Vec::new();
+    for _ in 0..8 {
+        let brawler = common::brawler(&pool).await;
+        let links = Arc::clone(&links);
+        attempts.push(tokio::spawn(async move { links.record_use(link.id, brawler.id, now).await }));
+    }
+    let mut counted = 0;
+    for attempt in attempts {
+        match attempt.await.unwrap() {
+            Ok(true) => counted += 1,
+            Ok(false) => panic!("every brawler is new to the link"),
+            Err(AppError::Conflict(_)) => {}
+            Err(e) => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    assert_eq!(counted, 3);
+    assert_eq!(use_count(&links, &link).await, 3);
+}
+
+#[tokio::test]
+async fn link_joins_fall_back_to_another_free_role_slot() {
+    let pool = require_db!();
+    let links = MissionInviteLinkPostgres::new(Arc::clone(&pool));
+    let crew_repo = CrewOperationPostgres::new(Arc::clone(&pool));
+    let invites = common::mission_invites(&pool);
+    let chief = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+    crew_repo
+        .set_role_slots(mission_id, vec!["Top".to_string(), "Mid".to_string()])
+        .await
+        .unwrap();
+    let slots = crew_repo.role_slots(mission_id).await.unwrap();
+    let token = common::unique("token");
+    links
+        .create(AddMissionInviteLinkEntity {
+            mission_id,
+            token_hash: secure_token::hash(&token),
+            created_by: Some(chief.id),
+            role_slot_id: Some(slots[0].id),
+            max_uses: None,
+            expires_at: None,
+        })
+        .await
+        .unwrap();
+
+    let mut joiners = Vec::new();
+    for _ in 0..3 {
+        let brawler = common::brawler(&pool).await;
+        invites.accept_link(&token, brawler.id).await.unwrap();
+        joiners.push(brawler.id);
+    }
+
+    // The link's slot, then the other free one; only a full lineup leaves a joiner without a role.
+    let holders: Vec<Option<i32>> = crew_repo
+        .role_slots(mission_id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|slot| slot.brawler_id)
+        .collect();
+    assert_eq!(holders, vec![Some(joiners[0]), Some(joiners[1])]);
+    assert!(crew_repo.is_member(mission_id, joiners[2]).await.unwrap());
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use std::sync::Arc;
+
+use server::{
+    application::use_cases::authentication::AuthenticationUseCase,
+    config::{config_loader::get_login_guard_env, config_model::JwtEnv},
+    domain::{
+        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        errors::AppError,
+        repositories::BrawlerRepository,
+    },
+    infrastructure::{
+        argon2,
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{brawlers::BrawlerPostgres, login_attempts::LoginAttemptPostgres},
+        },
+        jwt::{JwtKeys, authentication_model::LoginModel},
+    },
+};
+
+const PASSWORD: &str = "correct horse battery staple";
+
+type Authentication = AuthenticationUseCase<BrawlerPostgres, LoginAttemptPostgres>;
+
+fn authentication(pool: &Arc<PgPoolSquad>) -> Arc<Authentication> {
+    let jwt_keys = Arc::new(JwtKeys::from_env(&JwtEnv {
+        secret: "lockout-test-secret".to_string(),
+        ttl: 1,
+        kid: "test".to_string(),
+        previous_secrets: Vec::new(),
+    }));
+    Arc::new(AuthenticationUseCase::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(pool))),
+        Arc::new(LoginAttemptPostgres::new(Arc::clone(pool))),
+        jwt_keys,
+    ))
+}
+
+async fn brawler_with_password(pool: &Arc<PgPoolSquad>) -> BrawlerEntity {
+    let username = common::unique("login");
+    BrawlerPostgres::new(Arc::clone(pool))
+        .register(RegisterBrawlerEntity {
+            display_name: username.clone(),
+            username,
+            password: argon2::hash(PASSWORD.to_string()).unwrap(),
+        })
+        .await
+        .unwrap()
+}
+
+fn login(username: &str, password: &str) -> LoginModel {
+    LoginModel {
+        username: username.to_string(),
+        password: password.to_string(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn parallel_guesses_stop_at_the_attempt_limit() {
+    let pool = require_db!();
+    let authentication = authentication(&pool);
+    let brawler = brawler_with_password(&pool).await;
+    let max_attempts = get_login_guard_env().max_attempts_per_user;
+
+    let guesses = (0..max_attempts + 6)
+        .map(|i| {
+            let authentication = Arc::clone(&authentication);
+            let model = login(&brawler.username, &format!("guess-{i}"));
+            tokio::spawn(async move { authentication.login(model, None).await })
+        })
+        .collect::<Vec<_>>();
+
+    let mut checked = 0;
+    for guess in guesses {
+        match guess.await.unwrap() {
+            Err(AppError::Unauthorized(_)) => checked += 1,
+            Err(AppError::TooManyRequests(_)) => {}
+            other => panic!("unexpected login result: {:?}", other.map(|_| ())),
+        }
+    }
+    assert_eq!(checked, max_attempts);
+
+    let locked = authentication.login(login(&brawler.username, PASSWORD), None).await;
+    assert!(matches!(locked, Err(AppError::TooManyRequests(_))));
+}
+
+#[tokio::test]
+async fn disabled_accounts_do_not_reset_the_lockout() {
+    let pool = require_db!();
+    let authentication = authentication(&pool);
+    let brawler = brawler_with_password(&pool).await;
+    let max_attempts = get_login_guard_env().max_attempts_per_user;
+
+    for _ in 1..max_attempts {
+        let result = authentication.login(login(&brawler.username, "wrong"), None).await;
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+
+    BrawlerPostgres::new(Arc::clone(&pool))
+        .set_disabled(brawler.id, true)
+        .await
+        .unwrap();
+    let disabled = authentication.login(login(&brawler.username, PASSWORD), None).await;
+    assert!(matches!(disabled, Err(AppError::Forbidden(_))));
+
+    // The right password on a disabled account still counts against the streak.
+    let locked = authentication.login(login(&brawler.username, "wrong"), None).await;
+    assert!(matches!(locked, Err(AppError::TooManyRequests(_))));
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use server::domain::{
+    errors::AppError,
+    value_objects::mission_role_model::SetRoleSlotsModel,
+};
+
+#[tokio::test]
+async fn accepting_an_invite_leaves_places_held_for_the_waitlist() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let invites = common::mission_invites(&pool);
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let waiting = common::brawler(&pool).await;
+    let invited = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 1);
+
+    let invite = invites.invite(mission_id, chief.id, invited.id).await.unwrap();
+    crew.join(mission_id, member.id, None).await.unwrap();
+    crew.join_waitlist(mission_id, waiting.id).await.unwrap();
+    // The freed place is offered to the waitlist and held while the offer runs.
+    crew.leave(mission_id, member.id).await.unwrap();
+
+    assert!(matches!(invites.accept(invite.id, invited.id, None).await, Err(AppError::Conflict(_))));
+    crew.confirm_waitlist_offer(mission_id, waiting.id, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn accepting_an_invite_takes_a_free_role_slot() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let invites = common::mission_invites(&pool);
+    let chief = common::brawler(&pool).await;
+    let invited = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+    let slots = crew
+        .set_role_slots(
+            mission_id,
+            chief.id,
+            SetRoleSlotsModel { labels: vec!["Tank".to_string(), "Healer".to_string()], preset: None },
+        )
+        .await
+        .unwrap();
+
+    let invite = invites.invite(mission_id, chief.id, invited.id).await.unwrap();
+    assert!(matches!(invites.accept(invite.id, invited.id, None).await, Err(AppError::Validation(_))));
+    invites.accept(invite.id, invited.id, Some(slots[1].id)).await.unwrap();
+
+    let slots = crew.get_role_slots(mission_id, chief.id).await.unwrap();
+    assert_eq!(slots[1].brawler_id, Some(invited.id));
+    assert_eq!(slots[0].brawler_id, None);
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use std::{collections::HashSet, sync::Arc};
+
+use server::{
+    application::{
+        services::mission_realtime::MissionRealtimeService,
+        use_cases::mission_operation::MissionOperationUseCase,
+    },
+    config::config_loader::get_validation_env,
+    domain::{
+        entities::notification::{Notification, NotificationType},
+        repositories::mission_viewing::MissionViewingRepository,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                achievements::AchievementRepositoryImpl, brawlers::BrawlerPostgres,
+                mission_messages::MissionMessagePostgres, mission_operation::MissionOperationPostgres,
+                mission_viewing::MissionViewingPostgres,
+            },
+        },
+        services::notification_service::NotificationServiceImpl,
+    },
+};
+use tokio::sync::broadcast;
+
+type MissionOperation = MissionOperationUseCase<
+    MissionOperationPostgres,
+    MissionViewingPostgres,
+    AchievementRepositoryImpl,
+    BrawlerPostgres,
+    MissionMessagePostgres,
+>;
+
+fn mission_operation(pool: &Arc<PgPoolSquad>) -> (MissionOperation, broadcast::Receiver<Notification>) {
+    let (tx, rx) = broadcast::channel(1024);
+    let use_case = MissionOperationUseCase::new(
+        Arc::new(MissionOperationPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(pool))),
+        Arc::new(AchievementRepositoryImpl::new(Arc::clone(pool))),
+        Arc::new(BrawlerPostgres::new(Arc::clone(pool))),
+        Arc::new(MissionMessagePostgres::new(Arc::clone(pool))),
+        Arc::new(NotificationServiceImpl::new(tx)),
+        Arc::new(MissionRealtimeService::new()),
+    );
+    (use_case, rx)
+}
+
+async fn status(pool: &Arc<PgPoolSquad>, mission_id: i32, chief_id: i32) -> String {
+    MissionViewingPostgres::new(Arc::clone(pool))
+        .get_one(mission_id, chief_id)
+        .await
+        .unwrap()
+        .status
+}
+
+fn drain(rx: &mut broadcast::Receiver<Notification>) -> Vec<Notification> {
+    std::iter::from_fn(|| rx.try_recv().ok()).collect()
+}
+
+/// Recipients of the start reminders about `mission_id` among `sent`.
+fn reminded(sent: &[Notification], mission_id: i32) -> HashSet<i32> {
+    sent.iter()
+        .filter(|notification| matches!(notification.notification_type, NotificationType::MissionReminder))
+        .filter(|notification| notification.metadata["mission_id"] == mission_id)
+        .filter_map(|notification| notification.recipient_id)
+        .collect()
+}
+
+#[tokio::test]
+async fn due_missions_start_once_they_have_crew() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let (operation, _rx) = mission_operation(&pool);
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let crewed = common::mission(&pool, chief.id, "Open", 5);
+    let empty = common::mission(&pool, chief.id, "Open", 5);
+    let later = common::mission(&pool, chief.id, "Open", 5);
+    common::execute(
+        &pool,
+        &format!(
+            "UPDATE missions SET scheduled_start_at = now() - interval '1 minute' WHERE id IN ({crewed}, {empty});
+             UPDATE missions SET scheduled_start_at = now() + interval '1 day' WHERE id = {later};"
+        ),
+    );
+    crew.join(crewed, member.id, None).await.unwrap();
+    crew.join(later, member.id, None).await.unwrap();
+
+    operation.start_due_missions().await.unwrap();
+
+    assert_eq!(status(&pool, crewed, chief.id).await, "InProgress");
+    assert_eq!(status(&pool, empty, chief.id).await, "Open");
+    assert_eq!(status(&pool, later, chief.id).await, "Open");
+
+    // Each mission is only tried once, so crew arriving late does not start it.
+    crew.join(empty, member.id, None).await.unwrap();
+    operation.start_due_missions().await.unwrap();
+    assert_eq!(status(&pool, empty, chief.id).await, "Open");
+}
+
+#[tokio::test]
+async fn overdue_missions_fail() {
+    let pool = require_db!();
+    let (operation, _rx) = mission_operation(&pool);
+    let chief = common::brawler(&pool).await;
+    let overdue = common::mission(&pool, chief.id, "InProgress", 5);
+    let running = common::mission(&pool, chief.id, "InProgress", 5);
+    let open = common::mission(&pool, chief.id, "Open", 5);
+    common::execute(
+        &pool,
+        &format!(
+            "UPDATE missions SET deadline_at = now() - interval '1 minute' WHERE id IN ({overdue}, {open});
+             UPDATE missions SET deadline_at = now() + interval '1 day' WHERE id = {running};"
+        ),
+    );
+
+    operation.fail_overdue_missions().await.unwrap();
+
+    assert_eq!(status(&pool, overdue, chief.id).await, "Failed");
+    assert_eq!(status(&pool, running, chief.id).await, "InProgress");
+    assert_eq!(status(&pool, open, chief.id).await, "Open");
+}
+
+#[tokio::test]
+async fn start_reminders_reach_chief_and_crew_once() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let (operation, mut rx) = mission_operation(&pool);
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let soon = common::mission(&pool, chief.id, "Open", 5);
+    let later = common::mission(&pool, chief.id, "Open", 5);
+    common::execute(
+        &pool,
+        &format!(
+            "UPDATE missions SET scheduled_start_at = now() + interval '5 minutes' WHERE id = {soon};
+             UPDATE missions SET scheduled_start_at = now() + interval '1 day' WHERE id = {later};"
+        ),
+    );
+    crew.join(soon, member.id, None).await.unwrap();
+    crew.join(later, member.id, None).await.unwrap();
+
+    operation.send_start_reminders().await.unwrap();
+    let sent = drain(&mut rx);
+    assert_eq!(reminded(&sent, soon), HashSet::from([chief.id, member.id]));
+    assert!(reminded(&sent, later).is_empty());
+
+    operation.send_start_reminders().await.unwrap();
+    assert!(reminded(&drain(&mut rx), soon).is_empty());
+}
+
+#[tokio::test]
+async fn missions_filled_to_the_crew_limit_can_start() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let (operation, _rx) = mission_operation(&pool);
+    let chief = common::brawler(&pool).await;
+    let max_crew = get_validation_env().max_crew;
+    let by_hand = common::mission(&pool, chief.id, "Open", max_crew);
+    let scheduled = common::mission(&pool, chief.id, "Open", max_crew);
+    for _ in 0..max_crew {
+        let member = common::brawler(&pool).await;
+        crew.join(by_hand, member.id, None).await.unwrap();
+        crew.join(scheduled, member.id, None).await.unwrap();
+    }
+    common::execute(
+        &pool,
+        &format!("UPDATE missions SET scheduled_start_at = now() - interval '1 minute' WHERE id = {scheduled}"),
+    );
+
+    operation.in_progress(by_hand, chief.id).await.unwrap();
+    operation.start_due_missions().await.unwrap();
+
+    assert_eq!(status(&pool, by_hand, chief.id).await, "InProgress");
+    assert_eq!(status(&pool, scheduled, chief.id).await, "InProgress");
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use axum::Extension;
+use futures::{SinkExt, StreamExt};
+use server::{
+    application::services::mission_realtime::MissionRealtimeService,
+    config::{
+        config_loader::{get_auth_cookie_env, get_validation_env},
+        config_model::JwtEnv,
+    },
+    domain::value_objects::ban_model::BanModel,
+    infrastructure::{
+        database::repositories::{brawlers::BrawlerPostgres, login_attempts::LoginAttemptPostgres},
+        http::{middlewares::auth::SessionAuthenticator, routers::mission_ws},
+        jwt::{JwtKeys, jwt_model::Passport},
+    },
+};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    MaybeTlsStream, WebSocketStream, connect_async,
+    tungstenite::{self, Message, client::IntoClientRequest},
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+async fn connect(addr: &str, mission_id: i32, token: &str) -> Result<Socket, tungstenite::Error> {
+    let mut request = format!("ws://{addr}/{mission_id}").into_client_request().unwrap();
+    request
+        .headers_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    connect_async(request).await.map(|(socket, _)| socket)
+}
+
+/// Reads frames until the server drops the socket; panics if it stays open.
+async fn until_closed(socket: &mut Socket) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while let Some(Ok(frame)) = socket.next().await {
+            if let Message::Close(_) = frame {
+                break;
+            }
+        }
+    })
+    .await
+    .expect("socket was not closed");
+}
+
+async fn next_text(socket: &mut Socket) -> String {
+    loop {
+        let frame = tokio::time::timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("no frame arrived")
+            .expect("socket closed")
+            .unwrap();
+        if let Message::Text(text) = frame {
+            return text.to_string();
+        }
+    }
+}
+
+#[tokio::test]
+async fn kicked_and_banned_member_is_cut_off_from_the_live_chat() {
+    let pool = require_db!();
+    let realtime = Arc::new(MissionRealtimeService::new());
+    let crew = common::crew_operation_with(&pool, Arc::clone(&realtime));
+    let jwt_keys = Arc::new(JwtKeys::from_env(&JwtEnv {
+        secret: "mission-ws-test-secret".to_string(),
+        ttl: 1,
+        kid: "test".to_string(),
+        previous_secrets: Vec::new(),
+    }));
+    let sessions: Arc<SessionAuthenticator> = Arc::new(SessionAuthenticator::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(&pool))),
+        Arc::new(LoginAttemptPostgres::new(Arc::clone(&pool))),
+        Arc::clone(&jwt_keys),
+    ));
+    let app = mission_ws::routes(Arc::clone(&pool))
+        .layer(Extension(Arc::clone(&realtime)))
+        .layer(Extension(get_validation_env()))
+        .layer(Extension(get_auth_cookie_env()))
+        .layer(Extension(Arc::clone(&jwt_keys)))
+        .layer(Extension(sessions));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let bystander = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+    crew.join(mission_id, member.id, None).await.unwrap();
+    crew.join(mission_id, bystander.id, None).await.unwrap();
+    let member_token = Passport::new(&jwt_keys, member.clone()).unwrap().token;
+    let bystander_token = Passport::new(&jwt_keys, bystander).unwrap().token;
+
+    let mut member_socket = connect(&addr, mission_id, &member_token).await.unwrap();
+    let mut bystander_socket = connect(&addr, mission_id, &bystander_token).await.unwrap();
+    member_socket.send(Message::Text("still here".into())).await.unwrap();
+    assert!(next_text(&mut bystander_socket).await.contains("still here"));
+
+    crew.kick_crew(mission_id, chief.id, member.id, Some(BanModel::default())).await.unwrap();
+
+    until_closed(&mut member_socket).await;
+    assert!(next_text(&mut bystander_socket).await.contains("was kicked"));
+    match connect(&addr, mission_id, &member_token).await {
+        Err(tungstenite::Error::Http(response)) => assert_eq!(response.status(), 403),
+        other => panic!("banned member reconnected: {:?}", other.map(|_| ())),
+    }
+}
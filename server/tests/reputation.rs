@@ -0,0 +1,107 @@
+mod common;
+
+use std::sync::Arc;
+
+use server::{
+    application::use_cases::mission_ratings::MissionRatingUseCase,
+    domain::{
+        entities::mission_ratings::AddMissionRatingEntity,
+        errors::AppError,
+        repositories::mission_ratings::MissionRatingRepository,
+        value_objects::rating_model::{
+            RateBrawlerModel, RatingTag, REPUTATION_PRIOR_SCORE, REPUTATION_PRIOR_WEIGHT,
+        },
+    },
+    infrastructure::database::repositories::{
+        mission_ratings::MissionRatingPostgres, mission_viewing::MissionViewingPostgres,
+    },
+};
+
+fn rating(mission_id: i32, rater_id: i32, ratee_id: i32, score: i32, tags: &[&str]) -> AddMissionRatingEntity {
+    AddMissionRatingEntity {
+        mission_id,
+        rater_id,
+        ratee_id,
+        score,
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+    }
+}
+
+/// The Bayesian average the repository keeps on `brawlers.reputation`.
+fn expected(scores: &[i32]) -> f64 {
+    let prior = REPUTATION_PRIOR_WEIGHT as f64;
+    let total: i32 = scores.iter().sum();
+    (f64::from(total) + REPUTATION_PRIOR_SCORE * prior) / (scores.len() as f64 + prior)
+}
+
+#[tokio::test]
+async fn reputation_is_a_bayesian_average_of_ratings() {
+    let pool = require_db!();
+    let ratings = MissionRatingPostgres::new(Arc::clone(&pool));
+    let ratee = common::brawler(&pool).await;
+    let first = common::brawler(&pool).await;
+    let second = common::brawler(&pool).await;
+    let mission = common::mission(&pool, ratee.id, "Completed", 5);
+
+    let fresh = ratings.reputation(ratee.id).await.unwrap();
+    assert_eq!(fresh.rating_count, 0);
+    assert!((fresh.reputation - REPUTATION_PRIOR_SCORE).abs() < 1e-9);
+
+    // A single top score only nudges the reputation away from the prior.
+    ratings.rate(rating(mission, first.id, ratee.id, 5, &["shotcaller"])).await.unwrap();
+    let one = ratings.reputation(ratee.id).await.unwrap();
+    assert_eq!(one.rating_count, 1);
+    assert!((one.reputation - expected(&[5])).abs() < 1e-9);
+    assert!(one.reputation < 4.0);
+
+    ratings.rate(rating(mission, second.id, ratee.id, 1, &["no-show"])).await.unwrap();
+    let two = ratings.reputation(ratee.id).await.unwrap();
+    assert_eq!(two.rating_count, 2);
+    assert!((two.reputation - expected(&[5, 1])).abs() < 1e-9);
+
+    // Rating the same brawler again replaces the earlier score instead of adding one.
+    ratings.rate(rating(mission, second.id, ratee.id, 4, &["shotcaller"])).await.unwrap();
+    let revised = ratings.reputation(ratee.id).await.unwrap();
+    assert_eq!(revised.rating_count, 2);
+    assert!((revised.reputation - expected(&[5, 4])).abs() < 1e-9);
+    assert_eq!(revised.tags.len(), 1);
+    assert_eq!(revised.tags[0].tag, "shotcaller");
+    assert_eq!(revised.tags[0].count, 2);
+}
+
+#[tokio::test]
+async fn only_the_crew_a_mission_finished_with_can_rate() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let ratings = MissionRatingUseCase::new(
+        Arc::new(MissionRatingPostgres::new(Arc::clone(&pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&pool))),
+    );
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let latecomer = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 5);
+    crew.join(mission_id, member.id, None).await.unwrap();
+    common::execute(
+        &pool,
+        &format!("UPDATE missions SET status = 'Failed', finished_at = now() WHERE id = {mission_id}"),
+    );
+    // Failed missions stay joinable, but joining one now gives no say in how it went.
+    crew.join(mission_id, latecomer.id, None).await.unwrap();
+
+    let toxic = || RateBrawlerModel { score: 1, tags: vec![RatingTag::Toxic] };
+    assert!(matches!(
+        ratings.rate(mission_id, latecomer.id, member.id, toxic()).await,
+        Err(AppError::Forbidden(_))
+    ));
+    assert!(matches!(ratings.get_sheet(mission_id, latecomer.id).await, Err(AppError::Forbidden(_))));
+    assert!(matches!(
+        ratings.rate(mission_id, member.id, latecomer.id, toxic()).await,
+        Err(AppError::NotFound(_))
+    ));
+
+    let sheet = ratings.get_sheet(mission_id, member.id).await.unwrap();
+    let peers: Vec<i32> = sheet.peers.iter().map(|peer| peer.brawler_id).collect();
+    assert_eq!(peers, vec![chief.id]);
+    ratings.rate(mission_id, chief.id, member.id, toxic()).await.unwrap();
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use std::sync::Arc;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{Request, StatusCode, header::AUTHORIZATION},
+    response::IntoResponse,
+};
+use server::{
+    config::config_model::JwtEnv,
+    domain::{repositories::BrawlerRepository, value_objects::roles::Roles},
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{brawlers::BrawlerPostgres, login_attempts::LoginAttemptPostgres},
+        },
+        http::{extractors::CurrentBrawler, middlewares::auth::SessionAuthenticator},
+        jwt::{JwtKeys, jwt_model::Passport},
+    },
+};
+
+struct Sessions {
+    jwt_keys: Arc<JwtKeys>,
+    authenticator: Arc<SessionAuthenticator>,
+}
+
+impl Sessions {
+    fn new(brawlers: Arc<BrawlerPostgres>, pool: &Arc<PgPoolSquad>) -> Self {
+        let jwt_keys = Arc::new(JwtKeys::from_env(&JwtEnv {
+            secret: "session-test-secret".to_string(),
+            ttl: 1,
+            kid: "test".to_string(),
+            previous_secrets: Vec::new(),
+        }));
+        let authenticator = Arc::new(SessionAuthenticator::new(
+            brawlers,
+            Arc::new(LoginAttemptPostgres::new(Arc::clone(pool))),
+            Arc::clone(&jwt_keys),
+        ));
+        Self { jwt_keys, authenticator }
+    }
+
+    async fn current(&self, token: &str) -> Result<CurrentBrawler, StatusCode> {
+        let (mut parts, _) = Request::builder()
+            .header(AUTHORIZATION, format!("Bearer {token}"))
+            .body(())
+            .unwrap()
+            .into_parts();
+        parts.extensions.insert(Arc::clone(&self.jwt_keys));
+        parts.extensions.insert(Arc::clone(&self.authenticator));
+
+        <CurrentBrawler as FromRequestParts<()>>::from_request_parts(&mut parts, &())
+            .await
+            .map_err(|e| e.into_response().status())
+    }
+}
+
+#[tokio::test]
+async fn disabled_brawlers_existing_token_is_rejected() {
+    let pool = require_db!();
+    let brawlers = Arc::new(BrawlerPostgres::new(Arc::clone(&pool)));
+    let sessions = Sessions::new(Arc::clone(&brawlers), &pool);
+    let brawler = common::brawler(&pool).await;
+    let id = brawler.id;
+    let token = Passport::new(&sessions.jwt_keys, brawler).unwrap().token;
+
+    assert_eq!(sessions.current(&token).await.unwrap().id, id);
+
+    brawlers.set_disabled(id, true).await.unwrap();
+    assert_eq!(sessions.current(&token).await.unwrap_err(), StatusCode::UNAUTHORIZED);
+
+    // Re-enabling does not revive tokens issued before the account was disabled.
+    brawlers.set_disabled(id, false).await.unwrap();
+    assert_eq!(sessions.current(&token).await.unwrap_err(), StatusCode::UNAUTHORIZED);
+
+    let fresh = Passport::new(&sessions.jwt_keys, brawlers.find_by_id(id).await.unwrap()).unwrap();
+    assert!(sessions.current(&fresh.token).await.is_ok());
+}
+
+#[tokio::test]
+async fn role_change_revokes_tokens_and_role_comes_from_the_database() {
+    let pool = require_db!();
+    let brawlers = Arc::new(BrawlerPostgres::new(Arc::clone(&pool)));
+    let sessions = Sessions::new(Arc::clone(&brawlers), &pool);
+    let brawler = common::brawler(&pool).await;
+    let id = brawler.id;
+
+    brawlers.set_role(id, Roles::Admin).await.unwrap();
+    let admin_token = Passport::new(&sessions.jwt_keys, brawlers.find_by_id(id).await.unwrap()).unwrap().token;
+    assert_eq!(sessions.current(&admin_token).await.unwrap().role, Roles::Admin);
+
+    brawlers.set_role(id, Roles::User).await.unwrap();
+    assert_eq!(sessions.current(&admin_token).await.unwrap_err(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn deleted_account_token_is_rejected() {
+    let pool = require_db!();
+    let brawlers = Arc::new(BrawlerPostgres::new(Arc::clone(&pool)));
+    let sessions = Sessions::new(Arc::clone(&brawlers), &pool);
+    let brawler = common::brawler(&pool).await;
+    let id = brawler.id;
+    let token = Passport::new(&sessions.jwt_keys, brawler).unwrap().token;
+
+    brawlers.delete_account(id).await.unwrap();
+    assert_eq!(sessions.current(&token).await.unwrap_err(), StatusCode::UNAUTHORIZED);
+}
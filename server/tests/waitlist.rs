@@ -0,0 +1,128 @@
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use server::{
+    domain::{
+        entities::mission_waitlist::AddMissionWaitlistEntity,
+        errors::AppError,
+        repositories::mission_waitlist::MissionWaitlistRepository,
+        value_objects::waitlist_model::WaitlistStatus,
+    },
+    infrastructure::database::repositories::mission_waitlist::MissionWaitlistPostgres,
+};
+
+async fn enqueue(waitlist: &MissionWaitlistPostgres, mission_id: i32, brawler_id: i32) {
+    waitlist
+        .enqueue(AddMissionWaitlistEntity { mission_id, brawler_id })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn free_places_are_offered_in_queue_order() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let waitlist = MissionWaitlistPostgres::new(Arc::clone(&pool));
+    let chief = common::brawler(&pool).await;
+    let member = common::brawler(&pool).await;
+    let first = common::brawler(&pool).await;
+    let second = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 2);
+    crew.join(mission_id, member.id, None).await.unwrap();
+    enqueue(&waitlist, mission_id, first.id).await;
+    enqueue(&waitlist, mission_id, second.id).await;
+
+    assert!(matches!(
+        waitlist.enqueue(AddMissionWaitlistEntity { mission_id, brawler_id: first.id }).await,
+        Err(AppError::Conflict(_))
+    ));
+
+    let now = Utc::now().naive_utc();
+    let expires_at = now + Duration::minutes(30);
+    let offers = waitlist.offer_open_places(mission_id, now, expires_at).await.unwrap();
+    assert_eq!(offers.len(), 1);
+    assert_eq!(offers[0].brawler_id, first.id);
+    assert_eq!(offers[0].status, WaitlistStatus::Offered.to_string());
+
+    // The open offer holds the only place, so nothing more is offered.
+    assert!(waitlist.offer_open_places(mission_id, now, expires_at).await.unwrap().is_empty());
+    assert_eq!(waitlist.count_held_places(mission_id, second.id, now).await.unwrap(), 1);
+    assert_eq!(waitlist.count_held_places(mission_id, first.id, now).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn lapsed_offers_expire_and_pass_to_the_next_in_line() {
+    let pool = require_db!();
+    let waitlist = MissionWaitlistPostgres::new(Arc::clone(&pool));
+    let chief = common::brawler(&pool).await;
+    let first = common::brawler(&pool).await;
+    let second = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 1);
+    enqueue(&waitlist, mission_id, first.id).await;
+    enqueue(&waitlist, mission_id, second.id).await;
+
+    // Offer with a window that has already run out.
+    let now = Utc::now().naive_utc();
+    let offers = waitlist
+        .offer_open_places(mission_id, now - Duration::minutes(2), now - Duration::minutes(1))
+        .await
+        .unwrap();
+    assert_eq!(offers[0].brawler_id, first.id);
+    assert_eq!(waitlist.count_held_places(mission_id, second.id, now).await.unwrap(), 0);
+
+    assert!(waitlist.expire_offers(now).await.unwrap().contains(&mission_id));
+    assert!(waitlist.find_active(mission_id, first.id).await.unwrap().is_none());
+
+    let offers = waitlist
+        .offer_open_places(mission_id, now, now + Duration::minutes(30))
+        .await
+        .unwrap();
+    assert_eq!(offers.len(), 1);
+    assert_eq!(offers[0].brawler_id, second.id);
+    assert_eq!(offers[0].position, 1);
+}
+
+#[tokio::test]
+async fn brawlers_already_in_the_crew_leave_the_queue() {
+    let pool = require_db!();
+    let crew = common::crew_operation(&pool);
+    let waitlist = MissionWaitlistPostgres::new(Arc::clone(&pool));
+    let chief = common::brawler(&pool).await;
+    let waiting = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 3);
+    enqueue(&waitlist, mission_id, waiting.id).await;
+    crew.join(mission_id, waiting.id, None).await.unwrap();
+
+    let now = Utc::now().naive_utc();
+    assert!(waitlist.offer_open_places(mission_id, now, now + Duration::minutes(30)).await.unwrap().is_empty());
+    assert!(waitlist.find_active(mission_id, waiting.id).await.unwrap().is_none());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_promotions_do_not_oversell_a_place() {
+    let pool = require_db!();
+    let waitlist = Arc::new(MissionWaitlistPostgres::new(Arc::clone(&pool)));
+    let chief = common::brawler(&pool).await;
+    let mission_id = common::mission(&pool, chief.id, "Open", 1);
+    for _ in 0..4 {
+        let waiting = common::brawler(&pool).await;
+        enqueue(&waitlist, mission_id, waiting.id).await;
+    }
+
+    let now = Utc::now().naive_utc();
+    let promotions = (0..4).map(|_| {
+        let waitlist = Arc::clone(&waitlist);
+        tokio::spawn(async move { waitlist.offer_open_places(mission_id, now, now + Duration::minutes(30)).await })
+    });
+    let mut offered = 0;
+    for promotion in promotions.collect::<Vec<_>>() {
+        offered += promotion.await.unwrap().unwrap().len();
+    }
+
+    assert_eq!(offered, 1);
+    let queue = waitlist.find_by_mission(mission_id).await.unwrap();
+    let offers = queue.iter().filter(|entry| entry.status == WaitlistStatus::Offered.to_string());
+    assert_eq!(offers.count(), 1);
+}